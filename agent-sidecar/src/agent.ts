@@ -223,6 +223,12 @@ export async function runAgentQuery(
         }
 
         case "result": {
+          if ("usage" in msg && msg.usage) {
+            sendEvent("usage", {
+              input_tokens: msg.usage.input_tokens ?? 0,
+              output_tokens: msg.usage.output_tokens ?? 0,
+            });
+          }
           if (msg.subtype === "success") {
             if (!streamed && msg.result) {
               sendEvent("token", msg.result);
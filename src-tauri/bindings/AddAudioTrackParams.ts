@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type AddAudioTrackParams = { 
+/**
+ * File name in the setup's media directory.
+ */
+path: string, 
+/**
+ * Linear playback gain. Defaults to 1.0.
+ */
+gain: number, muted: boolean, 
+/**
+ * Make this the primary file that analysis runs on. The first audio
+ * added to a sequence is primary either way.
+ */
+primary: boolean, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type AddCueParams = { name: string, 
+/**
+ * Start time in seconds.
+ */
+time: number, 
+/**
+ * End time in seconds, for a cue covering a range.
+ */
+end: number | null, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { EasingFunction } from "./EasingFunction";
+
+export type AddCurvePresetParams = { kind: EasingFunction, 
+/**
+ * Library name for the curve. Defaults to the preset's name, e.g. "Ease In Out Cubic".
+ */
+name: string | null, };
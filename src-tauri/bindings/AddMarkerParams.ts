@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Color } from "./Color";
+
+export type AddMarkerParams = { 
+/**
+ * Time in seconds.
+ */
+time: number, label: string, 
+/**
+ * Defaults to amber.
+ */
+color: Color | null, };
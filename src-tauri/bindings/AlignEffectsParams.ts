@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AlignMode } from "./AlignMode";
+import type { EffectLocation } from "./EffectLocation";
+
+export type AlignEffectsParams = { 
+/**
+ * Effects to line up; at least two.
+ */
+targets: Array<EffectLocation>, mode: AlignMode, };
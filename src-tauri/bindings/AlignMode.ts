@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * How `align_effects` lines effects up. Every effect keeps its duration.
+ */
+export type AlignMode = "AlignStart" | "AlignEnd" | "DistributeEvenly";
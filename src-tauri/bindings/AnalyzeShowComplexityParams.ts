@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type AnalyzeShowComplexityParams = { 
+/**
+ * Flag tracks holding more effects than this. Defaults to 500.
+ */
+max_effects: number, 
+/**
+ * Flag tracks where more effects than this play at once. Defaults to 8.
+ */
+max_overlap: number, };
@@ -4,4 +4,4 @@
  * Structured error type for the application. Replaces stringly-typed errors
  * so the frontend can match on error codes and display appropriate UI.
  */
-export type AppError = { "code": "NotFound", "detail": { what: string, } } | { "code": "InvalidIndex", "detail": { what: string, index: number, } } | { "code": "ValidationError", "detail": { message: string, } } | { "code": "IoError", "detail": { message: string, } } | { "code": "NoSetup" } | { "code": "NoSequence" } | { "code": "NoSettings" } | { "code": "ApiError", "detail": { message: string, } } | { "code": "ImportError", "detail": { message: string, } } | { "code": "SettingsSaveError", "detail": { message: string, } } | { "code": "PythonNotReady" } | { "code": "PythonError", "detail": { message: string, } } | { "code": "AnalysisError", "detail": { message: string, } } | { "code": "ModelNotInstalled", "detail": { model: string, } } | { "code": "AgentError", "detail": { message: string, } } | { "code": "Cancelled", "detail": { operation: string, } };
+export type AppError = { "code": "NotFound", "detail": { what: string, } } | { "code": "InvalidIndex", "detail": { what: string, index: number, } } | { "code": "TrackLocked", "detail": { index: number, name: string, } } | { "code": "ValidationError", "detail": { message: string, } } | { "code": "InvalidTimeRange", "detail": { start: number, end: number, reason: string, } } | { "code": "InvalidParam", "detail": { key: string, reason: string, } } | { "code": "IoError", "detail": { message: string, } } | { "code": "NoSetup" } | { "code": "NoSequence" } | { "code": "NoSettings" } | { "code": "ApiError", "detail": { message: string, } } | { "code": "ImportError", "detail": { message: string, } } | { "code": "SettingsSaveError", "detail": { message: string, } } | { "code": "PythonNotReady" } | { "code": "PythonError", "detail": { message: string, } } | { "code": "AnalysisError", "detail": { message: string, } } | { "code": "ModelNotInstalled", "detail": { model: string, } } | { "code": "AgentError", "detail": { message: string, } } | { "code": "Cancelled", "detail": { operation: string, } } | { "code": "Busy", "detail": { operation: string, } };
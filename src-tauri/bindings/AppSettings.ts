@@ -1,6 +1,8 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { AnalysisFeatures } from "./AnalysisFeatures";
 import type { LlmProviderConfig } from "./LlmProviderConfig";
+import type { OutputSettings } from "./OutputSettings";
+import type { SequenceDefaults } from "./SequenceDefaults";
 
 /**
  * Application-level settings stored in the OS config directory.
@@ -13,4 +15,9 @@ use_gpu: boolean,
 /**
  * Default features to run when analyzing audio. None = all enabled.
  */
-default_analysis_features: AnalysisFeatures | null, };
+default_analysis_features: AnalysisFeatures | null, output: OutputSettings, 
+/**
+ * How many edits the undo history keeps before evicting the oldest.
+ * Read once at startup; a changed value takes effect on the next launch.
+ */
+max_undo_levels: number, sequence_defaults: SequenceDefaults, };
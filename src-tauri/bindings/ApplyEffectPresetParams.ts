@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BlendMode } from "./BlendMode";
+
+export type ApplyEffectPresetParams = { name: string, track_index: number, start: number, end: number, blend_mode: BlendMode, opacity: number, };
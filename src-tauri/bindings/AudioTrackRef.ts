@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One audio layer of a sequence: the song, a click track, a stem.
+ */
+export type AudioTrackRef = { 
+/**
+ * File name in the setup's media directory.
+ */
+path: string, 
+/**
+ * Linear playback gain; 1.0 plays the file as recorded.
+ */
+gain: number, muted: boolean, };
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Universe } from "./Universe";
+
+export type AutoPatchParams = { 
+/**
+ * First universe to patch into (0-indexed).
+ */
+start_universe: Universe, 
+/**
+ * Channels to use in each universe before wrapping to the next (1-512).
+ */
+channels_per_universe: number, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CommandResponse } from "./CommandResponse";
+
+/**
+ * Outcome of one command in a batch, in request order.
+ */
+export type BatchItemResponse = { ok: boolean, data: CommandResponse | null, error: string | null, };
@@ -1,4 +1,13 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { ChatRole } from "./ChatRole";
+import type { TokenUsage } from "./TokenUsage";
 
-export type ChatHistoryEntry = { role: ChatRole, text: string, };
+export type ChatHistoryEntry = { role: ChatRole, text: string, 
+/**
+ * Tokens the provider reported for the turn that produced this entry.
+ */
+usage?: TokenUsage | null, 
+/**
+ * Command name for `Tool` entries, whose text is the command's output message.
+ */
+tool?: string | null, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Color correction matrix for a controller's output, so pixel batches that
+ * render the same color differently can be matched to each other.
+ */
+export type ColorCalibration = { 
+/**
+ * Row-major 3×3 matrix: output channel `i` is `matrix[i] · (r, g, b)`.
+ */
+matrix: [[number, number, number], [number, number, number], [number, number, number]], };
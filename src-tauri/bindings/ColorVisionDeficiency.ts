@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A dichromatic color vision deficiency: one cone type is missing.
+ */
+export type ColorVisionDeficiency = "protanopia" | "deuteranopia" | "tritanopia";
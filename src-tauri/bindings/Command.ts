@@ -1,43 +1,93 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AddAudioTrackParams } from "./AddAudioTrackParams";
+import type { AddCueParams } from "./AddCueParams";
+import type { AddCurvePresetParams } from "./AddCurvePresetParams";
 import type { AddEffectParams } from "./AddEffectParams";
+import type { AddMarkerParams } from "./AddMarkerParams";
 import type { AddTrackParams } from "./AddTrackParams";
+import type { AlignEffectsParams } from "./AlignEffectsParams";
 import type { AnalyzeAudioParams } from "./AnalyzeAudioParams";
+import type { AnalyzeShowComplexityParams } from "./AnalyzeShowComplexityParams";
+import type { ApplyEffectPresetParams } from "./ApplyEffectPresetParams";
+import type { AutoPatchParams } from "./AutoPatchParams";
 import type { BatchEditParams } from "./BatchEditParams";
 import type { CancelOperationParams } from "./CancelOperationParams";
 import type { CheckVixenPreviewFileParams } from "./CheckVixenPreviewFileParams";
 import type { CompileScriptPreviewParams } from "./CompileScriptPreviewParams";
 import type { ConversationIdParams } from "./ConversationIdParams";
+import type { CreateSequenceFromTemplateParams } from "./CreateSequenceFromTemplateParams";
 import type { CreateSequenceParams } from "./CreateSequenceParams";
 import type { CreateSetupParams } from "./CreateSetupParams";
+import type { CrossfadeParams } from "./CrossfadeParams";
+import type { DeleteCueParams } from "./DeleteCueParams";
 import type { DeleteEffectsParams } from "./DeleteEffectsParams";
+import type { DeleteMarkerParams } from "./DeleteMarkerParams";
 import type { DeleteTrackParams } from "./DeleteTrackParams";
+import type { ExportAnalysisParams } from "./ExportAnalysisParams";
+import type { ExportConversationParams } from "./ExportConversationParams";
+import type { ExportFramePngParams } from "./ExportFramePngParams";
+import type { ExportLibraryBundleParams } from "./ExportLibraryBundleParams";
+import type { ExportXlightsParams } from "./ExportXlightsParams";
+import type { FindEffectsParams } from "./FindEffectsParams";
+import type { GenerateFromBeatsParams } from "./GenerateFromBeatsParams";
 import type { GetAnalysisDetailParams } from "./GetAnalysisDetailParams";
 import type { GetBeatsInRangeParams } from "./GetBeatsInRangeParams";
+import type { GetChordsParams } from "./GetChordsParams";
 import type { GetEffectDetailParams } from "./GetEffectDetailParams";
 import type { GetFrameFilteredParams } from "./GetFrameFilteredParams";
 import type { GetFrameParams } from "./GetFrameParams";
+import type { GetStemEnergyParams } from "./GetStemEnergyParams";
+import type { GetWaveformParams } from "./GetWaveformParams";
+import type { GoToCueParams } from "./GoToCueParams";
+import type { HashSequenceFramesParams } from "./HashSequenceFramesParams";
 import type { HelpParams } from "./HelpParams";
+import type { IdentifyFixtureParams } from "./IdentifyFixtureParams";
+import type { ImportAnalysisParams } from "./ImportAnalysisParams";
+import type { ImportLibraryBundleParams } from "./ImportLibraryBundleParams";
+import type { ImportLorParams } from "./ImportLorParams";
 import type { ImportMediaParams } from "./ImportMediaParams";
 import type { ImportVixenParams } from "./ImportVixenParams";
 import type { ImportVixenSequenceParams } from "./ImportVixenSequenceParams";
+import type { ImportVixenSequencesParams } from "./ImportVixenSequencesParams";
 import type { ImportVixenSetupParams } from "./ImportVixenSetupParams";
 import type { InitializeDataDirParams } from "./InitializeDataDirParams";
+import type { ListEffectInstancesParams } from "./ListEffectInstancesParams";
+import type { MergeSequenceParams } from "./MergeSequenceParams";
 import type { MoveEffectToTrackParams } from "./MoveEffectToTrackParams";
 import type { NameParams } from "./NameParams";
+import type { NudgeEffectsParams } from "./NudgeEffectsParams";
+import type { OutputSettings } from "./OutputSettings";
 import type { PreviewScriptFrameParams } from "./PreviewScriptFrameParams";
 import type { PreviewScriptParams } from "./PreviewScriptParams";
+import type { ProfileScriptParams } from "./ProfileScriptParams";
+import type { QuantizeEffectsParams } from "./QuantizeEffectsParams";
+import type { QuantizeGradientParams } from "./QuantizeGradientParams";
+import type { RemoveAudioTrackParams } from "./RemoveAudioTrackParams";
 import type { RenameParams } from "./RenameParams";
 import type { RenderEffectThumbnailParams } from "./RenderEffectThumbnailParams";
+import type { ReorderTrackParams } from "./ReorderTrackParams";
+import type { ResetEffectParamsParams } from "./ResetEffectParamsParams";
+import type { SaveEffectPresetParams } from "./SaveEffectPresetParams";
+import type { ScanLorDirectoryParams } from "./ScanLorDirectoryParams";
 import type { ScanVixenDirectoryParams } from "./ScanVixenDirectoryParams";
 import type { SeekParams } from "./SeekParams";
 import type { SendAgentMessageParams } from "./SendAgentMessageParams";
+import type { SequenceDefaults } from "./SequenceDefaults";
+import type { SetEffectFadesParams } from "./SetEffectFadesParams";
 import type { SetGlobalCurveParams } from "./SetGlobalCurveParams";
 import type { SetGlobalGradientParams } from "./SetGlobalGradientParams";
 import type { SetLlmConfigParams } from "./SetLlmConfigParams";
 import type { SetLoopingParams } from "./SetLoopingParams";
 import type { SetRegionParams } from "./SetRegionParams";
+import type { SetTrackBlendParams } from "./SetTrackBlendParams";
+import type { SetTrackLockedParams } from "./SetTrackLockedParams";
+import type { SetTrackMutedParams } from "./SetTrackMutedParams";
+import type { SetTrackSoloParams } from "./SetTrackSoloParams";
+import type { SimulateColorVisionParams } from "./SimulateColorVisionParams";
 import type { SlugParams } from "./SlugParams";
+import type { StartLiveOutputParams } from "./StartLiveOutputParams";
 import type { TickParams } from "./TickParams";
+import type { TransformGradientParams } from "./TransformGradientParams";
 import type { UpdateEffectParamParams } from "./UpdateEffectParamParams";
 import type { UpdateEffectTimeRangeParams } from "./UpdateEffectTimeRangeParams";
 import type { UpdateSequenceSettingsParams } from "./UpdateSequenceSettingsParams";
@@ -52,4 +102,4 @@ import type { WriteScriptParams } from "./WriteScriptParams";
  * through the same executor. Adding a variant causes compiler errors until
  * it's fully handled.
  */
-export type Command = { "command": "AddEffect", "params": AddEffectParams } | { "command": "DeleteEffects", "params": DeleteEffectsParams } | { "command": "UpdateEffectParam", "params": UpdateEffectParamParams } | { "command": "UpdateEffectTimeRange", "params": UpdateEffectTimeRangeParams } | { "command": "AddTrack", "params": AddTrackParams } | { "command": "DeleteTrack", "params": DeleteTrackParams } | { "command": "MoveEffectToTrack", "params": MoveEffectToTrackParams } | { "command": "UpdateSequenceSettings", "params": UpdateSequenceSettingsParams } | { "command": "BatchEdit", "params": BatchEditParams } | { "command": "Seek", "params": SeekParams } | { "command": "SetRegion", "params": SetRegionParams } | { "command": "SetLooping", "params": SetLoopingParams } | { "command": "GetEffectDetail", "params": GetEffectDetailParams } | { "command": "Help", "params": HelpParams } | { "command": "GetBeatsInRange", "params": GetBeatsInRangeParams } | { "command": "GetAnalysisDetail", "params": GetAnalysisDetailParams } | { "command": "SetGlobalGradient", "params": SetGlobalGradientParams } | { "command": "DeleteGlobalGradient", "params": NameParams } | { "command": "RenameGlobalGradient", "params": RenameParams } | { "command": "SetGlobalCurve", "params": SetGlobalCurveParams } | { "command": "DeleteGlobalCurve", "params": NameParams } | { "command": "RenameGlobalCurve", "params": RenameParams } | { "command": "WriteGlobalScript", "params": WriteScriptParams } | { "command": "CompileGlobalScript", "params": WriteScriptParams } | { "command": "GetGlobalScriptSource", "params": NameParams } | { "command": "DeleteGlobalScript", "params": NameParams } | { "command": "CompileScriptPreview", "params": CompileScriptPreviewParams } | { "command": "RenameGlobalScript", "params": RenameParams } | { "command": "GetScriptParams", "params": NameParams } | { "command": "InitializeDataDir", "params": InitializeDataDirParams } | { "command": "SetLlmConfig", "params": SetLlmConfigParams } | { "command": "CreateSetup", "params": CreateSetupParams } | { "command": "OpenSetup", "params": SlugParams } | { "command": "DeleteSetup", "params": SlugParams } | { "command": "UpdateSetupFixtures", "params": UpdateSetupFixturesParams } | { "command": "UpdateSetupOutputs", "params": UpdateSetupOutputsParams } | { "command": "UpdateSetupLayout", "params": UpdateSetupLayoutParams } | { "command": "CreateSequence", "params": CreateSequenceParams } | { "command": "OpenSequence", "params": SlugParams } | { "command": "DeleteSequence", "params": SlugParams } | { "command": "ImportMedia", "params": ImportMediaParams } | { "command": "DeleteMedia", "params": NameParams } | { "command": "ResolveMediaPath", "params": NameParams } | { "command": "SwitchAgentConversation", "params": ConversationIdParams } | { "command": "DeleteAgentConversation", "params": ConversationIdParams } | { "command": "ImportVixen", "params": ImportVixenParams } | { "command": "ImportVixenSetup", "params": ImportVixenSetupParams } | { "command": "ImportVixenSequence", "params": ImportVixenSequenceParams } | { "command": "ScanVixenDirectory", "params": ScanVixenDirectoryParams } | { "command": "CheckVixenPreviewFile", "params": CheckVixenPreviewFileParams } | { "command": "Tick", "params": TickParams } | { "command": "GetFrame", "params": GetFrameParams } | { "command": "GetFrameFiltered", "params": GetFrameFilteredParams } | { "command": "RenderEffectThumbnail", "params": RenderEffectThumbnailParams } | { "command": "PreviewScript", "params": PreviewScriptParams } | { "command": "PreviewScriptFrame", "params": PreviewScriptFrameParams } | { "command": "CancelOperation", "params": CancelOperationParams } | { "command": "Play" } | { "command": "Pause" } | { "command": "Undo" } | { "command": "Redo" } | { "command": "GetPlayback" } | { "command": "GetUndoState" } | { "command": "GetShow" } | { "command": "GetDesignGuide" } | { "command": "ListEffects" } | { "command": "DescribeShow" } | { "command": "GetAnalysisSummary" } | { "command": "GetSections" } | { "command": "GetAnalysis" } | { "command": "ListGlobalGradients" } | { "command": "ListGlobalCurves" } | { "command": "GetDslReference" } | { "command": "ListGlobalScripts" } | { "command": "GetSettings" } | { "command": "GetLlmConfig" } | { "command": "ListSetups" } | { "command": "SaveSetup" } | { "command": "ListSequences" } | { "command": "SaveCurrentSequence" } | { "command": "ListMedia" } | { "command": "GetAgentChatHistory" } | { "command": "ListAgentConversations" } | { "command": "NewAgentConversation" } | { "command": "ExecuteVixenImport", "params": VixenImportConfig } | { "command": "AnalyzeAudio", "params": AnalyzeAudioParams } | { "command": "SendAgentMessage", "params": SendAgentMessageParams } | { "command": "GetPythonStatus" } | { "command": "SetupPythonEnv" } | { "command": "StartPythonSidecar" } | { "command": "StopPythonSidecar" } | { "command": "CancelAgentMessage" } | { "command": "ClearAgentSession" };
+export type Command = { "command": "AddEffect", "params": AddEffectParams } | { "command": "ApplyEffectPreset", "params": ApplyEffectPresetParams } | { "command": "DeleteEffects", "params": DeleteEffectsParams } | { "command": "UpdateEffectParam", "params": UpdateEffectParamParams } | { "command": "ResetEffectParams", "params": ResetEffectParamsParams } | { "command": "UpdateEffectTimeRange", "params": UpdateEffectTimeRangeParams } | { "command": "NudgeEffects", "params": NudgeEffectsParams } | { "command": "QuantizeEffects", "params": QuantizeEffectsParams } | { "command": "AlignEffects", "params": AlignEffectsParams } | { "command": "CrossfadeEffects", "params": CrossfadeParams } | { "command": "SetEffectFades", "params": SetEffectFadesParams } | { "command": "AddTrack", "params": AddTrackParams } | { "command": "DeleteTrack", "params": DeleteTrackParams } | { "command": "ReorderTrack", "params": ReorderTrackParams } | { "command": "SetTrackLocked", "params": SetTrackLockedParams } | { "command": "SetTrackBlend", "params": SetTrackBlendParams } | { "command": "AddMarker", "params": AddMarkerParams } | { "command": "DeleteMarker", "params": DeleteMarkerParams } | { "command": "AddCue", "params": AddCueParams } | { "command": "DeleteCue", "params": DeleteCueParams } | { "command": "AddAudioTrack", "params": AddAudioTrackParams } | { "command": "RemoveAudioTrack", "params": RemoveAudioTrackParams } | { "command": "MoveEffectToTrack", "params": MoveEffectToTrackParams } | { "command": "UpdateSequenceSettings", "params": UpdateSequenceSettingsParams } | { "command": "BatchEdit", "params": BatchEditParams } | { "command": "GenerateFromBeats", "params": GenerateFromBeatsParams } | { "command": "MergeSequence", "params": MergeSequenceParams } | { "command": "Seek", "params": SeekParams } | { "command": "GoToCue", "params": GoToCueParams } | { "command": "SetRegion", "params": SetRegionParams } | { "command": "SetLooping", "params": SetLoopingParams } | { "command": "SetTrackMuted", "params": SetTrackMutedParams } | { "command": "SetTrackSolo", "params": SetTrackSoloParams } | { "command": "GetEffectDetail", "params": GetEffectDetailParams } | { "command": "ListEffectInstances", "params": ListEffectInstancesParams } | { "command": "FindEffects", "params": FindEffectsParams } | { "command": "AnalyzeShowComplexity", "params": AnalyzeShowComplexityParams } | { "command": "Help", "params": HelpParams } | { "command": "GetBeatsInRange", "params": GetBeatsInRangeParams } | { "command": "GetChords", "params": GetChordsParams } | { "command": "GetStemEnergy", "params": GetStemEnergyParams } | { "command": "GetAnalysisDetail", "params": GetAnalysisDetailParams } | { "command": "ExportAnalysis", "params": ExportAnalysisParams } | { "command": "ImportAnalysis", "params": ImportAnalysisParams } | { "command": "SetGlobalGradient", "params": SetGlobalGradientParams } | { "command": "DeleteGlobalGradient", "params": NameParams } | { "command": "RenameGlobalGradient", "params": RenameParams } | { "command": "TransformGradient", "params": TransformGradientParams } | { "command": "QuantizeGradientToPalette", "params": QuantizeGradientParams } | { "command": "SaveEffectPreset", "params": SaveEffectPresetParams } | { "command": "DeleteEffectPreset", "params": NameParams } | { "command": "SetGlobalCurve", "params": SetGlobalCurveParams } | { "command": "DeleteGlobalCurve", "params": NameParams } | { "command": "RenameGlobalCurve", "params": RenameParams } | { "command": "AddCurvePreset", "params": AddCurvePresetParams } | { "command": "FindLibraryUsage", "params": NameParams } | { "command": "ExportLibraryBundle", "params": ExportLibraryBundleParams } | { "command": "ImportLibraryBundle", "params": ImportLibraryBundleParams } | { "command": "WriteGlobalScript", "params": WriteScriptParams } | { "command": "CompileGlobalScript", "params": WriteScriptParams } | { "command": "GetGlobalScriptSource", "params": NameParams } | { "command": "DeleteGlobalScript", "params": NameParams } | { "command": "CompileScriptPreview", "params": CompileScriptPreviewParams } | { "command": "RenameGlobalScript", "params": RenameParams } | { "command": "GetScriptParams", "params": NameParams } | { "command": "GetScriptMeta", "params": NameParams } | { "command": "InitializeDataDir", "params": InitializeDataDirParams } | { "command": "SetLlmConfig", "params": SetLlmConfigParams } | { "command": "SetOutputSettings", "params": OutputSettings } | { "command": "SetSequenceDefaults", "params": SequenceDefaults } | { "command": "CreateSetup", "params": CreateSetupParams } | { "command": "OpenSetup", "params": SlugParams } | { "command": "DeleteSetup", "params": SlugParams } | { "command": "UpdateSetupFixtures", "params": UpdateSetupFixturesParams } | { "command": "UpdateSetupOutputs", "params": UpdateSetupOutputsParams } | { "command": "AutoPatch", "params": AutoPatchParams } | { "command": "UpdateSetupLayout", "params": UpdateSetupLayoutParams } | { "command": "CreateSequence", "params": CreateSequenceParams } | { "command": "CreateSequenceFromTemplate", "params": CreateSequenceFromTemplateParams } | { "command": "OpenSequence", "params": SlugParams } | { "command": "DeleteSequence", "params": SlugParams } | { "command": "ImportMedia", "params": ImportMediaParams } | { "command": "DeleteMedia", "params": NameParams } | { "command": "ResolveMediaPath", "params": NameParams } | { "command": "GetWaveform", "params": GetWaveformParams } | { "command": "SwitchAgentConversation", "params": ConversationIdParams } | { "command": "DeleteAgentConversation", "params": ConversationIdParams } | { "command": "GetConversationUsage", "params": ConversationIdParams } | { "command": "ExportConversation", "params": ExportConversationParams } | { "command": "ImportVixen", "params": ImportVixenParams } | { "command": "ImportVixenSetup", "params": ImportVixenSetupParams } | { "command": "ImportVixenSequence", "params": ImportVixenSequenceParams } | { "command": "ImportVixenSequences", "params": ImportVixenSequencesParams } | { "command": "ScanVixenDirectory", "params": ScanVixenDirectoryParams } | { "command": "CheckVixenPreviewFile", "params": CheckVixenPreviewFileParams } | { "command": "ScanLorDirectory", "params": ScanLorDirectoryParams } | { "command": "ImportLor", "params": ImportLorParams } | { "command": "ExportXlights", "params": ExportXlightsParams } | { "command": "Tick", "params": TickParams } | { "command": "GetFrame", "params": GetFrameParams } | { "command": "GetFrameFiltered", "params": GetFrameFilteredParams } | { "command": "SimulateColorVision", "params": SimulateColorVisionParams } | { "command": "ExportFramePng", "params": ExportFramePngParams } | { "command": "HashSequenceFrames", "params": HashSequenceFramesParams } | { "command": "RenderEffectThumbnail", "params": RenderEffectThumbnailParams } | { "command": "PreviewScript", "params": PreviewScriptParams } | { "command": "PreviewScriptFrame", "params": PreviewScriptFrameParams } | { "command": "ProfileScript", "params": ProfileScriptParams } | { "command": "StartLiveOutput", "params": StartLiveOutputParams } | { "command": "IdentifyFixture", "params": IdentifyFixtureParams } | { "command": "CancelOperation", "params": CancelOperationParams } | { "command": "Play" } | { "command": "Pause" } | { "command": "Undo" } | { "command": "Redo" } | { "command": "GetPlayback" } | { "command": "GetUndoState" } | { "command": "StopLiveOutput" } | { "command": "GetOutputStats" } | { "command": "GetShow" } | { "command": "GetDesignGuide" } | { "command": "ListEffects" } | { "command": "DescribeShow" } | { "command": "ListMarkers" } | { "command": "GetCommandSchema" } | { "command": "GetAnalysisSummary" } | { "command": "GetSections" } | { "command": "GetAnalysis" } | { "command": "ListGlobalGradients" } | { "command": "ListGlobalCurves" } | { "command": "ListEffectPresets" } | { "command": "ListCurvePresets" } | { "command": "GetDslReference" } | { "command": "ListGlobalScripts" } | { "command": "CompileAllGlobalScripts" } | { "command": "GetSettings" } | { "command": "GetLlmConfig" } | { "command": "ListSetups" } | { "command": "SaveSetup" } | { "command": "UndoSetup" } | { "command": "RedoSetup" } | { "command": "GetSetupUndoState" } | { "command": "ListSequences" } | { "command": "SaveCurrentSequence" } | { "command": "ListMedia" } | { "command": "GetAgentChatHistory" } | { "command": "ListAgentConversations" } | { "command": "NewAgentConversation" } | { "command": "ExecuteVixenImport", "params": VixenImportConfig } | { "command": "AnalyzeAudio", "params": AnalyzeAudioParams } | { "command": "SendAgentMessage", "params": SendAgentMessageParams } | { "command": "GetPythonStatus" } | { "command": "SetupPythonEnv" } | { "command": "StartPythonSidecar" } | { "command": "StopPythonSidecar" } | { "command": "CancelAgentMessage" } | { "command": "ClearAgentSession" };
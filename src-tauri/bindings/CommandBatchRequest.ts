@@ -0,0 +1,22 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Command } from "./Command";
+
+/**
+ * Body of `POST /api/commands/batch`: an ordered list of commands run through
+ * the regular dispatcher. Each mutating command is its own undo step unless
+ * `as_batch_edit` folds them into a single `BatchEdit`.
+ */
+export type CommandBatchRequest = { commands: Array<Command>, 
+/**
+ * Stop at the first failing command instead of running the rest.
+ */
+stop_on_error: boolean, 
+/**
+ * Execute the commands as one undoable `BatchEdit`. Only edit commands
+ * that `BatchEdit` supports are accepted in this mode.
+ */
+as_batch_edit: boolean, 
+/**
+ * Undo description used with `as_batch_edit`.
+ */
+description: string, };
@@ -1,3 +1,3 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export type CommandCategory = "Edit" | "Playback" | "Query" | "Analysis" | "Library" | "Script" | "Settings" | "Setup" | "Sequence" | "Media" | "Chat" | "Import" | "Python" | "Agent";
+export type CommandCategory = "Edit" | "Playback" | "Query" | "Analysis" | "Library" | "Script" | "Settings" | "Setup" | "Sequence" | "Media" | "Chat" | "Import" | "Python" | "Agent" | "Output";
@@ -4,34 +4,53 @@ import type { AppSettings } from "./AppSettings";
 import type { AudioAnalysis } from "./AudioAnalysis";
 import type { BeatsInRange } from "./BeatsInRange";
 import type { ChatHistoryEntry } from "./ChatHistoryEntry";
+import type { ChordEvent } from "./ChordEvent";
 import type { ColorGradient } from "./ColorGradient";
 import type { ConversationSummary } from "./ConversationSummary";
+import type { ConversationUsage } from "./ConversationUsage";
 import type { Curve } from "./Curve";
+import type { EasingFunction } from "./EasingFunction";
 import type { EffectDetail } from "./EffectDetail";
 import type { EffectInfo } from "./EffectInfo";
+import type { EffectInstanceEntry } from "./EffectInstanceEntry";
+import type { EffectLocation } from "./EffectLocation";
+import type { EffectPreset } from "./EffectPreset";
 import type { EffectThumbnail } from "./EffectThumbnail";
 import type { Frame } from "./Frame";
+import type { FrameDigest } from "./FrameDigest";
+import type { GenerateFromBeatsResult } from "./GenerateFromBeatsResult";
 import type { JsonValue } from "./JsonValue";
+import type { LibraryImportSummary } from "./LibraryImportSummary";
+import type { LiveOutputInfo } from "./LiveOutputInfo";
 import type { LlmConfigInfo } from "./LlmConfigInfo";
+import type { LorDiscovery } from "./LorDiscovery";
+import type { Marker } from "./Marker";
 import type { MediaFile } from "./MediaFile";
 import type { NewConversationResult } from "./NewConversationResult";
+import type { OutputStats } from "./OutputStats";
+import type { Patch } from "./Patch";
 import type { PlaybackInfo } from "./PlaybackInfo";
 import type { PythonEnvStatus } from "./PythonEnvStatus";
 import type { ScriptCompileResult } from "./ScriptCompileResult";
+import type { ScriptMeta } from "./ScriptMeta";
 import type { ScriptParamInfo } from "./ScriptParamInfo";
 import type { ScriptPreviewData } from "./ScriptPreviewData";
+import type { ScriptProfile } from "./ScriptProfile";
 import type { SequenceSummary } from "./SequenceSummary";
 import type { Setup } from "./Setup";
 import type { SetupSummary } from "./SetupSummary";
 import type { Show } from "./Show";
 import type { SongSection } from "./SongSection";
 import type { TickResult } from "./TickResult";
+import type { TrackComplexity } from "./TrackComplexity";
 import type { UndoState } from "./UndoState";
 import type { VixenDiscovery } from "./VixenDiscovery";
 import type { VixenImportResult } from "./VixenImportResult";
+import type { VixenSequenceImport } from "./VixenSequenceImport";
+import type { Waveform } from "./Waveform";
 
 /**
  * Typed result for every command. ts-rs generates a discriminated union
  * that TypeScript can narrow by `command`.
  */
-export type CommandResult = { "command": "AddEffect", "data": number } | { "command": "DeleteEffects" } | { "command": "UpdateEffectParam" } | { "command": "UpdateEffectTimeRange" } | { "command": "AddTrack", "data": number } | { "command": "DeleteTrack" } | { "command": "MoveEffectToTrack", "data": number } | { "command": "UpdateSequenceSettings" } | { "command": "BatchEdit" } | { "command": "Seek" } | { "command": "SetRegion" } | { "command": "SetLooping" } | { "command": "GetEffectDetail", "data": EffectDetail } | { "command": "Help", "data": string } | { "command": "GetBeatsInRange", "data": BeatsInRange } | { "command": "GetAnalysisDetail", "data": JsonValue } | { "command": "SetGlobalGradient" } | { "command": "DeleteGlobalGradient" } | { "command": "RenameGlobalGradient" } | { "command": "SetGlobalCurve" } | { "command": "DeleteGlobalCurve" } | { "command": "RenameGlobalCurve" } | { "command": "WriteGlobalScript" } | { "command": "CompileGlobalScript", "data": ScriptCompileResult } | { "command": "GetGlobalScriptSource", "data": string } | { "command": "DeleteGlobalScript" } | { "command": "CompileScriptPreview", "data": ScriptCompileResult } | { "command": "RenameGlobalScript" } | { "command": "GetScriptParams", "data": Array<ScriptParamInfo> } | { "command": "InitializeDataDir", "data": AppSettings } | { "command": "SetLlmConfig" } | { "command": "CreateSetup", "data": SetupSummary } | { "command": "OpenSetup", "data": Setup } | { "command": "DeleteSetup" } | { "command": "UpdateSetupFixtures" } | { "command": "UpdateSetupOutputs" } | { "command": "UpdateSetupLayout" } | { "command": "CreateSequence", "data": SequenceSummary } | { "command": "OpenSequence", "data": Show } | { "command": "DeleteSequence" } | { "command": "ImportMedia", "data": MediaFile } | { "command": "DeleteMedia" } | { "command": "ResolveMediaPath", "data": string } | { "command": "SwitchAgentConversation" } | { "command": "DeleteAgentConversation" } | { "command": "ImportVixen", "data": SetupSummary } | { "command": "ImportVixenSetup", "data": SetupSummary } | { "command": "ImportVixenSequence", "data": SequenceSummary } | { "command": "ScanVixenDirectory", "data": VixenDiscovery } | { "command": "CheckVixenPreviewFile", "data": number } | { "command": "Tick", "data": TickResult | null } | { "command": "GetFrame", "data": Frame } | { "command": "GetFrameFiltered", "data": Frame } | { "command": "RenderEffectThumbnail", "data": EffectThumbnail | null } | { "command": "PreviewScript", "data": ScriptPreviewData } | { "command": "PreviewScriptFrame", "data": Array<[number, number, number, number]> } | { "command": "CancelOperation", "data": boolean } | { "command": "Play" } | { "command": "Pause" } | { "command": "Undo" } | { "command": "Redo" } | { "command": "GetPlayback", "data": PlaybackInfo } | { "command": "GetUndoState", "data": UndoState } | { "command": "GetShow", "data": Show } | { "command": "GetDesignGuide", "data": string } | { "command": "ListEffects", "data": Array<EffectInfo> } | { "command": "DescribeShow", "data": string } | { "command": "GetAnalysisSummary", "data": AnalysisSummary } | { "command": "GetSections", "data": Array<SongSection> } | { "command": "GetAnalysis", "data": AudioAnalysis | null } | { "command": "ListGlobalGradients", "data": Array<[string, ColorGradient]> } | { "command": "ListGlobalCurves", "data": Array<[string, Curve]> } | { "command": "GetDslReference", "data": string } | { "command": "ListGlobalScripts", "data": Array<[string, string]> } | { "command": "GetSettings", "data": AppSettings | null } | { "command": "GetLlmConfig", "data": LlmConfigInfo } | { "command": "ListSetups", "data": Array<SetupSummary> } | { "command": "SaveSetup" } | { "command": "ListSequences", "data": Array<SequenceSummary> } | { "command": "SaveCurrentSequence" } | { "command": "ListMedia", "data": Array<MediaFile> } | { "command": "GetAgentChatHistory", "data": Array<ChatHistoryEntry> } | { "command": "ListAgentConversations", "data": Array<ConversationSummary> } | { "command": "NewAgentConversation", "data": NewConversationResult } | { "command": "ExecuteVixenImport", "data": VixenImportResult } | { "command": "AnalyzeAudio", "data": AudioAnalysis } | { "command": "SendAgentMessage" } | { "command": "GetPythonStatus", "data": PythonEnvStatus } | { "command": "SetupPythonEnv" } | { "command": "StartPythonSidecar", "data": number } | { "command": "StopPythonSidecar" } | { "command": "CancelAgentMessage" } | { "command": "ClearAgentSession" };
+export type CommandResult = { "command": "AddEffect", "data": number } | { "command": "ApplyEffectPreset", "data": number } | { "command": "DeleteEffects" } | { "command": "UpdateEffectParam" } | { "command": "ResetEffectParams" } | { "command": "UpdateEffectTimeRange" } | { "command": "NudgeEffects" } | { "command": "QuantizeEffects" } | { "command": "AlignEffects" } | { "command": "CrossfadeEffects" } | { "command": "SetEffectFades" } | { "command": "AddTrack", "data": number } | { "command": "DeleteTrack" } | { "command": "ReorderTrack" } | { "command": "SetTrackLocked" } | { "command": "SetTrackBlend" } | { "command": "AddMarker", "data": number } | { "command": "DeleteMarker" } | { "command": "AddCue", "data": number } | { "command": "DeleteCue" } | { "command": "AddAudioTrack", "data": number } | { "command": "RemoveAudioTrack" } | { "command": "MoveEffectToTrack", "data": number } | { "command": "UpdateSequenceSettings" } | { "command": "BatchEdit" } | { "command": "GenerateFromBeats", "data": GenerateFromBeatsResult } | { "command": "MergeSequence" } | { "command": "Seek" } | { "command": "GoToCue" } | { "command": "SetRegion" } | { "command": "SetLooping" } | { "command": "SetTrackMuted" } | { "command": "SetTrackSolo" } | { "command": "GetEffectDetail", "data": EffectDetail } | { "command": "ListEffectInstances", "data": Array<EffectInstanceEntry> } | { "command": "FindEffects", "data": Array<EffectLocation> } | { "command": "AnalyzeShowComplexity", "data": Array<TrackComplexity> } | { "command": "Help", "data": string } | { "command": "GetBeatsInRange", "data": BeatsInRange } | { "command": "GetChords", "data": Array<ChordEvent> } | { "command": "GetStemEnergy", "data": Array<number> } | { "command": "GetAnalysisDetail", "data": JsonValue } | { "command": "ExportAnalysis" } | { "command": "ImportAnalysis" } | { "command": "SetGlobalGradient" } | { "command": "DeleteGlobalGradient" } | { "command": "RenameGlobalGradient" } | { "command": "TransformGradient" } | { "command": "QuantizeGradientToPalette", "data": ColorGradient } | { "command": "SaveEffectPreset" } | { "command": "DeleteEffectPreset" } | { "command": "SetGlobalCurve" } | { "command": "DeleteGlobalCurve" } | { "command": "RenameGlobalCurve" } | { "command": "AddCurvePreset", "data": string } | { "command": "FindLibraryUsage", "data": Array<EffectLocation> } | { "command": "ExportLibraryBundle" } | { "command": "ImportLibraryBundle", "data": LibraryImportSummary } | { "command": "WriteGlobalScript" } | { "command": "CompileGlobalScript", "data": ScriptCompileResult } | { "command": "GetGlobalScriptSource", "data": string } | { "command": "DeleteGlobalScript" } | { "command": "CompileScriptPreview", "data": ScriptCompileResult } | { "command": "RenameGlobalScript" } | { "command": "GetScriptParams", "data": Array<ScriptParamInfo> } | { "command": "GetScriptMeta", "data": ScriptMeta } | { "command": "InitializeDataDir", "data": AppSettings } | { "command": "SetLlmConfig" } | { "command": "SetOutputSettings" } | { "command": "SetSequenceDefaults" } | { "command": "CreateSetup", "data": SetupSummary } | { "command": "OpenSetup", "data": Setup } | { "command": "DeleteSetup" } | { "command": "UpdateSetupFixtures" } | { "command": "UpdateSetupOutputs" } | { "command": "AutoPatch", "data": Array<Patch> } | { "command": "UpdateSetupLayout" } | { "command": "CreateSequence", "data": SequenceSummary } | { "command": "CreateSequenceFromTemplate", "data": SequenceSummary } | { "command": "OpenSequence", "data": Show } | { "command": "DeleteSequence" } | { "command": "ImportMedia", "data": MediaFile } | { "command": "DeleteMedia" } | { "command": "ResolveMediaPath", "data": string } | { "command": "GetWaveform", "data": Waveform } | { "command": "SwitchAgentConversation" } | { "command": "DeleteAgentConversation" } | { "command": "GetConversationUsage", "data": ConversationUsage } | { "command": "ExportConversation" } | { "command": "ImportVixen", "data": SetupSummary } | { "command": "ImportVixenSetup", "data": SetupSummary } | { "command": "ImportVixenSequence", "data": SequenceSummary } | { "command": "ImportVixenSequences", "data": Array<VixenSequenceImport> } | { "command": "ScanVixenDirectory", "data": VixenDiscovery } | { "command": "CheckVixenPreviewFile", "data": number } | { "command": "ScanLorDirectory", "data": LorDiscovery } | { "command": "ImportLor", "data": SetupSummary } | { "command": "ExportXlights" } | { "command": "Tick", "data": TickResult | null } | { "command": "GetFrame", "data": Frame } | { "command": "GetFrameFiltered", "data": Frame } | { "command": "SimulateColorVision", "data": Frame } | { "command": "ExportFramePng" } | { "command": "HashSequenceFrames", "data": FrameDigest } | { "command": "RenderEffectThumbnail", "data": EffectThumbnail | null } | { "command": "PreviewScript", "data": ScriptPreviewData } | { "command": "PreviewScriptFrame", "data": Array<[number, number, number, number]> } | { "command": "ProfileScript", "data": ScriptProfile } | { "command": "StartLiveOutput", "data": LiveOutputInfo } | { "command": "IdentifyFixture" } | { "command": "CancelOperation", "data": boolean } | { "command": "Play" } | { "command": "Pause" } | { "command": "Undo" } | { "command": "Redo" } | { "command": "GetPlayback", "data": PlaybackInfo } | { "command": "GetUndoState", "data": UndoState } | { "command": "StopLiveOutput" } | { "command": "GetOutputStats", "data": Array<OutputStats> } | { "command": "GetShow", "data": Show } | { "command": "GetDesignGuide", "data": string } | { "command": "ListEffects", "data": Array<EffectInfo> } | { "command": "DescribeShow", "data": string } | { "command": "ListMarkers", "data": Array<Marker> } | { "command": "GetCommandSchema", "data": JsonValue } | { "command": "GetAnalysisSummary", "data": AnalysisSummary } | { "command": "GetSections", "data": Array<SongSection> } | { "command": "GetAnalysis", "data": AudioAnalysis | null } | { "command": "ListGlobalGradients", "data": Array<[string, ColorGradient]> } | { "command": "ListGlobalCurves", "data": Array<[string, Curve]> } | { "command": "ListEffectPresets", "data": Array<[string, EffectPreset]> } | { "command": "ListCurvePresets", "data": Array<[EasingFunction, Curve]> } | { "command": "GetDslReference", "data": string } | { "command": "ListGlobalScripts", "data": Array<[string, string]> } | { "command": "CompileAllGlobalScripts", "data": Array<ScriptCompileResult> } | { "command": "GetSettings", "data": AppSettings | null } | { "command": "GetLlmConfig", "data": LlmConfigInfo } | { "command": "ListSetups", "data": Array<SetupSummary> } | { "command": "SaveSetup" } | { "command": "UndoSetup" } | { "command": "RedoSetup" } | { "command": "GetSetupUndoState", "data": UndoState } | { "command": "ListSequences", "data": Array<SequenceSummary> } | { "command": "SaveCurrentSequence" } | { "command": "ListMedia", "data": Array<MediaFile> } | { "command": "GetAgentChatHistory", "data": Array<ChatHistoryEntry> } | { "command": "ListAgentConversations", "data": Array<ConversationSummary> } | { "command": "NewAgentConversation", "data": NewConversationResult } | { "command": "ExecuteVixenImport", "data": VixenImportResult } | { "command": "AnalyzeAudio", "data": AudioAnalysis } | { "command": "SendAgentMessage" } | { "command": "GetPythonStatus", "data": PythonEnvStatus } | { "command": "SetupPythonEnv" } | { "command": "StartPythonSidecar", "data": number } | { "command": "StopPythonSidecar" } | { "command": "CancelAgentMessage" } | { "command": "ClearAgentSession" };
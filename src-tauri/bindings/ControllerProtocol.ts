@@ -3,4 +3,4 @@
 /**
  * How a controller communicates with the sequencer.
  */
-export type ControllerProtocol = { "E131": { unicast_address: string | null, } } | { "ArtNet": { address: string | null, } } | { "Serial": { port: string, baud_rate: number, } };
+export type ControllerProtocol = { "E131": { unicast_address: string | null, } } | { "ArtNet": { address: string | null, } } | { "Serial": { port: string, baud_rate: number, } } | { "Ddp": { address: string, } };
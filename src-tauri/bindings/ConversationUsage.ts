@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TokenUsage } from "./TokenUsage";
+
+/**
+ * Cumulative usage for one conversation, returned by `GetConversationUsage`.
+ */
+export type ConversationUsage = { conversation_id: string, usage: TokenUsage, 
+/**
+ * Estimated cost in USD. None when no rate is configured for the model.
+ */
+estimated_cost: number | null, };
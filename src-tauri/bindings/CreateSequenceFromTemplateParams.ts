@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type CreateSequenceFromTemplateParams = { name: string, 
+/**
+ * Template id: "pop", "edm" or "ballad".
+ */
+template: string, 
+/**
+ * Media filename to attach. Its cached analysis sections, if any,
+ * replace the template's default section timing.
+ */
+audio_file: string | null, };
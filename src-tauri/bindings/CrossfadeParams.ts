@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { EffectLocation } from "./EffectLocation";
+
+export type CrossfadeParams = { 
+/**
+ * Effect that fades out across the window.
+ */
+from: EffectLocation, 
+/**
+ * Effect that fades in across the window.
+ */
+to: EffectLocation, 
+/**
+ * Crossfade window in seconds; must lie within both effects.
+ */
+start: number, end: number, };
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A named transport position, optionally spanning a range, for jumping
+ * around a show during rehearsal.
+ */
+export type Cue = { name: string, 
+/**
+ * Start time in seconds.
+ */
+time: number, 
+/**
+ * End time in seconds, if the cue covers a range.
+ */
+end?: number, };
@@ -1,8 +1,10 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CurveInterp } from "./CurveInterp";
 import type { CurvePoint } from "./CurvePoint";
 
 /**
- * Piecewise-linear curve mapping time (x) to value (y), both normalized [0, 1].
- * Points are always sorted by x. Evaluate via binary search + linear interpolation.
+ * Curve mapping time (x) to value (y), both normalized [0, 1]. Points are
+ * always sorted by x. Evaluate via binary search, then interpolate the
+ * segment per `interp` (linear unless set).
  */
-export type Curve = { points: Array<CurvePoint>, };
+export type Curve = { points: Array<CurvePoint>, interp?: CurveInterp, };
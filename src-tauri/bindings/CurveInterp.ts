@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * How a curve fills in between its points.
+ */
+export type CurveInterp = "Linear" | "CatmullRom";
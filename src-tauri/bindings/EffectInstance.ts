@@ -1,5 +1,6 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { BlendMode } from "./BlendMode";
+import type { Curve } from "./Curve";
 import type { EffectKind } from "./EffectKind";
 import type { EffectParams } from "./EffectParams";
 import type { TimeRange } from "./TimeRange";
@@ -13,4 +14,18 @@ export type EffectInstance = { kind: EffectKind, params: EffectParams, time_rang
  * Values outside [0.0, 1.0] are safe: `Color::scale()` clamps the factor,
  * and the evaluator uses opacity only via `scale()`.
  */
-opacity: number, };
+opacity: number, 
+/**
+ * Optional envelope over the effect's normalized time that scales
+ * `opacity`, e.g. a crossfade ramp. `None` keeps opacity constant.
+ */
+opacity_curve?: Curve, 
+/**
+ * Seconds over which opacity ramps up from zero at the start.
+ * Applied on top of `opacity_curve`; zero means no fade.
+ */
+fade_in: number, 
+/**
+ * Seconds over which opacity ramps down to zero at the end.
+ */
+fade_out: number, };
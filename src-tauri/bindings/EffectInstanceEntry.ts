@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { EffectKind } from "./EffectKind";
+
+/**
+ * One placed effect, located by its indices in the active sequence.
+ */
+export type EffectInstanceEntry = { track_index: number, effect_index: number, kind: EffectKind, start: number, end: number, };
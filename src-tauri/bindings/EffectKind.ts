@@ -4,4 +4,4 @@
  * Which effect type an instance uses.
  * Built-in effects are enum variants; DSL scripts use `Script(name)`.
  */
-export type EffectKind = "Solid" | "Chase" | "Rainbow" | "Strobe" | "Gradient" | "Twinkle" | "Fade" | "Wipe" | { "Script": string };
+export type EffectKind = "Solid" | "Chase" | "Rainbow" | "Strobe" | "Gradient" | "Twinkle" | "Fade" | "Wipe" | "Sparkle" | { "Script": string };
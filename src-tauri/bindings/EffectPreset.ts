@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { EffectKind } from "./EffectKind";
+import type { EffectParams } from "./EffectParams";
+
+/**
+ * A reusable effect configuration: the kind plus a snapshot of its params.
+ * Stored in the global library and instantiated onto tracks.
+ */
+export type EffectPreset = { kind: EffectKind, params: EffectParams, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TranscriptFormat } from "./TranscriptFormat";
+
+export type ExportConversationParams = { conversation_id: string, 
+/**
+ * Destination file (`.md` or `.json`).
+ */
+path: string, format: TranscriptFormat, };
@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Color } from "./Color";
+
+export type ExportFramePngParams = { 
+/**
+ * Destination `.png` file.
+ */
+path: string, 
+/**
+ * Time in seconds of the frame to export.
+ */
+time: number, width: number, height: number, 
+/**
+ * Fill behind the bulbs. Defaults to black, like the preview.
+ */
+background: Color | null, };
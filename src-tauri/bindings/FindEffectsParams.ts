@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { EffectKind } from "./EffectKind";
+import type { ParamPredicate } from "./ParamPredicate";
+
+export type FindEffectsParams = { 
+/**
+ * Only match effects of this kind.
+ */
+kind: EffectKind | null, 
+/**
+ * Every predicate must hold. Params an effect leaves unset are compared
+ * at their schema default.
+ */
+predicates: Array<ParamPredicate>, };
@@ -14,4 +14,9 @@ export type FixtureDef = { id: FixtureId, name: string, color_model: ColorModel,
 /**
  * Number of individually addressable pixels. 1 for simple fixtures.
  */
-pixel_count: number, pixel_type: PixelType, bulb_shape: BulbShape, display_radius_override?: number | null, channel_order: ChannelOrder, };
+pixel_count: number, pixel_type: PixelType, bulb_shape: BulbShape, display_radius_override?: number | null, channel_order: ChannelOrder, 
+/**
+ * Output gamma for this fixture, overriding the show-wide default in
+ * output settings. Only applied when packing output, never to frames.
+ */
+gamma?: number | null, };
@@ -10,4 +10,10 @@ export type FixtureLayout = { fixture_id: FixtureId,
 /**
  * One position per pixel. Length must equal the fixture's pixel_count.
  */
-pixel_positions: Array<Position2D>, shape: LayoutShape, };
+pixel_positions: Array<Position2D>, shape: LayoutShape, 
+/**
+ * Preview-only brightness multiplier, so props that are much brighter or
+ * dimmer in person look right on screen. Never applied to output.
+ * Absent means 1.0.
+ */
+preview_gain?: number, };
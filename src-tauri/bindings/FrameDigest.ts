@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Fingerprint of a sequence's rendered output, for regression checks.
+ */
+export type FrameDigest = { 
+/**
+ * 64-bit FNV-1a hash of the sampled frames, as 16 hex digits.
+ */
+digest: string, 
+/**
+ * Number of frames that went into the digest.
+ */
+frames: number, };
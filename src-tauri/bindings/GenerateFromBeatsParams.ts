@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Color } from "./Color";
+
+export type GenerateFromBeatsParams = { 
+/**
+ * Colors cycled across successive beats.
+ */
+colors: Array<Color>, 
+/**
+ * Pulse length in seconds. Defaults to half a beat at the analyzed tempo.
+ */
+pulse_length: number | null, };
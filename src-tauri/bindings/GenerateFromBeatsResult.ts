@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Typed return for GenerateFromBeats.
+ */
+export type GenerateFromBeatsResult = { tracks: number, effects: number, 
+/**
+ * True if beats were dropped to stay under the effect cap.
+ */
+truncated: boolean, };
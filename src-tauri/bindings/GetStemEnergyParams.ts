@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type GetStemEnergyParams = { 
+/**
+ * Stem name: "vocals", "drums", "bass" or "other".
+ */
+stem: string, 
+/**
+ * Times to sample, in seconds.
+ */
+times: Array<number>, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type GetWaveformParams = { 
+/**
+ * Number of min/max peak pairs spanning the audio file.
+ */
+buckets: number, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type GoToCueParams = { 
+/**
+ * Index into the time-sorted cue list.
+ */
+cue_index: number, 
+/**
+ * For a ranged cue, also set the playback region to it and enable looping.
+ */
+loop_region: boolean, };
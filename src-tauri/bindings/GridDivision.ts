@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Note value of one snap/quantize grid step, counting the beat as a quarter note.
+ */
+export type GridDivision = "Whole" | "Half" | "Quarter" | "Eighth" | "Sixteenth" | "QuarterTriplet" | "EighthTriplet";
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type HashSequenceFramesParams = { 
+/**
+ * Hash every Nth frame. Defaults to 1, every frame.
+ */
+every: number, };
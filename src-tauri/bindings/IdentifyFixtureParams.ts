@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type IdentifyFixtureParams = { fixture_id: number, 
+/**
+ * How long to light the fixture, in seconds. Defaults to 10.
+ */
+duration: number, 
+/**
+ * Run a single pixel along the fixture instead of lighting all of it.
+ */
+chase: boolean, };
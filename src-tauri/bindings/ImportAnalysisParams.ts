@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ImportAnalysisParams = { path: string, 
+/**
+ * Attach the analysis even if it was exported for a different audio file.
+ */
+force: boolean, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { NameCollision } from "./NameCollision";
+
+export type ImportLibraryBundleParams = { path: string, 
+/**
+ * What to do with items whose name is already in the library. Defaults to Skip.
+ */
+on_collision: NameCollision, };
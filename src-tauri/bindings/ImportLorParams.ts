@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ImportLorParams = { 
+/**
+ * Path to a `.lms` sequence or `.lcc` channel configuration.
+ */
+path: string, 
+/**
+ * Name for the new setup. Defaults to the file name.
+ */
+setup_name: string | null, };
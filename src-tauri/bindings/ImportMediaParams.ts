@@ -1,3 +1,7 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export type ImportMediaParams = { source_path: string, };
+export type ImportMediaParams = { source_path: string, 
+/**
+ * Also set the current sequence's duration to the audio's length.
+ */
+set_duration: boolean, };
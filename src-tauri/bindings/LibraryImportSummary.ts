@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Item counts from merging a library bundle.
+ */
+export type LibraryImportSummary = { added: number, overwritten: number, renamed: number, skipped: number, };
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { EffectKind } from "./EffectKind";
+
+export type ListEffectInstancesParams = { 
+/**
+ * Only list effects that overlap [start, end] (seconds). Either bound may be omitted.
+ */
+start: number | null, end: number | null, 
+/**
+ * Only list effects of this kind.
+ */
+kind: EffectKind | null, };
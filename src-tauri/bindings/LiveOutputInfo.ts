@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Typed return for StartLiveOutput.
+ */
+export type LiveOutputInfo = { 
+/**
+ * Controllers now receiving frames.
+ */
+controllers: Array<string>, 
+/**
+ * Controllers left out, with the reason.
+ */
+skipped: Array<string>, };
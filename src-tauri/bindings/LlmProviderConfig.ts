@@ -1,12 +1,14 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { LlmProviderKind } from "./LlmProviderKind";
+import type { ModelRate } from "./ModelRate";
 
 /**
- * Full configuration for the LLM provider (Anthropic only).
+ * Full configuration for the LLM provider.
  *
  * The `api_key` field is never written to `settings.json`. It is stored in a
  * separate credentials file and loaded/saved via [`load_api_key`]/[`save_api_key`].
  */
-export type LlmProviderConfig = { 
+export type LlmProviderConfig = { provider: LlmProviderKind, 
 /**
  * Received over IPC but never persisted in settings.json (stored in separate credentials file).
  */
@@ -14,4 +16,13 @@ api_key?: string | null,
 /**
  * Model override. None = use provider default.
  */
-model: string | null, };
+model: string | null, 
+/**
+ * API base URL override. Required for `OpenAiCompatible`; None = provider default.
+ */
+base_url: string | null, 
+/**
+ * Token prices keyed by model name, used to estimate conversation cost.
+ * The `"default"` entry applies when no model override is set.
+ */
+rates: { [key in string]: ModelRate }, };
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { LorFileInfo } from "./LorFileInfo";
+
+/**
+ * Discovery result from scanning a directory of Light-O-Rama files.
+ */
+export type LorDiscovery = { lor_dir: string, 
+/**
+ * Musical sequences (`.lms`).
+ */
+sequences: Array<LorFileInfo>, 
+/**
+ * Channel configurations (`.lcc`): channels only, no effects.
+ */
+channel_configs: Array<LorFileInfo>, };
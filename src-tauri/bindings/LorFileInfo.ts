@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Info about a discovered LOR file.
+ */
+export type LorFileInfo = { filename: string, path: string, size_bytes: number, };
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Color } from "./Color";
+
+/**
+ * A labelled point on the timeline, used for annotation, navigation and snapping.
+ * Independent of analysis `SongSection`s, which are derived from the audio.
+ */
+export type Marker = { 
+/**
+ * Time in seconds.
+ */
+time: number, label: string, color: Color, };
@@ -1,3 +1,7 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export type MediaFile = { filename: string, size_bytes: number, };
+export type MediaFile = { filename: string, size_bytes: number, 
+/**
+ * Audio length in seconds, detected on import.
+ */
+duration?: number, };
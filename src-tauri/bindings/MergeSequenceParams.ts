@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type MergeSequenceParams = { 
+/**
+ * Slug of the sequence whose tracks are appended.
+ */
+slug: string, 
+/**
+ * Setup the sequence lives in. Defaults to the current setup.
+ */
+setup: string | null, 
+/**
+ * Seconds added to every merged effect's start and end.
+ */
+time_offset: number, };
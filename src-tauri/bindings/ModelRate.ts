@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Price of a model in USD per million tokens.
+ */
+export type ModelRate = { input_per_mtok: number, output_per_mtok: number, };
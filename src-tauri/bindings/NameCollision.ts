@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * What to do when an imported library item has the same name as an
+ * existing one.
+ */
+export type NameCollision = "Skip" | "Overwrite" | "Rename";
@@ -0,0 +1,17 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { EffectLocation } from "./EffectLocation";
+
+export type NudgeEffectsParams = { 
+/**
+ * Effects to shift together.
+ */
+targets: Array<EffectLocation>, 
+/**
+ * Signed shift; negative moves earlier.
+ */
+delta: number, 
+/**
+ * When true, `delta` counts frames at the sequence's frame rate
+ * instead of seconds.
+ */
+frames: boolean, };
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * How evaluated frames are packed into channel bytes for live output and export.
+ */
+export type OutputSettings = { 
+/**
+ * Apply ordered dithering when quantizing channels to 8 bits. Off by
+ * default so output is exactly reproducible.
+ */
+dither: boolean, 
+/**
+ * Gamma for fixtures without their own. None = linear.
+ */
+gamma: number | null, };
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Send counters for one controller in a live-output session.
+ */
+export type OutputStats = { controller: string, packets_sent: number, 
+/**
+ * Frames whose send failed part way or entirely.
+ */
+send_errors: number, last_error: string | null, };
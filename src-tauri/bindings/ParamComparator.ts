@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * How a [`ParamPredicate`] compares an effect's param against its value.
+ */
+export type ParamComparator = "Eq" | "Ne" | "Lt" | "Le" | "Gt" | "Ge";
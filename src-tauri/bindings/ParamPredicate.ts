@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ParamComparator } from "./ParamComparator";
+import type { ParamKey } from "./ParamKey";
+import type { ParamValue } from "./ParamValue";
+
+/**
+ * A test on one effect param, e.g. `Rate Gt 10`. Ordering comparators only
+ * match numeric params.
+ */
+export type ParamPredicate = { key: ParamKey, op: ParamComparator, value: ParamValue, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Brightness profile across a chase pulse, from head to tail.
+ */
+export type PulseShape = "square" | "triangle" | "gaussian";
@@ -0,0 +1,13 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { EffectLocation } from "./EffectLocation";
+import type { GridDivision } from "./GridDivision";
+
+export type QuantizeEffectsParams = { 
+/**
+ * Effects whose start and end snap to the grid.
+ */
+targets: Array<EffectLocation>, 
+/**
+ * Grid spacing as a note value at the analyzed tempo. Defaults to quarter notes.
+ */
+grid: GridDivision, };
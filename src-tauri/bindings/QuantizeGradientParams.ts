@@ -0,0 +1,17 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Color } from "./Color";
+import type { ColorGradient } from "./ColorGradient";
+
+export type QuantizeGradientParams = { gradient: ColorGradient, 
+/**
+ * Colors the prop can show. Each band takes the perceptually nearest one.
+ */
+palette: Array<Color>, 
+/**
+ * Number of equal-width bands. Defaults to 16.
+ */
+steps: number, 
+/**
+ * Also save the result to the global library under this name.
+ */
+save_as: string | null, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type SaveEffectPresetParams = { name: string, 
+/**
+ * Effect in the current sequence whose kind and params are snapshotted.
+ */
+track_index: number, effect_index: number, };
@@ -2,4 +2,8 @@
 import type { ScriptError } from "./ScriptError";
 import type { ScriptParamInfo } from "./ScriptParamInfo";
 
-export type ScriptCompileResult = { success: boolean, errors: Array<ScriptError>, name: string, params: Array<ScriptParamInfo> | null, };
+export type ScriptCompileResult = { success: boolean, errors: Array<ScriptError>, 
+/**
+ * Non-fatal diagnostics from a successful compile.
+ */
+warnings: Array<ScriptError>, name: string, params: Array<ScriptParamInfo> | null, };
@@ -1,3 +1,15 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export type ScriptError = { message: string, offset: number, };
+export type ScriptError = { message: string, 
+/**
+ * Byte offset into the source.
+ */
+offset: number, 
+/**
+ * 1-based line of `offset`.
+ */
+line: number, 
+/**
+ * 1-based column of `offset`, in characters.
+ */
+col: number, };
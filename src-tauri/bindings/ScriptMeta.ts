@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Descriptive metadata from a script's `@` directives.
+ */
+export type ScriptMeta = { 
+/**
+ * `@title`, falling back to `@name`.
+ */
+title: string | null, author: string | null, description: string | null, spatial: boolean, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ScriptOpCount } from "./ScriptOpCount";
+
+/**
+ * VM instruction counts from evaluating one frame of a script.
+ */
+export type ScriptProfile = { pixel_count: number, total_ops: number, ops_per_pixel: number, 
+/**
+ * Executed count per op kind, most frequent first.
+ */
+ops: Array<ScriptOpCount>, };
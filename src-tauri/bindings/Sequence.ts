@@ -1,4 +1,7 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AudioTrackRef } from "./AudioTrackRef";
+import type { Cue } from "./Cue";
+import type { Marker } from "./Marker";
 import type { MotionPath } from "./MotionPath";
 import type { Track } from "./Track";
 
@@ -16,9 +19,15 @@ duration: number,
  */
 frame_rate: number, 
 /**
- * Audio file path, if any.
+ * Primary audio file, if any: the one analysis runs on. It is also one
+ * of `audio_tracks`.
  */
 audio_file: string | null, 
+/**
+ * Every audio layer played with the sequence, the primary included.
+ * Older files only have `audio_file`, which becomes the single layer on load.
+ */
+audio_tracks: Array<AudioTrackRef>, 
 /**
  * Tracks layered bottom (index 0) to top.
  */
@@ -26,4 +35,21 @@ tracks: Array<Track>,
 /**
  * Named motion paths. Key = path name.
  */
-motion_paths: { [key in string]: MotionPath }, };
+motion_paths: { [key in string]: MotionPath }, 
+/**
+ * Timeline markers, sorted by time. Absent in older files.
+ */
+markers: Array<Marker>, 
+/**
+ * Named cues, sorted by time. Absent in older files.
+ */
+cues: Array<Cue>, 
+/**
+ * Last-used playback region (start, end) in seconds, restored on open.
+ * Absent in older files.
+ */
+region: [number, number] | null, 
+/**
+ * Whether playback was looping the region when last saved.
+ */
+looping: boolean, };
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Starting duration and frame rate for newly created sequences.
+ */
+export type SequenceDefaults = { 
+/**
+ * Length in seconds.
+ */
+duration: number, 
+/**
+ * Frames per second.
+ */
+frame_rate: number, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type SetEffectFadesParams = { track_index: number, effect_index: number, 
+/**
+ * Seconds of opacity ramp-up at the start; 0 for none.
+ */
+fade_in: number, 
+/**
+ * Seconds of opacity ramp-down at the end; 0 for none.
+ */
+fade_out: number, };
@@ -1,3 +1,13 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { LlmProviderKind } from "./LlmProviderKind";
+import type { ModelRate } from "./ModelRate";
 
-export type SetLlmConfigParams = { api_key: string, model: string | null, };
+export type SetLlmConfigParams = { provider: LlmProviderKind, api_key: string, model: string | null, 
+/**
+ * API base URL override (e.g. a proxy). None = provider default.
+ */
+base_url: string | null, 
+/**
+ * Per-model token prices for cost estimates. None = keep existing rates.
+ */
+rates: { [key in string]: ModelRate } | null, };
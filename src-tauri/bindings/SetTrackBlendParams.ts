@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BlendMode } from "./BlendMode";
+
+export type SetTrackBlendParams = { track_index: number, blend_mode: BlendMode, 
+/**
+ * Master opacity of the whole track, 0.0-1.0.
+ */
+opacity: number, };
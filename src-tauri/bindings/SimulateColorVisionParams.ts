@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ColorVisionDeficiency } from "./ColorVisionDeficiency";
+import type { Frame } from "./Frame";
+
+export type SimulateColorVisionParams = { kind: ColorVisionDeficiency, 
+/**
+ * A frame as returned by `get_frame`.
+ */
+frame: Frame, };
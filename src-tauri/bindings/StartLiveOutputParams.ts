@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type StartLiveOutputParams = { 
+/**
+ * Controller update rate in frames per second. Frames between the
+ * sequence's own are interpolated. Defaults to the sequence's frame rate.
+ */
+output_fps: number | null, };
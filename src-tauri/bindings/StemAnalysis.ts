@@ -16,4 +16,13 @@ bass: string,
 /**
  * Relative path to other/accompaniment stem WAV
  */
-other: string, };
+other: string, 
+/**
+ * RMS energy envelope per stem name (e.g. "drums"), normalized to 0-1.
+ * Absent in older analyses.
+ */
+energy: { [key in string]: Array<number> }, 
+/**
+ * Time step between energy samples in seconds
+ */
+energy_time_step: number, };
@@ -1,3 +1,14 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export type TickParams = { dt: number, };
+export type TickParams = { dt: number, 
+/**
+ * `seq` of the last tick this client applied. When it is still the
+ * latest, the result is a delta against it; otherwise (or when absent)
+ * the full frame is returned.
+ */
+since: number | null, 
+/**
+ * Identifies the caller, so each client gets deltas against the frames
+ * it received. Clients that omit it share one baseline.
+ */
+client: string | null, };
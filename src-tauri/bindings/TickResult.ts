@@ -1,4 +1,19 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { Frame } from "./Frame";
 
-export type TickResult = { frame: Frame, current_time: number, playing: boolean, };
+export type TickResult = { frame: Frame, current_time: number, playing: boolean, 
+/**
+ * Number of this frame. Pass it back as `TickParams::since` to receive
+ * only what changes on the next tick.
+ */
+seq: number, 
+/**
+ * Whether `frame` holds only the fixtures that changed since the frame
+ * numbered `since`, rather than every lit fixture.
+ */
+delta: boolean, 
+/**
+ * In a delta, fixtures that went dark since that frame. Full frames
+ * leave dark fixtures out, so a delta lists them here.
+ */
+cleared: Array<number>, };
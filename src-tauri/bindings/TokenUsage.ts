@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Prompt/response token counts reported by the LLM provider.
+ */
+export type TokenUsage = { input_tokens: bigint, output_tokens: bigint, };
@@ -1,9 +1,29 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BlendMode } from "./BlendMode";
 import type { EffectInstance } from "./EffectInstance";
 import type { EffectTarget } from "./EffectTarget";
 
 /**
  * A track targets a set of fixtures and contains a list of non-overlapping effect instances.
- * Tracks are layered bottom-to-top; blend mode lives on each EffectInstance.
+ * Tracks are layered bottom-to-top. Each effect blends onto the track's layer with its own
+ * blend mode; the track's `blend_mode` and `opacity` then combine that layer with the
+ * tracks below.
  */
-export type Track = { name: string, target: EffectTarget, effects: Array<EffectInstance>, };
+export type Track = { name: string, target: EffectTarget, 
+/**
+ * Locked tracks reject effect edits. Absent in older files, meaning unlocked.
+ */
+locked: boolean, 
+/**
+ * Muted tracks are skipped during evaluation unless another track is soloed.
+ */
+muted: boolean, 
+/**
+ * How the track's composited effects combine with the tracks below.
+ * Absent in older files, meaning `Override`.
+ */
+blend_mode: BlendMode, 
+/**
+ * Master opacity of the whole track (0.0-1.0). Absent in older files, meaning 1.0.
+ */
+opacity: number, effects: Array<EffectInstance>, };
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Load figures for one track of the active sequence.
+ */
+export type TrackComplexity = { track_index: number, name: string, effect_count: number, 
+/**
+ * Most effects playing at the same instant.
+ */
+max_overlap: number, 
+/**
+ * Pixels the track's target covers, rendered once per playing effect.
+ */
+pixel_count: number, 
+/**
+ * Why the track was flagged; empty when it is within every threshold.
+ */
+warnings: Array<string>, };
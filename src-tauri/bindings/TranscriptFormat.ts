@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * File format for an exported conversation transcript.
+ */
+export type TranscriptFormat = "Markdown" | "Json";
@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type TransformGradientParams = { 
+/**
+ * Library gradient to transform.
+ */
+name: string, 
+/**
+ * Name to save the result under.
+ */
+new_name: string, 
+/**
+ * Flip the gradient end to end.
+ */
+reverse: boolean, 
+/**
+ * Shift stops forward by this fraction, wrapping at 1. Applied after `reverse`.
+ */
+rotate: number, };
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Region } from "./Region";
+
+/**
+ * Body of `POST /api/transport`: one playback action, so hardware surfaces
+ * (a Stream Deck, a foot pedal) can drive the playhead without knowing the
+ * full command schema.
+ */
+export type TransportAction = { "action": "play" } | { "action": "pause" } | { "action": "seek", time: number, } | { "action": "region", region: Region | null, };
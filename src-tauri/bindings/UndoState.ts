@@ -3,4 +3,12 @@
 /**
  * Undo/redo state for the UI.
  */
-export type UndoState = { can_undo: boolean, can_redo: boolean, undo_description: string | null, redo_description: string | null, };
+export type UndoState = { can_undo: boolean, can_redo: boolean, undo_description: string | null, redo_description: string | null, 
+/**
+ * Number of edits that can currently be undone.
+ */
+undo_depth: number, 
+/**
+ * The most undo entries kept; older edits are forgotten.
+ */
+max_undo_levels: number, };
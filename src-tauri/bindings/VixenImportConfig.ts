@@ -8,4 +8,19 @@ export type VixenImportConfig = { vixen_dir: string, setup_name: string, import_
  * Optional user-provided path to the file containing preview/layout data.
  * When set, overrides auto-detection in `find_preview_file`.
  */
-preview_file_override: string | null, sequence_paths: Array<string>, media_filenames: Array<string>, };
+preview_file_override: string | null, sequence_paths: Array<string>, media_filenames: Array<string>, 
+/**
+ * Tolerance for simplifying imported curves, in normalized curve units.
+ * `None` uses the importer default; `0` keeps every point.
+ */
+curve_epsilon: number | null, 
+/**
+ * Most effects kept per imported sequence; the rest are dropped with a
+ * warning. `None` uses the importer default.
+ */
+max_effects: number | null, 
+/**
+ * Add groups of fixtures that sit close together in the imported layout,
+ * alongside the groups from the Vixen project.
+ */
+group_by_position: boolean, };
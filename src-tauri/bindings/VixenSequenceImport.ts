@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SequenceSummary } from "./SequenceSummary";
+
+/**
+ * Outcome for one `.tim` file of a batch sequence import. Exactly one of
+ * `sequence` and `error` is set.
+ */
+export type VixenSequenceImport = { path: string, sequence: SequenceSummary | null, error: string | null, };
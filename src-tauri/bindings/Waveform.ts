@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WaveformPeak } from "./WaveformPeak";
+
+/**
+ * Downsampled waveform of a whole audio file.
+ */
+export type Waveform = { 
+/**
+ * Length of the decoded audio in seconds. Buckets span `[0, duration]`.
+ */
+duration: number, peaks: Array<WaveformPeak>, };
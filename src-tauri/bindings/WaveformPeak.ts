@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Lowest and highest sample (-1.0 to 1.0) within one bucket.
+ */
+export type WaveformPeak = { min: number, max: number, };
@@ -70,9 +70,41 @@ def analyze_stems(
             logger.warning("Expected stem not found: %s", stem_path)
             stem_files[stem] = ""
 
+    energy, time_step = _energy_envelopes(stem_files)
+
     return {
         "vocals": stem_files.get("vocals", ""),
         "drums": stem_files.get("drums", ""),
         "bass": stem_files.get("bass", ""),
         "other": stem_files.get("other", ""),
+        "energy": energy,
+        "energy_time_step": time_step,
     }
+
+
+def _energy_envelopes(stem_files: dict) -> tuple:
+    """RMS energy per stem, each normalized to 0-1 by its own peak.
+
+    Stems that are missing or fail to load are left out.
+    """
+    import librosa
+    import numpy as np
+
+    sr = 22050
+    hop_length = 512
+    energy = {}
+    for stem, path in stem_files.items():
+        if not path:
+            continue
+        try:
+            y, _ = librosa.load(path, sr=sr)
+        except Exception as e:
+            logger.warning("Could not load stem %s for energy: %s", path, e)
+            continue
+        rms = librosa.feature.rms(y=y, hop_length=hop_length)[0]
+        peak = float(np.max(rms)) if len(rms) > 0 else 0.0
+        if peak > 0.0:
+            rms = rms / peak
+        energy[stem] = [round(float(x), 4) for x in rms]
+
+    return energy, round(hop_length / sr, 8)
@@ -4,8 +4,9 @@ use std::sync::Arc;
 use tauri::{AppHandle, Manager};
 use tokio::io::{AsyncBufReadExt, BufReader};
 
-use crate::chat::{ChatEmitter, ChatHistoryEntry, ChatRole};
+use crate::chat::{ChatEmitter, ChatHistoryEntry, ChatRole, TokenUsage};
 use crate::error::AppError;
+use crate::settings::LlmProviderKind;
 use crate::state::AppState;
 
 // ── Runtime detection ────────────────────────────────────────────
@@ -126,11 +127,24 @@ pub async fn start_agent_sidecar(
     };
 
     // Gather env vars
-    let api_key = state
+    let llm_config = state
         .settings
         .lock()
         .as_ref()
-        .and_then(|s| s.llm.api_key.clone());
+        .map(|s| s.llm.clone())
+        .unwrap_or_default();
+    let provider = crate::chat::llm_provider(&llm_config);
+    // The sidecar runs on the Claude Agent SDK, which only speaks the
+    // Anthropic API. Refuse other providers rather than silently ignoring them.
+    if provider.kind() != LlmProviderKind::Anthropic {
+        return Err(AppError::AgentError {
+            message: format!(
+                "The agent does not support the '{}' provider yet; switch to Anthropic",
+                provider.kind().slug()
+            ),
+        });
+    }
+    let api_key = llm_config.api_key.clone();
 
     let data_dir = crate::state::get_data_dir(state)
         .map_err(|e| AppError::AgentError {
@@ -139,11 +153,7 @@ pub async fn start_agent_sidecar(
         .to_string_lossy()
         .to_string();
 
-    let model = state
-        .settings
-        .lock()
-        .as_ref()
-        .and_then(|s| s.llm.model.clone());
+    let model = llm_config.model.clone();
 
     let mut cmd = tokio::process::Command::new(&command);
     for arg in &args {
@@ -157,12 +167,13 @@ pub async fn start_agent_sidecar(
 
     cmd.env("AGENT_PORT", "0") // Let OS pick a free port
         .env("VIBELIGHTS_PORT", api_port.to_string())
-        .env("VIBELIGHTS_DATA_DIR", &data_dir);
+        .env("VIBELIGHTS_DATA_DIR", &data_dir)
+        .env(provider.base_url_env(), provider.base_url());
 
     // Only pass API key / model if explicitly configured; otherwise let the
     // SDK use the user's existing Claude Code OAuth credentials from ~/.claude/
     if let Some(ref key) = api_key {
-        cmd.env("ANTHROPIC_API_KEY", key);
+        cmd.env(provider.api_key_env(), key);
     }
     if let Some(ref m) = model {
         cmd.env("VIBELIGHTS_MODEL", m);
@@ -356,6 +367,8 @@ pub async fn send_message(
     state.agent_display_messages.lock().push(ChatHistoryEntry {
         role: ChatRole::User,
         text: message.clone(),
+        usage: None,
+        tool: None,
     });
 
     // Update conversation title from first user message
@@ -428,6 +441,7 @@ async fn stream_agent_response(
     let mut data_buf = String::new();
     let mut leftover = String::new();
     let mut assistant_text = String::new();
+    let mut usage = TokenUsage::default();
 
     tokio::pin!(stream);
 
@@ -446,7 +460,7 @@ async fn stream_agent_response(
             if line.is_empty() {
                 // Empty line = end of SSE event
                 if !event_name.is_empty() && !data_buf.is_empty() {
-                    process_sse_event(state, emitter, &event_name, &data_buf, &mut assistant_text);
+                    process_sse_event(state, emitter, &event_name, &data_buf, &mut assistant_text, &mut usage);
                 }
                 event_name.clear();
                 data_buf.clear();
@@ -466,15 +480,24 @@ async fn stream_agent_response(
 
     // Process any remaining event
     if !event_name.is_empty() && !data_buf.is_empty() {
-        process_sse_event(state, emitter, &event_name, &data_buf, &mut assistant_text);
+        process_sse_event(state, emitter, &event_name, &data_buf, &mut assistant_text, &mut usage);
     }
 
     // Push assistant message to display history
-    if !assistant_text.trim().is_empty() {
-        state.agent_display_messages.lock().push(ChatHistoryEntry {
-            role: ChatRole::Assistant,
-            text: assistant_text,
-        });
+    {
+        let mut messages = state.agent_display_messages.lock();
+        if !assistant_text.trim().is_empty() {
+            messages.push(ChatHistoryEntry {
+                role: ChatRole::Assistant,
+                text: assistant_text,
+                usage: Some(usage),
+                tool: None,
+            });
+        } else if let Some(last) = messages.last_mut() {
+            // No text to show (e.g. a tool-only turn): keep the tokens on the
+            // preceding entry so the conversation total stays accurate.
+            last.usage = Some(last.usage.unwrap_or_default() + usage);
+        }
     }
 
     Ok(())
@@ -486,6 +509,7 @@ fn process_sse_event(
     event: &str,
     data: &str,
     assistant_text: &mut String,
+    usage: &mut TokenUsage,
 ) {
     match event {
         "token" => {
@@ -500,6 +524,15 @@ fn process_sse_event(
                 let id = val.get("id").and_then(serde_json::Value::as_str).unwrap_or("");
                 let tool = val.get("tool").and_then(serde_json::Value::as_str).unwrap_or("tool");
                 emitter.emit_tool_call(id, tool);
+                // Flush text streamed so far so the history keeps it ahead of the tool call.
+                if !assistant_text.trim().is_empty() {
+                    state.agent_display_messages.lock().push(ChatHistoryEntry {
+                        role: ChatRole::Assistant,
+                        text: std::mem::take(assistant_text),
+                        usage: None,
+                        tool: None,
+                    });
+                }
             }
         }
         "tool_result" => {
@@ -514,6 +547,12 @@ fn process_sse_event(
                     .and_then(serde_json::Value::as_str)
                     .unwrap_or("");
                 emitter.emit_tool_result(id, tool, result);
+                state.agent_display_messages.lock().push(ChatHistoryEntry {
+                    role: ChatRole::Tool,
+                    text: result.to_string(),
+                    usage: None,
+                    tool: Some(tool.to_string()),
+                });
             }
         }
         "thinking" => {
@@ -521,6 +560,12 @@ fn process_sse_event(
                 emitter.emit_thinking(val);
             }
         }
+        "usage" => {
+            // Missing or malformed counts are treated as zero.
+            if let Ok(reported) = serde_json::from_str::<TokenUsage>(data) {
+                *usage += reported;
+            }
+        }
         "session_id" => {
             if let Ok(sid) = serde_json::from_str::<String>(data) {
                 *state.agent_session_id.lock() = Some(sid);
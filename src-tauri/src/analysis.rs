@@ -1,5 +1,6 @@
 use std::path::Path;
 
+use serde::{Deserialize, Serialize};
 use tauri::AppHandle;
 
 use crate::error::AppError;
@@ -25,6 +26,53 @@ pub fn save_analysis(path: &Path, analysis: &AudioAnalysis) -> Result<(), AppErr
     Ok(())
 }
 
+// ── Portable export ───────────────────────────────────────────────
+
+/// Current version of the portable analysis export format.
+pub const ANALYSIS_EXPORT_VERSION: u32 = 1;
+
+/// Self-describing analysis file that can be shared or archived without the
+/// Python sidecar. Records which audio file the analysis was computed for so
+/// imports can refuse to attach it to a different song.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnalysisExport {
+    pub version: u32,
+    pub audio_file: String,
+    pub analysis: AudioAnalysis,
+}
+
+/// Write `analysis` for `audio_file` to `path` in the portable export format.
+pub fn export_analysis(path: &Path, audio_file: &str, analysis: &AudioAnalysis) -> Result<(), AppError> {
+    let export = AnalysisExport {
+        version: ANALYSIS_EXPORT_VERSION,
+        audio_file: audio_file.to_string(),
+        analysis: analysis.clone(),
+    };
+    let data = serde_json::to_string_pretty(&export).map_err(|e| AppError::AnalysisError {
+        message: format!("Failed to serialize analysis export: {e}"),
+    })?;
+    crate::project::atomic_write(path, data.as_bytes())?;
+    Ok(())
+}
+
+/// Read a portable analysis export, rejecting files that don't match the
+/// expected schema or were written by a newer format version.
+pub fn import_analysis(path: &Path) -> Result<AnalysisExport, AppError> {
+    let data = std::fs::read_to_string(path)?;
+    let export: AnalysisExport = serde_json::from_str(&data).map_err(|e| AppError::AnalysisError {
+        message: format!("Not a valid analysis export: {e}"),
+    })?;
+    if export.version > ANALYSIS_EXPORT_VERSION {
+        return Err(AppError::AnalysisError {
+            message: format!(
+                "Analysis export version {} is newer than supported version {ANALYSIS_EXPORT_VERSION}",
+                export.version
+            ),
+        });
+    }
+    Ok(export)
+}
+
 // ── HTTP client to sidecar ────────────────────────────────────────
 
 /// Run audio analysis by POSTing to the Python sidecar and streaming SSE progress.
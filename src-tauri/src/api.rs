@@ -1,18 +1,21 @@
 use std::net::SocketAddr;
 use std::sync::Arc;
+use std::time::Instant;
 
 use axum::extract::{Extension, Path};
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use axum::routing::{get, post};
 use axum::{Json, Router};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use tower_http::cors::CorsLayer;
 
 use crate::chat;
-use crate::registry::{catalog, CommandOutput, CommandResult};
-use crate::state::AppState;
+use crate::error::AppError;
+use crate::registry::params::{BatchAction, BatchEditParams, Region, SeekParams, SetRegionParams};
+use crate::registry::{catalog, execute, Command, CommandOutput, CommandResult};
+use crate::state::{AppState, PlaybackInfo};
 
 // ── Response types ───────────────────────────────────────────────
 
@@ -54,6 +57,154 @@ pub struct CommandResponse {
     pub data_file: Option<String>,
 }
 
+// ── Command batches ──────────────────────────────────────────────
+
+/// Body of `POST /api/commands/batch`: an ordered list of commands run through
+/// the regular dispatcher. Each mutating command is its own undo step unless
+/// `as_batch_edit` folds them into a single `BatchEdit`.
+#[derive(Deserialize)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub struct CommandBatchRequest {
+    pub commands: Vec<Command>,
+    /// Stop at the first failing command instead of running the rest.
+    #[serde(default)]
+    pub stop_on_error: bool,
+    /// Execute the commands as one undoable `BatchEdit`. Only edit commands
+    /// that `BatchEdit` supports are accepted in this mode.
+    #[serde(default)]
+    pub as_batch_edit: bool,
+    /// Undo description used with `as_batch_edit`.
+    #[serde(default)]
+    pub description: String,
+}
+
+/// Outcome of one command in a batch, in request order.
+#[derive(Serialize)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub struct BatchItemResponse {
+    pub ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<CommandResponse>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Run a command batch in order through the sync dispatcher, like
+/// `/api/tools`. Errors are reported per item; with `stop_on_error` the
+/// remaining commands are skipped and the result list ends at the failure.
+pub fn run_command_batch(
+    state: &Arc<AppState>,
+    request: CommandBatchRequest,
+) -> Result<Vec<BatchItemResponse>, String> {
+    let commands = if request.as_batch_edit {
+        let actions = request
+            .commands
+            .into_iter()
+            .map(BatchAction::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        vec![Command::BatchEdit(BatchEditParams {
+            description: request.description,
+            commands: actions,
+        })]
+    } else {
+        request.commands
+    };
+
+    let conversation_id = state.agent_chats.lock().active_id.clone();
+    let mut results = Vec::with_capacity(commands.len());
+    for cmd in commands {
+        let name = cmd.info().name;
+        // Log the same shape as /api/tools: the command's params as input.
+        let input = serde_json::to_value(&cmd)
+            .ok()
+            .and_then(|mut v| v.get_mut("params").map(Value::take))
+            .unwrap_or_else(|| serde_json::json!({}));
+        let start = Instant::now();
+        let result = execute::execute(state, cmd);
+        let outcome = result
+            .as_ref()
+            .map(|o| o.message.as_str())
+            .map_err(ToString::to_string);
+        crate::audit::log_tool_call(
+            &state.app_config_dir,
+            conversation_id.as_deref(),
+            name,
+            &input,
+            outcome.as_deref().map_err(String::as_str),
+            start.elapsed(),
+        );
+        match result {
+            Ok(output) => results.push(BatchItemResponse {
+                ok: true,
+                data: Some(build_response(state, name, output)),
+                error: None,
+            }),
+            Err(e) => {
+                results.push(BatchItemResponse {
+                    ok: false,
+                    data: None,
+                    error: Some(e.to_string()),
+                });
+                if request.stop_on_error {
+                    break;
+                }
+            }
+        }
+    }
+    Ok(results)
+}
+
+// ── Transport ────────────────────────────────────────────────────
+
+/// Body of `POST /api/transport`: one playback action, so hardware surfaces
+/// (a Stream Deck, a foot pedal) can drive the playhead without knowing the
+/// full command schema.
+#[derive(Deserialize)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum TransportAction {
+    Play,
+    Pause,
+    /// Move the playhead to `time` seconds.
+    Seek { time: f64 },
+    /// Set the playback region, or clear it with `null`.
+    Region { region: Option<Region> },
+}
+
+impl TransportAction {
+    fn into_command(self) -> Command {
+        match self {
+            Self::Play => Command::Play,
+            Self::Pause => Command::Pause,
+            Self::Seek { time } => Command::Seek(SeekParams { time }),
+            Self::Region { region } => Command::SetRegion(SetRegionParams { region }),
+        }
+    }
+}
+
+/// Current playback state, as reported by `GetPlayback`.
+pub fn transport_state(state: &Arc<AppState>) -> Result<PlaybackInfo, AppError> {
+    match execute::execute(state, Command::GetPlayback)?.result {
+        CommandResult::GetPlayback(info) => Ok(info),
+        _ => Err(AppError::ApiError {
+            message: "GetPlayback returned an unexpected result".into(),
+        }),
+    }
+}
+
+/// Apply a transport action through the playback commands and return the
+/// resulting playback state.
+pub fn run_transport(
+    state: &Arc<AppState>,
+    action: TransportAction,
+) -> Result<PlaybackInfo, AppError> {
+    execute::execute(state, action.into_command())?;
+    transport_state(state)
+}
+
 // ── Scratch file helpers ─────────────────────────────────────────
 
 /// Threshold in bytes — data larger than this gets written to a scratch file.
@@ -125,6 +276,16 @@ async fn post_batch(
     }
 }
 
+async fn post_command_batch(
+    Extension(state): Extension<Arc<AppState>>,
+    Json(body): Json<CommandBatchRequest>,
+) -> impl IntoResponse {
+    match run_command_batch(&state, body) {
+        Ok(results) => ok_json(results).into_response(),
+        Err(e) => err_json(StatusCode::BAD_REQUEST, e).into_response(),
+    }
+}
+
 async fn get_show(Extension(state): Extension<Arc<AppState>>) -> impl IntoResponse {
     match chat::execute_tool_api(&state, "get_show", &serde_json::json!({})) {
         Ok(output) => ok_json(build_response(&state, "get_show", output)).into_response(),
@@ -139,6 +300,23 @@ async fn get_playback(Extension(state): Extension<Arc<AppState>>) -> impl IntoRe
     }
 }
 
+async fn get_transport(Extension(state): Extension<Arc<AppState>>) -> impl IntoResponse {
+    match transport_state(&state) {
+        Ok(info) => ok_json(info).into_response(),
+        Err(e) => err_json(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+async fn post_transport(
+    Extension(state): Extension<Arc<AppState>>,
+    Json(body): Json<TransportAction>,
+) -> impl IntoResponse {
+    match run_transport(&state, body) {
+        Ok(info) => ok_json(info).into_response(),
+        Err(e) => err_json(StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+    }
+}
+
 async fn get_analysis_summary(Extension(state): Extension<Arc<AppState>>) -> impl IntoResponse {
     match chat::execute_tool_api(&state, "get_analysis_summary", &serde_json::json!({})) {
         Ok(output) => {
@@ -158,8 +336,10 @@ pub async fn start_api_server(state: Arc<AppState>) -> Result<u16, String> {
         .route("/api/tools/{name}", post(post_tool))
         .route("/api/tools", get(get_tools))
         .route("/api/batch", post(post_batch))
+        .route("/api/commands/batch", post(post_command_batch))
         .route("/api/show", get(get_show))
         .route("/api/playback", get(get_playback))
+        .route("/api/transport", get(get_transport).post(post_transport))
         .route("/api/analysis/summary", get(get_analysis_summary))
         .layer(cors)
         .layer(Extension(state));
@@ -181,3 +361,175 @@ pub async fn start_api_server(state: Arc<AppState>) -> Result<u16, String> {
 
     Ok(port)
 }
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::indexing_slicing)]
+mod tests {
+    use super::*;
+
+    async fn post_batch_json(state: &Arc<AppState>, body: Value) -> Value {
+        let request: CommandBatchRequest = serde_json::from_value(body).unwrap();
+        let response = post_command_batch(Extension(Arc::clone(state)), Json(request))
+            .await
+            .into_response();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        serde_json::from_slice(&bytes).unwrap()
+    }
+
+    #[tokio::test]
+    async fn batch_runs_play_seek_pause_in_order() {
+        let state = Arc::new(AppState::for_test(crate::demo::create_demo_show()));
+        let body = serde_json::json!({
+            "commands": [
+                { "command": "Play" },
+                { "command": "Seek", "params": { "time": 12.5 } },
+                { "command": "Pause" },
+            ],
+        });
+
+        let response = post_batch_json(&state, body).await;
+        assert_eq!(response["ok"], true);
+        let results = response["data"].as_array().unwrap();
+        assert_eq!(results.len(), 3);
+        let commands: Vec<&str> = results
+            .iter()
+            .map(|r| {
+                assert_eq!(r["ok"], true);
+                r["data"]["result"]["command"].as_str().unwrap()
+            })
+            .collect();
+        assert_eq!(commands, ["Play", "Seek", "Pause"]);
+
+        let playback = state.playback.lock();
+        assert!(!playback.playing);
+        assert!((playback.current_time - 12.5).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn batch_items_are_audit_logged() {
+        let dir = std::env::temp_dir().join("vibelights_test_batch_audit");
+        let _ = std::fs::remove_dir_all(&dir);
+        let state = Arc::new(AppState {
+            app_config_dir: dir.clone(),
+            ..AppState::for_test(crate::demo::create_demo_show())
+        });
+        let body = serde_json::json!({
+            "commands": [
+                { "command": "Seek", "params": { "time": 3.0 } },
+                { "command": "DeleteTrack", "params": { "track_index": 99 } },
+            ],
+        });
+
+        post_batch_json(&state, body).await;
+
+        let logs = crate::paths::agent_logs_dir(&dir);
+        let file = std::fs::read_dir(&logs).unwrap().next().unwrap().unwrap();
+        let entries: Vec<Value> = std::fs::read_to_string(file.path())
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0]["tool"], "seek");
+        assert_eq!(entries[0]["input"]["time"], 3.0);
+        assert_eq!(entries[0]["ok"], true);
+        assert_eq!(entries[1]["ok"], false);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    async fn transport_json(state: &Arc<AppState>, body: Option<Value>) -> Value {
+        let extension = Extension(Arc::clone(state));
+        let response = match body {
+            Some(body) => {
+                let action: TransportAction = serde_json::from_value(body).unwrap();
+                post_transport(extension, Json(action)).await.into_response()
+            }
+            None => get_transport(extension).await.into_response(),
+        };
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        serde_json::from_slice(&bytes).unwrap()
+    }
+
+    #[tokio::test]
+    async fn transport_toggles_play_and_reports_state() {
+        let state = Arc::new(AppState::for_test(crate::demo::create_demo_show()));
+
+        let played = transport_json(&state, Some(serde_json::json!({ "action": "play" }))).await;
+        assert_eq!(played["ok"], true);
+        assert_eq!(played["data"]["playing"], true);
+        assert_eq!(transport_json(&state, None).await["data"]["playing"], true);
+
+        let body = serde_json::json!({ "action": "seek", "time": 4.0 });
+        assert_eq!(transport_json(&state, Some(body)).await["data"]["current_time"], 4.0);
+
+        let paused = transport_json(&state, Some(serde_json::json!({ "action": "pause" }))).await;
+        assert_eq!(paused["data"]["playing"], false);
+        let read_back = transport_json(&state, None).await;
+        assert_eq!(read_back["data"]["playing"], false);
+        assert_eq!(read_back["data"]["current_time"], 4.0);
+    }
+
+    #[tokio::test]
+    async fn stop_on_error_skips_remaining_commands() {
+        let state = Arc::new(AppState::for_test(crate::demo::create_demo_show()));
+        let body = serde_json::json!({
+            "commands": [{ "command": "Undo" }, { "command": "Play" }],
+            "stop_on_error": true,
+        });
+
+        let response = post_batch_json(&state, body).await;
+        let results = response["data"].as_array().unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0]["ok"], false);
+        assert!(!state.playback.lock().playing);
+    }
+
+    #[tokio::test]
+    async fn as_batch_edit_accepts_every_batch_action_command() {
+        let show = crate::demo::create_demo_show();
+        let sequence = show.sequences[0].name.clone();
+        let state = Arc::new(AppState::for_test(show));
+        *state.current_sequence.lock() = Some(sequence);
+        let body = serde_json::json!({
+            "commands": [
+                {
+                    "command": "SetTrackBlend",
+                    "params": { "track_index": 0, "blend_mode": "Multiply", "opacity": 0.5 },
+                },
+                { "command": "SetTrackLocked", "params": { "track_index": 0, "locked": true } },
+            ],
+            "as_batch_edit": true,
+        });
+
+        let response = post_batch_json(&state, body).await;
+        let results = response["data"].as_array().unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0]["ok"], true);
+        let show = state.show.lock();
+        let track = &show.sequences[0].tracks[0];
+        assert_eq!(track.blend_mode, crate::model::BlendMode::Multiply);
+        assert!(track.locked);
+    }
+
+    #[test]
+    fn batch_actions_round_trip_through_commands() {
+        let actions: Vec<BatchAction> = serde_json::from_value(serde_json::json!([
+            { "action": "set_track_blend",
+              "params": { "track_index": 1, "blend_mode": "Add", "opacity": 1.0 } },
+            { "action": "write_script", "params": { "name": "s", "source": "" } },
+            { "action": "delete_track", "params": { "track_index": 2 } },
+        ]))
+        .unwrap();
+        for action in actions {
+            let name = serde_json::to_value(&action).unwrap()["action"].clone();
+            let back = BatchAction::try_from(Command::from(action)).unwrap();
+            assert_eq!(serde_json::to_value(&back).unwrap()["action"], name);
+        }
+        assert!(BatchAction::try_from(Command::Play).is_err());
+    }
+}
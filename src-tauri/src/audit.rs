@@ -26,7 +26,8 @@ struct ToolAuditEntry<'a> {
 /// Log a single tool execution to today's JSONL audit file.
 ///
 /// This is best-effort: failures are silently ignored so they never
-/// affect the caller's control flow.
+/// affect the caller's control flow. Headless state has no config
+/// directory, so nothing is logged there.
 pub fn log_tool_call(
     app_config_dir: &Path,
     conversation_id: Option<&str>,
@@ -35,6 +36,10 @@ pub fn log_tool_call(
     result: Result<&str, &str>,
     duration: Duration,
 ) {
+    if app_config_dir.as_os_str().is_empty() {
+        return;
+    }
+
     let now = SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .unwrap_or_default()
@@ -1,23 +1,26 @@
 // CLI binary — panicking on unrecoverable errors is standard for CLI tools.
 #![allow(clippy::unwrap_used, clippy::expect_used, clippy::unreachable, clippy::indexing_slicing)]
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process;
 use std::sync::atomic::AtomicU16;
 use std::sync::Arc;
 
 use parking_lot::Mutex;
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use clap::{Parser, Subcommand};
 use serde_json::Value;
 
-use vibe_lights::dispatcher::CommandDispatcher;
+use vibe_lights::dispatcher::{CommandDispatcher, DEFAULT_MAX_UNDO_LEVELS};
+use vibe_lights::export::fseq;
 use vibe_lights::model::Show;
 use vibe_lights::registry::{self, Command, CommandOutput};
 use vibe_lights::settings;
-use vibe_lights::state::{AppState, CancellationRegistry, PlaybackState};
+use vibe_lights::state::{
+    AnalysisCache, AppState, CancellationRegistry, FrameCache, PlaybackState, ThumbnailCache,
+};
 
 // ── CLI argument parsing ─────────────────────────────────────────
 
@@ -164,6 +167,12 @@ enum Commands {
         #[arg(long, default_value = "20")]
         iterations: usize,
     },
+    /// Render the --setup/--sequence to an FSEQ file without the GUI
+    Render {
+        /// Output .fseq path
+        #[arg(long)]
+        output: PathBuf,
+    },
 }
 
 #[derive(Subcommand)]
@@ -246,12 +255,18 @@ fn initialize_state(
         playback: Mutex::new(PlaybackState {
             playing: false,
             current_time: 0.0,
+            frame_index: 0,
             sequence_index: 0,
-            last_tick: None,
+            clock_anchor: None,
             region: None,
             looping: false,
+            solo_tracks: HashSet::new(),
         }),
-        dispatcher: Mutex::new(CommandDispatcher::new()),
+        dispatcher: Mutex::new(CommandDispatcher::with_max_undo_levels(
+            loaded_settings
+                .as_ref()
+                .map_or(DEFAULT_MAX_UNDO_LEVELS, |s| s.max_undo_levels),
+        )),
         app_config_dir,
         settings: Mutex::new(loaded_settings.clone()),
         current_setup: Mutex::new(None),
@@ -259,7 +274,8 @@ fn initialize_state(
         script_cache: Mutex::new(HashMap::new()),
         python_sidecar: Mutex::new(None),
         python_port: AtomicU16::new(0),
-        analysis_cache: Mutex::new(indexmap::IndexMap::new()),
+        analysis_cache: Mutex::new(AnalysisCache::new()),
+        waveform_cache: Mutex::new(indexmap::IndexMap::new()),
         agent_sidecar: Mutex::new(None),
         agent_port: AtomicU16::new(0),
         agent_session_id: Mutex::new(None),
@@ -268,6 +284,10 @@ fn initialize_state(
         cancellation: CancellationRegistry::new(),
         global_libraries: Mutex::new(global_libs),
         api_port: AtomicU16::new(0),
+        thumbnail_cache: ThumbnailCache::new(),
+        frame_cache: FrameCache::new(),
+        tick_baselines: Mutex::new(HashMap::new()),
+        live_output: Mutex::new(None),
     });
 
     // Load agent chat history
@@ -458,8 +478,8 @@ fn build_command(cmd: &Commands) -> Command {
             process::exit(1);
         }
         Commands::ChatClear => Command::NewAgentConversation,
-        // Bench is handled separately before this function is called
-        Commands::Bench { .. } => unreachable!(),
+        // Bench and Render are handled separately before this function is called
+        Commands::Bench { .. } | Commands::Render { .. } => unreachable!(),
     }
 }
 
@@ -548,7 +568,7 @@ fn run_bench(data_dir: &str, setup_slug: &str, sequence_slug: &str, time: f64, i
 
     // Measure serialization overhead
     {
-        let frame = engine::evaluate(&show, 0, time, None, None, &HashMap::new(), &HashMap::new());
+        let frame = engine::evaluate(&show, 0, time, None, None, &HashMap::new(), &HashMap::new(), &HashSet::new(), None);
         let json = serde_json::to_string(&frame).unwrap();
         eprintln!("Frame JSON size: {} bytes ({:.1} KB)", json.len(), json.len() as f64 / 1024.0);
         eprintln!("Frame fixture count: {}", frame.fixtures.len());
@@ -557,7 +577,7 @@ fn run_bench(data_dir: &str, setup_slug: &str, sequence_slug: &str, time: f64, i
 
         let start = std::time::Instant::now();
         for _ in 0..20 {
-            let f = engine::evaluate(&show, 0, time, None, None, &HashMap::new(), &HashMap::new());
+            let f = engine::evaluate(&show, 0, time, None, None, &HashMap::new(), &HashMap::new(), &HashSet::new(), None);
             let j = serde_json::to_string(&f).unwrap();
             std::hint::black_box(&j);
         }
@@ -565,7 +585,7 @@ fn run_bench(data_dir: &str, setup_slug: &str, sequence_slug: &str, time: f64, i
         eprintln!("Eval + serialize: {ser_time:?}");
 
         // Measure serialize alone
-        let frame2 = engine::evaluate(&show, 0, time, None, None, &HashMap::new(), &HashMap::new());
+        let frame2 = engine::evaluate(&show, 0, time, None, None, &HashMap::new(), &HashMap::new(), &HashSet::new(), None);
         let start2 = std::time::Instant::now();
         for _ in 0..20 {
             let j = serde_json::to_string(&frame2).unwrap();
@@ -577,14 +597,14 @@ fn run_bench(data_dir: &str, setup_slug: &str, sequence_slug: &str, time: f64, i
 
     // Warmup
     eprintln!("\nWarmup...");
-    let _ = engine::evaluate(&show, 0, time, None, None, &HashMap::new(), &HashMap::new());
+    let _ = engine::evaluate(&show, 0, time, None, None, &HashMap::new(), &HashMap::new(), &HashSet::new(), None);
 
     // Benchmark (evaluate only)
     eprintln!("Benchmarking {iterations} iterations at t={time}...\n");
     let mut times = Vec::with_capacity(iterations);
     for _ in 0..iterations {
         let start = Instant::now();
-        let frame = engine::evaluate(&show, 0, time, None, None, &HashMap::new(), &HashMap::new());
+        let frame = engine::evaluate(&show, 0, time, None, None, &HashMap::new(), &HashMap::new(), &HashSet::new(), None);
         let elapsed = start.elapsed();
         times.push(elapsed);
         std::hint::black_box(&frame);
@@ -609,6 +629,54 @@ fn run_bench(data_dir: &str, setup_slug: &str, sequence_slug: &str, time: f64, i
     eprintln!("  fps:    {:.1}", 1.0 / avg.as_secs_f64());
 }
 
+// ── Render mode ─────────────────────────────────────────────────
+
+fn run_render(cli: &Cli, output: &Path) {
+    let (Some(setup_slug), Some(sequence_slug)) = (cli.setup.as_deref(), cli.sequence.as_deref())
+    else {
+        eprintln!("Error: render requires --setup and --sequence");
+        process::exit(1);
+    };
+    let loaded_settings = settings::load_settings(&dirs_config_dir());
+    let app_settings = match (cli.data_dir.as_deref(), loaded_settings) {
+        (Some(dd), Some(mut s)) => {
+            s.data_dir = PathBuf::from(dd);
+            s
+        }
+        (Some(dd), None) => settings::AppSettings::new(PathBuf::from(dd)),
+        (None, Some(s)) => s,
+        (None, None) => {
+            eprintln!("Error: no data directory configured; pass --data-dir");
+            process::exit(1);
+        }
+    };
+    let output_settings = app_settings.output;
+
+    let start = std::time::Instant::now();
+    let rendered = vibe_lights::render::render_sequence(app_settings, setup_slug, sequence_slug)
+        .unwrap_or_else(|e| {
+            eprintln!("Error: {e}");
+            process::exit(1);
+        });
+    let bytes = fseq::write_fseq(
+        &rendered.show,
+        &rendered.frames,
+        rendered.frame_rate,
+        output_settings,
+    );
+    if let Err(e) = std::fs::write(output, &bytes) {
+        eprintln!("Error: failed to write {}: {e}", output.display());
+        process::exit(1);
+    }
+    eprintln!(
+        "Rendered {} frames ({} channels) to {} in {:?}",
+        rendered.frames.len(),
+        fseq::channel_count(&rendered.show),
+        output.display(),
+        start.elapsed()
+    );
+}
+
 // ── Main ─────────────────────────────────────────────────────────
 
 #[tokio::main]
@@ -627,6 +695,10 @@ async fn main() {
         run_bench(data_dir, setup, sequence, *time, *iterations);
         return;
     }
+    if let Commands::Render { output } = &cli.command {
+        run_render(&cli, output);
+        return;
+    }
 
     // Initialize state from disk
     let state = initialize_state(
@@ -1,11 +1,14 @@
+use std::fmt::Write;
 use std::sync::Arc;
 use std::time::Instant;
 
 use serde::{Deserialize, Serialize};
+use schemars::JsonSchema;
 use serde_json::Value;
 use ts_rs::TS;
 
 use crate::registry;
+use crate::settings::{LlmProviderConfig, LlmProviderKind, ModelRate};
 use crate::state::AppState;
 
 // ── ChatEmitter trait ────────────────────────────────────────────
@@ -85,6 +88,79 @@ pub enum ChatRole {
 pub struct ChatHistoryEntry {
     pub role: ChatRole,
     pub text: String,
+    /// Tokens the provider reported for the turn that produced this entry.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub usage: Option<TokenUsage>,
+    /// Command name for `Tool` entries, whose text is the command's output message.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tool: Option<String>,
+}
+
+/// File format for an exported conversation transcript.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS, JsonSchema)]
+#[ts(export)]
+pub enum TranscriptFormat {
+    Markdown,
+    Json,
+}
+
+/// Prompt/response token counts reported by the LLM provider.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct TokenUsage {
+    #[serde(default)]
+    pub input_tokens: u64,
+    #[serde(default)]
+    pub output_tokens: u64,
+}
+
+impl TokenUsage {
+    #[must_use]
+    pub fn total(self) -> u64 {
+        self.input_tokens + self.output_tokens
+    }
+
+    /// Estimated cost in USD at the given rate.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn cost(self, rate: &ModelRate) -> f64 {
+        (self.input_tokens as f64 * rate.input_per_mtok
+            + self.output_tokens as f64 * rate.output_per_mtok)
+            / 1_000_000.0
+    }
+}
+
+impl std::ops::Add for TokenUsage {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            input_tokens: self.input_tokens + other.input_tokens,
+            output_tokens: self.output_tokens + other.output_tokens,
+        }
+    }
+}
+
+impl std::ops::AddAssign for TokenUsage {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl std::iter::Sum for TokenUsage {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), |acc, u| acc + u)
+    }
+}
+
+/// Cumulative usage for one conversation, returned by `GetConversationUsage`.
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct ConversationUsage {
+    pub conversation_id: String,
+    pub usage: TokenUsage,
+    /// Estimated cost in USD. None when no rate is configured for the model.
+    pub estimated_cost: Option<f64>,
 }
 
 // ── Tool execution ───────────────────────────────────────────────
@@ -125,6 +201,97 @@ fn execute_tool_api_inner(
     registry::execute::execute(state, cmd).map_err(|e| e.to_string())
 }
 
+// ── LLM providers ────────────────────────────────────────────────
+
+/// Connection details for a particular LLM API, exported to the agent
+/// sidecar as environment variables.
+pub trait LlmProvider: Send + Sync {
+    fn kind(&self) -> LlmProviderKind;
+
+    /// Base URL requests are sent to.
+    fn base_url(&self) -> &str;
+
+    /// Environment variable the API key is exported as for the agent sidecar.
+    fn api_key_env(&self) -> &'static str;
+
+    /// Environment variable the base URL is exported as for the agent sidecar.
+    fn base_url_env(&self) -> &'static str;
+}
+
+/// Anthropic Messages API.
+pub struct AnthropicProvider {
+    base_url: String,
+}
+
+impl LlmProvider for AnthropicProvider {
+    fn kind(&self) -> LlmProviderKind {
+        LlmProviderKind::Anthropic
+    }
+
+    fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    fn api_key_env(&self) -> &'static str {
+        "ANTHROPIC_API_KEY"
+    }
+
+    fn base_url_env(&self) -> &'static str {
+        "ANTHROPIC_BASE_URL"
+    }
+}
+
+/// OpenAI chat-completions API. Also used for OpenAI-compatible local
+/// servers, which differ only in base URL.
+pub struct OpenAiProvider {
+    kind: LlmProviderKind,
+    base_url: String,
+}
+
+impl LlmProvider for OpenAiProvider {
+    fn kind(&self) -> LlmProviderKind {
+        self.kind
+    }
+
+    fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    fn api_key_env(&self) -> &'static str {
+        "OPENAI_API_KEY"
+    }
+
+    fn base_url_env(&self) -> &'static str {
+        "OPENAI_BASE_URL"
+    }
+}
+
+const ANTHROPIC_BASE_URL: &str = "https://api.anthropic.com";
+const OPENAI_BASE_URL: &str = "https://api.openai.com/v1";
+
+/// Build the provider described by `config`. A configured `base_url` overrides
+/// the provider default.
+pub fn llm_provider(config: &LlmProviderConfig) -> Box<dyn LlmProvider> {
+    let base_url = |default: &str| {
+        config
+            .base_url
+            .clone()
+            .filter(|u| !u.is_empty())
+            .unwrap_or_else(|| default.to_string())
+    };
+    match config.provider {
+        LlmProviderKind::Anthropic => Box::new(AnthropicProvider {
+            base_url: base_url(ANTHROPIC_BASE_URL),
+        }),
+        kind @ (LlmProviderKind::OpenAi | LlmProviderKind::OpenAiCompatible) => {
+            Box::new(OpenAiProvider {
+                kind,
+                base_url: base_url(OPENAI_BASE_URL),
+            })
+        }
+    }
+}
+
 // ── Agent chat persistence (multi-conversation) ────────────────
 
 /// A single conversation in the agent chat history.
@@ -137,6 +304,13 @@ pub struct AgentConversation {
     pub messages: Vec<ChatHistoryEntry>,
 }
 
+impl AgentConversation {
+    /// Total tokens across every message in the conversation.
+    pub fn usage(&self) -> TokenUsage {
+        self.messages.iter().filter_map(|m| m.usage).sum()
+    }
+}
+
 /// Root structure stored in `{app_config_dir}/agent-chats.json`.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct AgentChatsData {
@@ -153,6 +327,7 @@ pub struct ConversationSummary {
     pub created_at: String,
     pub message_count: usize,
     pub is_active: bool,
+    pub usage: TokenUsage,
 }
 
 const MAX_CONVERSATIONS: usize = 20;
@@ -402,10 +577,85 @@ pub fn list_agent_conversations(state: &Arc<AppState>) -> Vec<ConversationSummar
             created_at: c.created_at.clone(),
             message_count: c.messages.len(),
             is_active: chats.active_id.as_deref() == Some(&c.id),
+            usage: c.usage(),
         })
         .collect()
 }
 
+/// Cumulative token usage for a conversation, priced at the configured rate
+/// for the current model.
+pub fn agent_conversation_usage(
+    state: &Arc<AppState>,
+    id: &str,
+) -> Result<ConversationUsage, String> {
+    {
+        let mut chats = state.agent_chats.lock();
+        sync_active_to_chats(state, &mut chats);
+    }
+
+    let usage = state
+        .agent_chats
+        .lock()
+        .conversations
+        .iter()
+        .find(|c| c.id == id)
+        .map(AgentConversation::usage)
+        .ok_or_else(|| format!("Conversation '{id}' not found"))?;
+
+    let estimated_cost = state
+        .settings
+        .lock()
+        .as_ref()
+        .and_then(|s| s.llm.rate_for_current_model())
+        .map(|rate| usage.cost(&rate));
+
+    Ok(ConversationUsage {
+        conversation_id: id.to_string(),
+        usage,
+        estimated_cost,
+    })
+}
+
+/// Render a conversation (including the active one's unsaved messages) as a
+/// transcript in the given format.
+pub fn export_agent_conversation(
+    state: &Arc<AppState>,
+    id: &str,
+    format: TranscriptFormat,
+) -> Result<String, String> {
+    let mut chats = state.agent_chats.lock();
+    sync_active_to_chats(state, &mut chats);
+    let conv = chats
+        .conversations
+        .iter()
+        .find(|c| c.id == id)
+        .ok_or_else(|| format!("Conversation '{id}' not found"))?;
+    match format {
+        TranscriptFormat::Markdown => Ok(transcript_markdown(conv)),
+        TranscriptFormat::Json => serde_json::to_string_pretty(conv)
+            .map_err(|e| format!("Failed to serialize conversation: {e}")),
+    }
+}
+
+fn transcript_markdown(conv: &AgentConversation) -> String {
+    let mut out = format!("# {}\n\n_Started {}_\n", conv.title, conv.created_at);
+    for entry in &conv.messages {
+        match entry.role {
+            ChatRole::User => {
+                let _ = write!(out, "\n## User\n\n{}\n", entry.text);
+            }
+            ChatRole::Assistant => {
+                let _ = write!(out, "\n## Assistant\n\n{}\n", entry.text);
+            }
+            ChatRole::Tool => {
+                let tool = entry.tool.as_deref().unwrap_or("tool");
+                let _ = write!(out, "\n**Tool call:** `{tool}`\n\n```\n{}\n```\n", entry.text);
+            }
+        }
+    }
+    out
+}
+
 /// Clear agent chat state (in-memory and on disk). Used for full reset.
 pub fn clear_agent_chat(state: &Arc<AppState>) {
     *state.agent_session_id.lock() = None;
@@ -414,3 +664,73 @@ pub fn clear_agent_chat(state: &Arc<AppState>) {
     let path = crate::paths::agent_chats_file_path(&state.app_config_dir);
     let _ = std::fs::remove_file(&path);
 }
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic, clippy::indexing_slicing)]
+mod tests {
+    use super::*;
+
+    fn provider(kind: LlmProviderKind) -> Box<dyn LlmProvider> {
+        llm_provider(&LlmProviderConfig {
+            provider: kind,
+            base_url: (kind == LlmProviderKind::OpenAiCompatible)
+                .then(|| "http://localhost:1234/v1".to_string()),
+            ..LlmProviderConfig::default()
+        })
+    }
+
+    #[test]
+    fn compatible_provider_uses_configured_base_url() {
+        assert_eq!(
+            provider(LlmProviderKind::OpenAiCompatible).base_url(),
+            "http://localhost:1234/v1"
+        );
+        assert_eq!(provider(LlmProviderKind::OpenAi).base_url(), OPENAI_BASE_URL);
+        assert_eq!(provider(LlmProviderKind::Anthropic).base_url(), ANTHROPIC_BASE_URL);
+    }
+
+    #[test]
+    fn usage_accumulates_across_messages() {
+        let entry = |role, input_tokens, output_tokens| ChatHistoryEntry {
+            role,
+            text: String::new(),
+            usage: Some(TokenUsage {
+                input_tokens,
+                output_tokens,
+            }),
+            tool: None,
+        };
+        let conv = AgentConversation {
+            id: "c1".into(),
+            created_at: String::new(),
+            title: "Test".into(),
+            session_id: None,
+            messages: vec![
+                ChatHistoryEntry {
+                    role: ChatRole::User,
+                    text: "hi".into(),
+                    usage: None,
+                    tool: None,
+                },
+                entry(ChatRole::Assistant, 1200, 300),
+                entry(ChatRole::Assistant, 800, 200),
+            ],
+        };
+        let usage = conv.usage();
+        assert_eq!(usage.input_tokens, 2000);
+        assert_eq!(usage.output_tokens, 500);
+
+        let rate = ModelRate {
+            input_per_mtok: 3.0,
+            output_per_mtok: 15.0,
+        };
+        assert!((usage.cost(&rate) - 0.0135).abs() < 1e-12);
+    }
+
+    #[test]
+    fn entries_without_usage_deserialize_as_none() {
+        let entry: ChatHistoryEntry =
+            serde_json::from_str(r#"{"role":"assistant","text":"ok"}"#).unwrap();
+        assert!(entry.usage.is_none());
+    }
+}
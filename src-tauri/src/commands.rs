@@ -52,6 +52,16 @@ pub struct TickResult {
     pub frame: crate::engine::Frame,
     pub current_time: f64,
     pub playing: bool,
+    /// Number of this frame. Pass it back as `TickParams::since` to receive
+    /// only what changes on the next tick.
+    #[ts(type = "number")]
+    pub seq: u64,
+    /// Whether `frame` holds only the fixtures that changed since the frame
+    /// numbered `since`, rather than every lit fixture.
+    pub delta: bool,
+    /// In a delta, fixtures that went dark since that frame. Full frames
+    /// leave dark fixtures out, so a delta lists them here.
+    pub cleared: Vec<u32>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, ts_rs::TS)]
@@ -59,6 +69,8 @@ pub struct TickResult {
 pub struct ScriptCompileResult {
     pub success: bool,
     pub errors: Vec<ScriptError>,
+    /// Non-fatal diagnostics from a successful compile.
+    pub warnings: Vec<ScriptError>,
     pub name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub params: Option<Vec<ScriptParamInfo>>,
@@ -72,6 +84,17 @@ pub struct ScriptParamInfo {
     pub default: Option<crate::model::timeline::ParamValue>,
 }
 
+/// Descriptive metadata from a script's `@` directives.
+#[derive(Debug, Clone, serde::Serialize, ts_rs::TS)]
+#[ts(export)]
+pub struct ScriptMeta {
+    /// `@title`, falling back to `@name`.
+    pub title: Option<String>,
+    pub author: Option<String>,
+    pub description: Option<String>,
+    pub spatial: bool,
+}
+
 #[derive(Debug, Clone, serde::Serialize, ts_rs::TS)]
 #[ts(export)]
 pub struct ScriptPreviewData {
@@ -80,11 +103,36 @@ pub struct ScriptPreviewData {
     pub pixels: Vec<u8>,
 }
 
+/// VM instruction counts from evaluating one frame of a script.
+#[derive(Debug, Clone, serde::Serialize, ts_rs::TS)]
+#[ts(export)]
+pub struct ScriptProfile {
+    pub pixel_count: usize,
+    #[ts(type = "number")]
+    pub total_ops: u64,
+    pub ops_per_pixel: f64,
+    /// Executed count per op kind, most frequent first.
+    pub ops: Vec<ScriptOpCount>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ts_rs::TS)]
+#[ts(export)]
+pub struct ScriptOpCount {
+    pub op: String,
+    #[ts(type = "number")]
+    pub count: u64,
+}
+
 #[derive(Debug, Clone, serde::Serialize, ts_rs::TS)]
 #[ts(export)]
 pub struct ScriptError {
     pub message: String,
+    /// Byte offset into the source.
     pub offset: usize,
+    /// 1-based line of `offset`.
+    pub line: usize,
+    /// 1-based column of `offset`, in characters.
+    pub col: usize,
 }
 
 #[derive(Debug, Clone, serde::Serialize, ts_rs::TS)]
@@ -97,28 +145,36 @@ pub struct EffectThumbnail {
     pub end_time: f64,
 }
 
+/// Fingerprint of a sequence's rendered output, for regression checks.
+#[derive(Debug, Clone, serde::Serialize, ts_rs::TS)]
+#[ts(export)]
+pub struct FrameDigest {
+    /// 64-bit FNV-1a hash of the sampled frames, as 16 hex digits.
+    pub digest: String,
+    /// Number of frames that went into the digest.
+    pub frames: usize,
+}
+
 // ── Helper functions (used by registry handlers) ─────────────────
 
 /// Recompile all scripts from the global library
-/// (e.g., after loading a show from disk).
+/// (e.g., after loading a show from disk, or after any library script
+/// changes, since scripts inline the functions of the libraries they `use`).
 /// Returns a list of scripts that failed to compile.
 pub fn recompile_all_scripts(state: &AppState) -> Vec<String> {
-    let sources: Vec<(String, String)> = {
-        let libs = state.global_libraries.lock();
-        libs.scripts.iter().map(|(k, v)| (k.clone(), v.clone())).collect()
-    };
+    let sources = state.global_libraries.lock().scripts.clone();
 
     let mut failures = Vec::new();
     let mut cache = state.script_cache.lock();
     cache.clear();
 
-    for (name, source) in sources {
-        match dsl::compile_source(&source) {
+    for (name, source) in &sources {
+        match dsl::compile_source_with(source, |lib| sources.get(lib).cloned()) {
             Ok(compiled) => {
-                cache.insert(name, std::sync::Arc::new(compiled));
+                cache.insert(name.clone(), std::sync::Arc::new(compiled));
             }
             Err(_) => {
-                failures.push(name);
+                failures.push(name.clone());
             }
         }
     }
@@ -29,6 +29,7 @@ pub fn create_demo_show() -> Show {
             bulb_shape: BulbShape::default(),
             display_radius_override: None,
             channel_order: ChannelOrder::default(),
+            gamma: None,
         });
 
         let pixel_positions: Vec<Position2D> = (0..cols)
@@ -42,6 +43,7 @@ pub fn create_demo_show() -> Show {
             fixture_id,
             pixel_positions,
             shape: LayoutShape::default(),
+            preview_gain: None,
         });
     }
 
@@ -57,12 +59,21 @@ pub fn create_demo_show() -> Show {
         duration: 30.0,
         frame_rate: 30.0,
         audio_file: None,
+        audio_tracks: Vec::new(),
         motion_paths: std::collections::HashMap::new(),
+        markers: Vec::new(),
+        cues: Vec::new(),
+        region: None,
+        looping: false,
         tracks: vec![
             // Base layer: slow rainbow across all strings.
             Track {
                 name: "Rainbow Base".into(),
                 target: EffectTarget::Group(GroupId(0)),
+                locked: false,
+                muted: false,
+                blend_mode: BlendMode::Override,
+                opacity: 1.0,
                 effects: vec![EffectInstance {
                     kind: EffectKind::Rainbow,
                     params: EffectParams::new()
@@ -72,12 +83,19 @@ pub fn create_demo_show() -> Show {
                     time_range: TimeRange::new(0.0, 30.0).unwrap(),
                     blend_mode: BlendMode::Override,
                     opacity: 1.0,
+                    opacity_curve: None,
+                    fade_in: 0.0,
+                    fade_out: 0.0,
                 }],
             },
             // Chase on top strings, additive.
             Track {
                 name: "Chase Top".into(),
                 target: EffectTarget::Fixtures(vec![FixtureId(0), FixtureId(1)]),
+                locked: false,
+                muted: false,
+                blend_mode: BlendMode::Override,
+                opacity: 1.0,
                 effects: vec![EffectInstance {
                     kind: EffectKind::Chase,
                     params: EffectParams::new()
@@ -89,12 +107,19 @@ pub fn create_demo_show() -> Show {
                     time_range: TimeRange::new(0.0, 20.0).unwrap(),
                     blend_mode: BlendMode::Add,
                     opacity: 1.0,
+                    opacity_curve: None,
+                    fade_in: 0.0,
+                    fade_out: 0.0,
                 }],
             },
             // Twinkle overlay on bottom strings.
             Track {
                 name: "Twinkle Bottom".into(),
                 target: EffectTarget::Fixtures(vec![FixtureId(3), FixtureId(4)]),
+                locked: false,
+                muted: false,
+                blend_mode: BlendMode::Override,
+                opacity: 1.0,
                 effects: vec![EffectInstance {
                     kind: EffectKind::Twinkle,
                     params: EffectParams::new()
@@ -104,12 +129,19 @@ pub fn create_demo_show() -> Show {
                     time_range: TimeRange::new(0.0, 30.0).unwrap(),
                     blend_mode: BlendMode::Add,
                     opacity: 1.0,
+                    opacity_curve: None,
+                    fade_in: 0.0,
+                    fade_out: 0.0,
                 }],
             },
             // Strobe burst in the middle, 15-20 seconds.
             Track {
                 name: "Strobe Burst".into(),
                 target: EffectTarget::Fixtures(vec![FixtureId(2)]),
+                locked: false,
+                muted: false,
+                blend_mode: BlendMode::Override,
+                opacity: 1.0,
                 effects: vec![EffectInstance {
                     kind: EffectKind::Strobe,
                     params: EffectParams::new()
@@ -119,12 +151,19 @@ pub fn create_demo_show() -> Show {
                     time_range: TimeRange::new(15.0, 20.0).unwrap(),
                     blend_mode: BlendMode::Max,
                     opacity: 1.0,
+                    opacity_curve: None,
+                    fade_in: 0.0,
+                    fade_out: 0.0,
                 }],
             },
             // Gradient sweep at the end.
             Track {
                 name: "Gradient Finale".into(),
                 target: EffectTarget::Group(GroupId(0)),
+                locked: false,
+                muted: false,
+                blend_mode: BlendMode::Override,
+                opacity: 1.0,
                 effects: vec![EffectInstance {
                     kind: EffectKind::Gradient,
                     params: EffectParams::new()
@@ -140,6 +179,9 @@ pub fn create_demo_show() -> Show {
                     time_range: TimeRange::new(20.0, 30.0).unwrap(),
                     blend_mode: BlendMode::Alpha,
                     opacity: 1.0,
+                    opacity_curve: None,
+                    fade_in: 0.0,
+                    fade_out: 0.0,
                 }],
             },
         ],
@@ -87,6 +87,19 @@ pub fn describe_sequence(seq: &Sequence) -> String {
     if let Some(ref audio) = seq.audio_file {
         lines.push(format!("Audio: {audio}"));
     }
+    if seq.audio_tracks.len() > 1 {
+        lines.push(format!("Audio tracks ({})", seq.audio_tracks.len()));
+        for (i, layer) in seq.audio_tracks.iter().enumerate() {
+            let mut line = format!("  [{i}] {} gain={:.2}", layer.path, layer.gain);
+            if seq.audio_file.as_ref() == Some(&layer.path) {
+                line.push_str(" (primary)");
+            }
+            if layer.muted {
+                line.push_str(" (muted)");
+            }
+            lines.push(line);
+        }
+    }
 
     lines.push(format!("\nTracks ({})", seq.tracks.len()));
     for (i, track) in seq.tracks.iter().enumerate() {
@@ -5,10 +5,11 @@ use ts_rs::TS;
 
 use crate::error::AppError;
 use crate::model::{
-    BlendMode, EffectInstance, EffectKind, EffectParams, EffectTarget,
+    AudioTrackRef, BlendMode, Cue, Curve, EffectInstance, EffectKind, EffectParams, EffectTarget, Marker,
     ParamKey, ParamValue, Sequence, TimeRange,
 };
 use crate::registry::params::FieldUpdate;
+use crate::setup::Setup;
 
 /// An undoable editing command. Each variant corresponds to one user action.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -22,6 +23,12 @@ pub enum EditCommand {
         blend_mode: BlendMode,
         opacity: f64,
     },
+    /// Insert a fully-configured effect, e.g. one instantiated from a preset.
+    InsertEffect {
+        sequence_index: usize,
+        track_index: usize,
+        effect: EffectInstance,
+    },
     DeleteEffects {
         sequence_index: usize,
         targets: Vec<(usize, usize)>,
@@ -33,6 +40,13 @@ pub enum EditCommand {
         key: ParamKey,
         value: ParamValue,
     },
+    /// Replace an effect's params wholesale, e.g. to reset them to defaults.
+    SetEffectParams {
+        sequence_index: usize,
+        track_index: usize,
+        effect_index: usize,
+        params: EffectParams,
+    },
     UpdateEffectTimeRange {
         sequence_index: usize,
         track_index: usize,
@@ -40,21 +54,92 @@ pub enum EditCommand {
         start: f64,
         end: f64,
     },
+    /// Shift a set of `(track_index, effect_index)` effects by `delta` seconds.
+    NudgeEffects {
+        sequence_index: usize,
+        targets: Vec<(usize, usize)>,
+        delta: f64,
+    },
     MoveEffectToTrack {
         sequence_index: usize,
         from_track: usize,
         effect_index: usize,
         to_track: usize,
     },
+    SetEffectOpacityCurve {
+        sequence_index: usize,
+        track_index: usize,
+        effect_index: usize,
+        curve: Option<Curve>,
+    },
+    SetEffectFades {
+        sequence_index: usize,
+        track_index: usize,
+        effect_index: usize,
+        fade_in: f64,
+        fade_out: f64,
+    },
     AddTrack {
         sequence_index: usize,
         name: String,
         target: EffectTarget,
     },
+    /// Append fully-built tracks (with their effects), e.g. from another sequence.
+    AddTracks {
+        sequence_index: usize,
+        tracks: Vec<crate::model::Track>,
+    },
     DeleteTrack {
         sequence_index: usize,
         track_index: usize,
     },
+    ReorderTrack {
+        sequence_index: usize,
+        from_index: usize,
+        to_index: usize,
+    },
+    /// Undoable because undo restores whole-sequence snapshots: a lock change
+    /// outside the undo stack would be silently reverted by undoing an older edit.
+    SetTrackLocked {
+        sequence_index: usize,
+        track_index: usize,
+        locked: bool,
+    },
+    /// Set how a whole track combines with the tracks below it.
+    SetTrackBlend {
+        sequence_index: usize,
+        track_index: usize,
+        blend_mode: BlendMode,
+        opacity: f64,
+    },
+    /// Insert a marker, keeping the sequence's markers sorted by time.
+    AddMarker {
+        sequence_index: usize,
+        marker: Marker,
+    },
+    DeleteMarker {
+        sequence_index: usize,
+        marker_index: usize,
+    },
+    /// Insert a cue, keeping the sequence's cues sorted by time.
+    AddCue {
+        sequence_index: usize,
+        cue: Cue,
+    },
+    DeleteCue {
+        sequence_index: usize,
+        cue_index: usize,
+    },
+    /// Add an audio layer; `primary` also makes it the file analysis runs on.
+    AddAudioTrack {
+        sequence_index: usize,
+        track: AudioTrackRef,
+        primary: bool,
+    },
+    RemoveAudioTrack {
+        sequence_index: usize,
+        audio_index: usize,
+    },
     UpdateSequenceSettings {
         sequence_index: usize,
         name: Option<String>,
@@ -69,10 +154,45 @@ pub enum EditCommand {
 }
 
 impl EditCommand {
+    /// Where track `index` of sequence `sequence_index` ends up once this
+    /// command has run, or `None` if the command deletes it.
+    pub fn remap_track(&self, sequence_index: usize, index: usize) -> Option<usize> {
+        match self {
+            EditCommand::DeleteTrack { sequence_index: seq, track_index }
+                if *seq == sequence_index =>
+            {
+                match index.cmp(track_index) {
+                    std::cmp::Ordering::Less => Some(index),
+                    std::cmp::Ordering::Equal => None,
+                    std::cmp::Ordering::Greater => Some(index - 1),
+                }
+            }
+            EditCommand::ReorderTrack { sequence_index: seq, from_index, to_index }
+                if *seq == sequence_index =>
+            {
+                let (from, to) = (*from_index, *to_index);
+                if index == from {
+                    Some(to)
+                } else if from < index && index <= to {
+                    Some(index - 1)
+                } else if to <= index && index < from {
+                    Some(index + 1)
+                } else {
+                    Some(index)
+                }
+            }
+            EditCommand::Batch { commands, .. } => commands
+                .iter()
+                .try_fold(index, |i, cmd| cmd.remap_track(sequence_index, i)),
+            _ => Some(index),
+        }
+    }
+
     /// Human-readable description for UI tooltips and chat context.
     pub fn description(&self) -> String {
         match self {
             EditCommand::AddEffect { kind, .. } => format!("Add {kind:?} effect"),
+            EditCommand::InsertEffect { effect, .. } => format!("Add {:?} effect", effect.kind),
             EditCommand::DeleteEffects { targets, .. } => {
                 let n = targets.len();
                 if n == 1 {
@@ -82,12 +202,44 @@ impl EditCommand {
                 }
             }
             EditCommand::UpdateEffectParam { key, .. } => format!("Update {key:?}"),
+            EditCommand::SetEffectParams { .. } => "Reset effect params".to_string(),
             EditCommand::UpdateEffectTimeRange { .. } => "Update effect timing".to_string(),
+            EditCommand::NudgeEffects { targets, .. } => {
+                let n = targets.len();
+                if n == 1 {
+                    "Nudge effect".to_string()
+                } else {
+                    format!("Nudge {n} effects")
+                }
+            }
             EditCommand::MoveEffectToTrack { .. } => "Move effect to track".to_string(),
+            EditCommand::SetEffectOpacityCurve { .. } => "Set effect opacity curve".to_string(),
+            EditCommand::SetEffectFades { .. } => "Set effect fades".to_string(),
             EditCommand::AddTrack { name, .. } => format!("Add track \"{name}\""),
+            EditCommand::AddTracks { tracks, .. } => format!("Add {} tracks", tracks.len()),
             EditCommand::DeleteTrack { track_index, .. } => {
                 format!("Delete track {track_index}")
             }
+            EditCommand::ReorderTrack { from_index, to_index, .. } => {
+                format!("Move track {from_index} to {to_index}")
+            }
+            EditCommand::SetTrackLocked { track_index, locked, .. } => {
+                let verb = if *locked { "Lock" } else { "Unlock" };
+                format!("{verb} track {track_index}")
+            }
+            EditCommand::SetTrackBlend { track_index, .. } => {
+                format!("Set blend of track {track_index}")
+            }
+            EditCommand::AddMarker { marker, .. } => format!("Add marker \"{}\"", marker.label),
+            EditCommand::DeleteMarker { marker_index, .. } => {
+                format!("Delete marker {marker_index}")
+            }
+            EditCommand::AddCue { cue, .. } => format!("Add cue \"{}\"", cue.name),
+            EditCommand::DeleteCue { cue_index, .. } => format!("Delete cue {cue_index}"),
+            EditCommand::AddAudioTrack { track, .. } => format!("Add audio \"{}\"", track.path),
+            EditCommand::RemoveAudioTrack { audio_index, .. } => {
+                format!("Remove audio track {audio_index}")
+            }
             EditCommand::UpdateSequenceSettings { name, .. } => {
                 if let Some(n) = name {
                     format!("Rename sequence to \"{n}\"")
@@ -117,6 +269,11 @@ impl EditCommand {
                 effect_index,
                 ..
             } => Some(format!("time:{sequence_index}:{track_index}:{effect_index}")),
+            EditCommand::SetTrackBlend {
+                sequence_index,
+                track_index,
+                ..
+            } => Some(format!("track_blend:{sequence_index}:{track_index}")),
             _ => None,
         }
     }
@@ -125,12 +282,27 @@ impl EditCommand {
     fn sequence_index(&self) -> usize {
         match self {
             EditCommand::AddEffect { sequence_index, .. }
+            | EditCommand::InsertEffect { sequence_index, .. }
             | EditCommand::DeleteEffects { sequence_index, .. }
             | EditCommand::UpdateEffectParam { sequence_index, .. }
+            | EditCommand::SetEffectParams { sequence_index, .. }
             | EditCommand::UpdateEffectTimeRange { sequence_index, .. }
+            | EditCommand::NudgeEffects { sequence_index, .. }
             | EditCommand::MoveEffectToTrack { sequence_index, .. }
+            | EditCommand::SetEffectOpacityCurve { sequence_index, .. }
+            | EditCommand::SetEffectFades { sequence_index, .. }
             | EditCommand::AddTrack { sequence_index, .. }
+            | EditCommand::AddTracks { sequence_index, .. }
             | EditCommand::DeleteTrack { sequence_index, .. }
+            | EditCommand::ReorderTrack { sequence_index, .. }
+            | EditCommand::SetTrackLocked { sequence_index, .. }
+            | EditCommand::SetTrackBlend { sequence_index, .. }
+            | EditCommand::AddMarker { sequence_index, .. }
+            | EditCommand::DeleteMarker { sequence_index, .. }
+            | EditCommand::AddCue { sequence_index, .. }
+            | EditCommand::DeleteCue { sequence_index, .. }
+            | EditCommand::AddAudioTrack { sequence_index, .. }
+            | EditCommand::RemoveAudioTrack { sequence_index, .. }
             | EditCommand::UpdateSequenceSettings { sequence_index, .. } => *sequence_index,
             EditCommand::Batch { commands, .. } => {
                 commands.first().map_or(0, EditCommand::sequence_index)
@@ -155,6 +327,10 @@ pub struct UndoState {
     pub can_redo: bool,
     pub undo_description: Option<String>,
     pub redo_description: Option<String>,
+    /// Number of edits that can currently be undone.
+    pub undo_depth: usize,
+    /// The most undo entries kept; older edits are forgotten.
+    pub max_undo_levels: usize,
 }
 
 /// An undo entry: the snapshot of the sequence before the command was applied,
@@ -168,7 +344,14 @@ struct UndoEntry {
     coalesce_key: Option<String>,
 }
 
-const MAX_UNDO_LEVELS: usize = 50;
+/// A setup undo entry: the whole setup as it was before the edit.
+struct SetupUndoEntry {
+    description: String,
+    snapshot: Setup,
+}
+
+/// Undo history depth used when settings don't specify one.
+pub const DEFAULT_MAX_UNDO_LEVELS: usize = 50;
 
 /// Maximum elapsed time between two coalescing edits for them to share one
 /// undo entry. If a coalescing edit arrives after this window, it starts a
@@ -183,6 +366,13 @@ pub struct CommandDispatcher {
     last_coalesce_key: Option<String>,
     /// The timestamp of the most recent coalescing edit.
     last_coalesce_time: Option<Instant>,
+    /// Cap on `undo_stack`; the oldest entries are evicted past it.
+    max_undo_levels: usize,
+    /// Setup edits (fixtures, outputs, layout) are saved straight to disk
+    /// rather than applied to the show, so they keep their own history.
+    /// Shares `max_undo_levels` with the sequence stack.
+    setup_undo_stack: Vec<SetupUndoEntry>,
+    setup_redo_stack: Vec<SetupUndoEntry>,
 }
 
 impl Default for CommandDispatcher {
@@ -193,11 +383,33 @@ impl Default for CommandDispatcher {
 
 impl CommandDispatcher {
     pub fn new() -> Self {
+        Self::with_max_undo_levels(DEFAULT_MAX_UNDO_LEVELS)
+    }
+
+    /// A dispatcher that keeps at most `max_undo_levels` undo entries
+    /// (at least one).
+    pub fn with_max_undo_levels(max_undo_levels: usize) -> Self {
         Self {
             undo_stack: Vec::new(),
             redo_stack: Vec::new(),
             last_coalesce_key: None,
             last_coalesce_time: None,
+            max_undo_levels: max_undo_levels.max(1),
+            setup_undo_stack: Vec::new(),
+            setup_redo_stack: Vec::new(),
+        }
+    }
+
+    /// Drop undo entries from the bottom of each undo stack until it fits the
+    /// cap. The redo stacks hold snapshots of their own, so they are never touched.
+    fn evict_oldest(&mut self) {
+        let excess = self.undo_stack.len().saturating_sub(self.max_undo_levels);
+        if excess > 0 {
+            self.undo_stack.drain(..excess);
+        }
+        let excess = self.setup_undo_stack.len().saturating_sub(self.max_undo_levels);
+        if excess > 0 {
+            self.setup_undo_stack.drain(..excess);
         }
     }
 
@@ -288,9 +500,7 @@ impl CommandDispatcher {
                 snapshot,
                 coalesce_key: new_coalesce_key.clone(),
             });
-            if self.undo_stack.len() > MAX_UNDO_LEVELS {
-                self.undo_stack.remove(0);
-            }
+            self.evict_oldest();
             self.redo_stack.clear();
 
             // Update coalesce tracking for coalescing commands.
@@ -374,6 +584,7 @@ impl CommandDispatcher {
             snapshot: current,
             coalesce_key: None,
         });
+        self.evict_oldest();
 
         Ok(description)
     }
@@ -385,6 +596,8 @@ impl CommandDispatcher {
             can_redo: !self.redo_stack.is_empty(),
             undo_description: self.undo_stack.last().map(|e| e.description.clone()),
             redo_description: self.redo_stack.last().map(|e| e.description.clone()),
+            undo_depth: self.undo_stack.len(),
+            max_undo_levels: self.max_undo_levels,
         }
     }
 
@@ -396,6 +609,61 @@ impl CommandDispatcher {
         self.last_coalesce_time = None;
     }
 
+    /// Record a setup edit that has already been saved. `before` is the setup
+    /// as it was beforehand, restored by `undo_setup`.
+    pub fn record_setup_edit(&mut self, description: &str, before: Setup) {
+        self.setup_undo_stack.push(SetupUndoEntry {
+            description: description.to_string(),
+            snapshot: before,
+        });
+        self.evict_oldest();
+        self.setup_redo_stack.clear();
+    }
+
+    /// Undo the last setup edit. Takes the setup as it is now (kept for redo)
+    /// and returns the description plus the setup to save in its place.
+    pub fn undo_setup(&mut self, current: Setup) -> Result<(String, Setup), AppError> {
+        let entry = self.setup_undo_stack.pop().ok_or(AppError::ValidationError {
+            message: "Nothing to undo in the setup".into(),
+        })?;
+        self.setup_redo_stack.push(SetupUndoEntry {
+            description: entry.description.clone(),
+            snapshot: current,
+        });
+        Ok((entry.description, entry.snapshot))
+    }
+
+    /// Redo the last undone setup edit. Mirrors `undo_setup`.
+    pub fn redo_setup(&mut self, current: Setup) -> Result<(String, Setup), AppError> {
+        let entry = self.setup_redo_stack.pop().ok_or(AppError::ValidationError {
+            message: "Nothing to redo in the setup".into(),
+        })?;
+        self.setup_undo_stack.push(SetupUndoEntry {
+            description: entry.description.clone(),
+            snapshot: current,
+        });
+        self.evict_oldest();
+        Ok((entry.description, entry.snapshot))
+    }
+
+    /// Undo/redo state of the setup history.
+    pub fn setup_undo_state(&self) -> UndoState {
+        UndoState {
+            can_undo: !self.setup_undo_stack.is_empty(),
+            can_redo: !self.setup_redo_stack.is_empty(),
+            undo_description: self.setup_undo_stack.last().map(|e| e.description.clone()),
+            redo_description: self.setup_redo_stack.last().map(|e| e.description.clone()),
+            undo_depth: self.setup_undo_stack.len(),
+            max_undo_levels: self.max_undo_levels,
+        }
+    }
+
+    /// Forget the setup history (e.g., when switching setups).
+    pub fn clear_setup_history(&mut self) {
+        self.setup_undo_stack.clear();
+        self.setup_redo_stack.clear();
+    }
+
     /// Apply a command to the show, returning the result. Does not manage undo state.
     #[allow(clippy::self_only_used_in_recursion)]
     fn apply(
@@ -413,16 +681,20 @@ impl CommandDispatcher {
                 blend_mode,
                 opacity,
             } => {
-                let time_range = TimeRange::new(*start, *end).ok_or(AppError::ValidationError {
-                    message: format!("Invalid time range: {start}..{end}"),
+                let duration = seq_mut(show, *sequence_index)?.duration;
+                let time_range = TimeRange::clamped(*start, *end, duration).map_err(|reason| {
+                    AppError::InvalidTimeRange { start: *start, end: *end, reason }
                 })?;
-                let track = track_mut(show, *sequence_index, *track_index)?;
+                let track = unlocked_track_mut(show, *sequence_index, *track_index)?;
                 let effect = EffectInstance {
                     kind: kind.clone(),
                     params: EffectParams::new(),
                     time_range,
                     blend_mode: *blend_mode,
                     opacity: *opacity,
+                    opacity_curve: None,
+                    fade_in: 0.0,
+                    fade_out: 0.0,
                 };
                 let insert_pos = track.effects.partition_point(|e| {
                     e.time_range.start() < time_range.start()
@@ -431,6 +703,18 @@ impl CommandDispatcher {
                 Ok(CommandResult::Index(insert_pos))
             }
 
+            EditCommand::InsertEffect {
+                sequence_index,
+                track_index,
+                effect,
+            } => {
+                let track = unlocked_track_mut(show, *sequence_index, *track_index)?;
+                let start = effect.time_range.start();
+                let insert_pos = track.effects.partition_point(|e| e.time_range.start() < start);
+                track.effects.insert(insert_pos, effect.clone());
+                Ok(CommandResult::Index(insert_pos))
+            }
+
             EditCommand::DeleteEffects {
                 sequence_index,
                 targets,
@@ -444,6 +728,7 @@ impl CommandDispatcher {
                 for (track_idx, mut effect_indices) in by_track {
                     let track = sequence.tracks.get_mut(track_idx)
                         .ok_or(AppError::InvalidIndex { what: "track".into(), index: track_idx })?;
+                    ensure_unlocked(track, track_idx)?;
                     effect_indices.sort_unstable();
                     effect_indices.dedup();
                     for &idx in effect_indices.iter().rev() {
@@ -463,10 +748,36 @@ impl CommandDispatcher {
                 value,
             } => {
                 let effect = effect_mut(show, *sequence_index, *track_index, *effect_index)?;
+                // Script params are declared by the compiled script, not here.
+                if let Some(def) = crate::effects::resolve_effect(&effect.kind) {
+                    let invalid = |reason: String| AppError::InvalidParam {
+                        key: key.to_string(),
+                        reason,
+                    };
+                    def.param_schema()
+                        .iter()
+                        .find(|s| s.key == *key)
+                        .ok_or_else(|| {
+                            invalid(format!("{} effects have no such param", effect.kind))
+                        })?
+                        .check(value)
+                        .map_err(invalid)?;
+                }
                 effect.params.set_mut(key.clone(), value.clone());
                 Ok(CommandResult::Bool(true))
             }
 
+            EditCommand::SetEffectParams {
+                sequence_index,
+                track_index,
+                effect_index,
+                params,
+            } => {
+                let effect = effect_mut(show, *sequence_index, *track_index, *effect_index)?;
+                effect.params.clone_from(params);
+                Ok(CommandResult::Bool(true))
+            }
+
             EditCommand::UpdateEffectTimeRange {
                 sequence_index,
                 track_index,
@@ -474,10 +785,11 @@ impl CommandDispatcher {
                 start,
                 end,
             } => {
-                let time_range = TimeRange::new(*start, *end).ok_or(AppError::ValidationError {
-                    message: format!("Invalid time range: {start}..{end}"),
+                let duration = seq_mut(show, *sequence_index)?.duration;
+                let time_range = TimeRange::clamped(*start, *end, duration).map_err(|reason| {
+                    AppError::InvalidTimeRange { start: *start, end: *end, reason }
                 })?;
-                let track = track_mut(show, *sequence_index, *track_index)?;
+                let track = unlocked_track_mut(show, *sequence_index, *track_index)?;
                 let effect = track.effects.get_mut(*effect_index)
                     .ok_or(AppError::InvalidIndex { what: "effect".into(), index: *effect_index })?;
                 effect.time_range = time_range;
@@ -490,6 +802,61 @@ impl CommandDispatcher {
                 Ok(CommandResult::Bool(true))
             }
 
+            EditCommand::NudgeEffects {
+                sequence_index,
+                targets,
+                delta,
+            } => {
+                let sequence = seq_mut(show, *sequence_index)?;
+                let mut by_track: std::collections::BTreeMap<usize, Vec<usize>> =
+                    std::collections::BTreeMap::new();
+                for (track_idx, effect_idx) in targets {
+                    by_track.entry(*track_idx).or_default().push(*effect_idx);
+                }
+                // Validate every target before touching any, so a bad index
+                // doesn't leave half the selection moved.
+                let mut shifted = Vec::new();
+                for (&track_idx, effect_indices) in &mut by_track {
+                    let track = sequence.tracks.get(track_idx)
+                        .ok_or(AppError::InvalidIndex { what: "track".into(), index: track_idx })?;
+                    ensure_unlocked(track, track_idx)?;
+                    effect_indices.sort_unstable();
+                    effect_indices.dedup();
+                    for &effect_idx in effect_indices.iter() {
+                        let range = track.effects.get(effect_idx)
+                            .ok_or(AppError::InvalidIndex { what: "effect".into(), index: effect_idx })?
+                            .time_range;
+                        let (start, end) = (range.start() + delta, range.end() + delta);
+                        let time_range = TimeRange::new(start, end).ok_or_else(|| {
+                            AppError::InvalidTimeRange {
+                                start,
+                                end,
+                                reason: "must be finite, start >= 0, start < end".into(),
+                            }
+                        })?;
+                        shifted.push((track_idx, effect_idx, time_range));
+                    }
+                }
+                for (track_idx, effect_idx, time_range) in shifted {
+                    if let Some(effect) = sequence.tracks.get_mut(track_idx)
+                        .and_then(|t| t.effects.get_mut(effect_idx))
+                    {
+                        effect.time_range = time_range;
+                    }
+                }
+                for track_idx in by_track.keys() {
+                    if let Some(track) = sequence.tracks.get_mut(*track_idx) {
+                        track.effects.sort_by(|a, b| {
+                            a.time_range
+                                .start()
+                                .partial_cmp(&b.time_range.start())
+                                .unwrap_or(std::cmp::Ordering::Equal)
+                        });
+                    }
+                }
+                Ok(CommandResult::Unit)
+            }
+
             EditCommand::MoveEffectToTrack {
                 sequence_index,
                 from_track,
@@ -502,9 +869,10 @@ impl CommandDispatcher {
                 if *effect_index >= from.effects.len() {
                     return Err(AppError::InvalidIndex { what: "effect".into(), index: *effect_index });
                 }
-                if *to_track >= sequence.tracks.len() {
-                    return Err(AppError::InvalidIndex { what: "destination track".into(), index: *to_track });
-                }
+                let dest = sequence.tracks.get(*to_track)
+                    .ok_or(AppError::InvalidIndex { what: "destination track".into(), index: *to_track })?;
+                ensure_unlocked(from, *from_track)?;
+                ensure_unlocked(dest, *to_track)?;
                 let effect = sequence.tracks.get_mut(*from_track)
                     .ok_or(AppError::InvalidIndex { what: "source track".into(), index: *from_track })?
                     .effects.remove(*effect_index);
@@ -517,6 +885,30 @@ impl CommandDispatcher {
                 Ok(CommandResult::Index(insert_pos))
             }
 
+            EditCommand::SetEffectOpacityCurve {
+                sequence_index,
+                track_index,
+                effect_index,
+                curve,
+            } => {
+                let effect = effect_mut(show, *sequence_index, *track_index, *effect_index)?;
+                effect.opacity_curve.clone_from(curve);
+                Ok(CommandResult::Bool(true))
+            }
+
+            EditCommand::SetEffectFades {
+                sequence_index,
+                track_index,
+                effect_index,
+                fade_in,
+                fade_out,
+            } => {
+                let effect = effect_mut(show, *sequence_index, *track_index, *effect_index)?;
+                effect.fade_in = *fade_in;
+                effect.fade_out = *fade_out;
+                Ok(CommandResult::Bool(true))
+            }
+
             EditCommand::AddTrack {
                 sequence_index,
                 name,
@@ -526,12 +918,26 @@ impl CommandDispatcher {
                 let track = crate::model::Track {
                     name: name.clone(),
                     target: target.clone(),
+                    locked: false,
+                    muted: false,
+                    blend_mode: BlendMode::Override,
+                    opacity: 1.0,
                     effects: Vec::new(),
                 };
                 sequence.tracks.push(track);
                 Ok(CommandResult::Index(sequence.tracks.len() - 1))
             }
 
+            EditCommand::AddTracks {
+                sequence_index,
+                tracks,
+            } => {
+                let sequence = seq_mut(show, *sequence_index)?;
+                let first = sequence.tracks.len();
+                sequence.tracks.extend(tracks.iter().cloned());
+                Ok(CommandResult::Index(first))
+            }
+
             EditCommand::DeleteTrack {
                 sequence_index,
                 track_index,
@@ -544,6 +950,114 @@ impl CommandDispatcher {
                 Ok(CommandResult::Unit)
             }
 
+            EditCommand::ReorderTrack {
+                sequence_index,
+                from_index,
+                to_index,
+            } => {
+                let sequence = seq_mut(show, *sequence_index)?;
+                let len = sequence.tracks.len();
+                if *from_index >= len {
+                    return Err(AppError::InvalidIndex { what: "source track".into(), index: *from_index });
+                }
+                if *to_index >= len {
+                    return Err(AppError::InvalidIndex { what: "destination track".into(), index: *to_index });
+                }
+                let track = sequence.tracks.remove(*from_index);
+                sequence.tracks.insert(*to_index, track);
+                Ok(CommandResult::Index(*to_index))
+            }
+
+            EditCommand::SetTrackLocked {
+                sequence_index,
+                track_index,
+                locked,
+            } => {
+                track_mut(show, *sequence_index, *track_index)?.locked = *locked;
+                Ok(CommandResult::Bool(*locked))
+            }
+
+            EditCommand::SetTrackBlend {
+                sequence_index,
+                track_index,
+                blend_mode,
+                opacity,
+            } => {
+                if !(0.0..=1.0).contains(opacity) {
+                    return Err(AppError::ValidationError {
+                        message: format!("Track opacity ({opacity}) must be between 0 and 1"),
+                    });
+                }
+                let track = track_mut(show, *sequence_index, *track_index)?;
+                track.blend_mode = *blend_mode;
+                track.opacity = *opacity;
+                Ok(CommandResult::Unit)
+            }
+
+            EditCommand::AddMarker {
+                sequence_index,
+                marker,
+            } => {
+                let sequence = seq_mut(show, *sequence_index)?;
+                let index = sequence.markers.partition_point(|m| m.time <= marker.time);
+                sequence.markers.insert(index, marker.clone());
+                Ok(CommandResult::Index(index))
+            }
+
+            EditCommand::DeleteMarker {
+                sequence_index,
+                marker_index,
+            } => {
+                let sequence = seq_mut(show, *sequence_index)?;
+                if *marker_index >= sequence.markers.len() {
+                    return Err(AppError::InvalidIndex { what: "marker".into(), index: *marker_index });
+                }
+                sequence.markers.remove(*marker_index);
+                Ok(CommandResult::Unit)
+            }
+
+            EditCommand::AddCue {
+                sequence_index,
+                cue,
+            } => {
+                let sequence = seq_mut(show, *sequence_index)?;
+                let index = sequence.cues.partition_point(|c| c.time <= cue.time);
+                sequence.cues.insert(index, cue.clone());
+                Ok(CommandResult::Index(index))
+            }
+
+            EditCommand::DeleteCue {
+                sequence_index,
+                cue_index,
+            } => {
+                let sequence = seq_mut(show, *sequence_index)?;
+                if *cue_index >= sequence.cues.len() {
+                    return Err(AppError::InvalidIndex { what: "cue".into(), index: *cue_index });
+                }
+                sequence.cues.remove(*cue_index);
+                Ok(CommandResult::Unit)
+            }
+
+            EditCommand::AddAudioTrack {
+                sequence_index,
+                track,
+                primary,
+            } => {
+                let sequence = seq_mut(show, *sequence_index)?;
+                Ok(CommandResult::Index(sequence.add_audio_track(track.clone(), *primary)))
+            }
+
+            EditCommand::RemoveAudioTrack {
+                sequence_index,
+                audio_index,
+            } => {
+                let sequence = seq_mut(show, *sequence_index)?;
+                sequence.remove_audio_track(*audio_index).ok_or_else(|| {
+                    AppError::InvalidIndex { what: "audio track".into(), index: *audio_index }
+                })?;
+                Ok(CommandResult::Unit)
+            }
+
             EditCommand::UpdateSequenceSettings {
                 sequence_index,
                 name,
@@ -557,8 +1071,8 @@ impl CommandDispatcher {
                 }
                 if let Some(af) = audio_file {
                     match af {
-                        FieldUpdate::Set(path) => sequence.audio_file = Some(path.clone()),
-                        FieldUpdate::Clear => sequence.audio_file = None,
+                        FieldUpdate::Set(path) => sequence.set_primary_audio(Some(path.clone())),
+                        FieldUpdate::Clear => sequence.set_primary_audio(None),
                     }
                 }
                 if let Some(d) = duration {
@@ -614,14 +1128,34 @@ fn track_mut(
         })
 }
 
+/// Error if `track` is locked against effect edits.
+fn ensure_unlocked(track: &crate::model::Track, index: usize) -> Result<(), AppError> {
+    if track.locked {
+        return Err(AppError::TrackLocked { index, name: track.name.clone() });
+    }
+    Ok(())
+}
+
+/// Like `track_mut`, but rejects locked tracks. Used by effect-level edits.
+fn unlocked_track_mut(
+    show: &mut crate::model::Show,
+    seq_index: usize,
+    track_index: usize,
+) -> Result<&mut crate::model::Track, AppError> {
+    let track = track_mut(show, seq_index, track_index)?;
+    ensure_unlocked(track, track_index)?;
+    Ok(track)
+}
+
 /// Get a mutable reference to an effect by sequence + track + effect index.
+/// Rejects locked tracks.
 fn effect_mut(
     show: &mut crate::model::Show,
     seq_index: usize,
     track_index: usize,
     effect_index: usize,
 ) -> Result<&mut EffectInstance, AppError> {
-    track_mut(show, seq_index, track_index)?
+    unlocked_track_mut(show, seq_index, track_index)?
         .effects
         .get_mut(effect_index)
         .ok_or(AppError::InvalidIndex {
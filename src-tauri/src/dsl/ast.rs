@@ -24,13 +24,33 @@ impl Span {
 #[derive(Debug, Clone)]
 pub struct Script {
     pub metadata: Vec<Metadata>,
+    pub uses: Vec<UseDecl>,
     pub type_defs: Vec<TypeDef>,
     pub params: Vec<ParamDef>,
     pub functions: Vec<FnDef>,
     pub body: Vec<Stmt>,
 }
 
-/// `@name "Fire Flicker"` or `@spatial false`
+impl Script {
+    /// String value of a `@key "value"` metadata entry.
+    pub fn meta_str(&self, key: &str) -> Option<&str> {
+        self.metadata.iter().find(|m| m.key == key).and_then(|m| match &m.value {
+            MetaValue::Str(s) => Some(s.as_str()),
+            MetaValue::Bool(_) => None,
+        })
+    }
+
+    /// Boolean value of a `@key true|false` metadata entry.
+    pub fn meta_bool(&self, key: &str) -> Option<bool> {
+        self.metadata.iter().find(|m| m.key == key).and_then(|m| match m.value {
+            MetaValue::Bool(b) => Some(b),
+            MetaValue::Str(_) => None,
+        })
+    }
+}
+
+/// `@name "Fire Flicker"`, `@author "Sam"` or `@spatial false`.
+/// Descriptive only: metadata never reaches the compiled program.
 #[derive(Debug, Clone)]
 pub struct Metadata {
     pub key: String,
@@ -44,6 +64,13 @@ pub enum MetaValue {
     Bool(bool),
 }
 
+/// `use "easing";`: pull a library script's `fn` definitions into this script.
+#[derive(Debug, Clone)]
+pub struct UseDecl {
+    pub library: String,
+    pub span: Span,
+}
+
 /// `enum Foo { A, B, C }` or `flags Bar { X, Y, Z }`
 #[derive(Debug, Clone)]
 pub struct TypeDef {
@@ -107,6 +134,7 @@ pub enum TypeName {
     Bool,
     Color,
     Vec2,
+    Vec3,
     Gradient,
     Curve,
 }
@@ -174,7 +202,9 @@ pub enum ExprKind {
         then_body: Vec<Stmt>,
         else_body: Option<Vec<Stmt>>,
     },
-    /// Switch expression: `switch expr { case Pat => body, default => body }`
+    /// Switch expression: `switch expr { case Pat => body, default => body }`.
+    /// `match expr { Pat => body, _ => body }` parses to the same node with a
+    /// mandatory default.
     Switch {
         scrutinee: Box<Expr>,
         cases: Vec<(Expr, Vec<Stmt>)>,
@@ -107,6 +107,19 @@ pub static BUILTINS: &[BuiltinFn] = &[
         name: "map", params: &[("x", TypeName::Float), ("in_min", TypeName::Float), ("in_max", TypeName::Float), ("out_min", TypeName::Float), ("out_max", TypeName::Float)], ret: TypeName::Float,
         op: Op::Map, category: "math", description: "Remap x from [in_min, in_max] to [out_min, out_max]. Returns out_min if in_min == in_max",
     },
+    // ── Waveforms ───────────────────────────────────────────────
+    BuiltinFn {
+        name: "saw", params: &[("phase", TypeName::Float)], ret: TypeName::Float,
+        op: Op::Saw, category: "wave", description: "Sawtooth: ramps 0 to 1 over each unit of phase, then wraps",
+    },
+    BuiltinFn {
+        name: "tri", params: &[("phase", TypeName::Float)], ret: TypeName::Float,
+        op: Op::Tri, category: "wave", description: "Triangle: 0 at whole phases, peaks at 1 halfway between",
+    },
+    BuiltinFn {
+        name: "square", params: &[("phase", TypeName::Float), ("duty", TypeName::Float)], ret: TypeName::Float,
+        op: Op::Square, category: "wave", description: "Square: 1 for the first duty fraction of each unit of phase, else 0",
+    },
     // ── Color constructors ──────────────────────────────────────
     BuiltinFn {
         name: "rgb", params: &[("r", TypeName::Float), ("g", TypeName::Float), ("b", TypeName::Float)], ret: TypeName::Color,
@@ -116,10 +129,26 @@ pub static BUILTINS: &[BuiltinFn] = &[
         name: "hsv", params: &[("h", TypeName::Float), ("s", TypeName::Float), ("v", TypeName::Float)], ret: TypeName::Color,
         op: Op::Hsv, category: "color", description: "HSV color (h: 0-360, s: 0-1, v: 0-1)",
     },
+    BuiltinFn {
+        name: "hsl", params: &[("h", TypeName::Float), ("s", TypeName::Float), ("l", TypeName::Float)], ret: TypeName::Color,
+        op: Op::Hsl, category: "color", description: "HSL color (h: 0-360, s: 0-1, l: 0-1)",
+    },
+    BuiltinFn {
+        name: "kelvin", params: &[("k", TypeName::Float)], ret: TypeName::Color,
+        op: Op::ColorTemp, category: "color", description: "White of a given color temperature in kelvin (2700 = warm, 6500 = neutral)",
+    },
     BuiltinFn {
         name: "rgba", params: &[("r", TypeName::Float), ("g", TypeName::Float), ("b", TypeName::Float), ("a", TypeName::Float)], ret: TypeName::Color,
         op: Op::Rgba, category: "color", description: "RGBA color (0.0-1.0 range)",
     },
+    BuiltinFn {
+        name: "lerp_color", params: &[("a", TypeName::Color), ("b", TypeName::Color), ("t", TypeName::Float)], ret: TypeName::Color,
+        op: Op::ColorMix, category: "color", description: "Blend two colors: a at t=0, b at t=1 (same as mix() on colors)",
+    },
+    BuiltinFn {
+        name: "palette", params: &[("i", TypeName::Float), ("a", TypeName::Color), ("b", TypeName::Color), ("c", TypeName::Color), ("d", TypeName::Color)], ret: TypeName::Color,
+        op: Op::Palette, category: "color", description: "Sample four colors by fractional index: 0 = a, 1 = b, 2 = c, 3 = d, blending in between. Clamped to [0, 3]; repeat a color for fewer stops",
+    },
     // ── Vec2 ────────────────────────────────────────────────────
     BuiltinFn {
         name: "vec2", params: &[("x", TypeName::Float), ("y", TypeName::Float)], ret: TypeName::Vec2,
@@ -131,7 +160,7 @@ pub static BUILTINS: &[BuiltinFn] = &[
     },
     BuiltinFn {
         name: "length", params: &[("v", TypeName::Vec2)], ret: TypeName::Float,
-        op: Op::Length, category: "vec2", description: "Length of vec2",
+        op: Op::Length, category: "vec2", description: "Length of vec2 or vec3",
     },
     BuiltinFn {
         name: "dot", params: &[("a", TypeName::Vec2), ("b", TypeName::Vec2)], ret: TypeName::Float,
@@ -153,6 +182,24 @@ pub static BUILTINS: &[BuiltinFn] = &[
         name: "rotate", params: &[("v", TypeName::Vec2), ("angle", TypeName::Float)], ret: TypeName::Vec2,
         op: Op::Rotate, category: "vec2", description: "Rotate vec2 by angle in radians",
     },
+    // ── Vec3 ────────────────────────────────────────────────────
+    BuiltinFn {
+        name: "vec3", params: &[("x", TypeName::Float), ("y", TypeName::Float), ("z", TypeName::Float)], ret: TypeName::Vec3,
+        op: Op::MakeVec3, category: "vec3", description: "Construct vec3",
+    },
+    BuiltinFn {
+        name: "color_to_vec3", params: &[("c", TypeName::Color)], ret: TypeName::Vec3,
+        op: Op::ColorToVec3, category: "vec3", description: "RGB channels of a color as vec3(r, g, b), each 0.0-1.0",
+    },
+    BuiltinFn {
+        name: "vec3_to_color", params: &[("v", TypeName::Vec3)], ret: TypeName::Color,
+        op: Op::Vec3ToColor, category: "vec3", description: "Color from vec3(r, g, b), each clamped to 0.0-1.0",
+    },
+    // ── Spatial ─────────────────────────────────────────────────
+    BuiltinFn {
+        name: "pos_of", params: &[("i", TypeName::Float)], ret: TypeName::Float,
+        op: Op::PosOf, category: "spatial", description: "Normalized position of pixel i (clamped to the fixture). pos_of(pixel + 1) - pos is the spacing to the next pixel",
+    },
     // ── Hash / Random ───────────────────────────────────────────
     BuiltinFn {
         name: "hash", params: &[("a", TypeName::Float), ("b", TypeName::Float)], ret: TypeName::Float,
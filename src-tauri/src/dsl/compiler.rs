@@ -1,6 +1,6 @@
-use super::ast::{BinOp, Expr, ParamType, Span, UnaryOp};
+use super::ast::{BinOp, Expr, ParamType, Span, TypeName, UnaryOp};
 use super::builtins::{self, BuiltinVar};
-use super::error::CompileError;
+use super::error::{CompileError, CompileWarning};
 use super::typeck::{TypedExpr, TypedExprKind, TypedScript, TypedStmt, TypedStmtKind};
 
 /// A compiled DSL script ready for VM execution.
@@ -18,6 +18,8 @@ pub struct CompiledScript {
     pub enums: Vec<EnumDef>,
     /// Flags definitions: name → flag names (for runtime bitmask resolution).
     pub flags: Vec<FlagsDef>,
+    /// Non-fatal diagnostics from type checking (unused params, locals).
+    pub warnings: Vec<CompileWarning>,
 }
 
 /// Enum type definition carried into the compiled script for runtime resolution.
@@ -112,11 +114,20 @@ pub enum Op {
     Mix,
     Smoothstep,
 
+    // Waveforms (phase wraps every 1.0)
+    Saw,
+    Tri,
+    Square,
+
     // Color
     /// Pop r, g, b → push Color
     Rgb,
     /// Pop h, s, v → push Color
     Hsv,
+    /// Pop h, s, l → push Color
+    Hsl,
+    /// Pop kelvin → push Color (black-body approximation)
+    ColorTemp,
     /// Pop r, g, b, a → push Color
     Rgba,
     /// Pop color, pop float → push scaled color
@@ -133,6 +144,8 @@ pub enum Op {
     Vec2Scale,
     /// Pop color_a, color_b, float_t → push lerp(a, b, t)
     ColorMix,
+    /// Pop index, a, b, c, d → push color sampled at fractional index in [0, 3]
+    Palette,
     /// Pop color → push float (hue 0-360)
     ColorHue,
     /// Pop color → push float (saturation 0-1)
@@ -165,12 +178,36 @@ pub enum Op {
     Vec2Y,
     /// Pop Vec2, Vec2 → push float distance
     Distance,
-    /// Pop Vec2 → push float length
+    /// Pop Vec2 or Vec3 → push float length
     Length,
     /// Pop Vec2, Vec2 → push float dot product
     Dot,
     /// Pop Vec2 → push normalized Vec2
     Normalize,
+    /// Pop float pixel index → push that pixel's normalized position
+    PosOf,
+
+    // Vec3
+    /// Pop x, y, z → push Vec3
+    MakeVec3,
+    /// Pop Vec3 → push float (x)
+    Vec3X,
+    /// Pop Vec3 → push float (y)
+    Vec3Y,
+    /// Pop Vec3 → push float (z)
+    Vec3Z,
+    /// Pop two Vec3 → push component-wise add
+    Vec3Add,
+    /// Pop two Vec3 → push component-wise subtract (a - b)
+    Vec3Sub,
+    /// Pop Vec3, pop float → push scaled Vec3
+    Vec3Scale,
+    /// Pop Vec3, Vec3 → push float dot product
+    Vec3Dot,
+    /// Pop color → push Vec3 of its RGB channels (0-1)
+    ColorToVec3,
+    /// Pop Vec3 → push Color (components clamped to 0-1)
+    Vec3ToColor,
 
     // Gradient/Curve/Color param evaluation
     /// Pop float t → push Color from gradient param
@@ -274,6 +311,7 @@ pub fn compile(typed: &TypedScript) -> Result<CompiledScript, CompileError> {
             name: td.name.clone(),
             flags: td.variants.clone(),
         }).collect(),
+        warnings: typed.warnings.clone(),
     })
 }
 
@@ -333,6 +371,30 @@ impl Compiler {
         Ok(())
     }
 
+    /// `a && b` / `a || b` as branches, so the right side only runs when it
+    /// decides the result. The bitwise `&` / `|` ops still evaluate both sides.
+    fn compile_short_circuit(
+        &mut self,
+        op: BinOp,
+        left: &TypedExpr,
+        right: &TypedExpr,
+    ) -> Result<(), CompileError> {
+        self.compile_expr(left)?;
+        if op == BinOp::Or {
+            // Left true: skip the right side and push true.
+            self.emit(Op::Not);
+        }
+        let jump_short = self.current_offset();
+        self.emit(Op::JumpIfFalse(0)); // placeholder
+        self.compile_expr(right)?;
+        let jump_end = self.current_offset();
+        self.emit(Op::Jump(0)); // placeholder
+        self.patch_jump(jump_short)?;
+        self.emit_const(if op == BinOp::Or { 1.0 } else { 0.0 })?;
+        self.patch_jump(jump_end)?;
+        Ok(())
+    }
+
     fn compile_stmt(&mut self, stmt: &TypedStmt) -> Result<(), CompileError> {
         match &stmt.kind {
             TypedStmtKind::Let { value, local_index, .. } => {
@@ -413,6 +475,9 @@ impl Compiler {
                     }
                 });
             }
+            TypedExprKind::BinOp { op: op @ (BinOp::And | BinOp::Or), left, right } => {
+                self.compile_short_circuit(*op, left, right)?;
+            }
             TypedExprKind::BinOp { op, left, right } => {
                 self.compile_expr(left)?;
                 self.compile_expr(right)?;
@@ -429,6 +494,7 @@ impl Compiler {
                     BinOp::Ge => Op::Ge,
                     BinOp::Eq => Op::Eq,
                     BinOp::Ne => Op::Ne,
+                    // Compiled as branches above; kept so the match stays exhaustive.
                     BinOp::And => Op::And,
                     BinOp::Or => Op::Or,
                     BinOp::BitOr => Op::BitOr,
@@ -496,6 +562,26 @@ impl Compiler {
                 self.compile_expr(factor)?;
                 self.emit(Op::Vec2Scale);
             }
+            TypedExprKind::Vec3Add { left, right } => {
+                self.compile_expr(left)?;
+                self.compile_expr(right)?;
+                self.emit(Op::Vec3Add);
+            }
+            TypedExprKind::Vec3Sub { left, right } => {
+                self.compile_expr(left)?;
+                self.compile_expr(right)?;
+                self.emit(Op::Vec3Sub);
+            }
+            TypedExprKind::Vec3Scale { vec, factor } => {
+                self.compile_expr(vec)?;
+                self.compile_expr(factor)?;
+                self.emit(Op::Vec3Scale);
+            }
+            TypedExprKind::Vec3Dot { left, right } => {
+                self.compile_expr(left)?;
+                self.compile_expr(right)?;
+                self.emit(Op::Vec3Dot);
+            }
             TypedExprKind::ColorMix { a, b, t } => {
                 self.compile_expr(a)?;
                 self.compile_expr(b)?;
@@ -504,6 +590,7 @@ impl Compiler {
             }
             TypedExprKind::Field { object, field } => {
                 self.compile_expr(object)?;
+                let is_vec3 = object.ty == TypeName::Vec3;
                 self.emit(match field.as_str() {
                     "r" => Op::ColorR,
                     "g" => Op::ColorG,
@@ -512,6 +599,9 @@ impl Compiler {
                     "hue" => Op::ColorHue,
                     "saturation" => Op::ColorSaturation,
                     "value" => Op::ColorValue,
+                    "x" if is_vec3 => Op::Vec3X,
+                    "y" if is_vec3 => Op::Vec3Y,
+                    "z" => Op::Vec3Z,
                     "x" => Op::Vec2X,
                     "y" => Op::Vec2Y,
                     _ => {
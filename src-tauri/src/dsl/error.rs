@@ -75,7 +75,25 @@ impl std::fmt::Display for CompileError {
 
 impl std::error::Error for CompileError {}
 
-fn offset_to_line_col(source: &str, offset: usize) -> (usize, usize) {
+/// A non-fatal diagnostic such as an unused param. Warnings never block
+/// compilation; they travel with the compiled script.
+#[derive(Debug, Clone)]
+pub struct CompileWarning {
+    pub message: String,
+    pub span: Span,
+}
+
+impl CompileWarning {
+    /// Format the warning with its source location.
+    pub fn format_with_source(&self, source: &str) -> String {
+        let (line, col) = offset_to_line_col(source, self.span.start);
+        format!("line {line}:{col}: {}", self.message)
+    }
+}
+
+/// 1-based line and column of byte `offset` in `source`. Columns count
+/// characters, not bytes.
+pub fn offset_to_line_col(source: &str, offset: usize) -> (usize, usize) {
     let mut line = 1;
     let mut col = 1;
     for (i, ch) in source.char_indices() {
@@ -23,6 +23,8 @@ pub enum Token {
     Switch,
     Case,
     Default,
+    Match,
+    Use,
 
     // Type names
     FloatTy,
@@ -376,6 +378,8 @@ impl<'a> Lexer<'a> {
             "switch" => Token::Switch,
             "case" => Token::Case,
             "default" => Token::Default,
+            "match" => Token::Match,
+            "use" => Token::Use,
             "float" => Token::FloatTy,
             "int" => Token::IntTy,
             "bool" => Token::BoolTy,
@@ -406,10 +410,10 @@ mod tests {
 
     #[test]
     fn keywords() {
-        let tokens = tok("let fn if else param enum flags");
+        let tokens = tok("let fn if else param enum flags use");
         assert_eq!(tokens, vec![
             Token::Let, Token::Fn, Token::If, Token::Else,
-            Token::Param, Token::Enum, Token::Flags, Token::Eof,
+            Token::Param, Token::Enum, Token::Flags, Token::Use, Token::Eof,
         ]);
     }
 
@@ -590,6 +594,16 @@ mod tests {
         ]);
     }
 
+    #[test]
+    fn match_keyword_and_wildcard() {
+        let tokens = tok("match x { _ => y }");
+        assert_eq!(tokens, vec![
+            Token::Match, Token::Ident("x".into()), Token::LBrace,
+            Token::Ident("_".into()), Token::FatArrow, Token::Ident("y".into()),
+            Token::RBrace, Token::Eof,
+        ]);
+    }
+
     #[test]
     fn star_star_vs_star() {
         let tokens = tok("a * b ** c");
@@ -80,19 +80,163 @@ pub mod optimize;
 )]
 pub mod vm;
 
+use std::ops::Range;
+
+use ast::{FnDef, Script, Span};
 use compiler::CompiledScript;
 use error::CompileError;
 
+/// Lex and parse a DSL source string without type checking, e.g. to read its
+/// `@` metadata block.
+pub fn parse_source(source: &str) -> Result<Script, Vec<CompileError>> {
+    parser::parse(lexer::lex(source)?)
+}
+
 /// Compile a DSL source string into a `CompiledScript` ready for VM execution.
 ///
 /// This is the primary public entry point for the DSL pipeline:
 /// source → lex → parse → type check → constant fold → compile → peephole → `CompiledScript`
 pub fn compile_source(source: &str) -> Result<CompiledScript, Vec<CompileError>> {
+    compile_source_with(source, |_| None)
+}
+
+/// Like [`compile_source`], resolving `use "name"` directives through
+/// `resolve_library`, which returns a library script's source by name.
+///
+/// The `fn` definitions of used libraries, and of the libraries they use, are
+/// inlined ahead of the script's own, so the VM never sees modules. A script's
+/// own functions shadow library functions of the same name. Errors and
+/// warnings inside library functions are reported at the script's `use`
+/// directive, naming the library.
+pub fn compile_source_with<F>(
+    source: &str,
+    resolve_library: F,
+) -> Result<CompiledScript, Vec<CompileError>>
+where
+    F: Fn(&str) -> Option<String>,
+{
     let tokens = lexer::lex(source)?;
-    let ast = parser::parse(tokens)?;
-    let typed = typeck::type_check(&ast)?;
+    let mut ast = parser::parse(tokens)?;
+    let mut inlined = Inlined {
+        chain: Vec::new(),
+        libraries: Vec::new(),
+        functions: Vec::new(),
+        next_offset: source.len() + 1,
+    };
+    for decl in &ast.uses {
+        include_library(&decl.library, decl.span, &resolve_library, &mut inlined)
+            .map_err(|e| vec![e])?;
+    }
+    ast.functions.splice(0..0, inlined.functions);
+    let libraries = inlined.libraries;
+    let relocate_errors = |errors: Vec<CompileError>| {
+        errors
+            .into_iter()
+            .map(|mut e| {
+                (e.message, e.span) = relocate(&libraries, e.message, e.span);
+                e
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let typed = typeck::type_check(&ast).map_err(relocate_errors)?;
     let folded = optimize::fold_constants(typed);
-    let mut compiled = compiler::compile(&folded).map_err(|e| vec![e])?;
+    let mut compiled = compiler::compile(&folded).map_err(|e| relocate_errors(vec![e]))?;
     compiled.ops = optimize::peephole(compiled.ops, &mut compiled.constants);
+    for w in &mut compiled.warnings {
+        let message = std::mem::take(&mut w.message);
+        (w.message, w.span) = relocate(&libraries, message, w.span);
+    }
     Ok(compiled)
 }
+
+/// Library functions gathered while expanding a script's `use` directives.
+struct Inlined {
+    /// Libraries currently being expanded, to catch cycles.
+    chain: Vec<String>,
+    libraries: Vec<InlinedLibrary>,
+    /// Functions of every included library, dependencies first.
+    functions: Vec<FnDef>,
+    /// Where the next library's spans start. Each library's spans are moved
+    /// past the script's and every earlier library's, so a diagnostic's span
+    /// tells which source it points into.
+    next_offset: usize,
+}
+
+/// A library whose functions were inlined.
+struct InlinedLibrary {
+    name: String,
+    /// The library's relocated spans.
+    spans: Range<usize>,
+    /// The `use` directive in the script being compiled that pulled it in.
+    used_at: Span,
+}
+
+/// Point a diagnostic inside an inlined library at the `use` directive that
+/// pulled the library in, since its span indexes the library's source rather
+/// than the script's.
+fn relocate(libraries: &[InlinedLibrary], message: String, span: Span) -> (String, Span) {
+    match libraries.iter().find(|lib| lib.spans.contains(&span.start)) {
+        Some(lib) => (format!("In library \"{}\": {message}", lib.name), lib.used_at),
+        None => (message, span),
+    }
+}
+
+/// Append the functions of `library` and everything it uses to
+/// `inlined.functions`, dependencies first, skipping libraries already
+/// included. Errors are reported at `span`, the `use` directive in the script
+/// being compiled.
+fn include_library<F>(
+    library: &str,
+    span: Span,
+    resolve_library: &F,
+    inlined: &mut Inlined,
+) -> Result<(), CompileError>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    if inlined.chain.iter().any(|name| name == library) {
+        return Err(CompileError::compiler(
+            format!("Library cycle: {} -> {library}", inlined.chain.join(" -> ")),
+            span,
+        ));
+    }
+    if inlined.libraries.iter().any(|lib| lib.name == library) {
+        return Ok(());
+    }
+    let source = resolve_library(library).ok_or_else(|| {
+        CompileError::compiler(format!("Unknown script library \"{library}\""), span)
+    })?;
+    let failed = |errors: Vec<CompileError>, offset: usize| {
+        let detail = errors.first().map_or_else(String::new, |e| {
+            let local = Span::new(e.span.start.saturating_sub(offset), e.span.end.saturating_sub(offset));
+            CompileError { span: local, ..e.clone() }.format_with_source(&source)
+        });
+        CompileError::compiler(format!("Library \"{library}\" failed to parse: {detail}"), span)
+    };
+    let base = inlined.next_offset;
+    inlined.next_offset += source.len() + 1;
+    let tokens = lexer::lex(&source).map_err(|errors| failed(errors, 0))?;
+    let relocated = tokens
+        .into_iter()
+        .map(|t| lexer::SpannedToken {
+            span: Span::new(t.span.start + base, t.span.end + base),
+            ..t
+        })
+        .collect();
+    let script = parser::parse(relocated).map_err(|errors| failed(errors, base))?;
+
+    inlined.chain.push(library.to_string());
+    for decl in &script.uses {
+        include_library(&decl.library, span, resolve_library, inlined)?;
+    }
+    inlined.chain.pop();
+
+    inlined.libraries.push(InlinedLibrary {
+        name: library.to_string(),
+        spans: base..base + source.len() + 1,
+        used_at: span,
+    });
+    inlined.functions.extend(script.functions);
+    Ok(())
+}
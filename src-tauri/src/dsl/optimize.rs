@@ -2,6 +2,8 @@ use super::ast::{BinOp, Span, TypeName, UnaryOp};
 use super::builtins::BuiltinVar;
 use super::compiler::Op;
 use super::typeck::{TypedExpr, TypedExprKind, TypedScript, TypedStmt, TypedStmtKind};
+use super::vm::{float_to_u8, saw, square, tri};
+use crate::model::color::Color;
 
 // ── Pass 1: Constant Folding on TypedExpr ────────────────────────────
 
@@ -96,7 +98,7 @@ fn fold_expr(expr: TypedExpr) -> TypedExpr {
             fold_binop(op, l, r, ty, span)
         }
 
-        // Builtin calls — fold if all args are constant floats
+        // Builtin calls — fold pure builtins whose args are all constant floats
         TypedExprKind::BuiltinCall { name, args } => {
             let folded_args: Vec<TypedExpr> = args.into_iter().map(fold_expr).collect();
 
@@ -117,6 +119,13 @@ fn fold_expr(expr: TypedExpr) -> TypedExpr {
                         span,
                     };
                 }
+                if let Some(Color { r, g, b, .. }) = eval_color_builtin(&name, &vals) {
+                    return TypedExpr {
+                        kind: TypedExprKind::ColorLit { r, g, b },
+                        ty,
+                        span,
+                    };
+                }
             }
 
             TypedExpr {
@@ -292,6 +301,54 @@ fn fold_expr(expr: TypedExpr) -> TypedExpr {
                 span,
             }
         }
+        TypedExprKind::Vec3Add { left, right } => {
+            let l = fold_expr(*left);
+            let r = fold_expr(*right);
+            TypedExpr {
+                kind: TypedExprKind::Vec3Add {
+                    left: Box::new(l),
+                    right: Box::new(r),
+                },
+                ty,
+                span,
+            }
+        }
+        TypedExprKind::Vec3Sub { left, right } => {
+            let l = fold_expr(*left);
+            let r = fold_expr(*right);
+            TypedExpr {
+                kind: TypedExprKind::Vec3Sub {
+                    left: Box::new(l),
+                    right: Box::new(r),
+                },
+                ty,
+                span,
+            }
+        }
+        TypedExprKind::Vec3Scale { vec, factor } => {
+            let v = fold_expr(*vec);
+            let f = fold_expr(*factor);
+            TypedExpr {
+                kind: TypedExprKind::Vec3Scale {
+                    vec: Box::new(v),
+                    factor: Box::new(f),
+                },
+                ty,
+                span,
+            }
+        }
+        TypedExprKind::Vec3Dot { left, right } => {
+            let l = fold_expr(*left);
+            let r = fold_expr(*right);
+            TypedExpr {
+                kind: TypedExprKind::Vec3Dot {
+                    left: Box::new(l),
+                    right: Box::new(r),
+                },
+                ty,
+                span,
+            }
+        }
         TypedExprKind::ColorMix { a, b, t } => {
             let fa = fold_expr(*a);
             let fb = fold_expr(*b);
@@ -525,6 +582,11 @@ fn eval_builtin(name: &str, args: &[f64]) -> Option<f64> {
             }
         }
 
+        // Waveforms
+        ("saw", [phase]) => Some(saw(*phase)),
+        ("tri", [phase]) => Some(tri(*phase)),
+        ("square", [phase, duty]) => Some(square(*phase, *duty)),
+
         // 1-arg easing
         ("ease_in", [t]) => Some(t * t),
         ("ease_out", [t]) => Some(t * (2.0 - t)),
@@ -556,16 +618,8 @@ fn eval_builtin(name: &str, args: &[f64]) -> Option<f64> {
             let s = (dot.sin() * 43758.5453).fract();
             Some(s.abs())
         }
-        ("random", [x]) => {
-            let dot = x * 12.9898;
-            let s = (dot.sin() * 43758.5453).fract();
-            Some(s.abs())
-        }
-        ("random_range", [seed, min, max]) => {
-            let dot = seed * 12.9898;
-            let h = (dot.sin() * 43758.5453).fract().abs();
-            Some(min + (max - min) * h)
-        }
+        // `random` and `random_range` are left to the VM: scripts treat them
+        // as a source of randomness, so they never fold.
 
         // 5-arg math
         ("map", [x, in_min, in_max, out_min, out_max]) => {
@@ -585,6 +639,18 @@ fn eval_builtin(name: &str, args: &[f64]) -> Option<f64> {
     }
 }
 
+/// Evaluate a color constructor on constant arguments exactly as the VM
+/// does. Only opaque constructors qualify, since `ColorLit` has no alpha.
+fn eval_color_builtin(name: &str, args: &[f64]) -> Option<Color> {
+    match (name, args) {
+        ("rgb", [r, g, b]) => Some(Color::rgb(float_to_u8(*r), float_to_u8(*g), float_to_u8(*b))),
+        ("hsv", [h, s, v]) => Some(Color::from_hsv(*h, *s, *v)),
+        ("hsl", [h, s, l]) => Some(Color::from_hsl(*h, *s, *l)),
+        ("kelvin", [k]) => Some(Color::from_kelvin(*k)),
+        _ => None,
+    }
+}
+
 // ── Pass 2: Peephole Optimization on Vec<Op> ─────────────────────────
 
 /// Apply peephole optimizations to compiled bytecode.
@@ -601,14 +667,30 @@ pub fn peephole(mut ops: Vec<Op>, constants: &mut Vec<f64>) -> Vec<Op> {
 }
 
 /// Single pass of peephole optimization. Returns (new_ops, changed).
+///
+/// A pattern never spans a jump target: code reached by a jump can't be
+/// merged with the ops before it, since they don't run on that path.
 fn peephole_pass(ops: &[Op], constants: &mut Vec<f64>) -> (Vec<Op>, bool) {
     let mut result = Vec::with_capacity(ops.len());
     let mut changed = false;
+    let targets: std::collections::HashSet<usize> = ops
+        .iter()
+        .filter_map(|op| match op {
+            Op::Jump(target) | Op::JumpIfFalse(target) => Some(*target as usize),
+            _ => None,
+        })
+        .collect();
+    // Whether `len` ops starting at `i` can be rewritten as one unit.
+    let spans_free = |i: usize, len: usize| (i + 1..i + len).all(|j| !targets.contains(&j));
+    // Old instruction index → new instruction index, for rewriting jumps.
+    let mut old_to_new = vec![0usize; ops.len() + 1];
     let mut i = 0;
 
     while i < ops.len() {
+        old_to_new[i] = result.len();
+
         // Pattern: PushConst(a), PushConst(b), <binop> → PushConst(a op b)
-        if i + 2 < ops.len() {
+        if i + 2 < ops.len() && spans_free(i, 3) {
             if let (Op::PushConst(ai), Op::PushConst(bi)) = (ops[i], ops[i + 1]) {
                 let a = const_val(constants, ai);
                 let b = const_val(constants, bi);
@@ -622,9 +704,9 @@ fn peephole_pass(ops: &[Op], constants: &mut Vec<f64>) -> (Vec<Op>, bool) {
             }
         }
 
-        // Pattern: PushConst(0.0), Add → remove both (identity: x + 0 = x)
-        // Pattern: PushConst(0.0), Sub → remove both (identity: x - 0 = x)
-        if i + 1 < ops.len() {
+        if i + 1 < ops.len() && spans_free(i, 2) {
+            // Pattern: PushConst(0.0), Add → remove both (identity: x + 0 = x)
+            // Pattern: PushConst(0.0), Sub → remove both (identity: x - 0 = x)
             if let Op::PushConst(ci) = ops[i] {
                 let c = const_val(constants, ci);
                 if c == 0.0 && matches!(ops[i + 1], Op::Add | Op::Sub) {
@@ -649,29 +731,31 @@ fn peephole_pass(ops: &[Op], constants: &mut Vec<f64>) -> (Vec<Op>, bool) {
                     continue;
                 }
             }
-        }
-
-        // Pattern: Not, Not → remove both
-        if i + 1 < ops.len() && ops[i] == Op::Not && ops[i + 1] == Op::Not {
-            changed = true;
-            i += 2;
-            continue;
-        }
 
-        // Pattern: Neg, Neg → remove both
-        if i + 1 < ops.len() && ops[i] == Op::Neg && ops[i + 1] == Op::Neg {
-            changed = true;
-            i += 2;
-            continue;
+            // Pattern: Not, Not → remove both
+            // Pattern: Neg, Neg → remove both
+            if (ops[i] == Op::Not && ops[i + 1] == Op::Not)
+                || (ops[i] == Op::Neg && ops[i + 1] == Op::Neg)
+            {
+                changed = true;
+                i += 2;
+                continue;
+            }
         }
 
         result.push(ops[i]);
         i += 1;
     }
+    old_to_new[ops.len()] = result.len();
 
-    // Fix up jump targets if we changed anything
     if changed {
-        fixup_jumps(ops, &mut result);
+        for op in &mut result {
+            if let Op::Jump(ref mut target) | Op::JumpIfFalse(ref mut target) = op {
+                if let Some(&new) = old_to_new.get(*target as usize) {
+                    *target = new as u16;
+                }
+            }
+        }
     }
 
     (result, changed)
@@ -717,109 +801,6 @@ fn try_fold_op(op: Op, a: f64, b: f64) -> Option<f64> {
     })
 }
 
-/// Rebuild jump targets after peephole changes.
-///
-/// Strategy: build an offset map from old instruction indices to new ones,
-/// then rewrite all Jump/JumpIfFalse targets.
-fn fixup_jumps(old_ops: &[Op], new_ops: &mut [Op]) {
-    // Build map: for old instruction index → new instruction index.
-    // Walk old ops, replaying the same pattern detection to track how many
-    // old ops map to how many new ops at each position.
-    let mut old_to_new = vec![0usize; old_ops.len() + 1];
-    let mut pos_in_new = 0usize;
-    let mut i = 0;
-    while i < old_ops.len() {
-        old_to_new[i] = pos_in_new;
-
-        // Detect the same patterns peephole_pass uses to know how many old ops
-        // map to how many new ops at this position
-        let skip = pattern_length(old_ops, i);
-        if skip > 0 {
-            // This pattern was transformed — count how many new ops it produced
-            let new_count = pattern_new_count(old_ops, i);
-            pos_in_new += new_count;
-            i += skip;
-        } else {
-            // Instruction survived as-is
-            pos_in_new += 1;
-            i += 1;
-        }
-    }
-    old_to_new[old_ops.len()] = pos_in_new;
-
-    // Rewrite jumps in new_ops
-    for op in new_ops.iter_mut() {
-        match op {
-            Op::Jump(ref mut target) | Op::JumpIfFalse(ref mut target) => {
-                let old_target = *target as usize;
-                if old_target <= old_ops.len() {
-                    *target = old_to_new[old_target] as u16;
-                }
-            }
-            _ => {}
-        }
-    }
-}
-
-/// Detect peephole patterns at position i, returning how many old ops the pattern consumes.
-/// Returns 0 if no pattern matches (instruction survives as-is).
-fn pattern_length(ops: &[Op], i: usize) -> usize {
-    // PushConst(a), PushConst(b), <binop> → PushConst(result)
-    if i + 2 < ops.len() {
-        if let (Op::PushConst(_), Op::PushConst(_)) = (ops[i], ops[i + 1]) {
-            if try_fold_op(ops[i + 2], 0.0, 0.0).is_some() {
-                return 3;
-            }
-        }
-    }
-
-    if i + 1 < ops.len() {
-        if let Op::PushConst(_) = ops[i] {
-            // Identity patterns: remove 2
-            if matches!(ops[i + 1], Op::Add | Op::Sub | Op::Mul | Op::Div) {
-                // Check if the constant triggers an identity/absorption pattern
-                // This is imprecise (we don't have access to constants here),
-                // but pattern_length is only called during fixup_jumps to replay
-                // the same decisions. We need to match the same patterns.
-                // Rather than passing constants, we'll use a different approach.
-                // See below — we refactor to avoid needing this.
-            }
-        }
-
-        // Not, Not or Neg, Neg
-        if ops[i] == Op::Not && ops[i + 1] == Op::Not {
-            return 2;
-        }
-        if ops[i] == Op::Neg && ops[i + 1] == Op::Neg {
-            return 2;
-        }
-    }
-
-    0
-}
-
-/// How many new ops a matched pattern produces.
-fn pattern_new_count(ops: &[Op], i: usize) -> usize {
-    // PushConst, PushConst, binop → 1 (PushConst(result))
-    if i + 2 < ops.len() {
-        if let (Op::PushConst(_), Op::PushConst(_)) = (ops[i], ops[i + 1]) {
-            if try_fold_op(ops[i + 2], 0.0, 0.0).is_some() {
-                return 1;
-            }
-        }
-    }
-
-    // Not+Not, Neg+Neg → 0
-    if i + 1 < ops.len()
-        && ((ops[i] == Op::Not && ops[i + 1] == Op::Not)
-            || (ops[i] == Op::Neg && ops[i + 1] == Op::Neg))
-    {
-        return 0;
-    }
-
-    1
-}
-
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod tests {
@@ -858,6 +839,7 @@ mod tests {
             curves: &[],
             colors: &[],
             paths: &[],
+            positions: &[],
         };
         vm::execute(compiled, &ctx)
     }
@@ -1032,4 +1014,56 @@ mod tests {
         let color = run_compiled(&compiled, 0.0, 0, 1);
         assert_eq!(color.r, 64); // 2/8 = 0.25 → 64
     }
+
+    #[test]
+    fn peephole_keeps_branch_targets_intact() {
+        // The else branch ends in `PushConst(0.0)` right before the `Mul`
+        // both branches jump to; folding them would break the then branch.
+        let src = "let x = t * (t > 0.5 ? 1.0 : 0.0); rgb(x, x, x)";
+        let opt = compile_opt(src);
+        let unopt = compile_unopt(src);
+        for t in [0.2, 0.8] {
+            assert_eq!(run_compiled(&opt, t, 0, 1), run_compiled(&unopt, t, 0, 1), "t = {t}");
+        }
+        assert_eq!(run_compiled(&opt, 0.8, 0, 1).r, 204);
+    }
+
+    #[test]
+    fn fold_pure_builtin_call_to_one_constant() {
+        use super::fold_constants;
+        use crate::dsl::typeck::{TypedExprKind, TypedStmtKind};
+
+        let fold_let = |src: &str| {
+            let typed = type_check(&parse(lex(src).unwrap()).unwrap()).unwrap();
+            match fold_constants(typed).body.into_iter().next().map(|s| s.kind) {
+                Some(TypedStmtKind::Let { value, .. }) => Some(value.kind),
+                _ => None,
+            }
+        };
+
+        assert!(matches!(
+            fold_let("let x = sin(0) + 1; rgb(x, x, x)"),
+            Some(TypedExprKind::FloatLit(v)) if (v - 1.0).abs() < 1e-12
+        ));
+        assert!(matches!(
+            fold_let("let x = sin(t); rgb(x, x, x)"),
+            Some(TypedExprKind::BuiltinCall { .. })
+        ));
+        assert!(matches!(
+            fold_let("let x = random(3.0); rgb(x, x, x)"),
+            Some(TypedExprKind::BuiltinCall { .. })
+        ));
+    }
+
+    #[test]
+    fn fold_color_constructors_match_runtime() {
+        let src = "let c = hsv(120.0, 1.0, 0.5); lerp_color(c, kelvin(3000.0), 0.25)";
+        let opt = compile_opt(src);
+        assert!(
+            !opt.ops.contains(&Op::Hsv) && !opt.ops.contains(&Op::ColorTemp),
+            "constant color constructors should fold, ops: {:?}",
+            opt.ops
+        );
+        assert_eq!(run_compiled(&opt, 0.0, 0, 1), run_compiled(&compile_unopt(src), 0.0, 0, 1));
+    }
 }
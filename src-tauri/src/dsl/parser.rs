@@ -24,6 +24,7 @@ impl Parser {
 
     fn parse_script(&mut self) -> Result<Script, Vec<CompileError>> {
         let mut metadata = Vec::new();
+        let mut uses = Vec::new();
         let mut type_defs = Vec::new();
         let mut params = Vec::new();
         let mut functions = Vec::new();
@@ -39,6 +40,12 @@ impl Parser {
                         Err(e) => self.errors.push(e),
                     }
                 }
+                Token::Use => {
+                    match self.parse_use() {
+                        Ok(u) => uses.push(u),
+                        Err(e) => self.errors.push(e),
+                    }
+                }
                 Token::Enum | Token::Flags => {
                     match self.parse_type_def() {
                         Ok(td) => type_defs.push(td),
@@ -73,6 +80,7 @@ impl Parser {
         if self.errors.is_empty() {
             Ok(Script {
                 metadata,
+                uses,
                 type_defs,
                 params,
                 functions,
@@ -166,6 +174,22 @@ impl Parser {
         self.skip_terminators();
     }
 
+    // ── Use directives ────────────────────────────────────────────
+
+    fn parse_use(&mut self) -> Result<UseDecl, CompileError> {
+        let start = self.expect(&Token::Use)?;
+        let Token::String(library) = self.peek().clone() else {
+            return Err(CompileError::parser(
+                "Expected library name string after 'use'",
+                self.span(),
+            ));
+        };
+        let end = self.span();
+        self.advance();
+        self.expect_terminator()?;
+        Ok(UseDecl { library, span: start.merge(end) })
+    }
+
     // ── Metadata ──────────────────────────────────────────────────
 
     fn parse_metadata(&mut self) -> Result<Metadata, CompileError> {
@@ -457,6 +481,7 @@ impl Parser {
             Token::BoolTy => { self.advance(); Ok(TypeName::Bool) }
             Token::ColorTy => { self.advance(); Ok(TypeName::Color) }
             Token::Ident(ref s) if s == "vec2" => { self.advance(); Ok(TypeName::Vec2) }
+            Token::Ident(ref s) if s == "vec3" => { self.advance(); Ok(TypeName::Vec3) }
             Token::GradientTy => { self.advance(); Ok(TypeName::Gradient) }
             Token::CurveTy => { self.advance(); Ok(TypeName::Curve) }
             _ => Err(CompileError::parser(
@@ -948,6 +973,9 @@ impl Parser {
             Token::Switch => {
                 self.parse_switch_expr()
             }
+            Token::Match => {
+                self.parse_match_expr()
+            }
             _ => {
                 Err(CompileError::parser(
                     format!("Unexpected token: {:?}", self.peek()),
@@ -1010,32 +1038,12 @@ impl Parser {
             if matches!(self.peek(), Token::Default) {
                 self.advance();
                 self.expect(&Token::FatArrow)?;
-                // Parse body: either a block or a single expression
-                let body = if matches!(self.peek(), Token::LBrace) {
-                    self.advance();
-                    self.skip_terminators();
-                    let b = self.parse_block()?;
-                    self.expect(&Token::RBrace)?;
-                    b
-                } else {
-                    let expr = self.parse_expr()?;
-                    vec![Stmt::Expr(expr)]
-                };
-                default = Some(body);
+                default = Some(self.parse_arm_body()?);
             } else if matches!(self.peek(), Token::Case) {
                 self.advance();
                 let pattern = self.parse_expr()?;
                 self.expect(&Token::FatArrow)?;
-                let body = if matches!(self.peek(), Token::LBrace) {
-                    self.advance();
-                    self.skip_terminators();
-                    let b = self.parse_block()?;
-                    self.expect(&Token::RBrace)?;
-                    b
-                } else {
-                    let expr = self.parse_expr()?;
-                    vec![Stmt::Expr(expr)]
-                };
+                let body = self.parse_arm_body()?;
                 cases.push((pattern, body));
             } else {
                 return Err(CompileError::parser(
@@ -1057,6 +1065,74 @@ impl Parser {
             span: start.merge(end_span),
         })
     }
+
+    /// `match expr { Pat => body, ..., _ => body }`. Arms may be separated by
+    /// commas or newlines, and the trailing `_` arm is required so every value
+    /// of the scrutinee produces a result.
+    fn parse_match_expr(&mut self) -> Result<Expr, CompileError> {
+        let start = self.span();
+        self.expect(&Token::Match)?;
+        let scrutinee = self.parse_expr()?;
+        self.expect(&Token::LBrace)?;
+        self.skip_terminators();
+
+        let mut cases = Vec::new();
+        let mut default = None;
+
+        while !matches!(self.peek(), Token::RBrace | Token::Eof) {
+            if default.is_some() {
+                return Err(CompileError::parser(
+                    "Unreachable match arm: '_' must be the last arm",
+                    self.span(),
+                ));
+            }
+            if matches!(self.peek(), Token::Ident(name) if name == "_") {
+                self.advance();
+                self.expect(&Token::FatArrow)?;
+                default = Some(self.parse_arm_body()?);
+            } else {
+                let pattern = self.parse_expr()?;
+                self.expect(&Token::FatArrow)?;
+                let body = self.parse_arm_body()?;
+                cases.push((pattern, body));
+            }
+            if matches!(self.peek(), Token::Comma) {
+                self.advance();
+            }
+            self.skip_terminators();
+        }
+        let end_span = self.span();
+        self.expect(&Token::RBrace)?;
+
+        if default.is_none() {
+            return Err(CompileError::parser(
+                "'match' must end with a '_ => ...' arm covering all other values",
+                start.merge(end_span),
+            ));
+        }
+
+        Ok(Expr {
+            kind: ExprKind::Switch {
+                scrutinee: Box::new(scrutinee),
+                cases,
+                default,
+            },
+            span: start.merge(end_span),
+        })
+    }
+
+    /// Body of a switch/match arm: either a `{ block }` or a single expression.
+    fn parse_arm_body(&mut self) -> Result<Vec<Stmt>, CompileError> {
+        if matches!(self.peek(), Token::LBrace) {
+            self.advance();
+            self.skip_terminators();
+            let body = self.parse_block()?;
+            self.expect(&Token::RBrace)?;
+            Ok(body)
+        } else {
+            Ok(vec![Stmt::Expr(self.parse_expr()?)])
+        }
+    }
 }
 
 #[cfg(test)]
@@ -1412,4 +1488,23 @@ default => rgb(0.0, 0.0, 0.0)
             }
         }
     }
+
+    #[test]
+    fn parse_match_desugars_to_switch_with_default() {
+        let script = parse_str("match x { 0 => rgb(1.0, 0.0, 0.0), 1 => rgb(0.0, 1.0, 0.0), _ => rgb(0.0, 0.0, 1.0) }");
+        assert!(matches!(
+            &script.body[0],
+            Stmt::Expr(Expr { kind: ExprKind::Switch { cases, default: Some(_), .. }, .. })
+                if cases.len() == 2
+        ));
+    }
+
+    #[test]
+    fn parse_match_requires_trailing_wildcard() {
+        let err = parse(lex("match x {\n0 => rgb(1.0, 0.0, 0.0)\n}").unwrap()).unwrap_err();
+        assert!(err.iter().any(|e| e.message.contains("'_ => ...'")), "got: {err:?}");
+
+        let err = parse(lex("match x {\n_ => rgb(1.0, 0.0, 0.0)\n0 => rgb(0.0, 0.0, 0.0)\n}").unwrap()).unwrap_err();
+        assert!(err.iter().any(|e| e.message.contains("Unreachable")), "got: {err:?}");
+    }
 }
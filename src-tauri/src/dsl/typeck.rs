@@ -1,8 +1,8 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use super::ast::*;
 use super::builtins::{self, BuiltinVar};
-use super::error::CompileError;
+use super::error::{CompileError, CompileWarning};
 
 /// Result of type checking: a validated script with type info and resolved names.
 #[derive(Debug, Clone)]
@@ -13,6 +13,7 @@ pub struct TypedScript {
     pub enums: Vec<TypeDef>,
     pub flags: Vec<TypeDef>,
     pub body: Vec<TypedStmt>,
+    pub warnings: Vec<CompileWarning>,
 }
 
 #[derive(Debug, Clone)]
@@ -96,7 +97,7 @@ pub enum TypedExprKind {
         color: Box<TypedExpr>,
         factor: Box<TypedExpr>,
     },
-    /// Field access: .r, .g, .b, .a, .x, .y
+    /// Field access: .r, .g, .b, .a, .x, .y, .z
     Field {
         object: Box<TypedExpr>,
         field: String,
@@ -150,6 +151,26 @@ pub enum TypedExprKind {
         vec: Box<TypedExpr>,
         factor: Box<TypedExpr>,
     },
+    /// vec3 + vec3 (component-wise add)
+    Vec3Add {
+        left: Box<TypedExpr>,
+        right: Box<TypedExpr>,
+    },
+    /// vec3 - vec3 (component-wise subtract)
+    Vec3Sub {
+        left: Box<TypedExpr>,
+        right: Box<TypedExpr>,
+    },
+    /// vec3 * float (component-wise scale)
+    Vec3Scale {
+        vec: Box<TypedExpr>,
+        factor: Box<TypedExpr>,
+    },
+    /// dot(vec3, vec3)
+    Vec3Dot {
+        left: Box<TypedExpr>,
+        right: Box<TypedExpr>,
+    },
     /// mix(color, color, float) → per-channel lerp
     ColorMix {
         a: Box<TypedExpr>,
@@ -182,6 +203,12 @@ struct TypeContext {
     errors: Vec<CompileError>,
     /// Current function inlining depth (guards against recursive functions)
     inline_depth: u16,
+    /// Param indices referenced anywhere in the body.
+    used_params: HashSet<u16>,
+    /// `let` bindings in declaration order: (name, span, local_index).
+    declared_locals: Vec<(String, Span, u16)>,
+    /// Local indices read at least once.
+    used_locals: HashSet<u16>,
 }
 
 impl TypeContext {
@@ -195,19 +222,16 @@ impl TypeContext {
             flags: HashMap::new(),
             errors: Vec::new(),
             inline_depth: 0,
+            used_params: HashSet::new(),
+            declared_locals: Vec::new(),
+            used_locals: HashSet::new(),
         }
     }
 
     fn check(&mut self, script: &Script) -> Result<TypedScript, Vec<CompileError>> {
         // Extract metadata
-        let name = script.metadata.iter()
-            .find(|m| m.key == "name")
-            .and_then(|m| if let MetaValue::Str(s) = &m.value { Some(s.clone()) } else { None })
-            .unwrap_or_default();
-        let spatial = script.metadata.iter()
-            .find(|m| m.key == "spatial")
-            .and_then(|m| if let MetaValue::Bool(b) = &m.value { Some(*b) } else { None })
-            .unwrap_or(false);
+        let name = script.meta_str("name").unwrap_or_default().to_string();
+        let spatial = script.meta_bool("spatial").unwrap_or(false);
 
         // Register type defs
         for td in &script.type_defs {
@@ -266,6 +290,7 @@ impl TypeContext {
         }
 
         if self.errors.is_empty() {
+            let warnings = self.unused_warnings(script);
             Ok(TypedScript {
                 name,
                 spatial,
@@ -273,12 +298,48 @@ impl TypeContext {
                 enums: script.type_defs.iter().filter(|td| td.kind == TypeDefKind::Enum).cloned().collect(),
                 flags: script.type_defs.iter().filter(|td| td.kind == TypeDefKind::Flags).cloned().collect(),
                 body: typed_body,
+                warnings,
             })
         } else {
             Err(std::mem::take(&mut self.errors))
         }
     }
 
+    /// Warnings for params and `let` bindings that are never read. Locals
+    /// named with a leading `_` are exempt. Function bodies are inlined per
+    /// call, so their bindings are reported once per source location.
+    fn unused_warnings(&self, script: &Script) -> Vec<CompileWarning> {
+        let mut warnings: Vec<CompileWarning> = script
+            .params
+            .iter()
+            .zip(0u16..)
+            .filter(|(_, idx)| !self.used_params.contains(idx))
+            .map(|(p, _)| CompileWarning {
+                message: format!("param `{}` declared but never used", p.name),
+                span: p.span,
+            })
+            .collect();
+
+        let mut reported = HashSet::new();
+        for (name, span, idx) in &self.declared_locals {
+            if name.starts_with('_') || self.used_locals.contains(idx) {
+                continue;
+            }
+            // A binding counts as used if any inlined copy of it was read.
+            let used_elsewhere = self
+                .declared_locals
+                .iter()
+                .any(|(_, s, i)| s == span && self.used_locals.contains(i));
+            if !used_elsewhere && reported.insert(span.start) {
+                warnings.push(CompileWarning {
+                    message: format!("local `{name}` assigned but never used"),
+                    span: *span,
+                });
+            }
+        }
+        warnings
+    }
+
     fn check_stmt(&mut self, stmt: &Stmt) -> Result<TypedStmt, CompileError> {
         match stmt {
             Stmt::Let { name, value, span } => {
@@ -289,6 +350,7 @@ impl TypeContext {
                 if let Some(scope) = self.locals.last_mut() {
                     scope.insert(name.clone(), (ty, local_idx));
                 }
+                self.declared_locals.push((name.clone(), *span, local_idx));
                 Ok(TypedStmt {
                     kind: TypedStmtKind::Let {
                         name: name.clone(),
@@ -343,9 +405,9 @@ impl TypeContext {
                     typed_right = Self::coerce_to_float(typed_right);
                 }
 
-                // ── Color/Vec2 arithmetic: handle before the generic numeric check ──
+                // ── Color/Vec2/Vec3 arithmetic: handle before the generic numeric check ──
 
-                // Mul: Color * Float / Float * Color / Vec2 * Float / Float * Vec2
+                // Mul: Color * Float / Float * Color / Vec2 * Float / Float * Vec2 (same for Vec3)
                 if matches!(op, BinOp::Mul) {
                     // Color scaling
                     let (is_color_scale, color_expr, factor_expr) = match (&typed_left.ty, &typed_right.ty) {
@@ -390,9 +452,31 @@ impl TypeContext {
                     // Restore bindings
                     typed_left = vec_expr;
                     typed_right = fac_expr;
+
+                    // Vec3 scaling
+                    let (is_vec3_scale, vec_expr, fac_expr) = match (&typed_left.ty, &typed_right.ty) {
+                        (TypeName::Vec3, TypeName::Float) => (true, typed_left, typed_right),
+                        (TypeName::Float, TypeName::Vec3) => (true, typed_right, typed_left),
+                        (TypeName::Vec3, TypeName::Int) => (true, typed_left, Self::coerce_to_float(typed_right)),
+                        (TypeName::Int, TypeName::Vec3) => (true, Self::coerce_to_float(typed_left), typed_right),
+                        _ => (false, typed_left, typed_right),
+                    };
+                    if is_vec3_scale {
+                        return Ok(TypedExpr {
+                            kind: TypedExprKind::Vec3Scale {
+                                vec: Box::new(vec_expr),
+                                factor: Box::new(fac_expr),
+                            },
+                            ty: TypeName::Vec3,
+                            span: expr.span,
+                        });
+                    }
+                    // Restore bindings
+                    typed_left = vec_expr;
+                    typed_right = fac_expr;
                 }
 
-                // Add: Color + Color, Vec2 + Vec2
+                // Add: Color + Color, Vec2 + Vec2, Vec3 + Vec3
                 if matches!(op, BinOp::Add) {
                     if typed_left.ty == TypeName::Color && typed_right.ty == TypeName::Color {
                         return Ok(TypedExpr {
@@ -414,9 +498,19 @@ impl TypeContext {
                             span: expr.span,
                         });
                     }
+                    if typed_left.ty == TypeName::Vec3 && typed_right.ty == TypeName::Vec3 {
+                        return Ok(TypedExpr {
+                            kind: TypedExprKind::Vec3Add {
+                                left: Box::new(typed_left),
+                                right: Box::new(typed_right),
+                            },
+                            ty: TypeName::Vec3,
+                            span: expr.span,
+                        });
+                    }
                 }
 
-                // Sub: Color - Color, Vec2 - Vec2
+                // Sub: Color - Color, Vec2 - Vec2, Vec3 - Vec3
                 if matches!(op, BinOp::Sub) {
                     if typed_left.ty == TypeName::Color && typed_right.ty == TypeName::Color {
                         return Ok(TypedExpr {
@@ -438,6 +532,16 @@ impl TypeContext {
                             span: expr.span,
                         });
                     }
+                    if typed_left.ty == TypeName::Vec3 && typed_right.ty == TypeName::Vec3 {
+                        return Ok(TypedExpr {
+                            kind: TypedExprKind::Vec3Sub {
+                                left: Box::new(typed_left),
+                                right: Box::new(typed_right),
+                            },
+                            ty: TypeName::Vec3,
+                            span: expr.span,
+                        });
+                    }
                 }
 
                 let result_ty = match op {
@@ -447,6 +551,7 @@ impl TypeContext {
                             let hint = match typed_left.ty {
                                 TypeName::Color => "For colors use: `color * float` (scale), `color + color` (add), `color - color` (subtract)",
                                 TypeName::Vec2 => "For vectors use: `vec2 * float` (scale), `vec2 + vec2` (add), `vec2 - vec2` (subtract)",
+                                TypeName::Vec3 => "For vectors use: `vec3 * float` (scale), `vec3 + vec3` (add), `vec3 - vec3` (subtract)",
                                 _ => "Only numeric types support arithmetic",
                             };
                             return Err(CompileError::type_error(
@@ -522,6 +627,7 @@ impl TypeContext {
 
                 // Check if it's a param eval (gradient/curve)
                 if let Some((param_ty, param_idx)) = self.params.get(name).cloned() {
+                    self.used_params.insert(param_idx);
                     match param_ty {
                         ParamType::Gradient => {
                             if args.len() != 1 {
@@ -661,6 +767,29 @@ impl TypeContext {
                     // Fall through to standard mix(float, float, float) handling below
                 }
 
+                // dot(vec3, vec3) → Vec3Dot; the `dot` builtin covers vec2
+                if name == "dot" && args.len() == 2 {
+                    let a = self.check_expr(&args[0])?;
+                    if a.ty == TypeName::Vec3 {
+                        let b = self.check_expr(&args[1])?;
+                        if b.ty != TypeName::Vec3 {
+                            return Err(CompileError::type_error(
+                                format!("dot() of vec3 needs a second vec3, got {:?}", b.ty),
+                                expr.span,
+                            ));
+                        }
+                        return Ok(TypedExpr {
+                            kind: TypedExprKind::Vec3Dot {
+                                left: Box::new(a),
+                                right: Box::new(b),
+                            },
+                            ty: TypeName::Float,
+                            span: expr.span,
+                        });
+                    }
+                    // Fall through to the vec2 builtin below
+                }
+
                 // Check built-in functions
                 let builtin = builtins::lookup_builtin(name).ok_or_else(|| {
                     CompileError::type_error(format!("Unknown function: '{name}'"), expr.span)
@@ -684,6 +813,25 @@ impl TypeContext {
                     if builtin.params[i].1 == TypeName::Float && typed_arg.ty == TypeName::Int {
                         typed_arg = Self::coerce_to_float(typed_arg);
                     }
+                    let (param_name, ref param_ty) = builtin.params[i];
+                    if *param_ty == TypeName::Color && typed_arg.ty != TypeName::Color {
+                        return Err(CompileError::type_error(
+                            format!(
+                                "'{name}' argument '{param_name}' must be color, got {:?}",
+                                typed_arg.ty
+                            ),
+                            arg.span,
+                        ));
+                    }
+                    if *param_ty == TypeName::Vec3 && typed_arg.ty != TypeName::Vec3 {
+                        return Err(CompileError::type_error(
+                            format!(
+                                "'{name}' argument '{param_name}' must be vec3, got {:?}",
+                                typed_arg.ty
+                            ),
+                            arg.span,
+                        ));
+                    }
                     typed_args.push(typed_arg);
                 }
 
@@ -773,7 +921,8 @@ impl TypeContext {
                 let typed_obj = self.check_expr(object)?;
                 let result_ty = match (&typed_obj.ty, field.as_str()) {
                     (TypeName::Color, "r" | "g" | "b" | "a" | "hue" | "saturation" | "value")
-                    | (TypeName::Vec2, "x" | "y") => TypeName::Float,
+                    | (TypeName::Vec2, "x" | "y")
+                    | (TypeName::Vec3, "x" | "y" | "z") => TypeName::Float,
                     _ => {
                         // Check if this is a flags field access (opts.Mirror)
                         if let TypedExprKind::LoadParam(idx) = typed_obj.kind {
@@ -875,11 +1024,18 @@ impl TypeContext {
                     ));
                 }
 
+                // Enum scrutinees accept bare variant names as patterns and
+                // compare with EnumEq instead of a float compare.
+                let enum_param = self.enum_param(&typed_scrutinee);
+
                 // Type-check each case
                 let mut typed_cases = Vec::new();
                 let mut branch_type: Option<TypeName> = None;
                 for (pattern, body) in cases {
-                    let mut typed_pat = self.check_expr(pattern)?;
+                    let mut typed_pat = match &enum_param {
+                        Some((_, enum_name)) => self.check_enum_pattern(pattern, enum_name)?,
+                        None => self.check_expr(pattern)?,
+                    };
                     // Auto-promote int to float if scrutinee is float
                     if typed_scrutinee.ty == TypeName::Float && typed_pat.ty == TypeName::Int {
                         typed_pat = Self::coerce_to_float(typed_pat);
@@ -928,6 +1084,7 @@ impl TypeContext {
                 let scrutinee_for_compare = typed_scrutinee;
                 let typed_expr = Self::desugar_switch(
                     scrutinee_for_compare,
+                    enum_param.map(|(param_index, _)| param_index),
                     &typed_cases,
                     typed_default.as_deref(),
                     result_ty.clone(),
@@ -968,10 +1125,11 @@ impl TypeContext {
         }
     }
 
-    fn resolve_ident(&self, name: &str, span: Span) -> Result<TypedExpr, CompileError> {
+    fn resolve_ident(&mut self, name: &str, span: Span) -> Result<TypedExpr, CompileError> {
         // Check locals (most recently defined first)
         for scope in self.locals.iter().rev() {
             if let Some((ty, idx)) = scope.get(name) {
+                self.used_locals.insert(*idx);
                 return Ok(TypedExpr {
                     kind: TypedExprKind::LoadLocal(*idx),
                     ty: ty.clone(),
@@ -982,6 +1140,7 @@ impl TypeContext {
 
         // Check params
         if let Some((param_ty, idx)) = self.params.get(name) {
+            self.used_params.insert(*idx);
             return match param_ty {
                 // Color params use a separate load path (not stored as f64)
                 ParamType::Color => Ok(TypedExpr {
@@ -1053,9 +1212,50 @@ impl TypeContext {
         })
     }
 
-    /// Desugar a type-checked switch into chained if/else expressions.
+    /// If `expr` loads an enum-typed param, return its index and enum name.
+    fn enum_param(&self, expr: &TypedExpr) -> Option<(u16, String)> {
+        let TypedExprKind::LoadParam(idx) = expr.kind else {
+            return None;
+        };
+        self.params.values().find_map(|(ty, pi)| match ty {
+            ParamType::Named(name) if *pi == idx && self.enums.contains_key(name) => {
+                Some((idx, name.clone()))
+            }
+            _ => None,
+        })
+    }
+
+    /// Check a switch/match pattern against an enum scrutinee: a bare variant
+    /// name resolves within the scrutinee's enum, and `Other.Variant` from a
+    /// different enum is rejected.
+    fn check_enum_pattern(&mut self, pattern: &Expr, enum_name: &str) -> Result<TypedExpr, CompileError> {
+        match &pattern.kind {
+            ExprKind::Ident(variant)
+                if self.enums.get(enum_name).is_some_and(|vs| vs.contains(variant)) =>
+            {
+                self.check_expr(&Expr {
+                    kind: ExprKind::EnumAccess {
+                        enum_name: enum_name.to_string(),
+                        variant: variant.clone(),
+                    },
+                    span: pattern.span,
+                })
+            }
+            ExprKind::EnumAccess { enum_name: other, variant } if other != enum_name => {
+                Err(CompileError::type_error(
+                    format!("Pattern '{other}.{variant}' does not belong to enum '{enum_name}'"),
+                    pattern.span,
+                ))
+            }
+            _ => self.check_expr(pattern),
+        }
+    }
+
+    /// Desugar a type-checked switch into chained if/else expressions. When
+    /// the scrutinee is an enum param, variant patterns compare via `EnumEq`.
     fn desugar_switch(
         scrutinee: TypedExpr,
+        enum_param: Option<u16>,
         cases: &[(TypedExpr, Vec<TypedStmt>)],
         default: Option<&[TypedStmt]>,
         result_ty: TypeName,
@@ -1088,12 +1288,23 @@ impl TypeContext {
         // Build chained if/else from last case to first
         for (pattern, body) in cases.iter().rev() {
             // Generate: if scrutinee == pattern { body } else { previous }
-            let condition = TypedExpr {
-                kind: TypedExprKind::BinOp {
+            let variant_index = match pattern.kind {
+                TypedExprKind::IntLit(v) => u16::try_from(v).ok(),
+                _ => None,
+            };
+            let kind = match enum_param.zip(variant_index) {
+                Some((param_index, variant_index)) => TypedExprKind::EnumEq {
+                    param_index,
+                    variant_index,
+                },
+                None => TypedExprKind::BinOp {
                     op: BinOp::Eq,
                     left: Box::new(scrutinee.clone()),
                     right: Box::new(pattern.clone()),
                 },
+            };
+            let condition = TypedExpr {
+                kind,
                 ty: TypeName::Bool,
                 span,
             };
@@ -1436,6 +1647,32 @@ mod tests {
             "Should error on mismatched branch types, got: {:?}", errors);
     }
 
+    // ── Warnings ────────────────────────────────────────────────
+
+    #[test]
+    fn unused_param_warns_once_with_its_span() {
+        let src = "param gain: float = 0.5;\nparam speed: float(0.1, 10.0) = 2.0;\nrgb(gain, gain, gain)";
+        let compiled = crate::dsl::compile_source(src).unwrap();
+        assert_eq!(compiled.warnings.len(), 1, "got: {:?}", compiled.warnings);
+        let warning = &compiled.warnings[0];
+        assert_eq!(warning.message, "param `speed` declared but never used");
+        assert!(src[warning.span.start..warning.span.end].starts_with("param speed"));
+    }
+
+    #[test]
+    fn unused_local_warns_unless_underscored() {
+        let typed = check("let a = 1.0;\nlet _b = 2.0;\nlet c = 3.0;\nrgb(c, c, c)");
+        let messages: Vec<&str> = typed.warnings.iter().map(|w| w.message.as_str()).collect();
+        assert_eq!(messages, ["local `a` assigned but never used"]);
+    }
+
+    #[test]
+    fn match_rejects_variant_of_other_enum() {
+        let errors = check_err("enum Mode { A, B }\nenum Other { A, B }\nparam mode: Mode = A;\nmatch mode {\nOther.B => rgb(1.0, 0.0, 0.0)\n_ => rgb(0.0, 0.0, 1.0)\n}");
+        assert!(errors.iter().any(|e| e.message.contains("does not belong to enum 'Mode'")),
+            "Should reject a foreign variant, got: {errors:?}");
+    }
+
     // ── Issue #74: Easing builtins ──────────────────────────────
 
     #[test]
@@ -1473,4 +1710,10 @@ mod tests {
             assert_eq!(*let_ty(&typed, 0), TypeName::Float, "{func} should return float");
         }
     }
+
+    #[test]
+    fn color_builtin_rejects_float_argument() {
+        let errors = check_err("lerp_color(0.5, rgb(0.0, 0.0, 1.0), t)");
+        assert!(errors.iter().any(|e| e.message.contains("argument 'a' must be color")));
+    }
 }
@@ -24,13 +24,14 @@ enum Value {
     Float(f64),
     Color(Color),
     Vec2(f64, f64),
+    Vec3(f64, f64, f64),
 }
 
 impl Value {
     fn as_float(self) -> f64 {
         match self {
             Self::Float(f) => f,
-            Self::Color(_) | Self::Vec2(_, _) => 0.0,
+            Self::Color(_) | Self::Vec2(_, _) | Self::Vec3(_, _, _) => 0.0,
         }
     }
 
@@ -47,6 +48,13 @@ impl Value {
             _ => (0.0, 0.0),
         }
     }
+
+    fn as_vec3(self) -> (f64, f64, f64) {
+        match self {
+            Self::Vec3(x, y, z) => (x, y, z),
+            _ => (0.0, 0.0, 0.0),
+        }
+    }
 }
 
 /// Reusable VM working memory. Create once per batch, reuse across pixels
@@ -87,6 +95,9 @@ pub struct VmContext<'a> {
     pub curves: &'a [Option<&'a Curve>],
     pub colors: &'a [Option<Color>],
     pub paths: &'a [Option<&'a MotionPath>],
+    /// Normalized position of every pixel, indexed by pixel. When empty,
+    /// `pos_of` falls back to the linear `pixel / (pixels - 1)` layout.
+    pub positions: &'a [f64],
 }
 
 /// Execute a compiled script for one pixel, returning the output color.
@@ -103,8 +114,88 @@ pub fn execute(script: &CompiledScript, ctx: &VmContext<'_>) -> Color {
 ///
 /// This avoids heap allocations on every pixel — call `execute_reuse` in a
 /// loop with the same `VmBuffers` for zero-alloc per-pixel evaluation.
-#[allow(clippy::too_many_lines)]
 pub fn execute_reuse(script: &CompiledScript, ctx: &VmContext<'_>, buffers: &mut VmBuffers) -> Color {
+    run(script, ctx, buffers, &mut Unobserved)
+}
+
+/// Execute a compiled script while counting every instruction it runs.
+///
+/// Counts accumulate across calls, so one `OpProfile` can cover a whole frame.
+pub fn execute_profiled(
+    script: &CompiledScript,
+    ctx: &VmContext<'_>,
+    buffers: &mut VmBuffers,
+    profile: &mut OpProfile,
+) -> Color {
+    profile.runs += 1;
+    run(script, ctx, buffers, profile)
+}
+
+/// Per-instruction execution counters gathered by `execute_profiled`.
+#[derive(Debug, Clone, Default)]
+pub struct OpProfile {
+    /// Times each instruction ran, indexed by instruction pointer.
+    pub hits: Vec<u64>,
+    /// Number of evaluations recorded.
+    pub runs: u64,
+}
+
+impl OpProfile {
+    pub fn new(script: &CompiledScript) -> Self {
+        Self {
+            hits: vec![0; script.ops.len()],
+            runs: 0,
+        }
+    }
+
+    /// Total instructions executed across all recorded evaluations.
+    pub fn total(&self) -> u64 {
+        self.hits.iter().sum()
+    }
+
+    /// Executed-instruction counts grouped by `Op` variant, most frequent first.
+    pub fn by_op(&self, script: &CompiledScript) -> Vec<(String, u64)> {
+        let mut counts: std::collections::BTreeMap<String, u64> = std::collections::BTreeMap::new();
+        for (op, &hits) in script.ops.iter().zip(&self.hits) {
+            if hits > 0 {
+                *counts.entry(op_name(*op)).or_default() += hits;
+            }
+        }
+        let mut counts: Vec<(String, u64)> = counts.into_iter().collect();
+        counts.sort_by_key(|c| std::cmp::Reverse(c.1));
+        counts
+    }
+}
+
+/// Variant name of an op without its operand, e.g. `PushConst` for `PushConst(3)`.
+fn op_name(op: Op) -> String {
+    let debug = format!("{op:?}");
+    debug.split('(').next().unwrap_or_default().to_string()
+}
+
+/// Hook called before each instruction executes. The unprofiled path uses
+/// `Unobserved`, which compiles away entirely.
+trait OpObserver {
+    fn observe(&mut self, ip: usize);
+}
+
+struct Unobserved;
+
+impl OpObserver for Unobserved {
+    #[inline]
+    fn observe(&mut self, _ip: usize) {}
+}
+
+impl OpObserver for OpProfile {
+    fn observe(&mut self, ip: usize) {
+        if let Some(hits) = self.hits.get_mut(ip) {
+            *hits += 1;
+        }
+    }
+}
+
+#[allow(clippy::too_many_lines)]
+fn run<O: OpObserver>(script: &CompiledScript, ctx: &VmContext<'_>, buffers: &mut VmBuffers, observer: &mut O) -> Color {
     buffers.reset(script.local_count as usize);
     let stack = &mut buffers.stack;
     let locals = &mut buffers.locals;
@@ -119,6 +210,7 @@ pub fn execute_reuse(script: &CompiledScript, ctx: &VmContext<'_>, buffers: &mut
         if instruction_count > MAX_INSTRUCTIONS || stack.len() >= MAX_STACK {
             return Color::BLACK;
         }
+        observer.observe(ip);
 
         match ops[ip] {
             Op::PushConst(idx) => {
@@ -278,6 +370,11 @@ pub fn execute_reuse(script: &CompiledScript, ctx: &VmContext<'_>, buffers: &mut
                 }
             }
 
+            // Waveforms
+            Op::Saw => float_unary(stack, &mut underflow, saw),
+            Op::Tri => float_unary(stack, &mut underflow, tri),
+            Op::Square => float_binop(stack, &mut underflow, square),
+
             // Color constructors
             Op::Rgb => {
                 if stack.len() >= 3 {
@@ -303,6 +400,23 @@ pub fn execute_reuse(script: &CompiledScript, ctx: &VmContext<'_>, buffers: &mut
                     underflow = true;
                 }
             }
+            Op::Hsl => {
+                if stack.len() >= 3 {
+                    let l = stack.pop().map_or(0.0, Value::as_float);
+                    let s = stack.pop().map_or(0.0, Value::as_float);
+                    let h = stack.pop().map_or(0.0, Value::as_float);
+                    stack.push(Value::Color(Color::from_hsl(h, s, l)));
+                } else {
+                    underflow = true;
+                }
+            }
+            Op::ColorTemp => {
+                if let Some(k) = stack.pop() {
+                    stack.push(Value::Color(Color::from_kelvin(k.as_float())));
+                } else {
+                    underflow = true;
+                }
+            }
             Op::Rgba => {
                 if stack.len() >= 4 {
                     let a = stack.pop().map_or(0.0, Value::as_float);
@@ -383,6 +497,18 @@ pub fn execute_reuse(script: &CompiledScript, ctx: &VmContext<'_>, buffers: &mut
                     underflow = true;
                 }
             }
+            Op::Palette => {
+                if stack.len() >= 5 {
+                    let d = stack.pop().map_or(Color::BLACK, Value::as_color);
+                    let c = stack.pop().map_or(Color::BLACK, Value::as_color);
+                    let b = stack.pop().map_or(Color::BLACK, Value::as_color);
+                    let a = stack.pop().map_or(Color::BLACK, Value::as_color);
+                    let i = stack.pop().map_or(0.0, Value::as_float);
+                    stack.push(Value::Color(palette(&[a, b, c, d], i)));
+                } else {
+                    underflow = true;
+                }
+            }
             Op::ColorHue => {
                 if let Some(val) = stack.pop() {
                     let c = val.as_color();
@@ -437,6 +563,13 @@ pub fn execute_reuse(script: &CompiledScript, ctx: &VmContext<'_>, buffers: &mut
                     underflow = true;
                 }
             }
+            Op::PosOf => {
+                if let Some(val) = stack.pop() {
+                    stack.push(Value::Float(pos_of(ctx, val.as_float())));
+                } else {
+                    underflow = true;
+                }
+            }
             Op::Map => {
                 if stack.len() >= 5 {
                     let out_max = stack.pop().map_or(0.0, Value::as_float);
@@ -537,6 +670,9 @@ pub fn execute_reuse(script: &CompiledScript, ctx: &VmContext<'_>, buffers: &mut
                 if let Some(val) = stack.pop() {
                     match val {
                         Value::Vec2(x, y) => stack.push(Value::Float((x * x + y * y).sqrt())),
+                        Value::Vec3(x, y, z) => {
+                            stack.push(Value::Float((x * x + y * y + z * z).sqrt()));
+                        }
                         _ => stack.push(Value::Float(0.0)),
                     }
                 } else {
@@ -575,6 +711,99 @@ pub fn execute_reuse(script: &CompiledScript, ctx: &VmContext<'_>, buffers: &mut
                 }
             }
 
+            // Vec3
+            Op::MakeVec3 => {
+                if stack.len() >= 3 {
+                    let z = stack.pop().map_or(0.0, Value::as_float);
+                    let y = stack.pop().map_or(0.0, Value::as_float);
+                    let x = stack.pop().map_or(0.0, Value::as_float);
+                    stack.push(Value::Vec3(x, y, z));
+                } else {
+                    underflow = true;
+                }
+            }
+            Op::Vec3X => {
+                if let Some(val) = stack.pop() {
+                    stack.push(Value::Float(val.as_vec3().0));
+                } else {
+                    underflow = true;
+                }
+            }
+            Op::Vec3Y => {
+                if let Some(val) = stack.pop() {
+                    stack.push(Value::Float(val.as_vec3().1));
+                } else {
+                    underflow = true;
+                }
+            }
+            Op::Vec3Z => {
+                if let Some(val) = stack.pop() {
+                    stack.push(Value::Float(val.as_vec3().2));
+                } else {
+                    underflow = true;
+                }
+            }
+            Op::Vec3Add => {
+                if stack.len() >= 2 {
+                    let (bx, by, bz) = stack.pop().map_or((0.0, 0.0, 0.0), Value::as_vec3);
+                    let (ax, ay, az) = stack.pop().map_or((0.0, 0.0, 0.0), Value::as_vec3);
+                    stack.push(Value::Vec3(ax + bx, ay + by, az + bz));
+                } else {
+                    underflow = true;
+                }
+            }
+            Op::Vec3Sub => {
+                if stack.len() >= 2 {
+                    let (bx, by, bz) = stack.pop().map_or((0.0, 0.0, 0.0), Value::as_vec3);
+                    let (ax, ay, az) = stack.pop().map_or((0.0, 0.0, 0.0), Value::as_vec3);
+                    stack.push(Value::Vec3(ax - bx, ay - by, az - bz));
+                } else {
+                    underflow = true;
+                }
+            }
+            Op::Vec3Scale => {
+                if stack.len() >= 2 {
+                    let factor = stack.pop().map_or(0.0, Value::as_float);
+                    let (x, y, z) = stack.pop().map_or((0.0, 0.0, 0.0), Value::as_vec3);
+                    stack.push(Value::Vec3(x * factor, y * factor, z * factor));
+                } else {
+                    underflow = true;
+                }
+            }
+            Op::Vec3Dot => {
+                if stack.len() >= 2 {
+                    let (bx, by, bz) = stack.pop().map_or((0.0, 0.0, 0.0), Value::as_vec3);
+                    let (ax, ay, az) = stack.pop().map_or((0.0, 0.0, 0.0), Value::as_vec3);
+                    stack.push(Value::Float(ax * bx + ay * by + az * bz));
+                } else {
+                    underflow = true;
+                }
+            }
+            Op::ColorToVec3 => {
+                if let Some(val) = stack.pop() {
+                    let c = val.as_color();
+                    stack.push(Value::Vec3(
+                        f64::from(c.r) / 255.0,
+                        f64::from(c.g) / 255.0,
+                        f64::from(c.b) / 255.0,
+                    ));
+                } else {
+                    underflow = true;
+                }
+            }
+            Op::Vec3ToColor => {
+                if let Some(val) = stack.pop() {
+                    let (r, g, b) = val.as_vec3();
+                    stack.push(Value::Color(Color::rgb(
+                        float_to_u8(r),
+                        float_to_u8(g),
+                        float_to_u8(b),
+                    )));
+                } else {
+                    underflow = true;
+                }
+            }
+
             // Gradient/Curve evaluation
             Op::EvalGradient(param_idx) => {
                 if let Some(t_val) = stack.pop() {
@@ -779,9 +1008,52 @@ pub fn execute_reuse(script: &CompiledScript, ctx: &VmContext<'_>, buffers: &mut
     stack.pop().map_or(Color::BLACK, Value::as_color)
 }
 
+/// Blend between adjacent palette stops at a fractional index, clamped to the stops.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss, clippy::cast_precision_loss)]
+fn palette(stops: &[Color; 4], index: f64) -> Color {
+    let last = stops.len() - 1;
+    let i = if index.is_finite() { index.clamp(0.0, last as f64) } else { 0.0 };
+    let lo = (i.floor() as usize).min(last - 1);
+    match (stops.get(lo), stops.get(lo + 1)) {
+        (Some(&from), Some(&to)) => from.lerp(to, i - lo as f64),
+        _ => Color::BLACK,
+    }
+}
+
+/// Sawtooth in [0, 1): the phase wrapped into its cycle. Wraps negative
+/// phases too, unlike `f64::fract`.
+pub(super) fn saw(phase: f64) -> f64 {
+    phase - phase.floor()
+}
+
+/// Triangle in [0, 1]: 0 at whole phases, 1 halfway through each cycle.
+pub(super) fn tri(phase: f64) -> f64 {
+    1.0 - (2.0 * saw(phase) - 1.0).abs()
+}
+
+/// Square in {0, 1}: high for the first `duty` fraction of each cycle.
+pub(super) fn square(phase: f64, duty: f64) -> f64 {
+    if saw(phase) < duty { 1.0 } else { 0.0 }
+}
+
+/// Normalized position of pixel `index`, clamped to the fixture's pixel range.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss, clippy::cast_precision_loss)]
+fn pos_of(ctx: &VmContext<'_>, index: f64) -> f64 {
+    let last = ctx.pixels.saturating_sub(1);
+    let i = (index.round().max(0.0) as usize).min(last);
+    ctx.positions
+        .get(i)
+        .copied()
+        .unwrap_or_else(|| if last > 0 { i as f64 / last as f64 } else { 0.0 })
+}
+
 /// Convert a float in [0.0, 1.0] to a u8 in [0, 255], clamped.
+/// NaN and infinities (e.g. from `log(0)` or `sqrt(-1)`) become 0.
 #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-fn float_to_u8(f: f64) -> u8 {
+pub(super) fn float_to_u8(f: f64) -> u8 {
+    if !f.is_finite() {
+        return 0;
+    }
     (f.clamp(0.0, 1.0) * 255.0).round() as u8
 }
 
@@ -1053,7 +1325,10 @@ mod tests {
         let script = parse(tokens).unwrap();
         let typed = type_check(&script).unwrap();
         let compiled = compile(&typed).unwrap();
+        run_compiled(&compiled, t, pixel, pixels)
+    }
 
+    fn run_compiled(compiled: &CompiledScript, t: f64, pixel: usize, pixels: usize) -> Color {
         let pos = if pixels > 1 { pixel as f64 / (pixels - 1) as f64 } else { 0.0 };
         let ctx = VmContext {
             t,
@@ -1067,9 +1342,10 @@ mod tests {
             curves: &[],
             colors: &[],
             paths: &[],
+            positions: &[],
         };
 
-        execute(&compiled, &ctx)
+        execute(compiled, &ctx)
     }
 
     #[test]
@@ -1096,6 +1372,16 @@ mod tests {
         assert_eq!(color.b, 0);
     }
 
+    #[test]
+    fn non_finite_channels_become_zero() {
+        // log(0) = -inf, -log(0) = +inf, sqrt(-1) = NaN.
+        let color = run("rgb(-log(t * 0.0), 0.5, sqrt(t - 1.0))");
+        assert_eq!((color.r, color.g, color.b), (0, 128, 0));
+
+        let color = run("hsv(log(t * 0.0), 1.0, 1.0)");
+        assert_eq!((color.r, color.g, color.b), (255, 0, 0));
+    }
+
     #[test]
     fn let_binding() {
         let color = run("let v = 0.5; rgb(v, v, v)");
@@ -1144,6 +1430,26 @@ mod tests {
         assert_eq!(color2.r, 128);
     }
 
+    #[test]
+    fn waveform_builtins() {
+        let wave = |t: f64| {
+            let c = run_with_ctx("rgb(saw(t), tri(t), square(t, 0.5))", t, 0, 10);
+            (c.r, c.g, c.b)
+        };
+        assert_eq!(wave(0.0), (0, 0, 255));
+        assert_eq!(wave(0.25), (64, 128, 255));
+        // tri peaks halfway; square drops at its duty cycle.
+        assert_eq!(wave(0.5), (128, 255, 0));
+        // saw(1) wraps back to the start of the next cycle.
+        assert_eq!(wave(1.0), (0, 0, 255));
+
+        // Constant-folded calls agree with the VM.
+        let c = run("rgb(saw(1.0), tri(0.5), square(0.25, 0.5))");
+        assert_eq!((c.r, c.g, c.b), (0, 255, 255));
+        let c = run("rgb(saw(-0.25), 0.0, 0.0)");
+        assert_eq!(c.r, 191);
+    }
+
     #[test]
     fn hsv_color() {
         // HSV(0, 1, 1) = pure red
@@ -1153,6 +1459,23 @@ mod tests {
         assert_eq!(color.b, 0);
     }
 
+    #[test]
+    fn hsl_color() {
+        let color = run("hsl(0.0, 1.0, 0.5)");
+        assert_eq!((color.r, color.g, color.b), (255, 0, 0));
+    }
+
+    #[test]
+    fn kelvin_color() {
+        let neutral = run("kelvin(6500.0)");
+        assert!(neutral.r.abs_diff(neutral.b) <= 12, "6500K should be near white: {neutral:?}");
+        assert!(neutral.g >= 240);
+
+        let warm = run("kelvin(2700.0)");
+        assert!(warm.r > warm.g && warm.g > warm.b, "2700K should be warm: {warm:?}");
+        assert!(warm.b < 200);
+    }
+
     #[test]
     fn color_scale() {
         let color = run("rgb(1.0, 1.0, 1.0).scale(0.5)");
@@ -1201,6 +1524,7 @@ mod tests {
             curves: &[],
             colors: &[],
             paths: &[],
+            positions: &[],
         };
 
         let color = execute(&compiled, &ctx);
@@ -1214,6 +1538,48 @@ mod tests {
         assert_eq!(color.r, 128);
     }
 
+    fn resolve_library(name: &str) -> Option<String> {
+        match name {
+            "easing" => Some("fn ease(x: float) -> float { x * x }".to_string()),
+            "loop_a" => Some("use \"loop_b\";\nfn a(x: float) -> float { x }".to_string()),
+            "loop_b" => Some("use \"loop_a\";\nfn b(x: float) -> float { x }".to_string()),
+            "typo" => Some("fn bad(x: float) -> float { x + missing }".to_string()),
+            "noisy" => Some("fn noisy(x: float) -> float {\n  let unused = 1.0;\n  x\n}".to_string()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn used_library_functions_are_inlined() {
+        let src = "use \"easing\";\nlet v = ease(0.5); rgb(v, v, v)";
+        let compiled = crate::dsl::compile_source_with(src, resolve_library).unwrap();
+        assert_eq!(run_compiled(&compiled, 0.0, 0, 1).r, 64);
+    }
+
+    #[test]
+    fn library_cycles_and_unknown_libraries_are_errors() {
+        let src = "use \"loop_a\";\nrgb(t, t, t)";
+        let cycle = crate::dsl::compile_source_with(src, resolve_library).unwrap_err();
+        assert!(cycle[0].message.contains("loop_a -> loop_b -> loop_a"), "{}", cycle[0].message);
+
+        let unknown = crate::dsl::compile_source("use \"easing\";\nrgb(t, t, t)").unwrap_err();
+        assert!(unknown[0].message.contains("Unknown script library"), "{}", unknown[0].message);
+    }
+
+    #[test]
+    fn library_diagnostics_point_at_the_use_directive() {
+        let src = "use \"typo\";\nlet v = bad(t); rgb(v, v, v)";
+        let errors = crate::dsl::compile_source_with(src, resolve_library).unwrap_err();
+        assert!(errors[0].message.starts_with("In library \"typo\": "), "{}", errors[0].message);
+        assert_eq!(src.get(errors[0].span.start..errors[0].span.end), Some("use \"typo\""));
+
+        let src = "use \"noisy\";\nlet v = noisy(t); rgb(v, v, v)";
+        let compiled = crate::dsl::compile_source_with(src, resolve_library).unwrap();
+        let warning = &compiled.warnings[0];
+        assert!(warning.message.contains("In library \"noisy\": local `unused`"), "{}", warning.message);
+        assert_eq!(warning.span.start, 0);
+    }
+
     #[test]
     fn color_param() {
         let src = "param bg: color = #ff0000;\nbg";
@@ -1235,6 +1601,7 @@ mod tests {
             curves: &[],
             colors: &colors,
             paths: &[],
+            positions: &[],
         };
         let color = execute(&compiled, &ctx);
         assert_eq!(color.r, 0);
@@ -1263,6 +1630,7 @@ mod tests {
             curves: &[],
             colors: &[],
             paths: &[],
+            positions: &[],
         };
         let color = execute(&compiled, &ctx);
         assert_eq!(color.r, 255);
@@ -1281,6 +1649,7 @@ mod tests {
             curves: &[],
             colors: &[],
             paths: &[],
+            positions: &[],
         };
         let color2 = execute(&compiled, &ctx2);
         assert_eq!(color2.r, 0);
@@ -1321,6 +1690,7 @@ rgb(r, g, b)
                 curves: &[],
                 colors: &[],
                 paths: &[],
+                positions: &[],
             };
             let dsl_color = execute(&compiled, &ctx);
             assert_eq!(dsl_color.r, native.r, "pixel {pixel}: r mismatch");
@@ -1387,6 +1757,7 @@ hsv(hue, 1.0, 1.0)
                     curves: &[],
                     colors: &[],
                     paths: &[],
+                    positions: &[],
                 };
                 let dsl_color = execute(&compiled, &ctx);
 
@@ -1444,6 +1815,7 @@ if phase < duty_cycle {
                 curves: &[],
                 colors: &[],
                 paths: &[],
+                positions: &[],
             };
             let dsl_color = execute(&compiled, &ctx);
 
@@ -1571,7 +1943,7 @@ if phase < duty_cycle {
         let ctx = VmContext {
             t: 0.0, pixel: 0, pixels: 1, pos: 0.0, pos2d: (0.0, 0.0),
             param_values: &[0.0], // Red = 0
-            abs_t: 0.0, gradients: &[], curves: &[], colors: &[], paths: &[],
+            abs_t: 0.0, gradients: &[], curves: &[], colors: &[], paths: &[], positions: &[],
         };
         let color = execute(&compiled, &ctx);
         assert_eq!(color.r, 255);
@@ -1589,7 +1961,7 @@ if phase < duty_cycle {
         let ctx = VmContext {
             t: 0.0, pixel: 0, pixels: 1, pos: 0.0, pos2d: (0.0, 0.0),
             param_values: &[1.0], // Green = 1
-            abs_t: 0.0, gradients: &[], curves: &[], colors: &[], paths: &[],
+            abs_t: 0.0, gradients: &[], curves: &[], colors: &[], paths: &[], positions: &[],
         };
         let color = execute(&compiled, &ctx);
         assert_eq!(color.r, 0);
@@ -1607,13 +1979,54 @@ if phase < duty_cycle {
         let ctx = VmContext {
             t: 0.0, pixel: 0, pixels: 1, pos: 0.0, pos2d: (0.0, 0.0),
             param_values: &[2.0], // Blue = 2 (falls to default)
-            abs_t: 0.0, gradients: &[], curves: &[], colors: &[], paths: &[],
+            abs_t: 0.0, gradients: &[], curves: &[], colors: &[], paths: &[], positions: &[],
         };
         let color = execute(&compiled, &ctx);
         assert_eq!(color.r, 0);
         assert_eq!(color.b, 255);
     }
 
+    #[test]
+    fn logical_ops_skip_the_right_side_once_decided() {
+        // At t = 0 the left side decides both, so `1.0 / t` never runs.
+        let cases = [
+            ("if t > 0.5 && 1.0 / t > 1.5 { rgb(1.0, 0.0, 0.0) } else { rgb(0.0, 0.0, 1.0) }", 0),
+            ("if t < 0.5 || 1.0 / t > 1.5 { rgb(1.0, 0.0, 0.0) } else { rgb(0.0, 0.0, 1.0) }", 255),
+        ];
+        for (src, red) in cases {
+            let compiled = crate::dsl::compile_source(src).unwrap();
+            let ctx = VmContext {
+                t: 0.0, pixel: 0, pixels: 1, pos: 0.0, pos2d: (0.0, 0.0), param_values: &[],
+                abs_t: 0.0, gradients: &[], curves: &[], colors: &[], paths: &[], positions: &[],
+            };
+            let mut profile = OpProfile::new(&compiled);
+            let color = execute_profiled(&compiled, &ctx, &mut VmBuffers::new(), &mut profile);
+            assert_eq!(color.r, red, "{src}");
+            assert!(profile.by_op(&compiled).iter().all(|(op, _)| op != "Div"), "{src}");
+        }
+        // When the left side doesn't decide, the right side still runs.
+        let both = "if t > 0.5 && 1.0 / t > 1.5 { rgb(1.0, 0.0, 0.0) } else { rgb(0.0, 0.0, 1.0) }";
+        assert_eq!(run_with_ctx(both, 0.6, 0, 1).r, 255);
+        assert_eq!(run_with_ctx(both, 0.9, 0, 1).r, 0);
+    }
+
+    #[test]
+    fn match_enum_evaluates_each_arm() {
+        let src = "enum Mode { Red, Green, Blue }\nparam mode: Mode = Red;\nmatch mode {\n    Red => rgb(1.0, 0.0, 0.0),\n    Mode.Green => rgb(0.0, 1.0, 0.0),\n    _ => rgb(0.0, 0.0, 1.0),\n}";
+        let compiled = crate::dsl::compile_source(src).unwrap();
+        assert!(compiled.ops.iter().any(|op| matches!(op, Op::EnumEq(_))));
+
+        for (mode, expected) in [(0.0, (255, 0, 0)), (1.0, (0, 255, 0)), (2.0, (0, 0, 255))] {
+            let ctx = VmContext {
+                t: 0.0, pixel: 0, pixels: 1, pos: 0.0, pos2d: (0.0, 0.0),
+                param_values: &[mode],
+                abs_t: 0.0, gradients: &[], curves: &[], colors: &[], paths: &[], positions: &[],
+            };
+            let color = execute(&compiled, &ctx);
+            assert_eq!((color.r, color.g, color.b), expected, "mode = {mode}");
+        }
+    }
+
     // ── Issue #74: Easing functions ─────────────────────────────
 
     #[test]
@@ -1870,6 +2283,27 @@ if phase < duty_cycle {
         assert_eq!(color.g, 128);
     }
 
+    // ── Vec3 ────────────────────────────────────────────────────
+
+    #[test]
+    fn vec3_add_converts_to_color() {
+        let color = run("vec3_to_color(vec3(1, 0, 0) + vec3(0, 1, 0))");
+        assert_eq!((color.r, color.g, color.b), (255, 255, 0));
+    }
+
+    #[test]
+    fn vec3_math_round_trips_through_color() {
+        // Halve the color, then read a component, a dot product, and a length back out
+        let color = run(
+            "let v = color_to_vec3(#ff00ff) * 0.5; \
+             let w = v - vec3(0.0, 0.0, 0.5); \
+             rgb(w.x, dot(v, vec3(1.0, 1.0, 1.0)), length(vec3(0.3, 0.4, 0.0)) + w.z)",
+        );
+        assert_eq!(color.r, 128); // 0.5
+        assert_eq!(color.g, 255); // 0.5 + 0.0 + 0.5
+        assert_eq!(color.b, 128); // 0.5 + 0.0
+    }
+
     // ── Color mix() ─────────────────────────────────────────────
 
     #[test]
@@ -2104,4 +2538,37 @@ if phase < duty_cycle {
         assert!(color.r <= 1, "rotate by PI: x should be ~-1.0");
         assert!(color.g <= 1, "rotate by PI: y should be ~0.0");
     }
+
+    #[test]
+    fn pos_of_neighbor_matches_pixel_spacing() {
+        // On a linear 5-pixel fixture the spacing is 1/4; scale it back up to 1.0.
+        let src = "let d = (pos_of(pixel + 1.0) - pos) * (pixels - 1.0); rgb(d, 0.0, 0.0)";
+        for pixel in 0..4 {
+            let color = run_with_ctx(src, 0.0, pixel, 5);
+            assert_eq!(color.r, 255, "pixel {pixel}: spacing should be 1/(pixels-1)");
+        }
+        // The last pixel has no right-hand neighbor; the index clamps to itself.
+        assert_eq!(run_with_ctx(src, 0.0, 4, 5).r, 0);
+    }
+
+    #[test]
+    fn lerp_color_matches_color_mix() {
+        let red = Color::rgb(255, 0, 0);
+        let blue = Color::rgb(0, 0, 255);
+        let via_builtin = run("lerp_color(rgb(1.0, 0.0, 0.0), rgb(0.0, 0.0, 1.0), 0.5)");
+        let via_mix = run("mix(rgb(1.0, 0.0, 0.0), rgb(0.0, 0.0, 1.0), 0.5)");
+        assert_eq!(via_builtin, via_mix);
+        assert_eq!(via_builtin, red.lerp(blue, 0.5));
+    }
+
+    #[test]
+    fn palette_blends_adjacent_stops_and_clamps() {
+        let stops = "rgb(1.0, 0.0, 0.0), rgb(0.0, 1.0, 0.0), rgb(0.0, 0.0, 1.0), rgb(1.0, 1.0, 1.0)";
+        let green = Color::rgb(0, 255, 0);
+        let blue = Color::rgb(0, 0, 255);
+        assert_eq!(run(&format!("palette(1.0, {stops})")), green);
+        assert_eq!(run(&format!("palette(1.5, {stops})")), green.lerp(blue, 0.5));
+        assert_eq!(run(&format!("palette(-2.0, {stops})")), Color::rgb(255, 0, 0));
+        assert_eq!(run(&format!("palette(7.0, {stops})")), Color::WHITE);
+    }
 }
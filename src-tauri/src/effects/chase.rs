@@ -2,13 +2,12 @@ use std::sync::LazyLock;
 
 use crate::model::{
     BlendMode, Color, ColorGradient, ColorMode, Curve, EffectParams, ParamKey, ParamSchema,
-    ParamType, ParamValue,
+    ParamType, ParamValue, PulseShape,
 };
 
 use super::Effect;
 
 static DEFAULT_MOVEMENT: LazyLock<Curve> = LazyLock::new(Curve::linear);
-static DEFAULT_PULSE: LazyLock<Curve> = LazyLock::new(Curve::triangle);
 static DEFAULT_WHITE_GRADIENT: LazyLock<ColorGradient> =
     LazyLock::new(|| ColorGradient::solid(Color::WHITE));
 
@@ -17,6 +16,30 @@ const DEFAULT_PULSE_WIDTH: f64 = 0.3;
 const DEFAULT_BACKGROUND_LEVEL: f64 = 0.0;
 const DEFAULT_REVERSE: bool = false;
 const DEFAULT_COLOR_MODE: ColorMode = ColorMode::Static;
+const DEFAULT_PULSE_SHAPE: PulseShape = PulseShape::Triangle;
+
+/// Brightness profile across the pulse: an explicit `PulseCurve` if set,
+/// otherwise the `PulseShape` preset.
+enum Pulse<'a> {
+    Curve(&'a Curve),
+    Shape(PulseShape),
+}
+
+impl<'a> Pulse<'a> {
+    fn from_params(params: &'a EffectParams) -> Self {
+        match params.get(&ParamKey::PulseCurve).and_then(ParamValue::as_curve) {
+            Some(curve) => Pulse::Curve(curve),
+            None => Pulse::Shape(params.pulse_shape_or(ParamKey::PulseShape, DEFAULT_PULSE_SHAPE)),
+        }
+    }
+
+    fn evaluate(&self, x: f64) -> f64 {
+        match self {
+            Pulse::Curve(curve) => curve.evaluate(x),
+            Pulse::Shape(shape) => shape.evaluate(x),
+        }
+    }
+}
 
 /// Batch evaluate: extract params once, loop over pixels.
 #[allow(clippy::too_many_arguments, clippy::cast_precision_loss, clippy::similar_names)]
@@ -31,7 +54,7 @@ pub fn evaluate_pixels_batch(
 ) {
     let gradient = params.gradient_or(ParamKey::Gradient, &DEFAULT_WHITE_GRADIENT);
     let movement_curve = params.curve_or(ParamKey::MovementCurve, &DEFAULT_MOVEMENT);
-    let pulse_curve = params.curve_or(ParamKey::PulseCurve, &DEFAULT_PULSE);
+    let pulse = Pulse::from_params(params);
     let color_mode = params.color_mode_or(ParamKey::ColorMode, DEFAULT_COLOR_MODE);
     let speed = params.float_or(ParamKey::Speed, DEFAULT_SPEED);
     let pulse_width = params.float_or(ParamKey::PulseWidth, DEFAULT_PULSE_WIDTH).clamp(0.01, 1.0);
@@ -58,7 +81,7 @@ pub fn evaluate_pixels_batch(
 
         let intensity = if dist < pulse_width {
             let pulse_pos = dist * inv_pulse;
-            pulse_curve.evaluate(pulse_pos).max(background_level)
+            pulse.evaluate(pulse_pos).max(background_level)
         } else {
             background_level
         };
@@ -74,8 +97,8 @@ pub fn evaluate_pixels_batch(
             ColorMode::Static | ColorMode::GradientPerPulse => gradient.evaluate(0.0),
         };
 
-        let effect_color = color.scale(intensity * opacity);
-        *pixel = pixel.blend(effect_color, blend_mode);
+        let effect_color = color.scale(intensity);
+        *pixel = pixel.blend_at(effect_color, blend_mode, opacity);
     }
 }
 
@@ -94,7 +117,7 @@ impl Effect for ChaseEffect {
     ) -> Color {
         let gradient = params.gradient_or(ParamKey::Gradient, &DEFAULT_WHITE_GRADIENT);
         let movement_curve = params.curve_or(ParamKey::MovementCurve, &DEFAULT_MOVEMENT);
-        let pulse_curve = params.curve_or(ParamKey::PulseCurve, &DEFAULT_PULSE);
+        let pulse = Pulse::from_params(params);
         let color_mode = params.color_mode_or(ParamKey::ColorMode, DEFAULT_COLOR_MODE);
         let speed = params.float_or(ParamKey::Speed, DEFAULT_SPEED);
         let pulse_width = params.float_or(ParamKey::PulseWidth, DEFAULT_PULSE_WIDTH).clamp(0.01, 1.0);
@@ -116,7 +139,7 @@ impl Effect for ChaseEffect {
 
         let intensity = if dist < pulse_width {
             let pulse_pos = dist / pulse_width;
-            pulse_curve.evaluate(pulse_pos).max(background_level)
+            pulse.evaluate(pulse_pos).max(background_level)
         } else {
             background_level
         };
@@ -161,6 +184,14 @@ impl Effect for ChaseEffect {
                 param_type: ParamType::Curve,
                 default: ParamValue::Curve(Curve::linear()),
             },
+            ParamSchema {
+                key: ParamKey::PulseShape,
+                label: "Pulse Shape".into(),
+                param_type: ParamType::Text {
+                    options: crate::util::serde_variant_names(PulseShape::all()),
+                },
+                default: ParamValue::Text("triangle".into()),
+            },
             ParamSchema {
                 key: ParamKey::PulseCurve,
                 label: "Pulse Curve".into(),
@@ -237,4 +268,35 @@ mod tests {
         let far_away = effect.evaluate(0.0, 50, 100, &params);
         assert_eq!(far_away, Color::BLACK);
     }
+
+    #[test]
+    fn square_pulse_stays_bright_to_the_edge_where_gaussian_fades() {
+        // Head at 0.0, pulse_width=0.3: pos=0.71 sits at dist=0.29, just
+        // inside the tail of the pulse; pos=0.85 is its center.
+        let edge = |shape: &str| {
+            let params = EffectParams::new()
+                .set(ParamKey::PulseWidth, ParamValue::Float(0.3))
+                .set(ParamKey::PulseShape, ParamValue::Text(shape.into()));
+            let mut dest = vec![Color::BLACK; 100];
+            evaluate_pixels_batch(0.0, &mut dest, 0, 100, &params, BlendMode::Override, 1.0);
+            (dest[71].r, dest[85].r)
+        };
+        let (square_edge, square_mid) = edge("square");
+        let (gaussian_edge, gaussian_mid) = edge("gaussian");
+
+        assert_eq!(square_edge, 255);
+        assert!(gaussian_edge < 10, "gaussian edge {gaussian_edge}");
+        assert_eq!(square_mid, 255);
+        assert!(gaussian_mid > 250, "gaussian center {gaussian_mid}");
+    }
+
+    #[test]
+    fn explicit_pulse_curve_overrides_shape() {
+        let params = EffectParams::new()
+            .set(ParamKey::PulseWidth, ParamValue::Float(0.3))
+            .set(ParamKey::PulseShape, ParamValue::Text("square".into()))
+            .set(ParamKey::PulseCurve, ParamValue::Curve(Curve::constant(0.5)));
+        let c = ChaseEffect.evaluate(0.0, 71, 100, &params);
+        assert_eq!(c.r, 128);
+    }
 }
@@ -43,8 +43,8 @@ pub fn evaluate_pixels_batch(
             ColorMode::Static => gradient.evaluate(0.0),
         };
 
-        let effect_color = color.scale(intensity * opacity);
-        *pixel = pixel.blend(effect_color, blend_mode);
+        let effect_color = color.scale(intensity);
+        *pixel = pixel.blend_at(effect_color, blend_mode, opacity);
     }
 }
 
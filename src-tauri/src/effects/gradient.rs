@@ -21,14 +21,14 @@ pub fn evaluate_pixels_batch(
     if colors.is_empty() {
         let c = Color::BLACK;
         for pixel in dest.iter_mut() {
-            *pixel = pixel.blend(c, blend_mode);
+            *pixel = pixel.blend_at(c, blend_mode, opacity);
         }
         return;
     }
     if colors.len() == 1 {
         let c = colors[0];
         for pixel in dest.iter_mut() {
-            *pixel = pixel.blend(c, blend_mode);
+            *pixel = pixel.blend_at(c, blend_mode, opacity);
         }
         return;
     }
@@ -53,8 +53,7 @@ pub fn evaluate_pixels_batch(
             continue;
         };
         let effect_color = c_a.lerp(c_b, frac);
-        let effect_color = if opacity < 1.0 { effect_color.scale(opacity) } else { effect_color };
-        *pixel = pixel.blend(effect_color, blend_mode);
+        *pixel = pixel.blend_at(effect_color, blend_mode, opacity);
     }
 }
 
@@ -4,12 +4,13 @@ pub mod gradient;
 pub mod rainbow;
 pub mod script;
 pub mod solid;
+pub mod sparkle;
 pub mod strobe;
 pub mod twinkle;
 pub mod wipe;
 
 use crate::model::show::Position2D;
-use crate::model::{BlendMode, Color, EffectKind, EffectParams, ParamSchema};
+use crate::model::{BlendMode, Color, EffectKind, EffectParams, ParamKey, ParamSchema};
 
 /// The core effect abstraction. An effect is a pure function from
 /// (time, spatial position, parameters) → color.
@@ -51,6 +52,7 @@ pub fn resolve_effect(kind: &EffectKind) -> Option<Box<dyn Effect>> {
         EffectKind::Strobe => Some(Box::new(strobe::StrobeEffect)),
         EffectKind::Gradient => Some(Box::new(gradient::GradientEffect)),
         EffectKind::Twinkle => Some(Box::new(twinkle::TwinkleEffect)),
+        EffectKind::Sparkle => Some(Box::new(sparkle::SparkleEffect)),
         EffectKind::Fade => Some(Box::new(fade::FadeEffect)),
         EffectKind::Wipe => Some(Box::new(wipe::WipeEffect)),
         EffectKind::Script(_) => None,
@@ -62,6 +64,17 @@ pub fn needs_positions(kind: &EffectKind) -> bool {
     matches!(kind, EffectKind::Wipe)
 }
 
+/// Returns true if the effect wants the song's beat grid (e.g. a beat-synced Strobe).
+pub fn needs_beats(kind: &EffectKind, params: &EffectParams) -> bool {
+    matches!(kind, EffectKind::Strobe) && params.bool_or(ParamKey::BeatSync, false)
+}
+
+/// Returns true if a randomized effect has no explicit `Seed` and should get one
+/// derived from its position in the sequence, so stacked copies don't line up.
+pub fn needs_seed(kind: &EffectKind, params: &EffectParams) -> bool {
+    matches!(kind, EffectKind::Twinkle | EffectKind::Sparkle) && params.get(&ParamKey::Seed).is_none()
+}
+
 /// Evaluate all pixels in a fixture in bulk via enum dispatch on EffectKind.
 /// Extracts params once, then loops over pixels, blending in-place.
 /// Zero-allocation, inlineable. The `Effect` trait remains for future user-defined effects.
@@ -69,6 +82,9 @@ pub fn needs_positions(kind: &EffectKind) -> bool {
 /// `positions` is only populated for spatial effects (e.g. Wipe). Non-spatial effects
 /// ignore it entirely (zero overhead).
 ///
+/// `beats` holds beat times normalized to the effect's time range, and is only
+/// populated for effects where `needs_beats` is true.
+///
 /// Returns `false` for `Script` kinds (caller must handle via DSL VM).
 #[inline]
 #[allow(clippy::too_many_arguments)]
@@ -82,14 +98,16 @@ pub fn evaluate_pixels(
     blend_mode: BlendMode,
     opacity: f64,
     positions: Option<&[Position2D]>,
+    beats: Option<&[f64]>,
 ) -> bool {
     match kind {
         EffectKind::Solid => { solid::evaluate_pixels_batch(t, dest, global_offset, total_pixels, params, blend_mode, opacity); true }
         EffectKind::Chase => { chase::evaluate_pixels_batch(t, dest, global_offset, total_pixels, params, blend_mode, opacity); true }
         EffectKind::Rainbow => { rainbow::evaluate_pixels_batch(t, dest, global_offset, total_pixels, params, blend_mode, opacity); true }
-        EffectKind::Strobe => { strobe::evaluate_pixels_batch(t, dest, global_offset, total_pixels, params, blend_mode, opacity); true }
+        EffectKind::Strobe => { strobe::evaluate_pixels_batch(t, dest, global_offset, total_pixels, params, blend_mode, opacity, beats); true }
         EffectKind::Gradient => { gradient::evaluate_pixels_batch(t, dest, global_offset, total_pixels, params, blend_mode, opacity); true }
         EffectKind::Twinkle => { twinkle::evaluate_pixels_batch(t, dest, global_offset, total_pixels, params, blend_mode, opacity); true }
+        EffectKind::Sparkle => { sparkle::evaluate_pixels_batch(t, dest, global_offset, total_pixels, params, blend_mode, opacity); true }
         EffectKind::Fade => { fade::evaluate_pixels_batch(t, dest, global_offset, total_pixels, params, blend_mode, opacity); true }
         EffectKind::Wipe => { wipe::evaluate_pixels_batch(t, dest, global_offset, total_pixels, params, blend_mode, opacity, positions); true }
         EffectKind::Script(_) => false,
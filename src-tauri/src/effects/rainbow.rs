@@ -34,8 +34,7 @@ pub fn evaluate_pixels_batch(
         let spatial = (global_offset + i) as f64 * spatial_scale;
         let hue = (time_offset + spatial) % 360.0;
         let effect_color = Color::from_hsv(hue, saturation, brightness);
-        let effect_color = if opacity < 1.0 { effect_color.scale(opacity) } else { effect_color };
-        *pixel = pixel.blend(effect_color, blend_mode);
+        *pixel = pixel.blend_at(effect_color, blend_mode, opacity);
     }
 }
 
@@ -1,5 +1,5 @@
 use crate::dsl::compiler::CompiledScript;
-use crate::dsl::vm::{self, VmBuffers, VmContext};
+use crate::dsl::vm::{self, OpProfile, VmBuffers, VmContext};
 use crate::model::color::Color;
 use crate::model::color_gradient::ColorGradient;
 use crate::model::curve::Curve;
@@ -13,7 +13,7 @@ use crate::dsl::ast;
 ///
 /// This mirrors the signature of native `evaluate_pixels_batch` functions.
 /// `positions` is provided for spatial scripts (`@spatial true`).
-#[allow(clippy::cast_precision_loss, clippy::too_many_arguments, clippy::indexing_slicing, clippy::implicit_hasher)]
+#[allow(clippy::cast_precision_loss, clippy::too_many_arguments, clippy::implicit_hasher)]
 pub fn evaluate_pixels_batch(
     script: &CompiledScript,
     t: f64,
@@ -27,61 +27,9 @@ pub fn evaluate_pixels_batch(
     positions: Option<&[Position2D]>,
     motion_paths: Option<&std::collections::HashMap<String, MotionPath>>,
 ) {
-    // Build runtime param arrays from EffectParams.
-    // Each compiled param maps to a slot by index.
-    let param_count = script.params.len();
-    let mut param_values = vec![0.0f64; param_count];
-    let mut gradients_owned: Vec<Option<ColorGradient>> = vec![None; param_count];
-    let mut curves_owned: Vec<Option<Curve>> = vec![None; param_count];
-    let mut colors: Vec<Option<Color>> = vec![None; param_count];
-    let mut paths_resolved: Vec<Option<&MotionPath>> = vec![None; param_count];
-
-    for (i, cp) in script.params.iter().enumerate() {
-        let key = ParamKey::Custom(cp.name.clone());
-        if let Some(val) = params.get(&key) {
-            match val {
-                ParamValue::Float(f) => param_values[i] = *f,
-                ParamValue::Int(n) => param_values[i] = f64::from(*n),
-                ParamValue::Bool(b) => param_values[i] = if *b { 1.0 } else { 0.0 },
-                ParamValue::Color(c) => colors[i] = Some(*c),
-                ParamValue::ColorGradient(g) => gradients_owned[i] = Some(g.clone()),
-                ParamValue::Curve(c) => curves_owned[i] = Some(c.clone()),
-                ParamValue::EnumVariant(variant_name) => {
-                    if let ast::ParamType::Named(ref type_name) = cp.ty {
-                        param_values[i] = resolve_enum_variant(script, type_name, variant_name);
-                    }
-                }
-                ParamValue::FlagSet(flags) => {
-                    if let ast::ParamType::Named(ref type_name) = cp.ty {
-                        param_values[i] = resolve_flag_set(script, type_name, flags);
-                    }
-                }
-                ParamValue::PathRef(name) => {
-                    if let Some(lib) = motion_paths {
-                        paths_resolved[i] = lib.get(name.as_str());
-                    }
-                }
-                // These ParamValue types are not used by DSL scripts.
-                // Listed explicitly so new variants trigger a compiler warning.
-                ParamValue::ColorList(_)
-                | ParamValue::Text(_)
-                | ParamValue::ColorMode(_)
-                | ParamValue::WipeDirection(_)
-                | ParamValue::GradientRef(_)
-                | ParamValue::CurveRef(_) => {}
-            }
-        }
-    }
-
-    // Build reference arrays for the VM context
-    let gradient_refs: Vec<Option<&ColorGradient>> = gradients_owned
-        .iter()
-        .map(|g| g.as_ref())
-        .collect();
-    let curve_refs: Vec<Option<&Curve>> = curves_owned
-        .iter()
-        .map(|c| c.as_ref())
-        .collect();
+    let runtime = RuntimeParams::resolve(script, params, motion_paths);
+    let gradient_refs = runtime.gradient_refs();
+    let curve_refs = runtime.curve_refs();
 
     // Reuse a single VmBuffers across all pixels to avoid per-pixel heap allocations.
     let mut vm_buffers = VmBuffers::new();
@@ -106,18 +54,127 @@ pub fn evaluate_pixels_batch(
             pos,
             pos2d,
             abs_t,
-            param_values: &param_values,
+            param_values: &runtime.values,
+            gradients: &gradient_refs,
+            curves: &curve_refs,
+            colors: &runtime.colors,
+            paths: &runtime.paths,
+            positions: &[],
+        };
+
+        let color = vm::execute_reuse(script, &ctx, &mut vm_buffers);
+        *pixel = pixel.blend_at(color, blend_mode, opacity);
+    }
+}
+
+/// Profile one frame of a compiled DSL script, counting every VM instruction
+/// executed across `pixel_count` pixels at time `t`.
+#[allow(clippy::cast_precision_loss)]
+pub fn profile_frame(
+    script: &CompiledScript,
+    t: f64,
+    pixel_count: usize,
+    params: &EffectParams,
+) -> OpProfile {
+    let runtime = RuntimeParams::resolve(script, params, None);
+    let gradient_refs = runtime.gradient_refs();
+    let curve_refs = runtime.curve_refs();
+
+    let mut vm_buffers = VmBuffers::new();
+    let mut profile = OpProfile::new(script);
+    let divisor = (pixel_count.saturating_sub(1)).max(1) as f64;
+
+    for pixel in 0..pixel_count {
+        let pos = if pixel_count > 1 { pixel as f64 / divisor } else { 0.0 };
+        let ctx = VmContext {
+            t,
+            pixel,
+            pixels: pixel_count,
+            pos,
+            pos2d: (pos, 0.0),
+            abs_t: 0.0,
+            param_values: &runtime.values,
             gradients: &gradient_refs,
             curves: &curve_refs,
-            colors: &colors,
-            paths: &paths_resolved,
+            colors: &runtime.colors,
+            paths: &runtime.paths,
+            positions: &[],
         };
+        vm::execute_profiled(script, &ctx, &mut vm_buffers, &mut profile);
+    }
+    profile
+}
+
+/// Runtime param arrays built from `EffectParams`.
+/// Each compiled param maps to a slot by index.
+struct RuntimeParams<'a> {
+    values: Vec<f64>,
+    gradients: Vec<Option<ColorGradient>>,
+    curves: Vec<Option<Curve>>,
+    colors: Vec<Option<Color>>,
+    paths: Vec<Option<&'a MotionPath>>,
+}
+
+impl<'a> RuntimeParams<'a> {
+    #[allow(clippy::indexing_slicing, clippy::implicit_hasher)]
+    fn resolve(
+        script: &CompiledScript,
+        params: &EffectParams,
+        motion_paths: Option<&'a std::collections::HashMap<String, MotionPath>>,
+    ) -> Self {
+        let param_count = script.params.len();
+        let mut values = vec![0.0f64; param_count];
+        let mut gradients: Vec<Option<ColorGradient>> = vec![None; param_count];
+        let mut curves: Vec<Option<Curve>> = vec![None; param_count];
+        let mut colors: Vec<Option<Color>> = vec![None; param_count];
+        let mut paths: Vec<Option<&MotionPath>> = vec![None; param_count];
 
-        let mut color = vm::execute_reuse(script, &ctx, &mut vm_buffers);
-        if opacity < 1.0 {
-            color = color.scale(opacity);
+        for (i, cp) in script.params.iter().enumerate() {
+            let key = ParamKey::Custom(cp.name.clone());
+            if let Some(val) = params.get(&key) {
+                match val {
+                    ParamValue::Float(f) => values[i] = *f,
+                    ParamValue::Int(n) => values[i] = f64::from(*n),
+                    ParamValue::Bool(b) => values[i] = if *b { 1.0 } else { 0.0 },
+                    ParamValue::Color(c) => colors[i] = Some(*c),
+                    ParamValue::ColorGradient(g) => gradients[i] = Some(g.clone()),
+                    ParamValue::Curve(c) => curves[i] = Some(c.clone()),
+                    ParamValue::EnumVariant(variant_name) => {
+                        if let ast::ParamType::Named(ref type_name) = cp.ty {
+                            values[i] = resolve_enum_variant(script, type_name, variant_name);
+                        }
+                    }
+                    ParamValue::FlagSet(flags) => {
+                        if let ast::ParamType::Named(ref type_name) = cp.ty {
+                            values[i] = resolve_flag_set(script, type_name, flags);
+                        }
+                    }
+                    ParamValue::PathRef(name) => {
+                        if let Some(lib) = motion_paths {
+                            paths[i] = lib.get(name.as_str());
+                        }
+                    }
+                    // These ParamValue types are not used by DSL scripts.
+                    // Listed explicitly so new variants trigger a compiler warning.
+                    ParamValue::ColorList(_)
+                    | ParamValue::Text(_)
+                    | ParamValue::ColorMode(_)
+                    | ParamValue::WipeDirection(_)
+                    | ParamValue::GradientRef(_)
+                    | ParamValue::CurveRef(_) => {}
+                }
+            }
         }
-        *pixel = pixel.blend(color, blend_mode);
+
+        Self { values, gradients, curves, colors, paths }
+    }
+
+    fn gradient_refs(&self) -> Vec<Option<&ColorGradient>> {
+        self.gradients.iter().map(|g| g.as_ref()).collect()
+    }
+
+    fn curve_refs(&self) -> Vec<Option<&Curve>> {
+        self.curves.iter().map(|c| c.as_ref()).collect()
     }
 }
 
@@ -13,9 +13,8 @@ pub fn evaluate_pixels_batch(
     opacity: f64,
 ) {
     let color = params.color_or(ParamKey::Color, Color::WHITE);
-    let color = if opacity < 1.0 { color.scale(opacity) } else { color };
     for pixel in dest.iter_mut() {
-        *pixel = pixel.blend(color, blend_mode);
+        *pixel = pixel.blend_at(color, blend_mode, opacity);
     }
 }
 
@@ -0,0 +1,176 @@
+use crate::model::{BlendMode, Color, EffectParams, ParamKey, ParamSchema, ParamType, ParamValue};
+
+use super::twinkle::{hash_pixel, seed};
+use super::Effect;
+
+const DEFAULT_DENSITY: f64 = 0.2;
+const DEFAULT_SPEED: f64 = 8.0;
+const DEFAULT_DECAY: f64 = 0.15;
+/// Shortest tail, so a zero decay still shows the flash for an instant.
+const MIN_DECAY: f64 = 0.001;
+/// Most earlier slots searched for triggers whose tails are still fading.
+const MAX_LOOKBACK: u64 = 64;
+/// Keeps the trigger-offset hash independent of the trigger-chance hash.
+const OFFSET_SALT: u64 = 0x9E37_79B9;
+
+/// When pixel `pixel` flashes during `slot`, if it does at all. Each slot is
+/// `1 / speed` long; a pixel flashes in a slot with probability `density`, at
+/// a hashed point inside it.
+#[allow(clippy::cast_precision_loss)]
+fn trigger_time(pixel: usize, slot: u64, speed: f64, density: f64, seed: u64) -> Option<f64> {
+    if hash_pixel(pixel, slot, seed) >= density {
+        return None;
+    }
+    let offset = hash_pixel(pixel, slot ^ OFFSET_SALT, seed);
+    Some((slot as f64 + offset) / speed)
+}
+
+/// Brightness of one pixel at `t`: full at the moment it flashes, falling
+/// linearly to zero over `decay`. Overlapping tails keep the brightest.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn level(t: f64, pixel: usize, speed: f64, density: f64, decay: f64, seed: u64) -> f64 {
+    if t < 0.0 || speed <= 0.0 || density <= 0.0 {
+        return 0.0;
+    }
+    let slot = (t * speed) as u64;
+    let lookback = ((decay * speed).ceil() as u64).min(MAX_LOOKBACK);
+    (slot.saturating_sub(lookback)..=slot)
+        .filter_map(|s| trigger_time(pixel, s, speed, density, seed))
+        .map(|at| t - at)
+        .filter(|&age| age >= 0.0)
+        .map(|age| 1.0 - age / decay)
+        .fold(0.0, f64::max)
+}
+
+/// Sparkle params, extracted once per evaluation.
+struct Sparkle {
+    color: Color,
+    density: f64,
+    speed: f64,
+    decay: f64,
+    seed: u64,
+}
+
+impl Sparkle {
+    fn from_params(params: &EffectParams) -> Self {
+        Self {
+            color: params.color_or(ParamKey::Color, Color::WHITE),
+            density: params.float_or(ParamKey::Density, DEFAULT_DENSITY).clamp(0.0, 1.0),
+            speed: params.float_or(ParamKey::Speed, DEFAULT_SPEED),
+            decay: params.float_or(ParamKey::Decay, DEFAULT_DECAY).max(MIN_DECAY),
+            seed: seed(params),
+        }
+    }
+
+    fn level(&self, t: f64, pixel: usize) -> f64 {
+        level(t, pixel, self.speed, self.density, self.decay, self.seed)
+    }
+}
+
+/// Batch evaluate: extract params once, loop over pixels.
+#[allow(clippy::too_many_arguments)]
+pub fn evaluate_pixels_batch(
+    t: f64,
+    dest: &mut [Color],
+    global_offset: usize,
+    _total_pixels: usize,
+    params: &EffectParams,
+    blend_mode: BlendMode,
+    opacity: f64,
+) {
+    let sparkle = Sparkle::from_params(params);
+    for (i, pixel) in dest.iter_mut().enumerate() {
+        let level = sparkle.level(t, global_offset + i);
+        let effect_color = if level > 0.0 {
+            sparkle.color.scale(level)
+        } else {
+            Color::BLACK
+        };
+        *pixel = pixel.blend_at(effect_color, blend_mode, opacity);
+    }
+}
+
+/// Random per-pixel flashes that fade out over `Decay` instead of switching
+/// off like Twinkle. Deterministic, like Twinkle: the same (time, pixel)
+/// always produces the same result.
+pub struct SparkleEffect;
+
+impl Effect for SparkleEffect {
+    fn evaluate(
+        &self,
+        t: f64,
+        pixel_index: usize,
+        _pixel_count: usize,
+        params: &EffectParams,
+    ) -> Color {
+        let sparkle = Sparkle::from_params(params);
+        sparkle.color.scale(sparkle.level(t, pixel_index))
+    }
+
+    fn name(&self) -> &'static str {
+        "Sparkle"
+    }
+
+    fn param_schema(&self) -> Vec<ParamSchema> {
+        vec![
+            ParamSchema {
+                key: ParamKey::Color,
+                label: "Color".into(),
+                param_type: ParamType::Color,
+                default: ParamValue::Color(Color::WHITE),
+            },
+            ParamSchema {
+                key: ParamKey::Density,
+                label: "Density".into(),
+                param_type: ParamType::Float { min: 0.0, max: 1.0, step: 0.01 },
+                default: ParamValue::Float(DEFAULT_DENSITY),
+            },
+            ParamSchema {
+                key: ParamKey::Speed,
+                label: "Speed".into(),
+                param_type: ParamType::Float { min: 0.5, max: 30.0, step: 0.5 },
+                default: ParamValue::Float(DEFAULT_SPEED),
+            },
+            ParamSchema {
+                key: ParamKey::Decay,
+                label: "Decay".into(),
+                param_type: ParamType::Float { min: 0.0, max: 1.0, step: 0.01 },
+                default: ParamValue::Float(DEFAULT_DECAY),
+            },
+            ParamSchema {
+                key: ParamKey::Seed,
+                label: "Seed".into(),
+                param_type: ParamType::Int { min: 0, max: 9999 },
+                default: ParamValue::Int(0),
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::indexing_slicing)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn triggered_pixel_fades_to_black() {
+        let (speed, density, decay) = (DEFAULT_SPEED, DEFAULT_DENSITY, DEFAULT_DECAY);
+        // A pixel that flashes in the first slot and stays quiet for the
+        // next few, so its tail is the only light on it.
+        let (pixel, at) = (0..1000)
+            .find_map(|p| {
+                let quiet = (1..=3).all(|s| trigger_time(p, s, speed, density, 0).is_none());
+                trigger_time(p, 0, speed, density, 0).filter(|_| quiet).map(|at| (p, at))
+            })
+            .unwrap();
+
+        let params = EffectParams::new().set(ParamKey::Decay, ParamValue::Float(decay));
+        let red = |t: f64| SparkleEffect.evaluate(t, pixel, 1000, &params).r;
+
+        assert_eq!(red(at), 255);
+        let tail: Vec<u8> = [0.25, 0.5, 0.75].iter().map(|f| red(at + decay * f)).collect();
+        assert!(tail.windows(2).all(|w| w[0] > w[1]), "tail {tail:?} should fall");
+        assert!(tail[0] < 255 && tail[2] > 0);
+        assert_eq!(red(at + decay * 1.01), 0);
+    }
+}
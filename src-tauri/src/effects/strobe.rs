@@ -4,8 +4,31 @@ use super::Effect;
 
 const DEFAULT_RATE: f64 = 10.0;
 const DEFAULT_DUTY_CYCLE: f64 = 0.5;
+const DEFAULT_BEAT_SYNC: bool = false;
+const DEFAULT_BEAT_DIVISION: f64 = 1.0;
+
+/// Phase within the current flash, locked to the beat grid. `beats` are in
+/// effect-normalized time; `division` is flashes per beat (0.5 = every other beat).
+/// Times outside the grid extrapolate from the nearest beat interval.
+/// Returns `None` when there are too few beats to define an interval.
+#[allow(clippy::cast_precision_loss)]
+fn beat_phase(t: f64, beats: &[f64], division: f64) -> Option<f64> {
+    let last_segment = beats.len().checked_sub(2)?;
+    let k = beats.partition_point(|&b| b <= t).saturating_sub(1).min(last_segment);
+    let (b0, b1) = (*beats.get(k)?, *beats.get(k + 1)?);
+    let span = b1 - b0;
+    if span <= 0.0 {
+        return None;
+    }
+    let beat_pos = k as f64 + (t - b0) / span;
+    Some((beat_pos * division).rem_euclid(1.0))
+}
 
 /// Batch evaluate: extract params once, compute single color, blend all pixels.
+///
+/// With `BeatSync` on and a beat grid available, flashes follow the beats;
+/// otherwise the strobe free-runs at `Rate`.
+#[allow(clippy::too_many_arguments)]
 pub fn evaluate_pixels_batch(
     t: f64,
     dest: &mut [Color],
@@ -14,17 +37,22 @@ pub fn evaluate_pixels_batch(
     params: &EffectParams,
     blend_mode: BlendMode,
     opacity: f64,
+    beats: Option<&[f64]>,
 ) {
     let color = params.color_or(ParamKey::Color, Color::WHITE);
     let rate = params.float_or(ParamKey::Rate, DEFAULT_RATE);
     let duty_cycle = params.float_or(ParamKey::DutyCycle, DEFAULT_DUTY_CYCLE).clamp(0.0, 1.0);
+    let beat_sync = params.bool_or(ParamKey::BeatSync, DEFAULT_BEAT_SYNC);
+    let division = params.float_or(ParamKey::BeatDivision, DEFAULT_BEAT_DIVISION).max(0.125);
 
-    let phase = (t * rate).fract();
+    let phase = beats
+        .filter(|_| beat_sync)
+        .and_then(|b| beat_phase(t, b, division))
+        .unwrap_or_else(|| (t * rate).fract());
     let effect_color = if phase < duty_cycle { color } else { Color::BLACK };
-    let effect_color = if opacity < 1.0 { effect_color.scale(opacity) } else { effect_color };
 
     for pixel in dest.iter_mut() {
-        *pixel = pixel.blend(effect_color, blend_mode);
+        *pixel = pixel.blend_at(effect_color, blend_mode, opacity);
     }
 }
 
@@ -75,6 +103,18 @@ impl Effect for StrobeEffect {
                 param_type: ParamType::Float { min: 0.0, max: 1.0, step: 0.01 },
                 default: ParamValue::Float(DEFAULT_DUTY_CYCLE),
             },
+            ParamSchema {
+                key: ParamKey::BeatSync,
+                label: "Sync to Beats".into(),
+                param_type: ParamType::Bool,
+                default: ParamValue::Bool(DEFAULT_BEAT_SYNC),
+            },
+            ParamSchema {
+                key: ParamKey::BeatDivision,
+                label: "Flashes per Beat".into(),
+                param_type: ParamType::Float { min: 0.25, max: 8.0, step: 0.25 },
+                default: ParamValue::Float(DEFAULT_BEAT_DIVISION),
+            },
         ]
     }
 }
@@ -109,4 +149,44 @@ mod tests {
             .set(ParamKey::DutyCycle, ParamValue::Float(0.5));
         assert_eq!(effect.evaluate(0.25, 0, 1, &params), red);
     }
+
+    fn batch(t: f64, params: &EffectParams, beats: Option<&[f64]>) -> Color {
+        let mut dest = [Color::BLACK];
+        evaluate_pixels_batch(t, &mut dest, 0, 1, params, BlendMode::Override, 1.0, beats);
+        dest[0]
+    }
+
+    #[test]
+    fn beat_sync_flashes_follow_uneven_beats() {
+        // Beats at uneven spacing; two flashes per beat, each on for half its slot.
+        let beats = [0.0, 0.4, 1.0];
+        let params = EffectParams::new()
+            .set(ParamKey::Rate, ParamValue::Float(1.0))
+            .set(ParamKey::DutyCycle, ParamValue::Float(0.5))
+            .set(ParamKey::BeatSync, ParamValue::Bool(true))
+            .set(ParamKey::BeatDivision, ParamValue::Float(2.0));
+
+        // First beat [0, 0.4): on [0, 0.1), off [0.1, 0.2), on [0.2, 0.3), off [0.3, 0.4)
+        // Second beat [0.4, 1.0): on [0.4, 0.55), off [0.55, 0.7), on [0.7, 0.85), off after
+        let expected = [
+            (0.0, true), (0.09, true), (0.11, false), (0.21, true), (0.31, false),
+            (0.4, true), (0.54, true), (0.56, false), (0.71, true), (0.86, false),
+        ];
+        for (t, on) in expected {
+            let want = if on { Color::WHITE } else { Color::BLACK };
+            assert_eq!(batch(t, &params, Some(&beats)), want, "t={t}");
+        }
+    }
+
+    #[test]
+    fn beat_sync_falls_back_to_rate_without_analysis() {
+        let params = EffectParams::new()
+            .set(ParamKey::Rate, ParamValue::Float(1.0))
+            .set(ParamKey::DutyCycle, ParamValue::Float(0.5))
+            .set(ParamKey::BeatSync, ParamValue::Bool(true));
+        assert_eq!(batch(0.25, &params, None), Color::WHITE);
+        assert_eq!(batch(0.75, &params, None), Color::BLACK);
+        // A single beat can't define an interval either
+        assert_eq!(batch(0.75, &params, Some(&[0.1])), Color::BLACK);
+    }
 }
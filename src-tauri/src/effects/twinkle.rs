@@ -19,6 +19,7 @@ pub fn evaluate_pixels_batch(
     let color = params.color_or(ParamKey::Color, Color::WHITE);
     let density = params.float_or(ParamKey::Density, DEFAULT_DENSITY).clamp(0.0, 1.0);
     let speed = params.float_or(ParamKey::Speed, DEFAULT_SPEED);
+    let seed = seed(params);
 
     let slot = (t * speed) as u64;
     let next_slot = slot + 1;
@@ -29,17 +30,17 @@ pub fn evaluate_pixels_batch(
 
     for (i, pixel) in dest.iter_mut().enumerate() {
         let pixel_index = global_offset + i;
-        let brightness_current = hash_pixel(pixel_index, slot);
-        let brightness_next = hash_pixel(pixel_index, next_slot);
+        let brightness_current = hash_pixel(pixel_index, slot, seed);
+        let brightness_next = hash_pixel(pixel_index, next_slot, seed);
         let brightness = brightness_current * inv_frac + brightness_next * frac;
 
         let effect_color = if brightness > threshold {
-            let intensity = (brightness - threshold) * inv_density * opacity;
+            let intensity = (brightness - threshold) * inv_density;
             color.scale(intensity)
         } else {
             Color::BLACK
         };
-        *pixel = pixel.blend(effect_color, blend_mode);
+        *pixel = pixel.blend_at(effect_color, blend_mode, opacity);
     }
 }
 
@@ -47,10 +48,19 @@ pub fn evaluate_pixels_batch(
 /// (time, pixel) always produces the same result (no actual RNG needed).
 pub struct TwinkleEffect;
 
+/// The effect's `Seed` param; negative or missing seeds count as 0.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub(super) fn seed(params: &EffectParams) -> u64 {
+    params.float_or(ParamKey::Seed, 0.0).max(0.0) as u64
+}
+
 /// Simple deterministic hash for reproducible "randomness" without state.
+/// Seed 0 leaves the hash unchanged, so unseeded effects keep their pattern.
 #[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-fn hash_pixel(pixel: usize, time_slot: u64) -> f64 {
-    let mut x = (pixel as u64).wrapping_mul(2_654_435_761) ^ time_slot.wrapping_mul(2_246_822_519);
+pub(super) fn hash_pixel(pixel: usize, time_slot: u64, seed: u64) -> f64 {
+    let mut x = (pixel as u64).wrapping_mul(2_654_435_761)
+        ^ time_slot.wrapping_mul(2_246_822_519)
+        ^ seed.wrapping_mul(3_266_489_917);
     x = x.wrapping_mul(x).wrapping_add(x);
     x ^= x >> 16;
     (x & 0xFFFF) as f64 / 65535.0
@@ -68,14 +78,15 @@ impl Effect for TwinkleEffect {
         let color = params.color_or(ParamKey::Color, Color::WHITE);
         let density = params.float_or(ParamKey::Density, DEFAULT_DENSITY).clamp(0.0, 1.0);
         let speed = params.float_or(ParamKey::Speed, DEFAULT_SPEED);
+        let seed = seed(params);
 
         // Discrete time slots for twinkling.
         let slot = (t * speed) as u64;
         let next_slot = slot + 1;
 
         // Hash determines brightness at each slot.
-        let brightness_current = hash_pixel(pixel_index, slot);
-        let brightness_next = hash_pixel(pixel_index, next_slot);
+        let brightness_current = hash_pixel(pixel_index, slot, seed);
+        let brightness_next = hash_pixel(pixel_index, next_slot, seed);
 
         // Interpolate between slots for smooth transitions.
         let frac = (t * speed).fract();
@@ -114,6 +125,12 @@ impl Effect for TwinkleEffect {
                 param_type: ParamType::Float { min: 0.5, max: 30.0, step: 0.5 },
                 default: ParamValue::Float(DEFAULT_SPEED),
             },
+            ParamSchema {
+                key: ParamKey::Seed,
+                label: "Seed".into(),
+                param_type: ParamType::Int { min: 0, max: 9999 },
+                default: ParamValue::Int(0),
+            },
         ]
     }
 }
@@ -144,4 +161,19 @@ mod tests {
         let all_same = colors.windows(2).all(|w| w[0] == w[1]);
         assert!(!all_same, "twinkle should produce spatial variation");
     }
+
+    fn lit_pixels(seed: i32) -> Vec<usize> {
+        let params = EffectParams::new()
+            .set(ParamKey::Density, ParamValue::Float(0.5))
+            .set(ParamKey::Seed, ParamValue::Int(seed));
+        let mut dest = vec![Color::BLACK; 64];
+        evaluate_pixels_batch(0.4, &mut dest, 0, 64, &params, BlendMode::Override, 1.0);
+        dest.iter().enumerate().filter(|(_, c)| **c != Color::BLACK).map(|(i, _)| i).collect()
+    }
+
+    #[test]
+    fn seed_selects_distinct_but_stable_patterns() {
+        assert_eq!(lit_pixels(7), lit_pixels(7));
+        assert_ne!(lit_pixels(7), lit_pixels(8));
+    }
 }
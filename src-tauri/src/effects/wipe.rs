@@ -39,8 +39,12 @@ fn project_position(pos: Position2D, direction: WipeDirection, cx: f32, cy: f32)
             // Chebyshev distance (max of |dx|, |dy|) from center
             let dx = (x - cx).abs();
             let dy = (y - cy).abs();
-            let max_possible = 1.0f64.max(cx.max(1.0 - cx)).max(cy.max(1.0 - cy));
-            (dx.max(dy) / max_possible).clamp(0.0, 1.0)
+            let max_d = cx.max(1.0 - cx).max(cy.max(1.0 - cy));
+            if max_d > 0.0 {
+                (dx.max(dy) / max_d).clamp(0.0, 1.0)
+            } else {
+                0.0
+            }
         }
         WipeDirection::Circle => {
             // Euclidean distance from center
@@ -69,6 +73,27 @@ fn project_position(pos: Position2D, direction: WipeDirection, cx: f32, cy: f32)
     }
 }
 
+/// Scalar position for a pixel with no layout data. Linear directions sweep by
+/// index; radial directions place the pixels on the horizontal midline (as the
+/// evaluator does for fixtures without layout) so they still grow from the center.
+#[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+fn fallback_position(
+    index: usize,
+    count: usize,
+    direction: WipeDirection,
+    cx: f32,
+    cy: f32,
+) -> f64 {
+    match direction {
+        WipeDirection::Burst | WipeDirection::Circle | WipeDirection::Diamond => {
+            let x = if count > 1 { index as f32 / (count - 1) as f32 } else { 0.5 };
+            project_position(Position2D { x, y: 0.5 }, direction, cx, cy)
+        }
+        _ if count > 0 => index as f64 / count as f64,
+        _ => 0.0,
+    }
+}
+
 /// Batch evaluate: extract params once, loop over pixels with spatial positions.
 #[allow(clippy::too_many_arguments, clippy::cast_precision_loss, clippy::cast_possible_truncation, clippy::cast_sign_loss, clippy::similar_names)]
 pub fn evaluate_pixels_batch(
@@ -106,7 +131,6 @@ pub fn evaluate_pixels_batch(
     // head_pos ranges from -pulse_width to 1.0, mapped from head [0, 1]
     let head_pos = head * (1.0 + pulse_width) - pulse_width;
 
-    let inv_total = 1.0 / (total_pixels as f64);
     let inv_pulse = 1.0 / pulse_width;
 
     for (i, pixel) in dest.iter_mut().enumerate() {
@@ -116,8 +140,7 @@ pub fn evaluate_pixels_batch(
             let pos = positions.get(i).copied().unwrap_or(Position2D { x: 0.0, y: 0.0 });
             project_position(pos, direction, center_x, center_y)
         } else {
-            // Fallback: use linear index as horizontal position
-            ((global_offset + i) as f64) * inv_total
+            fallback_position(global_offset + i, total_pixels, direction, center_x, center_y)
         };
 
         // Distance from sweep head to this pixel
@@ -167,15 +190,15 @@ pub fn evaluate_pixels_batch(
             ColorMode::Static => gradient.evaluate(0.0),
         };
 
-        let effect_color = color.scale(intensity * opacity);
-        *pixel = pixel.blend(effect_color, blend_mode);
+        let effect_color = color.scale(intensity);
+        *pixel = pixel.blend_at(effect_color, blend_mode, opacity);
     }
 }
 
 pub struct WipeEffect;
 
 impl Effect for WipeEffect {
-    #[allow(clippy::cast_precision_loss)]
+    #[allow(clippy::cast_possible_truncation)]
     fn evaluate(
         &self,
         t: f64,
@@ -183,12 +206,11 @@ impl Effect for WipeEffect {
         pixel_count: usize,
         params: &EffectParams,
     ) -> Color {
-        // Fallback: use index-based horizontal position
-        let pos = if pixel_count > 0 {
-            pixel_index as f64 / pixel_count as f64
-        } else {
-            0.0
-        };
+        // No positions on this path: use the index-based fallback
+        let direction = params.wipe_direction_or(ParamKey::Direction, DEFAULT_DIRECTION);
+        let center_x = params.float_or(ParamKey::CenterX, DEFAULT_CENTER_X) as f32;
+        let center_y = params.float_or(ParamKey::CenterY, DEFAULT_CENTER_Y) as f32;
+        let pos = fallback_position(pixel_index, pixel_count, direction, center_x, center_y);
 
         let gradient = params.gradient_or(ParamKey::Gradient, &DEFAULT_WHITE_GRADIENT);
         let movement_curve = params.curve_or(ParamKey::MovementCurve, &DEFAULT_MOVEMENT);
@@ -478,6 +500,62 @@ mod tests {
         assert!(dest[0].r > dest[2].r, "center should be brighter than corner");
     }
 
+    #[test]
+    fn project_burst_edge_is_one() {
+        let val = project_position(pos(1.0, 0.5), WipeDirection::Burst, 0.5, 0.5);
+        assert!((val - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn circle_wipe_grid_reveals_center_out() {
+        // 5x5 grid: at every point in the sweep, a pixel closer to the center is
+        // never dimmer than one farther out.
+        let positions: Vec<Position2D> = (0..25)
+            .map(|i| pos((i % 5) as f32 / 4.0, (i / 5) as f32 / 4.0))
+            .collect();
+        let dist = |p: Position2D| ((p.x - 0.5).powi(2) + (p.y - 0.5).powi(2)).sqrt();
+        let params = EffectParams::new()
+            .set(ParamKey::Direction, ParamValue::WipeDirection(WipeDirection::Circle))
+            .set(ParamKey::PulseWidth, ParamValue::Float(0.05))
+            .set(ParamKey::WipeOn, ParamValue::Bool(true));
+
+        for step in 1..10 {
+            let t = f64::from(step) / 10.0;
+            let mut dest = vec![Color::BLACK; 25];
+            evaluate_pixels_batch(t, &mut dest, 0, 25, &params, BlendMode::Override, 1.0, Some(&positions));
+            for a in 0..25 {
+                for b in 0..25 {
+                    if dist(positions[a]) < dist(positions[b]) {
+                        assert!(
+                            dest[a].r >= dest[b].r,
+                            "t={t}: pixel {a} is closer to center but dimmer than pixel {b}"
+                        );
+                    }
+                }
+            }
+        }
+
+        // Midway the center is lit while the corners are still dark
+        let mut dest = vec![Color::BLACK; 25];
+        evaluate_pixels_batch(0.5, &mut dest, 0, 25, &params, BlendMode::Override, 1.0, Some(&positions));
+        assert!(dest[12].r > 200, "center should be lit, got r={}", dest[12].r);
+        assert!(dest[0].r < 50, "corner should be dark, got r={}", dest[0].r);
+        // Edge midpoints are reached before the corners
+        assert!(dest[2].r >= dest[0].r);
+    }
+
+    #[test]
+    fn circle_fallback_grows_from_middle_of_strand() {
+        let params = EffectParams::new()
+            .set(ParamKey::Direction, ParamValue::WipeDirection(WipeDirection::Circle))
+            .set(ParamKey::PulseWidth, ParamValue::Float(0.05));
+        let mut dest = vec![Color::BLACK; 11];
+        evaluate_pixels_batch(0.5, &mut dest, 0, 11, &params, BlendMode::Override, 1.0, None);
+        assert!(dest[5].r > 200, "middle pixel should be lit, got r={}", dest[5].r);
+        assert!(dest[0].r < 50 && dest[10].r < 50, "ends should still be dark");
+        assert_eq!(WipeEffect.evaluate(0.5, 5, 11, &params), dest[5]);
+    }
+
     #[test]
     fn wipe_off_inverts() {
         let positions: Vec<Position2D> = (0..10)
@@ -1,5 +1,5 @@
 use std::borrow::Cow;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 use crate::dsl::compiler::CompiledScript;
@@ -8,7 +8,9 @@ use crate::model::fixture::EffectTarget;
 use crate::model::show::Position2D;
 use crate::model::color_gradient::ColorGradient;
 use crate::model::curve::Curve;
-use crate::model::{Color, EffectKind, FixtureId, GroupId, Show};
+use crate::model::{
+    BlendMode, Color, ColorF, EffectKind, FixtureId, GroupId, ParamKey, ParamValue, Show, TimeRange,
+};
 use crate::util::base64_encode;
 
 /// A single frame of output: colors for every pixel of every fixture.
@@ -65,6 +67,55 @@ fn is_all_black(colors: &[Color]) -> bool {
     colors.iter().all(|c| *c == Color::BLACK)
 }
 
+/// Normalize the beats covering `range` (plus one on either side, so flashes
+/// at the edges still have a full interval) to the effect's [0, 1] time.
+fn effect_beat_times(beats: &[f64], range: &TimeRange) -> Vec<f64> {
+    let lo = beats.partition_point(|&b| b < range.start()).saturating_sub(1);
+    let hi = (beats.partition_point(|&b| b <= range.end()) + 1).min(beats.len());
+    beats
+        .get(lo..hi)
+        .unwrap_or_default()
+        .iter()
+        .map(|&b| range.normalize_unclamped(b))
+        .collect()
+}
+
+/// Seed for a randomized effect that didn't set one, derived from where it sits.
+pub(crate) fn default_seed(track_idx: usize, effect_idx: usize) -> ParamValue {
+    let seed = track_idx.wrapping_mul(1000).wrapping_add(effect_idx);
+    ParamValue::Int(i32::try_from(seed).unwrap_or(i32::MAX))
+}
+
+/// Starting pixels for a track layer. `Override` layers start from what's
+/// below, so their effects blend exactly as they would directly on the frame;
+/// other modes start from black and are blended on afterwards.
+fn layer_base(mode: BlendMode, below: Option<&Vec<ColorF>>, pixel_count: usize) -> Vec<ColorF> {
+    match below {
+        Some(pixels) if mode == BlendMode::Override => pixels.clone(),
+        _ => vec![ColorF::BLACK; pixel_count],
+    }
+}
+
+/// Blend an effect's raw output onto `dest`. Pixels the effect left
+/// transparent (e.g. outside a wipe's edge) keep what's below.
+fn blend_effect(pixels: &mut [ColorF], effect: &[Color], mode: BlendMode, opacity: f64) {
+    for (dest, &src) in pixels.iter_mut().zip(effect) {
+        if src != Color::TRANSPARENT {
+            *dest = dest.blend_at(src.into(), mode, opacity);
+        }
+    }
+}
+
+/// Combine a finished track layer with the frame below it. An `Override`
+/// layer crossfades from the frame by `opacity`; other modes blend the layer,
+/// scaled by `opacity`, onto it.
+fn blend_layer(below: &mut [ColorF], layer: &[ColorF], mode: BlendMode, opacity: f64) {
+    let opacity = opacity.clamp(0.0, 1.0);
+    for (dest, &src) in below.iter_mut().zip(layer) {
+        *dest = dest.blend_at(src, mode, opacity);
+    }
+}
+
 /// Evaluate the full show at a given time, producing a Frame.
 ///
 /// Pipeline:
@@ -72,12 +123,23 @@ fn is_all_black(colors: &[Color]) -> bool {
 /// 2. For each track (bottom to top):
 ///    a. Find all EffectInstances active at time `t`
 ///    b. For each targeted fixture, evaluate the effect
-///    c. Blend the result onto the accumulated frame using the track's blend mode
-/// 3. Encode only non-black fixtures as base64 for compact IPC transfer
+///    c. Blend the result onto the track's layer using the effect's blend mode
+///    d. Combine the layer with the frame using the track's blend mode and opacity
+/// 3. Quantize to 8 bits and encode only non-black fixtures as base64 for
+///    compact IPC transfer
+///
+/// Effects render 8-bit colors, but all blending happens in `f32` so stacks
+/// of low-opacity layers don't band.
 ///
 /// If `effect_filter` is provided, only the specified (track_index, effect_index)
 /// pairs are evaluated. All other effects are skipped.
-#[allow(clippy::cast_precision_loss, clippy::implicit_hasher)]
+///
+/// If `solo_tracks` is non-empty, only those tracks are evaluated; otherwise
+/// muted tracks are skipped.
+///
+/// `beats` is the song's beat grid in seconds, when analysis is loaded. Effects
+/// that sync to beats fall back to their free-running rate without it.
+#[allow(clippy::cast_precision_loss, clippy::implicit_hasher, clippy::too_many_arguments)]
 pub fn evaluate(
     show: &Show,
     sequence_index: usize,
@@ -86,6 +148,8 @@ pub fn evaluate(
     script_cache: Option<&HashMap<String, Arc<CompiledScript>>>,
     gradient_lib: &HashMap<String, ColorGradient>,
     curve_lib: &HashMap<String, Curve>,
+    solo_tracks: &HashSet<usize>,
+    beats: Option<&[f64]>,
 ) -> Frame {
     let Some(sequence) = show.sequences.get(sequence_index) else {
         return Frame {
@@ -124,11 +188,22 @@ pub fn evaluate(
     let all_fixture_ids: Vec<FixtureId> = show.fixtures.iter().map(|f| f.id).collect();
 
     // Initialize only targeted fixtures to black (lazy via HashMap).
-    let mut frame: HashMap<FixtureId, Vec<Color>> = HashMap::new();
+    let mut frame: HashMap<FixtureId, Vec<ColorF>> = HashMap::new();
     let mut warnings: Vec<String> = Vec::new();
+    // One fixture's raw effect output, reused across effects.
+    let mut effect_pixels: Vec<Color> = Vec::new();
 
     // Evaluate tracks bottom-to-top.
     for (track_idx, track) in sequence.tracks.iter().enumerate() {
+        let renders = if solo_tracks.is_empty() {
+            !track.muted
+        } else {
+            solo_tracks.contains(&track_idx)
+        };
+        if !renders {
+            continue;
+        }
+
         // If a filter is active, skip tracks that have no entries in the filter.
         if let Some(filter) = effect_filter {
             if !filter.iter().any(|&(ti, _)| ti == track_idx) {
@@ -150,7 +225,6 @@ pub fn evaluate(
                     && effect_filter
                         .is_none_or(|f| f.iter().any(|&(ti, fi)| ti == track_idx && fi == ei))
             })
-            .map(|(_, e)| e)
             .collect();
         if active.is_empty() {
             continue;
@@ -191,18 +265,31 @@ pub fn evaluate(
             .map(|id| pixel_counts.get(id).copied().unwrap_or(0))
             .sum();
 
-        for effect_instance in &active {
+        // Tracks with their own blend or opacity composite into a separate layer,
+        // which is combined with the frame once all their effects have run.
+        let direct = track.blends_directly();
+        let mut layer: HashMap<FixtureId, Vec<ColorF>> = HashMap::new();
+
+        for &(effect_idx, effect_instance) in &active {
             let t_normalized = effect_instance.time_range.normalize(t);
             let spatial = effects::needs_positions(&effect_instance.kind);
 
             // Resolve library references once per effect (outside per-fixture loop).
             // Use Cow to avoid cloning when there are no refs to resolve.
-            let resolved_params: Cow<'_, _> = if effect_instance.params.has_refs() {
+            let mut resolved_params: Cow<'_, _> = if effect_instance.params.has_refs() {
                 Cow::Owned(effect_instance.params.resolve_refs(gradient_lib, curve_lib))
             } else {
                 Cow::Borrowed(&effect_instance.params)
             };
 
+            // Unseeded randomized effects get a seed from their (track, effect) slot,
+            // which is stable across frames and saves.
+            if effects::needs_seed(&effect_instance.kind, &resolved_params) {
+                let seed = default_seed(track_idx, effect_idx);
+                resolved_params =
+                    Cow::Owned(resolved_params.into_owned().set(ParamKey::Seed, seed));
+            }
+
             // Build flat position vector for spatial effects (e.g. Wipe).
             // Non-spatial effects skip this entirely (zero overhead).
             let positions: Option<Vec<Position2D>> = if spatial {
@@ -244,6 +331,12 @@ pub fn evaluate(
                 None
             };
 
+            // Beat grid in effect-normalized time, for beat-synced effects only.
+            let effect_beats: Option<Vec<f64>> = beats
+                .filter(|_| effects::needs_beats(&effect_instance.kind, &resolved_params))
+                .map(|b| effect_beat_times(b, &effect_instance.time_range));
+
+            let opacity = effect_instance.opacity_at(t_normalized);
             let mut global_pixel_offset = 0usize;
 
             for &fixture_id in target_fixtures {
@@ -252,26 +345,26 @@ pub fn evaluate(
                     continue;
                 }
 
-                let pixels = frame
-                    .entry(fixture_id)
-                    .or_insert_with(|| vec![Color::BLACK; pixel_count]);
-
                 // Slice positions for this fixture (spatial effects only).
                 let fixture_positions = positions
                     .as_ref()
                     .and_then(|p| p.get(global_pixel_offset..global_pixel_offset + pixel_count));
 
                 // Phase 2: Batch pixel evaluation (params extracted once, not per-pixel).
+                // Effects write their raw colors; blending happens below, in float.
+                effect_pixels.clear();
+                effect_pixels.resize(pixel_count, Color::TRANSPARENT);
                 let handled = effects::evaluate_pixels(
                     &effect_instance.kind,
                     t_normalized,
-                    pixels,
+                    &mut effect_pixels,
                     global_pixel_offset,
                     total_pixels,
                     &resolved_params,
-                    effect_instance.blend_mode,
-                    effect_instance.opacity,
+                    BlendMode::Override,
+                    1.0,
                     fixture_positions,
+                    effect_beats.as_deref(),
                 );
 
                 // Fall through to DSL VM for Script effects.
@@ -284,12 +377,12 @@ pub fn evaluate(
                                 compiled,
                                 t_normalized,
                                 t,
-                                pixels,
+                                &mut effect_pixels,
                                 global_pixel_offset,
                                 total_pixels,
                                 &resolved_params,
-                                effect_instance.blend_mode,
-                                effect_instance.opacity,
+                                BlendMode::Override,
+                                1.0,
                                 fixture_positions,
                                 Some(motion_path_lib),
                             );
@@ -297,15 +390,37 @@ pub fn evaluate(
                     }
                 }
 
+                let pixels = if direct {
+                    frame
+                        .entry(fixture_id)
+                        .or_insert_with(|| vec![ColorF::BLACK; pixel_count])
+                } else {
+                    layer.entry(fixture_id).or_insert_with(|| {
+                        layer_base(track.blend_mode, frame.get(&fixture_id), pixel_count)
+                    })
+                };
+                blend_effect(pixels, &effect_pixels, effect_instance.blend_mode, opacity);
+
                 global_pixel_offset += pixel_count;
             }
         }
+
+        for (fixture_id, layer_pixels) in layer {
+            let below = frame
+                .entry(fixture_id)
+                .or_insert_with(|| vec![ColorF::BLACK; layer_pixels.len()]);
+            blend_layer(below, &layer_pixels, track.blend_mode, track.opacity);
+        }
     }
 
     // Only encode non-black fixtures as base64 for compact IPC transfer.
     Frame {
         fixtures: frame
             .into_iter()
+            .map(|(id, pixels)| {
+                let colors: Vec<Color> = pixels.into_iter().map(ColorF::to_color).collect();
+                (id, colors)
+            })
             .filter(|(_, colors)| !is_all_black(colors))
             .map(|(id, colors)| (id.0, colors_to_base64(&colors)))
             .collect(),
@@ -346,6 +461,7 @@ mod tests {
             bulb_shape: BulbShape::LED,
             display_radius_override: None,
             channel_order: ChannelOrder::Rgb,
+            gamma: None,
         }
     }
 
@@ -356,6 +472,9 @@ mod tests {
             time_range: TimeRange::new(start, end).unwrap(),
             blend_mode: BlendMode::Override,
             opacity: 1.0,
+            opacity_curve: None,
+            fade_in: 0.0,
+            fade_out: 0.0,
         }
     }
 
@@ -366,6 +485,9 @@ mod tests {
             time_range: TimeRange::new(start, end).unwrap(),
             blend_mode,
             opacity,
+            opacity_curve: None,
+            fade_in: 0.0,
+            fade_out: 0.0,
         }
     }
 
@@ -375,14 +497,7 @@ mod tests {
             fixtures,
             groups: vec![],
             layout: Layout { fixtures: vec![] },
-            sequences: vec![Sequence {
-                name: "Seq".into(),
-                duration: 10.0,
-                frame_rate: 30.0,
-                audio_file: None,
-                tracks,
-                motion_paths: std::collections::HashMap::new(),
-            }],
+            sequences: vec![Sequence::for_test("Seq", 10.0, tracks)],
             patches: vec![],
             controllers: vec![],
         }
@@ -436,10 +551,14 @@ mod tests {
             vec![Track {
                 name: "T1".into(),
                 target: EffectTarget::All,
+                locked: false,
+                muted: false,
+                blend_mode: BlendMode::Override,
+                opacity: 1.0,
                 effects: vec![solid_effect(0.0, 5.0, red)],
             }],
         );
-        let frame = evaluate(&show, 0, 2.5, None, None, &HashMap::new(), &HashMap::new());
+        let frame = evaluate(&show, 0, 2.5, None, None, &HashMap::new(), &HashMap::new(), &HashSet::new(), None);
         let colors = decode_fixture_colors(&frame, 1).expect("fixture should be in frame");
         assert_eq!(colors.len(), 5);
         for c in &colors {
@@ -456,19 +575,23 @@ mod tests {
             vec![Track {
                 name: "T1".into(),
                 target: EffectTarget::All,
+                locked: false,
+                muted: false,
+                blend_mode: BlendMode::Override,
+                opacity: 1.0,
                 effects: vec![solid_effect(2.0, 4.0, Color::WHITE)],
             }],
         );
         // Before range
-        let frame = evaluate(&show, 0, 1.0, None, None, &HashMap::new(), &HashMap::new());
+        let frame = evaluate(&show, 0, 1.0, None, None, &HashMap::new(), &HashMap::new(), &HashSet::new(), None);
         assert!(frame.fixtures.is_empty());
 
         // Inside range
-        let frame = evaluate(&show, 0, 3.0, None, None, &HashMap::new(), &HashMap::new());
+        let frame = evaluate(&show, 0, 3.0, None, None, &HashMap::new(), &HashMap::new(), &HashSet::new(), None);
         assert!(frame.fixtures.contains_key(&1));
 
         // Well past the end (beyond epsilon tolerance)
-        let frame = evaluate(&show, 0, 4.1, None, None, &HashMap::new(), &HashMap::new());
+        let frame = evaluate(&show, 0, 4.1, None, None, &HashMap::new(), &HashMap::new(), &HashSet::new(), None);
         assert!(frame.fixtures.is_empty());
     }
 
@@ -480,16 +603,24 @@ mod tests {
                 Track {
                     name: "Bottom".into(),
                     target: EffectTarget::All,
+                    locked: false,
+                    muted: false,
+                    blend_mode: BlendMode::Override,
+                    opacity: 1.0,
                     effects: vec![solid_effect(0.0, 5.0, Color::rgb(255, 0, 0))],
                 },
                 Track {
                     name: "Top".into(),
                     target: EffectTarget::All,
+                    locked: false,
+                    muted: false,
+                    blend_mode: BlendMode::Override,
+                    opacity: 1.0,
                     effects: vec![solid_effect(0.0, 5.0, Color::rgb(0, 255, 0))],
                 },
             ],
         );
-        let frame = evaluate(&show, 0, 1.0, None, None, &HashMap::new(), &HashMap::new());
+        let frame = evaluate(&show, 0, 1.0, None, None, &HashMap::new(), &HashMap::new(), &HashSet::new(), None);
         let colors = decode_fixture_colors(&frame, 1).unwrap();
         assert_eq!(colors[0], Color::rgb(0, 255, 0));
     }
@@ -502,22 +633,68 @@ mod tests {
                 Track {
                     name: "Bottom".into(),
                     target: EffectTarget::All,
+                    locked: false,
+                    muted: false,
+                    blend_mode: BlendMode::Override,
+                    opacity: 1.0,
                     effects: vec![solid_effect(0.0, 5.0, Color::rgb(200, 100, 0))],
                 },
                 Track {
                     name: "Top".into(),
                     target: EffectTarget::All,
+                    locked: false,
+                    muted: false,
+                    blend_mode: BlendMode::Override,
+                    opacity: 1.0,
                     effects: vec![solid_effect_blended(0.0, 5.0, Color::rgb(200, 200, 50), BlendMode::Add, 1.0)],
                 },
             ],
         );
-        let frame = evaluate(&show, 0, 1.0, None, None, &HashMap::new(), &HashMap::new());
+        let frame = evaluate(&show, 0, 1.0, None, None, &HashMap::new(), &HashMap::new(), &HashSet::new(), None);
         let colors = decode_fixture_colors(&frame, 1).unwrap();
         assert_eq!(colors[0].r, 255); // saturated
         assert_eq!(colors[0].g, 255); // 100+200 saturated
         assert_eq!(colors[0].b, 50);
     }
 
+    #[test]
+    fn override_effect_at_half_opacity_crossfades_with_below() {
+        let mut strobe = solid_effect_blended(0.0, 5.0, Color::WHITE, BlendMode::Override, 0.5);
+        strobe.kind = EffectKind::Strobe;
+        for top in [
+            solid_effect_blended(0.0, 5.0, Color::WHITE, BlendMode::Override, 0.5),
+            strobe,
+        ] {
+            let show = simple_show(
+                vec![fixture(1, 1)],
+                vec![
+                    Track {
+                        name: "Bottom".into(),
+                        target: EffectTarget::All,
+                        locked: false,
+                        muted: false,
+                        blend_mode: BlendMode::Override,
+                        opacity: 1.0,
+                        effects: vec![solid_effect(0.0, 5.0, Color::rgb(255, 0, 0))],
+                    },
+                    Track {
+                        name: "Top".into(),
+                        target: EffectTarget::All,
+                        locked: false,
+                        muted: false,
+                        blend_mode: BlendMode::Override,
+                        opacity: 1.0,
+                        effects: vec![top],
+                    },
+                ],
+            );
+            let frame = evaluate(&show, 0, 0.0, None, None, &HashMap::new(), &HashMap::new(), &HashSet::new(), None);
+            let colors = decode_fixture_colors(&frame, 1).unwrap();
+            // Pink: half white over red, not a dimmed white replacing it.
+            assert_eq!((colors[0].r, colors[0].g, colors[0].b), (255, 128, 128));
+        }
+    }
+
     #[test]
     fn two_tracks_multiply_blend() {
         let show = simple_show(
@@ -526,16 +703,24 @@ mod tests {
                 Track {
                     name: "Bottom".into(),
                     target: EffectTarget::All,
+                    locked: false,
+                    muted: false,
+                    blend_mode: BlendMode::Override,
+                    opacity: 1.0,
                     effects: vec![solid_effect(0.0, 5.0, Color::rgb(255, 128, 0))],
                 },
                 Track {
                     name: "Top".into(),
                     target: EffectTarget::All,
+                    locked: false,
+                    muted: false,
+                    blend_mode: BlendMode::Override,
+                    opacity: 1.0,
                     effects: vec![solid_effect_blended(0.0, 5.0, Color::WHITE, BlendMode::Multiply, 1.0)],
                 },
             ],
         );
-        let frame = evaluate(&show, 0, 1.0, None, None, &HashMap::new(), &HashMap::new());
+        let frame = evaluate(&show, 0, 1.0, None, None, &HashMap::new(), &HashMap::new(), &HashSet::new(), None);
         let colors = decode_fixture_colors(&frame, 1).unwrap();
         // Multiply with white is identity
         assert_eq!(colors[0].r, 255);
@@ -552,6 +737,10 @@ mod tests {
             vec![Track {
                 name: "T1".into(),
                 target: EffectTarget::All,
+                locked: false,
+                muted: false,
+                blend_mode: BlendMode::Override,
+                opacity: 1.0,
                 effects: vec![EffectInstance {
                     kind: EffectKind::Gradient,
                     params: EffectParams::new().set(
@@ -561,10 +750,13 @@ mod tests {
                     time_range: TimeRange::new(0.0, 5.0).unwrap(),
                     blend_mode: BlendMode::Override,
                     opacity: 1.0,
+                    opacity_curve: None,
+                    fade_in: 0.0,
+                    fade_out: 0.0,
                 }],
             }],
         );
-        let frame = evaluate(&show, 0, 1.0, None, None, &HashMap::new(), &HashMap::new());
+        let frame = evaluate(&show, 0, 1.0, None, None, &HashMap::new(), &HashMap::new(), &HashSet::new(), None);
         let f2 = decode_fixture_colors(&frame, 2).unwrap();
         // Fixture 2 starts at global pixel 5. With 10 total pixels, pixel 5 has pos=5/9≈0.56.
         // Fixture 2 pixel 3 has global pos 8/9≈0.89 → should be bright.
@@ -582,7 +774,7 @@ mod tests {
     #[test]
     fn empty_show_produces_empty_frame() {
         let show = Show::empty();
-        let frame = evaluate(&show, 0, 0.0, None, None, &HashMap::new(), &HashMap::new());
+        let frame = evaluate(&show, 0, 0.0, None, None, &HashMap::new(), &HashMap::new(), &HashSet::new(), None);
         assert!(frame.fixtures.is_empty());
     }
 
@@ -593,10 +785,14 @@ mod tests {
             vec![Track {
                 name: "T1".into(),
                 target: EffectTarget::All,
+                locked: false,
+                muted: false,
+                blend_mode: BlendMode::Override,
+                opacity: 1.0,
                 effects: vec![solid_effect(0.0, 5.0, Color::WHITE)],
             }],
         );
-        let frame = evaluate(&show, 0, 1.0, None, None, &HashMap::new(), &HashMap::new());
+        let frame = evaluate(&show, 0, 1.0, None, None, &HashMap::new(), &HashMap::new(), &HashSet::new(), None);
         assert!(!frame.fixtures.contains_key(&1));
         assert!(frame.fixtures.contains_key(&2));
     }
@@ -608,10 +804,14 @@ mod tests {
             vec![Track {
                 name: "T1".into(),
                 target: EffectTarget::All,
+                locked: false,
+                muted: false,
+                blend_mode: BlendMode::Override,
+                opacity: 1.0,
                 effects: vec![solid_effect(0.0, 5.0, Color::BLACK)],
             }],
         );
-        let frame = evaluate(&show, 0, 1.0, None, None, &HashMap::new(), &HashMap::new());
+        let frame = evaluate(&show, 0, 1.0, None, None, &HashMap::new(), &HashMap::new(), &HashSet::new(), None);
         assert!(frame.fixtures.is_empty());
     }
 
@@ -622,6 +822,10 @@ mod tests {
             vec![Track {
                 name: "T1".into(),
                 target: EffectTarget::Group(GroupId(10)),
+                locked: false,
+                muted: false,
+                blend_mode: BlendMode::Override,
+                opacity: 1.0,
                 effects: vec![solid_effect(0.0, 5.0, Color::rgb(255, 0, 0))],
             }],
         );
@@ -633,12 +837,47 @@ mod tests {
                 GroupMember::Fixture(FixtureId(3)),
             ],
         });
-        let frame = evaluate(&show, 0, 1.0, None, None, &HashMap::new(), &HashMap::new());
+        let frame = evaluate(&show, 0, 1.0, None, None, &HashMap::new(), &HashMap::new(), &HashSet::new(), None);
         assert!(frame.fixtures.contains_key(&1));
         assert!(!frame.fixtures.contains_key(&2)); // not in group
         assert!(frame.fixtures.contains_key(&3));
     }
 
+    #[test]
+    fn nested_group_target_lights_subgroup_fixtures_once() {
+        // Top group = fixture 1 + Sub; Sub = fixtures 1 and 2. Fixture 3 is outside.
+        let add = solid_effect_blended(0.0, 5.0, Color::rgb(100, 0, 0), BlendMode::Add, 1.0);
+        let mut show = simple_show(
+            vec![fixture(1, 2), fixture(2, 2), fixture(3, 2)],
+            vec![Track {
+                name: "T1".into(),
+                target: EffectTarget::Group(GroupId(10)),
+                locked: false,
+                muted: false,
+                blend_mode: BlendMode::Override,
+                opacity: 1.0,
+                effects: vec![add],
+            }],
+        );
+        show.groups.push(FixtureGroup {
+            id: GroupId(10),
+            name: "Top".into(),
+            members: vec![GroupMember::Fixture(FixtureId(1)), GroupMember::Group(GroupId(11))],
+        });
+        show.groups.push(FixtureGroup {
+            id: GroupId(11),
+            name: "Sub".into(),
+            members: vec![GroupMember::Fixture(FixtureId(1)), GroupMember::Fixture(FixtureId(2))],
+        });
+        let frame = evaluate(&show, 0, 1.0, None, None, &HashMap::new(), &HashMap::new(), &HashSet::new(), None);
+
+        let red = Color::rgb(100, 0, 0);
+        assert_eq!(decode_fixture_colors(&frame, 2), Some(vec![red; 2]), "nested member lit");
+        // Reached directly and through Sub, but only blended once.
+        assert_eq!(decode_fixture_colors(&frame, 1), Some(vec![red; 2]));
+        assert!(!frame.fixtures.contains_key(&3));
+    }
+
     #[test]
     fn effect_filter_limits_evaluation() {
         let show = simple_show(
@@ -647,18 +886,26 @@ mod tests {
                 Track {
                     name: "T0".into(),
                     target: EffectTarget::All,
+                    locked: false,
+                    muted: false,
+                    blend_mode: BlendMode::Override,
+                    opacity: 1.0,
                     effects: vec![solid_effect(0.0, 5.0, Color::rgb(255, 0, 0))],
                 },
                 Track {
                     name: "T1".into(),
                     target: EffectTarget::All,
+                    locked: false,
+                    muted: false,
+                    blend_mode: BlendMode::Override,
+                    opacity: 1.0,
                     effects: vec![solid_effect(0.0, 5.0, Color::rgb(0, 255, 0))],
                 },
             ],
         );
         // Only evaluate track 0, effect 0
         let filter = [(0usize, 0usize)];
-        let frame = evaluate(&show, 0, 1.0, Some(&filter), None, &HashMap::new(), &HashMap::new());
+        let frame = evaluate(&show, 0, 1.0, Some(&filter), None, &HashMap::new(), &HashMap::new(), &HashSet::new(), None);
         let colors = decode_fixture_colors(&frame, 1).unwrap();
         assert_eq!(colors[0], Color::rgb(255, 0, 0)); // track 1 was skipped
     }
@@ -671,16 +918,24 @@ mod tests {
                 Track {
                     name: "Bottom".into(),
                     target: EffectTarget::All,
+                    locked: false,
+                    muted: false,
+                    blend_mode: BlendMode::Override,
+                    opacity: 1.0,
                     effects: vec![solid_effect(0.0, 5.0, Color::rgb(200, 150, 100))],
                 },
                 Track {
                     name: "Top".into(),
                     target: EffectTarget::All,
+                    locked: false,
+                    muted: false,
+                    blend_mode: BlendMode::Override,
+                    opacity: 1.0,
                     effects: vec![solid_effect_blended(0.0, 5.0, Color::rgb(50, 200, 30), BlendMode::Subtract, 1.0)],
                 },
             ],
         );
-        let frame = evaluate(&show, 0, 1.0, None, None, &HashMap::new(), &HashMap::new());
+        let frame = evaluate(&show, 0, 1.0, None, None, &HashMap::new(), &HashMap::new(), &HashSet::new(), None);
         let colors = decode_fixture_colors(&frame, 1).unwrap();
         assert_eq!(colors[0].r, 150); // 200 - 50
         assert_eq!(colors[0].g, 0);   // 150 - 200 saturates to 0
@@ -695,16 +950,24 @@ mod tests {
                 Track {
                     name: "Bottom".into(),
                     target: EffectTarget::All,
+                    locked: false,
+                    muted: false,
+                    blend_mode: BlendMode::Override,
+                    opacity: 1.0,
                     effects: vec![solid_effect(0.0, 5.0, Color::rgb(200, 50, 100))],
                 },
                 Track {
                     name: "Top".into(),
                     target: EffectTarget::All,
+                    locked: false,
+                    muted: false,
+                    blend_mode: BlendMode::Override,
+                    opacity: 1.0,
                     effects: vec![solid_effect_blended(0.0, 5.0, Color::rgb(100, 150, 80), BlendMode::Min, 1.0)],
                 },
             ],
         );
-        let frame = evaluate(&show, 0, 1.0, None, None, &HashMap::new(), &HashMap::new());
+        let frame = evaluate(&show, 0, 1.0, None, None, &HashMap::new(), &HashMap::new(), &HashSet::new(), None);
         let colors = decode_fixture_colors(&frame, 1).unwrap();
         assert_eq!(colors[0].r, 100);
         assert_eq!(colors[0].g, 50);
@@ -719,16 +982,24 @@ mod tests {
                 Track {
                     name: "Bottom".into(),
                     target: EffectTarget::All,
+                    locked: false,
+                    muted: false,
+                    blend_mode: BlendMode::Override,
+                    opacity: 1.0,
                     effects: vec![solid_effect(0.0, 5.0, Color::rgb(200, 100, 0))],
                 },
                 Track {
                     name: "Top".into(),
                     target: EffectTarget::All,
+                    locked: false,
+                    muted: false,
+                    blend_mode: BlendMode::Override,
+                    opacity: 1.0,
                     effects: vec![solid_effect_blended(0.0, 5.0, Color::rgb(100, 50, 200), BlendMode::Average, 1.0)],
                 },
             ],
         );
-        let frame = evaluate(&show, 0, 1.0, None, None, &HashMap::new(), &HashMap::new());
+        let frame = evaluate(&show, 0, 1.0, None, None, &HashMap::new(), &HashMap::new(), &HashSet::new(), None);
         let colors = decode_fixture_colors(&frame, 1).unwrap();
         assert_eq!(colors[0].r, 150); // (200+100)/2
         assert_eq!(colors[0].g, 75);  // (100+50)/2
@@ -743,16 +1014,24 @@ mod tests {
                 Track {
                     name: "Bottom".into(),
                     target: EffectTarget::All,
+                    locked: false,
+                    muted: false,
+                    blend_mode: BlendMode::Override,
+                    opacity: 1.0,
                     effects: vec![solid_effect(0.0, 5.0, Color::rgb(128, 0, 255))],
                 },
                 Track {
                     name: "Top".into(),
                     target: EffectTarget::All,
+                    locked: false,
+                    muted: false,
+                    blend_mode: BlendMode::Override,
+                    opacity: 1.0,
                     effects: vec![solid_effect_blended(0.0, 5.0, Color::rgb(128, 128, 0), BlendMode::Screen, 1.0)],
                 },
             ],
         );
-        let frame = evaluate(&show, 0, 1.0, None, None, &HashMap::new(), &HashMap::new());
+        let frame = evaluate(&show, 0, 1.0, None, None, &HashMap::new(), &HashMap::new(), &HashSet::new(), None);
         let colors = decode_fixture_colors(&frame, 1).unwrap();
         // screen(128,128) = 255 - (127*127)/255 = 255 - 63 = 192
         assert_eq!(colors[0].r, 192);
@@ -770,17 +1049,25 @@ mod tests {
                 Track {
                     name: "Bottom".into(),
                     target: EffectTarget::All,
+                    locked: false,
+                    muted: false,
+                    blend_mode: BlendMode::Override,
+                    opacity: 1.0,
                     effects: vec![solid_effect(0.0, 5.0, Color::rgb(255, 128, 64))],
                 },
                 Track {
                     name: "Top".into(),
                     target: EffectTarget::All,
+                    locked: false,
+                    muted: false,
+                    blend_mode: BlendMode::Override,
+                    opacity: 1.0,
                     // fg is non-black → mask produces black
                     effects: vec![solid_effect_blended(0.0, 5.0, Color::rgb(10, 0, 0), BlendMode::Mask, 1.0)],
                 },
             ],
         );
-        let frame = evaluate(&show, 0, 1.0, None, None, &HashMap::new(), &HashMap::new());
+        let frame = evaluate(&show, 0, 1.0, None, None, &HashMap::new(), &HashMap::new(), &HashSet::new(), None);
         // All pixels should be black (masked out), so frame is empty
         assert!(frame.fixtures.is_empty());
     }
@@ -793,17 +1080,25 @@ mod tests {
                 Track {
                     name: "Bottom".into(),
                     target: EffectTarget::All,
+                    locked: false,
+                    muted: false,
+                    blend_mode: BlendMode::Override,
+                    opacity: 1.0,
                     effects: vec![solid_effect(0.0, 5.0, Color::rgb(200, 100, 50))],
                 },
                 Track {
                     name: "Top".into(),
                     target: EffectTarget::All,
+                    locked: false,
+                    muted: false,
+                    blend_mode: BlendMode::Override,
+                    opacity: 1.0,
                     // Pure white fg has brightness ~1.0, so bg is preserved
                     effects: vec![solid_effect_blended(0.0, 5.0, Color::WHITE, BlendMode::IntensityOverlay, 1.0)],
                 },
             ],
         );
-        let frame = evaluate(&show, 0, 1.0, None, None, &HashMap::new(), &HashMap::new());
+        let frame = evaluate(&show, 0, 1.0, None, None, &HashMap::new(), &HashMap::new(), &HashSet::new(), None);
         let colors = decode_fixture_colors(&frame, 1).unwrap();
         assert_eq!(colors[0].r, 200);
         assert_eq!(colors[0].g, 100);
@@ -817,16 +1112,79 @@ mod tests {
             vec![Track {
                 name: "T1".into(),
                 target: EffectTarget::All,
+                locked: false,
+                muted: false,
+                blend_mode: BlendMode::Override,
+                opacity: 1.0,
                 effects: vec![solid_effect_blended(0.0, 5.0, Color::rgb(200, 100, 50), BlendMode::Override, 0.5)],
             }],
         );
-        let frame = evaluate(&show, 0, 1.0, None, None, &HashMap::new(), &HashMap::new());
+        let frame = evaluate(&show, 0, 1.0, None, None, &HashMap::new(), &HashMap::new(), &HashSet::new(), None);
         let colors = decode_fixture_colors(&frame, 1).unwrap();
         assert_eq!(colors[0].r, 100);
         assert_eq!(colors[0].g, 50);
         assert_eq!(colors[0].b, 25);
     }
 
+    #[test]
+    fn track_opacity_half_halves_its_contribution() {
+        let track = |color: Color, blend_mode: BlendMode, opacity: f64| Track {
+            name: "T".into(),
+            target: EffectTarget::All,
+            locked: false,
+            muted: false,
+            blend_mode,
+            opacity,
+            effects: vec![solid_effect(0.0, 5.0, color)],
+        };
+        let render = |top: Track| {
+            let show = simple_show(
+                vec![fixture(1, 1)],
+                vec![track(Color::rgb(0, 0, 200), BlendMode::Override, 1.0), top],
+            );
+            let frame = evaluate(&show, 0, 1.0, None, None, &HashMap::new(), &HashMap::new(), &HashSet::new(), None);
+            decode_fixture_colors(&frame, 1).unwrap()[0]
+        };
+
+        // Override crossfades from the track below.
+        let c = render(track(Color::rgb(200, 0, 0), BlendMode::Override, 0.5));
+        assert_eq!((c.r, c.g, c.b), (100, 0, 100));
+
+        // Add contributes half the track's light on top of the track below.
+        let c = render(track(Color::rgb(200, 0, 0), BlendMode::Add, 0.5));
+        assert_eq!((c.r, c.g, c.b), (100, 0, 200));
+    }
+
+    #[test]
+    fn one_second_fade_in_ramps_opacity_over_first_second() {
+        let mut effect = solid_effect(2.0, 6.0, Color::rgb(200, 200, 200));
+        effect.fade_in = 1.0;
+        let show = simple_show(
+            vec![fixture(1, 1)],
+            vec![Track {
+                name: "T1".into(),
+                target: EffectTarget::All,
+                locked: false,
+                muted: false,
+                blend_mode: BlendMode::Override,
+                opacity: 1.0,
+                effects: vec![effect],
+            }],
+        );
+        let red_at = |t: f64| {
+            let frame = evaluate(&show, 0, t, None, None, &HashMap::new(), &HashMap::new(), &HashSet::new(), None);
+            decode_fixture_colors(&frame, 1).map_or(0, |c| c[0].r)
+        };
+
+        assert_eq!(red_at(2.0), 0);
+        assert_eq!(red_at(2.25), 50);
+        assert_eq!(red_at(2.5), 100);
+        assert_eq!(red_at(2.75), 150);
+        assert_eq!(red_at(3.0), 200);
+        // The body past the fade handle stays at full opacity.
+        assert_eq!(red_at(5.0), 200);
+    }
+
     #[test]
     fn opacity_zero_produces_no_output() {
         let show = simple_show(
@@ -834,11 +1192,147 @@ mod tests {
             vec![Track {
                 name: "T1".into(),
                 target: EffectTarget::All,
+                locked: false,
+                muted: false,
+                blend_mode: BlendMode::Override,
+                opacity: 1.0,
                 effects: vec![solid_effect_blended(0.0, 5.0, Color::WHITE, BlendMode::Override, 0.0)],
             }],
         );
-        let frame = evaluate(&show, 0, 1.0, None, None, &HashMap::new(), &HashMap::new());
+        let frame = evaluate(&show, 0, 1.0, None, None, &HashMap::new(), &HashMap::new(), &HashSet::new(), None);
         // opacity=0 means all black, so frame should be empty
         assert!(frame.fixtures.is_empty());
     }
+
+    #[test]
+    fn many_faint_layers_accumulate_in_float() {
+        // Each layer adds 0.4 of a level: rounding after every blend loses it all.
+        let dim = Color::rgb(10, 10, 10);
+        let layers = 40;
+        let show = simple_show(
+            vec![fixture(1, 1)],
+            vec![Track {
+                name: "Haze".into(),
+                target: EffectTarget::All,
+                locked: false,
+                muted: false,
+                blend_mode: BlendMode::Override,
+                opacity: 1.0,
+                effects: (0..layers)
+                    .map(|_| solid_effect_blended(0.0, 5.0, dim, BlendMode::Add, 0.04))
+                    .collect(),
+            }],
+        );
+
+        let eight_bit = (0..layers).fold(Color::BLACK, |c, _| c.blend_at(dim, BlendMode::Add, 0.04));
+        assert_eq!(eight_bit, Color::BLACK);
+
+        let frame = evaluate(&show, 0, 1.0, None, None, &HashMap::new(), &HashMap::new(), &HashSet::new(), None);
+        let colors = decode_fixture_colors(&frame, 1).unwrap();
+        assert_ne!(colors[0], eight_bit);
+        assert_eq!((colors[0].r, colors[0].g, colors[0].b), (16, 16, 16));
+    }
+
+    #[test]
+    fn solo_overrides_mute_and_other_tracks() {
+        let track = |id: u32, muted: bool| Track {
+            name: format!("T{id}"),
+            target: EffectTarget::Fixtures(vec![FixtureId(id)]),
+            locked: false,
+            muted,
+            blend_mode: BlendMode::Override,
+            opacity: 1.0,
+            effects: vec![solid_effect(0.0, 5.0, Color::WHITE)],
+        };
+        let show = simple_show(
+            vec![fixture(1, 1), fixture(2, 1), fixture(3, 1)],
+            vec![track(1, false), track(2, true), track(3, false)],
+        );
+
+        // Without solo, the muted track is skipped.
+        let frame = evaluate(&show, 0, 1.0, None, None, &HashMap::new(), &HashMap::new(), &HashSet::new(), None);
+        let mut lit: Vec<_> = frame.fixtures.keys().copied().collect();
+        lit.sort_unstable();
+        assert_eq!(lit, vec![1, 3]);
+
+        // Soloing the muted track renders it alone.
+        let solo = HashSet::from([1]);
+        let frame = evaluate(&show, 0, 1.0, None, None, &HashMap::new(), &HashMap::new(), &solo, None);
+        assert_eq!(frame.fixtures.keys().copied().collect::<Vec<_>>(), vec![2]);
+    }
+
+    #[test]
+    fn beat_synced_strobe_uses_song_beats() {
+        // One flash per beat, on for the first half. The effect starts mid-song,
+        // so beats must be mapped into its own time range.
+        let strobe = EffectInstance {
+            kind: EffectKind::Strobe,
+            params: EffectParams::new()
+                .set(ParamKey::Rate, ParamValue::Float(1.0))
+                .set(ParamKey::DutyCycle, ParamValue::Float(0.5))
+                .set(ParamKey::BeatSync, ParamValue::Bool(true)),
+            time_range: TimeRange::new(2.0, 6.0).unwrap(),
+            blend_mode: BlendMode::Override,
+            opacity: 1.0,
+            opacity_curve: None,
+            fade_in: 0.0,
+            fade_out: 0.0,
+        };
+        let show = simple_show(
+            vec![fixture(1, 1)],
+            vec![Track {
+                name: "T1".into(),
+                target: EffectTarget::All,
+                locked: false,
+                muted: false,
+                blend_mode: BlendMode::Override,
+                opacity: 1.0,
+                effects: vec![strobe],
+            }],
+        );
+        let beats = [0.0, 1.0, 2.5, 3.5, 4.5, 5.5, 6.5];
+        let lit = |t: f64, beats: Option<&[f64]>| {
+            let (gradients, curves) = (HashMap::new(), HashMap::new());
+            let frame = evaluate(&show, 0, t, None, None, &gradients, &curves, &HashSet::new(), beats);
+            frame.fixtures.contains_key(&1)
+        };
+
+        assert!(lit(2.6, Some(&beats)), "just after a beat should flash");
+        assert!(!lit(3.1, Some(&beats)), "second half of the beat should be dark");
+        assert!(lit(3.6, Some(&beats)));
+        assert!(!lit(4.2, Some(&beats)));
+        // Without beats, Rate 1 is one flash over the whole effect: on until 4.0
+        assert!(lit(3.1, None));
+    }
+
+    #[test]
+    fn unseeded_twinkles_on_different_tracks_differ() {
+        let twinkle = || EffectInstance {
+            kind: EffectKind::Twinkle,
+            params: EffectParams::new().set(ParamKey::Density, ParamValue::Float(0.5)),
+            time_range: TimeRange::new(0.0, 10.0).unwrap(),
+            blend_mode: BlendMode::Override,
+            opacity: 1.0,
+            opacity_curve: None,
+            fade_in: 0.0,
+            fade_out: 0.0,
+        };
+        let track = |id: u32| Track {
+            name: format!("T{id}"),
+            target: EffectTarget::Fixtures(vec![FixtureId(id)]),
+            locked: false,
+            muted: false,
+            blend_mode: BlendMode::Override,
+            opacity: 1.0,
+            effects: vec![twinkle()],
+        };
+        let show = simple_show(vec![fixture(1, 64), fixture(2, 64)], vec![track(1), track(2)]);
+        let (gradients, curves) = (HashMap::new(), HashMap::new());
+        let frame = evaluate(&show, 0, 1.3, None, None, &gradients, &curves, &HashSet::new(), None);
+
+        // Same params, same pixel count: only the derived seed tells them apart.
+        assert_ne!(decode_fixture_colors(&frame, 1), decode_fixture_colors(&frame, 2));
+        let again = evaluate(&show, 0, 1.3, None, None, &gradients, &curves, &HashSet::new(), None);
+        assert_eq!(frame.fixtures, again.fixtures);
+    }
 }
@@ -1,3 +1,4 @@
 pub mod evaluator;
+pub mod raster;
 
 pub use evaluator::{evaluate, Frame};
@@ -0,0 +1,368 @@
+//! Rasterize evaluated frames into RGB images.
+//!
+//! Each fixture pixel is drawn as a bulb at its layout position, sized by the
+//! fixture's display radius and outlined by its bulb shape. The geometry
+//! mirrors the preview canvas (letterboxed layout, additive bulbs on black), so
+//! rendered video and exported stills look like what the user sees while
+//! sequencing.
+
+use std::collections::HashMap;
+
+use super::evaluator::Frame;
+use crate::model::color::Color;
+use crate::model::fixture::{BulbFootprint, FixtureDef};
+use crate::model::show::{FixtureLayout, Layout, LayoutShape, Position2D, Show};
+use crate::util::base64_decode;
+
+/// Bulb radius in image pixels for a display radius multiplier of 1.
+pub const BASE_RADIUS: f32 = 3.0;
+/// Margin kept clear around the layout, in image pixels.
+pub const PADDING: f32 = 24.0;
+
+/// An RGB image, row-major, three bytes per pixel.
+#[derive(Debug, Clone)]
+pub struct Raster {
+    pub width: u32,
+    pub height: u32,
+    pub data: Vec<u8>,
+}
+
+impl Raster {
+    /// An all-black image.
+    pub fn new(width: u32, height: u32) -> Self {
+        Self::filled(width, height, Color::BLACK)
+    }
+
+    /// An image filled with `color`.
+    pub fn filled(width: u32, height: u32, color: Color) -> Self {
+        Self {
+            width,
+            height,
+            data: [color.r, color.g, color.b].repeat(width as usize * height as usize),
+        }
+    }
+
+    /// Encode as an 8-bit RGB PNG.
+    pub fn encode_png(&self) -> Result<Vec<u8>, png::EncodingError> {
+        let mut out = Vec::new();
+        let mut encoder = png::Encoder::new(&mut out, self.width, self.height);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.data)?;
+        writer.finish()?;
+        Ok(out)
+    }
+
+    /// The color at `(x, y)`, or `None` outside the image.
+    pub fn pixel(&self, x: u32, y: u32) -> Option<Color> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        let offset = (y as usize * self.width as usize + x as usize) * 3;
+        match self.data.get(offset..offset + 3)? {
+            &[r, g, b] => Some(Color::rgb(r, g, b)),
+            _ => None,
+        }
+    }
+
+    /// Draw one bulb centered at `(cx, cy)`. Colors add onto what is already
+    /// there, so overlapping bulbs brighten like real light.
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    pub fn draw_bulb(
+        &mut self,
+        cx: f32,
+        cy: f32,
+        radius: f32,
+        footprint: BulbFootprint,
+        color: Color,
+    ) {
+        if radius <= 0.0 {
+            return;
+        }
+        let x0 = (cx - radius).floor().max(0.0) as u32;
+        let y0 = (cy - radius).floor().max(0.0) as u32;
+        let x1 = ((cx + radius).ceil().max(0.0) as u32).min(self.width);
+        let y1 = ((cy + radius).ceil().max(0.0) as u32).min(self.height);
+
+        for y in y0..y1 {
+            let dy = y as f32 + 0.5 - cy;
+            for x in x0..x1 {
+                let dx = x as f32 + 0.5 - cx;
+                let covered = match footprint {
+                    BulbFootprint::Round => dx * dx + dy * dy <= radius * radius,
+                    BulbFootprint::Square => dx.abs() <= radius && dy.abs() <= radius,
+                };
+                if covered {
+                    self.add(x, y, color);
+                }
+            }
+        }
+    }
+
+    fn add(&mut self, x: u32, y: u32, color: Color) {
+        let offset = (y as usize * self.width as usize + x as usize) * 3;
+        if let Some(px) = self.data.get_mut(offset..offset + 3) {
+            for (channel, value) in px.iter_mut().zip([color.r, color.g, color.b]) {
+                *channel = channel.saturating_add(value);
+            }
+        }
+    }
+}
+
+/// Maps normalized layout positions onto the image, preserving aspect ratio.
+struct Placement {
+    min_x: f32,
+    min_y: f32,
+    scale: f32,
+    offset_x: f32,
+    offset_y: f32,
+}
+
+impl Placement {
+    #[allow(clippy::cast_precision_loss)]
+    fn fit(layout: &Layout, width: u32, height: u32) -> Self {
+        let (mut min_x, mut max_x) = (f32::INFINITY, f32::NEG_INFINITY);
+        let (mut min_y, mut max_y) = (f32::INFINITY, f32::NEG_INFINITY);
+        for p in layout.fixtures.iter().flat_map(|fl| &fl.pixel_positions) {
+            min_x = min_x.min(p.x);
+            max_x = max_x.max(p.x);
+            min_y = min_y.min(p.y);
+            max_y = max_y.max(p.y);
+        }
+        if !min_x.is_finite() {
+            (min_x, max_x, min_y, max_y) = (0.0, 1.0, 0.0, 1.0);
+        }
+        // A layout with no extent on an axis (one bulb, a straight line) is
+        // centered on that axis instead of pinned to the margin.
+        if max_x <= min_x {
+            min_x -= 0.5;
+            max_x = min_x + 1.0;
+        }
+        if max_y <= min_y {
+            min_y -= 0.5;
+            max_y = min_y + 1.0;
+        }
+        let (layout_w, layout_h) = (max_x - min_x, max_y - min_y);
+
+        // Small images would vanish behind the preview's fixed margin.
+        let padding = PADDING.min(width.min(height) as f32 / 8.0);
+        let avail_w = (width as f32 - padding * 2.0).max(1.0);
+        let avail_h = (height as f32 - padding * 2.0).max(1.0);
+
+        let scale = (avail_w / layout_w).min(avail_h / layout_h);
+        Self {
+            min_x,
+            min_y,
+            scale,
+            offset_x: padding + (avail_w - layout_w * scale) / 2.0,
+            offset_y: padding + (avail_h - layout_h * scale) / 2.0,
+        }
+    }
+
+    fn project(&self, x: f32, y: f32) -> (f32, f32) {
+        (
+            self.offset_x + (x - self.min_x) * self.scale,
+            self.offset_y + (y - self.min_y) * self.scale,
+        )
+    }
+}
+
+/// Every fixture's pixels end to end on one horizontal line, in show order.
+/// Stands in for the layout of a show that has never been laid out.
+#[allow(clippy::cast_precision_loss)]
+fn strip_layout(show: &Show) -> Layout {
+    let total: u32 = show.fixtures.iter().map(|f| f.pixel_count).sum();
+    let step = 1.0 / total.saturating_sub(1).max(1) as f32;
+    let mut next = 0u32;
+    let fixtures = show
+        .fixtures
+        .iter()
+        .map(|f| {
+            let pixel_positions = (next..next + f.pixel_count)
+                .map(|i| Position2D { x: i as f32 * step, y: 0.0 })
+                .collect();
+            next += f.pixel_count;
+            FixtureLayout {
+                fixture_id: f.id,
+                pixel_positions,
+                shape: LayoutShape::default(),
+                preview_gain: None,
+            }
+        })
+        .collect();
+    Layout { fixtures }
+}
+
+/// Scale a bulb color by a layout's preview gain. Gains above 1 saturate
+/// rather than wrap.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss, clippy::float_cmp)]
+fn apply_gain(color: Color, gain: f32) -> Color {
+    if gain == 1.0 {
+        return color;
+    }
+    let channel = |c: u8| (f32::from(c) * gain).round().min(255.0) as u8;
+    Color::rgb(channel(color.r), channel(color.g), channel(color.b))
+}
+
+/// Draw `frame` onto a black `width`×`height` image using the show's layout.
+///
+/// Fixtures without a layout entry are not drawn. Unknown fixture ids fall
+/// back to a plain LED bulb, like the preview.
+pub fn rasterize(show: &Show, frame: &Frame, width: u32, height: u32) -> Raster {
+    rasterize_on(show, frame, width, height, Color::BLACK)
+}
+
+/// Like [`rasterize`], but over a `background` fill. A show with no layout
+/// at all is drawn as a single strip so there is still something to see.
+pub fn rasterize_on(
+    show: &Show,
+    frame: &Frame,
+    width: u32,
+    height: u32,
+    background: Color,
+) -> Raster {
+    let strip;
+    let layout = if show.layout.fixtures.is_empty() {
+        strip = strip_layout(show);
+        &strip
+    } else {
+        &show.layout
+    };
+    let mut raster = Raster::filled(width, height, background);
+    let placement = Placement::fit(layout, width, height);
+    let defs: HashMap<u32, &FixtureDef> = show.fixtures.iter().map(|f| (f.id.0, f)).collect();
+
+    for layout in &layout.fixtures {
+        let Some(b64) = frame.fixtures.get(&layout.fixture_id.0) else {
+            continue;
+        };
+        let def = defs.get(&layout.fixture_id.0);
+        let radius = BASE_RADIUS * def.map_or(1.0, |d| d.display_radius());
+        let footprint = def.map_or(BulbFootprint::Round, |d| d.bulb_shape.footprint());
+        let gain = layout.preview_gain();
+
+        let rgba = base64_decode(b64);
+        for (pos, px) in layout.pixel_positions.iter().zip(rgba.chunks_exact(4)) {
+            let &[r, g, b, _] = px else {
+                continue;
+            };
+            if r == 0 && g == 0 && b == 0 {
+                continue;
+            }
+            let (cx, cy) = placement.project(pos.x, pos.y);
+            raster.draw_bulb(cx, cy, radius, footprint, apply_gain(Color::rgb(r, g, b), gain));
+        }
+    }
+    raster
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::indexing_slicing)]
+mod tests {
+    use super::*;
+    use crate::model::fixture::{BulbShape, ChannelOrder, ColorModel, FixtureId, PixelType};
+    use crate::util::base64_encode;
+
+    /// A show with one single-pixel fixture in the middle of the layout.
+    fn single_bulb_show(bulb_shape: BulbShape) -> Show {
+        Show {
+            name: "Test".into(),
+            fixtures: vec![FixtureDef {
+                id: FixtureId(0),
+                name: "Bulb".into(),
+                color_model: ColorModel::Rgb,
+                pixel_count: 1,
+                pixel_type: PixelType::Smart,
+                bulb_shape,
+                display_radius_override: Some(2.0),
+                channel_order: ChannelOrder::Rgb,
+                gamma: None,
+            }],
+            groups: vec![],
+            layout: Layout {
+                fixtures: vec![FixtureLayout {
+                    fixture_id: FixtureId(0),
+                    pixel_positions: vec![Position2D { x: 0.5, y: 0.5 }],
+                    shape: LayoutShape::default(),
+                    preview_gain: None,
+                }],
+            },
+            sequences: vec![],
+            patches: vec![],
+            controllers: vec![],
+        }
+    }
+
+    fn white_frame() -> Frame {
+        Frame {
+            fixtures: HashMap::from([(0, base64_encode(&[255, 255, 255, 255]))]),
+            warnings: None,
+        }
+    }
+
+    fn lit(raster: &Raster, x: u32, y: u32) -> bool {
+        raster.pixel(x, y).unwrap().r > 0
+    }
+
+    #[test]
+    fn square_bulb_fills_its_bounding_box_and_round_bulb_does_not() {
+        // 32×32 canvas, bulb centered at (16, 16) with radius 3 × 2.0 = 6,
+        // so its bounding box spans pixels 10..22 on each axis.
+        let square = rasterize(&single_bulb_show(BulbShape::Flood), &white_frame(), 32, 32);
+        let round = rasterize(&single_bulb_show(BulbShape::C9), &white_frame(), 32, 32);
+
+        for raster in [&square, &round] {
+            assert!(lit(raster, 16, 16));
+            assert!(lit(raster, 10, 16) && lit(raster, 21, 16));
+            assert!(!lit(raster, 9, 16) && !lit(raster, 22, 16));
+        }
+        for (x, y) in [(10, 10), (21, 10), (10, 21), (21, 21)] {
+            assert!(lit(&square, x, y), "square corner ({x}, {y}) should be lit");
+            assert!(!lit(&round, x, y), "round corner ({x}, {y}) should be dark");
+        }
+
+        let count = |r: &Raster| r.data.chunks(3).filter(|px| px[0] > 0).count();
+        assert_eq!(count(&square), 144);
+        // A disc of radius 6 covers roughly π·36 ≈ 113 pixels.
+        assert!(
+            (100..125).contains(&count(&round)),
+            "round covered {}",
+            count(&round)
+        );
+    }
+
+    #[test]
+    fn overlapping_bulbs_add_and_black_pixels_are_skipped() {
+        let mut raster = Raster::new(8, 8);
+        raster.draw_bulb(4.0, 4.0, 2.0, BulbFootprint::Round, Color::rgb(200, 10, 0));
+        raster.draw_bulb(4.0, 4.0, 2.0, BulbFootprint::Round, Color::rgb(100, 10, 0));
+        assert_eq!(raster.pixel(4, 4).unwrap(), Color::rgb(255, 20, 0));
+
+        let dark = Frame {
+            fixtures: HashMap::from([(0, base64_encode(&[0, 0, 0, 255]))]),
+            warnings: None,
+        };
+        let blank = rasterize(&single_bulb_show(BulbShape::LED), &dark, 16, 16);
+        assert!(blank.data.iter().all(|&c| c == 0));
+    }
+
+    #[test]
+    fn preview_gain_dims_the_raster_but_not_output() {
+        use crate::output::{fixture_rgb, Packing};
+        use crate::settings::OutputSettings;
+
+        let mut show = single_bulb_show(BulbShape::LED);
+        show.layout.fixtures[0].preview_gain = Some(0.5);
+
+        let raster = rasterize(&show, &white_frame(), 32, 32);
+        assert_eq!(raster.pixel(16, 16).unwrap(), Color::rgb(128, 128, 128));
+
+        let packing = Packing { settings: OutputSettings::default(), frame_number: 0 };
+        assert_eq!(fixture_rgb(&white_frame(), &show.fixtures[0], &packing), vec![255; 3]);
+    }
+}
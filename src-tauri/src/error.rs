@@ -11,7 +11,10 @@ use ts_rs::TS;
 pub enum AppError {
     NotFound { what: String },
     InvalidIndex { what: String, index: usize },
+    TrackLocked { index: usize, name: String },
     ValidationError { message: String },
+    InvalidTimeRange { start: f64, end: f64, reason: String },
+    InvalidParam { key: String, reason: String },
     IoError { message: String },
     NoSetup,
     NoSequence,
@@ -25,6 +28,7 @@ pub enum AppError {
     ModelNotInstalled { model: String },
     AgentError { message: String },
     Cancelled { operation: String },
+    Busy { operation: String },
 }
 
 impl fmt::Display for AppError {
@@ -34,7 +38,14 @@ impl fmt::Display for AppError {
             AppError::InvalidIndex { what, index } => {
                 write!(f, "Invalid {what} index: {index}")
             }
+            AppError::TrackLocked { index, name } => {
+                write!(f, "Track {index} (\"{name}\") is locked")
+            }
             AppError::ValidationError { message } => write!(f, "{message}"),
+            AppError::InvalidTimeRange { start, end, reason } => {
+                write!(f, "Invalid time range {start:.3}s-{end:.3}s: {reason}")
+            }
+            AppError::InvalidParam { key, reason } => write!(f, "Invalid param {key}: {reason}"),
             AppError::IoError { message } => write!(f, "I/O error: {message}"),
             AppError::NoSetup => write!(f, "No setup loaded"),
             AppError::NoSequence => write!(f, "No sequence loaded"),
@@ -52,6 +63,9 @@ impl fmt::Display for AppError {
             }
             AppError::AgentError { message } => write!(f, "Agent error: {message}"),
             AppError::Cancelled { operation } => write!(f, "Operation cancelled: {operation}"),
+            AppError::Busy { operation } => {
+                write!(f, "Operation already in progress: {operation}")
+            }
         }
     }
 }
@@ -0,0 +1,133 @@
+//! Export rendered frames as an uncompressed FSEQ v2 file.
+//!
+//! FSEQ is the channel-data format played by Falcon Player and xLights. Channels
+//! are laid out fixture by fixture in show order, three per pixel, with each
+//! fixture's gamma, channel order and controller calibration applied exactly as
+//! live output does.
+
+use crate::engine::Frame;
+use crate::model::show::Show;
+use crate::output::{calibrated_fixture_rgb, fixture_calibration, Packing};
+use crate::settings::OutputSettings;
+
+/// FSEQ sequence file extension.
+pub const FSEQ_EXT: &str = "fseq";
+
+/// Fixed header size; this writer emits no variable headers, so channel data
+/// starts right after it.
+const HEADER_LEN: u16 = 32;
+const MAJOR_VERSION: u8 = 2;
+const MINOR_VERSION: u8 = 0;
+
+/// Channels per frame: three per pixel across every fixture.
+pub fn channel_count(show: &Show) -> usize {
+    show.fixtures
+        .iter()
+        .map(|f| f.pixel_count as usize * 3)
+        .sum()
+}
+
+/// Encode `frames`, sampled at `frame_rate`, as an FSEQ v2 file.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn write_fseq(
+    show: &Show,
+    frames: &[Frame],
+    frame_rate: f64,
+    settings: OutputSettings,
+) -> Vec<u8> {
+    let channels = channel_count(show);
+    let frame_count = u32::try_from(frames.len()).unwrap_or(u32::MAX);
+    let step_ms = (1000.0 / frame_rate).round().clamp(1.0, 255.0) as u8;
+
+    let calibrations: Vec<_> = show
+        .fixtures
+        .iter()
+        .map(|f| fixture_calibration(show, f.id))
+        .collect();
+
+    let mut out = Vec::with_capacity(usize::from(HEADER_LEN) + channels * frames.len());
+    out.extend_from_slice(b"PSEQ");
+    out.extend_from_slice(&HEADER_LEN.to_le_bytes()); // channel data offset
+    out.push(MINOR_VERSION);
+    out.push(MAJOR_VERSION);
+    out.extend_from_slice(&HEADER_LEN.to_le_bytes()); // variable header offset
+    out.extend_from_slice(&u32::try_from(channels).unwrap_or(u32::MAX).to_le_bytes());
+    out.extend_from_slice(&frame_count.to_le_bytes());
+    out.push(step_ms);
+    out.push(0); // flags
+    out.push(0); // compression: none
+    out.push(0); // compression blocks
+    out.push(0); // sparse ranges
+    out.push(0); // reserved
+    out.extend_from_slice(&0u64.to_le_bytes()); // unique id
+
+    for (frame_number, frame) in (0u64..).zip(frames) {
+        let packing = Packing {
+            settings,
+            frame_number,
+        };
+        for (fixture, calibration) in show.fixtures.iter().zip(&calibrations) {
+            out.extend(calibrated_fixture_rgb(frame, fixture, &packing, calibration));
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::indexing_slicing)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::model::fixture::{
+        BulbShape, ChannelOrder, ColorModel, FixtureDef, FixtureId, PixelType,
+    };
+    use crate::util::base64_encode;
+
+    fn fixture(id: u32, pixel_count: u32, channel_order: ChannelOrder) -> FixtureDef {
+        FixtureDef {
+            id: FixtureId(id),
+            name: format!("F{id}"),
+            color_model: ColorModel::Rgb,
+            pixel_count,
+            pixel_type: PixelType::Smart,
+            bulb_shape: BulbShape::LED,
+            display_radius_override: None,
+            channel_order,
+            gamma: None,
+        }
+    }
+
+    #[test]
+    fn header_and_channels_follow_fixture_order() {
+        let mut show = Show::empty();
+        show.fixtures = vec![
+            fixture(0, 1, ChannelOrder::Rgb),
+            fixture(1, 2, ChannelOrder::Grb),
+        ];
+        let frame = Frame {
+            fixtures: HashMap::from([
+                (0, base64_encode(&[10, 20, 30, 255])),
+                (1, base64_encode(&[255, 0, 0, 255, 0, 0, 255, 255])),
+            ]),
+            warnings: None,
+        };
+        let dark = Frame {
+            fixtures: HashMap::new(),
+            warnings: None,
+        };
+
+        let bytes = write_fseq(&show, &[frame, dark], 40.0, OutputSettings::default());
+
+        assert_eq!(&bytes[0..4], b"PSEQ");
+        assert_eq!(u16::from_le_bytes([bytes[4], bytes[5]]), 32);
+        assert_eq!(bytes[7], 2);
+        assert_eq!(u32::from_le_bytes(bytes[10..14].try_into().unwrap()), 9);
+        assert_eq!(u32::from_le_bytes(bytes[14..18].try_into().unwrap()), 2);
+        assert_eq!(bytes[18], 25);
+        assert_eq!(bytes.len(), 32 + 9 * 2);
+        // Fixture 1 is GRB: red goes out as (0, 255, 0), blue stays last.
+        assert_eq!(&bytes[32..41], &[10, 20, 30, 0, 255, 0, 0, 0, 255]);
+        assert!(bytes[41..].iter().all(|&b| b == 0));
+    }
+}
@@ -0,0 +1,2 @@
+pub mod fseq;
+pub mod xlights;
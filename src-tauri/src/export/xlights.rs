@@ -0,0 +1,723 @@
+//! Export a sequence to an xLights `.xsq` file.
+//!
+//! Fixtures and groups become xLights models, each track becomes an effect
+//! layer on the models it targets, and markers become a timing track. Effects
+//! with no reasonable xLights equivalent are left out and reported as warnings.
+
+use std::collections::HashMap;
+
+use quick_xml::escape::escape;
+
+use crate::model::color::Color;
+use crate::model::color_gradient::ColorGradient;
+use crate::model::curve::Curve;
+use crate::model::fixture::EffectTarget;
+use crate::model::show::Show;
+use crate::model::timeline::{
+    BlendMode, EffectInstance, EffectKind, EffectParams, ParamKey, ParamValue, Sequence,
+    WipeDirection,
+};
+
+/// xLights sequence file extension.
+pub const XLIGHTS_SEQUENCE_EXT: &str = "xsq";
+
+/// xLights version recorded in the file header.
+const XLIGHTS_VERSION: &str = "2024.01";
+/// xLights palettes hold at most eight colors.
+const MAX_PALETTE_COLORS: usize = 8;
+/// Upper end of xLights' brightness range; 100 is unchanged.
+const MAX_BRIGHTNESS: f64 = 400.0;
+/// Timing track that carries the sequence's markers.
+const MARKER_TRACK: &str = "Markers";
+
+/// A sequence rendered as `.xsq` XML.
+#[derive(Debug)]
+pub struct XlightsExport {
+    pub xml: String,
+    /// Effects written, counting each copy on a model separately.
+    pub effect_count: usize,
+    /// Effects and settings that could not be carried over.
+    pub warnings: Vec<String>,
+}
+
+/// An xLights effect: its name, `EffectDB` settings and palette colors.
+struct MappedEffect {
+    name: &'static str,
+    settings: Vec<String>,
+    colors: Vec<Color>,
+}
+
+/// One effect on a layer, pointing into the shared settings and palette tables.
+#[derive(Clone)]
+struct PlacedEffect {
+    name: &'static str,
+    settings: usize,
+    palette: usize,
+    start_ms: u64,
+    end_ms: u64,
+}
+
+/// Deduplicated string table, like xLights' `EffectDB` and `ColorPalettes`.
+#[derive(Default)]
+struct Table(Vec<String>);
+
+impl Table {
+    fn intern(&mut self, entry: String) -> usize {
+        if let Some(i) = self.0.iter().position(|e| *e == entry) {
+            return i;
+        }
+        self.0.push(entry);
+        self.0.len() - 1
+    }
+}
+
+/// Translate `sequence` into xLights' `.xsq` format. Library gradient and
+/// curve references are resolved against the given libraries first.
+#[allow(clippy::implicit_hasher)]
+pub fn export_sequence(
+    show: &Show,
+    sequence: &Sequence,
+    gradient_lib: &HashMap<String, ColorGradient>,
+    curve_lib: &HashMap<String, Curve>,
+) -> XlightsExport {
+    let mut warnings = Vec::new();
+    let mut effect_db = Table::default();
+    let mut palettes = Table::default();
+    let mut effect_count = 0;
+
+    // Every fixture is a model; groups are added once a track uses them.
+    let mut elements: Vec<(String, Vec<Vec<PlacedEffect>>)> = show
+        .fixtures
+        .iter()
+        .map(|f| (f.name.clone(), Vec::new()))
+        .collect();
+
+    for track in &sequence.tracks {
+        let targets: Vec<&str> = match &track.target {
+            EffectTarget::All => show.fixtures.iter().map(|f| f.name.as_str()).collect(),
+            EffectTarget::Group(id) => show
+                .groups
+                .iter()
+                .filter(|g| g.id == *id)
+                .map(|g| g.name.as_str())
+                .collect(),
+            EffectTarget::Fixtures(ids) => show
+                .fixtures
+                .iter()
+                .filter(|f| ids.contains(&f.id))
+                .map(|f| f.name.as_str())
+                .collect(),
+        };
+        if targets.is_empty() {
+            warnings.push(format!(
+                "Track \"{}\" has no target models; skipped.",
+                track.name
+            ));
+            continue;
+        }
+
+        let mut layer = Vec::new();
+        for effect in &track.effects {
+            let params = effect.params.resolve_refs(gradient_lib, curve_lib);
+            let mapped = match map_effect(effect, &params, sequence.frame_rate) {
+                Ok(mapped) => mapped,
+                Err(reason) => {
+                    warnings.push(format!("Track \"{}\": {reason}; skipped.", track.name));
+                    continue;
+                }
+            };
+            if mapped.colors.len() > MAX_PALETTE_COLORS {
+                warnings.push(format!(
+                    "Track \"{}\": {} uses {} colors; xLights palettes keep the first {MAX_PALETTE_COLORS}.",
+                    track.name,
+                    mapped.name,
+                    mapped.colors.len()
+                ));
+            }
+            layer.push(PlacedEffect {
+                name: mapped.name,
+                settings: effect_db.intern(mapped.settings.join(",")),
+                palette: palettes.intern(palette_entry(&mapped.colors)),
+                start_ms: millis(effect.time_range.start()),
+                end_ms: millis(effect.time_range.end()),
+            });
+        }
+        if track.muted {
+            warnings.push(format!(
+                "Track \"{}\" is muted, which xLights layers can't express; exported as audible.",
+                track.name
+            ));
+        }
+        if !track.blends_directly() {
+            warnings.push(format!(
+                "Track \"{}\" has its own blend or opacity, which xLights layers can't express; \
+                 exported at full opacity.",
+                track.name
+            ));
+        }
+
+        effect_count += layer.len() * targets.len();
+        for name in targets {
+            let index = elements
+                .iter()
+                .position(|(n, _)| n == name)
+                .unwrap_or_else(|| {
+                    elements.push((name.to_string(), Vec::new()));
+                    elements.len() - 1
+                });
+            if let Some((_, layers)) = elements.get_mut(index) {
+                layers.push(layer.clone());
+            }
+        }
+    }
+
+    let xml = write_xsq(sequence, &elements, &effect_db, &palettes);
+    XlightsExport {
+        xml,
+        effect_count,
+        warnings,
+    }
+}
+
+// ── Effect mapping ─────────────────────────────────────────────────
+
+/// Map one effect to its closest xLights equivalent, or explain why it has none.
+fn map_effect(
+    effect: &EffectInstance,
+    params: &EffectParams,
+    frame_rate: f64,
+) -> Result<MappedEffect, String> {
+    let white = ColorGradient::solid(Color::WHITE);
+    let gradient_colors = || {
+        let mut colors: Vec<Color> = params
+            .gradient_or(ParamKey::Gradient, &white)
+            .stops()
+            .iter()
+            .map(|s| s.color)
+            .collect();
+        colors.dedup();
+        colors
+    };
+    let reverse = params.bool_or(ParamKey::Reverse, false);
+
+    let (name, mut settings, colors): (_, Vec<String>, Vec<Color>) = match &effect.kind {
+        EffectKind::Solid => (
+            "On",
+            on_settings(),
+            vec![params.color_or(ParamKey::Color, Color::WHITE)],
+        ),
+        EffectKind::Fade => {
+            let colors = gradient_colors();
+            if colors.len() > 1 {
+                let settings = vec!["E_TEXTCTRL_ColorWash_Cycles=1.0".into()];
+                ("Color Wash", settings, colors)
+            } else {
+                ("On", on_settings(), colors)
+            }
+        }
+        EffectKind::Gradient => {
+            let default = [Color::rgb(255, 0, 0), Color::rgb(0, 0, 255)];
+            let settings = vec![
+                "E_CHECKBOX_ColorWash_HFade=1".into(),
+                "E_TEXTCTRL_ColorWash_Cycles=1.0".into(),
+            ];
+            (
+                "Color Wash",
+                settings,
+                params.color_list_or(ParamKey::Colors, &default).to_vec(),
+            )
+        }
+        EffectKind::Rainbow => {
+            let saturation = params.float_or(ParamKey::Saturation, 1.0).clamp(0.0, 1.0);
+            let value = params.float_or(ParamKey::Brightness, 1.0).clamp(0.0, 1.0);
+            let hues = (0..6).map(|i| Color::from_hsv(f64::from(i) * 60.0, saturation, value));
+            let cycles = params.float_or(ParamKey::Speed, 1.0);
+            let settings = vec![
+                "E_CHECKBOX_ColorWash_CircularPalette=1".into(),
+                format!("E_TEXTCTRL_ColorWash_Cycles={cycles:.1}"),
+            ];
+            ("Color Wash", settings, hues.collect())
+        }
+        EffectKind::Chase => {
+            let direction = if reverse { "Right-Left" } else { "Left-Right" };
+            let width = params.float_or(ParamKey::PulseWidth, 0.3).clamp(0.01, 1.0);
+            let settings = vec![
+                format!("E_CHOICE_Chase_Type1={direction}"),
+                "E_CHOICE_SingleStrand_Colors=Palette".into(),
+                format!("E_SLIDER_Color_Mix1={}", percent(width)),
+                format!(
+                    "E_TEXTCTRL_Chase_Rotations={:.1}",
+                    params.float_or(ParamKey::Speed, 1.0)
+                ),
+            ];
+            ("SingleStrand", settings, gradient_colors())
+        }
+        EffectKind::Strobe => {
+            if params.bool_or(ParamKey::BeatSync, false) {
+                return Err("beat-synced strobes need the song's beat grid".into());
+            }
+            let duty = params.float_or(ParamKey::DutyCycle, 0.5).clamp(0.0, 1.0);
+            let settings = vec![
+                format!("E_SLIDER_Shimmer_Duty_Factor={}", percent(duty)),
+                format!(
+                    "E_TEXTCTRL_Shimmer_Cycles={:.1}",
+                    params.float_or(ParamKey::Rate, 10.0)
+                ),
+            ];
+            (
+                "Shimmer",
+                settings,
+                vec![params.color_or(ParamKey::Color, Color::WHITE)],
+            )
+        }
+        EffectKind::Twinkle => {
+            let density = params.float_or(ParamKey::Density, 0.3).clamp(0.0, 1.0);
+            let speed = params.float_or(ParamKey::Speed, 5.0).max(0.1);
+            let steps = (effect.time_range.duration() * frame_rate / speed)
+                .round()
+                .clamp(2.0, 200.0);
+            let settings = vec![
+                format!("E_SLIDER_Twinkle_Count={}", percent(density)),
+                format!("E_SLIDER_Twinkle_Steps={steps}"),
+                "E_CHECKBOX_Twinkle_ReRandom=1".into(),
+            ];
+            (
+                "Twinkle",
+                settings,
+                vec![params.color_or(ParamKey::Color, Color::WHITE)],
+            )
+        }
+        EffectKind::Sparkle => {
+            // xLights twinkles ramp up and down over `Steps` frames; match the
+            // ramp to the fade tail.
+            let density = params.float_or(ParamKey::Density, 0.2).clamp(0.0, 1.0);
+            let decay = params.float_or(ParamKey::Decay, 0.15).max(0.0);
+            let steps = (effect.time_range.duration() * frame_rate * decay)
+                .round()
+                .clamp(2.0, 200.0);
+            let settings = vec![
+                format!("E_SLIDER_Twinkle_Count={}", percent(density)),
+                format!("E_SLIDER_Twinkle_Steps={steps}"),
+                "E_CHECKBOX_Twinkle_ReRandom=1".into(),
+            ];
+            (
+                "Twinkle",
+                settings,
+                vec![params.color_or(ParamKey::Color, Color::WHITE)],
+            )
+        }
+        EffectKind::Wipe => {
+            let direction =
+                params.wipe_direction_or(ParamKey::Direction, WipeDirection::Horizontal);
+            let (wipe_type, wipe_direction) = wipe_shape(direction, reverse);
+            let settings = vec![
+                format!("E_CHOICE_Wipe_Type={wipe_type}"),
+                format!("E_CHOICE_Wipe_Direction={wipe_direction}"),
+            ];
+            ("Wipe", settings, gradient_colors())
+        }
+        EffectKind::Script(name) => {
+            return Err(format!(
+                "script effect \"{name}\" has no xLights equivalent"
+            ));
+        }
+    };
+
+    settings.extend(brightness_settings(effect, params));
+    if let Some(method) = layer_method(effect.blend_mode) {
+        settings.push(format!("T_CHOICE_LayerMethod={method}"));
+    }
+    Ok(MappedEffect {
+        name,
+        settings,
+        colors,
+    })
+}
+
+/// Full intensity from start to end; fades are expressed through brightness.
+fn on_settings() -> Vec<String> {
+    vec![
+        "E_TEXTCTRL_Eff_On_Start=100".into(),
+        "E_TEXTCTRL_Eff_On_End=100".into(),
+    ]
+}
+
+/// xLights wipe type and direction for one of our wipe shapes.
+fn wipe_shape(direction: WipeDirection, reverse: bool) -> (&'static str, &'static str) {
+    let pick = |forward, backward| if reverse { backward } else { forward };
+    match direction {
+        WipeDirection::Horizontal => ("Normal", pick("right", "left")),
+        WipeDirection::Vertical => ("Normal", pick("up", "down")),
+        WipeDirection::DiagonalUp => ("Normal", pick("up-right", "down-left")),
+        WipeDirection::DiagonalDown => ("Normal", pick("down-right", "up-left")),
+        WipeDirection::Burst | WipeDirection::Circle => ("Circle", pick("up", "down")),
+        WipeDirection::Diamond => ("Diamond", pick("up", "down")),
+    }
+}
+
+/// xLights layer blend for our blend modes; `None` keeps xLights' default (Normal).
+fn layer_method(mode: BlendMode) -> Option<&'static str> {
+    match mode {
+        BlendMode::Override | BlendMode::Alpha => None,
+        BlendMode::Add => Some("Additive"),
+        BlendMode::Subtract => Some("Subtractive"),
+        BlendMode::Max => Some("Max"),
+        BlendMode::Min => Some("Min"),
+        BlendMode::Average => Some("Average"),
+        BlendMode::Multiply | BlendMode::IntensityOverlay => Some("Brightness"),
+        BlendMode::Screen => Some("Highlight"),
+        BlendMode::Mask => Some("1 is Mask"),
+    }
+}
+
+/// Effect opacity, opacity curve and (for fades) intensity curve, combined
+/// into a brightness slider or, when it varies, a brightness value curve.
+fn brightness_settings(effect: &EffectInstance, params: &EffectParams) -> Option<String> {
+    let intensity = match effect.kind {
+        EffectKind::Fade => Some(
+            params
+                .get(&ParamKey::IntensityCurve)
+                .and_then(ParamValue::as_curve)
+                .cloned()
+                .unwrap_or_else(Curve::triangle),
+        ),
+        _ => None,
+    };
+    let curves: Vec<&Curve> = effect
+        .opacity_curve
+        .iter()
+        .chain(intensity.as_ref())
+        .collect();
+    let at = |x: f64| {
+        curves
+            .iter()
+            .fold(effect.opacity, |acc, c| acc * c.evaluate(x))
+    };
+
+    let mut xs: Vec<f64> = curves
+        .iter()
+        .flat_map(|c| c.points().iter().map(|p| p.x))
+        .collect();
+    xs.extend([0.0, 1.0]);
+    xs.sort_by(f64::total_cmp);
+    xs.dedup_by(|a, b| (*a - *b).abs() < 1e-6);
+
+    let values: Vec<f64> = xs.iter().map(|&x| at(x).clamp(0.0, 1.0)).collect();
+    let first = values.first().copied().unwrap_or(1.0);
+    if values.iter().all(|v| (v - first).abs() < 1e-6) {
+        return ((first - 1.0).abs() > 1e-6)
+            .then(|| format!("C_SLIDER_Brightness={}", percent(first)));
+    }
+
+    let points: Vec<String> = xs
+        .iter()
+        .zip(&values)
+        .map(|(x, v)| format!("{x:.2}:{:.4}", v * 100.0 / MAX_BRIGHTNESS))
+        .collect();
+    Some(format!(
+        "C_VALUECURVE_Brightness=Active=TRUE|Id=ID_VALUECURVE_Brightness|Type=Custom|Min=0.00|Max={MAX_BRIGHTNESS:.2}|RV=TRUE|Values={}|",
+        points.join(";")
+    ))
+}
+
+// ── XML output ─────────────────────────────────────────────────────
+
+/// One `ColorPalettes` entry: up to eight palette slots, unused ones disabled.
+fn palette_entry(colors: &[Color]) -> String {
+    let mut entry = Vec::with_capacity(MAX_PALETTE_COLORS * 2);
+    for slot in 0..MAX_PALETTE_COLORS {
+        let color = colors.get(slot).copied().unwrap_or(Color::WHITE);
+        let n = slot + 1;
+        entry.push(format!(
+            "C_BUTTON_Palette{n}=#{:02X}{:02X}{:02X}",
+            color.r, color.g, color.b
+        ));
+        entry.push(format!(
+            "C_CHECKBOX_Palette{n}={}",
+            u8::from(slot < colors.len())
+        ));
+    }
+    entry.join(",")
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn millis(seconds: f64) -> u64 {
+    (seconds.max(0.0) * 1000.0).round() as u64
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn percent(fraction: f64) -> i64 {
+    (fraction * 100.0).round() as i64
+}
+
+fn write_xsq(
+    sequence: &Sequence,
+    elements: &[(String, Vec<Vec<PlacedEffect>>)],
+    effect_db: &Table,
+    palettes: &Table,
+) -> String {
+    let mut out = String::new();
+    let mut line = |depth: usize, text: &str| {
+        out.push_str(&"  ".repeat(depth));
+        out.push_str(text);
+        out.push('\n');
+    };
+
+    line(0, r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    line(
+        0,
+        r#"<xsequence BaseChannel="0" ChanCtrlBasic="0" ChanCtrlColor="0" FixedPointTiming="1" ModelBlending="true">"#,
+    );
+    line(1, "<head>");
+    line(2, &format!("<version>{XLIGHTS_VERSION}</version>"));
+    line(
+        2,
+        &format!("<song>{}</song>", escape(sequence.name.as_str())),
+    );
+    let (kind, media) = match &sequence.audio_file {
+        Some(file) => ("Media", escape(file.as_str()).into_owned()),
+        None => ("Animation", String::new()),
+    };
+    line(2, &format!("<mediaFile>{media}</mediaFile>"));
+    line(
+        2,
+        &format!(
+            "<sequenceTiming>{} ms</sequenceTiming>",
+            millis(1.0 / sequence.frame_rate.max(1.0))
+        ),
+    );
+    line(2, &format!("<sequenceType>{kind}</sequenceType>"));
+    line(
+        2,
+        &format!(
+            "<sequenceDuration>{:.3}</sequenceDuration>",
+            sequence.duration
+        ),
+    );
+    line(1, "</head>");
+
+    line(1, "<ColorPalettes>");
+    for palette in &palettes.0 {
+        line(
+            2,
+            &format!("<ColorPalette>{}</ColorPalette>", escape(palette.as_str())),
+        );
+    }
+    line(1, "</ColorPalettes>");
+    line(1, "<EffectDB>");
+    for settings in &effect_db.0 {
+        line(
+            2,
+            &format!("<Effect>{}</Effect>", escape(settings.as_str())),
+        );
+    }
+    line(1, "</EffectDB>");
+
+    line(1, "<DisplayElements>");
+    if !sequence.markers.is_empty() {
+        line(
+            2,
+            &format!(
+                r#"<Element collapsed="0" type="timing" name="{MARKER_TRACK}" visible="1" active="1"/>"#
+            ),
+        );
+    }
+    for (name, _) in elements {
+        line(
+            2,
+            &format!(
+                r#"<Element collapsed="0" type="model" name="{}" visible="1"/>"#,
+                escape(name.as_str())
+            ),
+        );
+    }
+    line(1, "</DisplayElements>");
+
+    line(1, "<ElementEffects>");
+    if !sequence.markers.is_empty() {
+        line(
+            2,
+            &format!(r#"<Element type="timing" name="{MARKER_TRACK}">"#),
+        );
+        line(3, "<EffectLayer>");
+        let ends = sequence
+            .markers
+            .iter()
+            .skip(1)
+            .map(|m| m.time)
+            .chain([sequence.duration]);
+        for (marker, end) in sequence.markers.iter().zip(ends) {
+            line(
+                4,
+                &format!(
+                    r#"<Effect label="{}" startTime="{}" endTime="{}"/>"#,
+                    escape(marker.label.as_str()),
+                    millis(marker.time),
+                    millis(end.max(marker.time))
+                ),
+            );
+        }
+        line(3, "</EffectLayer>");
+        line(2, "</Element>");
+    }
+    for (name, layers) in elements {
+        line(
+            2,
+            &format!(r#"<Element type="model" name="{}">"#, escape(name.as_str())),
+        );
+        // Our tracks stack bottom-up; xLights lists its top layer first.
+        for layer in layers.iter().rev() {
+            line(3, "<EffectLayer>");
+            for e in layer {
+                line(
+                    4,
+                    &format!(
+                        r#"<Effect ref="{}" name="{}" startTime="{}" endTime="{}" palette="{}"/>"#,
+                        e.settings, e.name, e.start_ms, e.end_ms, e.palette
+                    ),
+                );
+            }
+            line(3, "</EffectLayer>");
+        }
+        if layers.is_empty() {
+            line(3, "<EffectLayer/>");
+        }
+        line(2, "</Element>");
+    }
+    line(1, "</ElementEffects>");
+    line(0, "</xsequence>");
+    out
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::indexing_slicing)]
+mod tests {
+    use super::*;
+    use crate::model::fixture::{
+        BulbShape, ChannelOrder, ColorModel, FixtureDef, FixtureId, PixelType,
+    };
+    use crate::model::show::Layout;
+    use crate::model::timeline::{Marker, TimeRange, Track};
+
+    fn fixture(id: u32, name: &str) -> FixtureDef {
+        FixtureDef {
+            id: FixtureId(id),
+            name: name.into(),
+            color_model: ColorModel::Rgb,
+            pixel_count: 50,
+            pixel_type: PixelType::Smart,
+            bulb_shape: BulbShape::LED,
+            display_radius_override: None,
+            channel_order: ChannelOrder::Rgb,
+            gamma: None,
+        }
+    }
+
+    fn effect(kind: EffectKind, start: f64, end: f64, params: EffectParams) -> EffectInstance {
+        EffectInstance {
+            kind,
+            params,
+            time_range: TimeRange::new(start, end).unwrap(),
+            blend_mode: BlendMode::Override,
+            opacity: 1.0,
+            opacity_curve: None,
+            fade_in: 0.0,
+            fade_out: 0.0,
+        }
+    }
+
+    fn show_with(effects: Vec<EffectInstance>) -> Show {
+        Show {
+            name: "Test".into(),
+            fixtures: vec![fixture(1, "Roof & Eaves"), fixture(2, "Tree")],
+            groups: vec![],
+            layout: Layout { fixtures: vec![] },
+            sequences: vec![Sequence {
+                frame_rate: 20.0,
+                audio_file: Some("song.mp3".into()),
+                markers: vec![Marker {
+                    time: 2.0,
+                    label: "Verse".into(),
+                    color: Marker::DEFAULT_COLOR,
+                }],
+                ..Sequence::for_test(
+                    "Song",
+                    10.0,
+                    vec![Track {
+                        name: "Roof".into(),
+                        target: EffectTarget::Fixtures(vec![FixtureId(1)]),
+                        locked: false,
+                        muted: false,
+                        blend_mode: BlendMode::Override,
+                        opacity: 1.0,
+                        effects,
+                    }],
+                )
+            }],
+            patches: vec![],
+            controllers: vec![],
+        }
+    }
+
+    fn export(show: &Show) -> XlightsExport {
+        export_sequence(show, &show.sequences[0], &HashMap::new(), &HashMap::new())
+    }
+
+    #[test]
+    fn exports_models_layers_and_effects() {
+        let red =
+            EffectParams::new().set(ParamKey::Color, ParamValue::Color(Color::rgb(255, 0, 0)));
+        let show = show_with(vec![
+            effect(EffectKind::Solid, 0.0, 1.5, red),
+            effect(EffectKind::Twinkle, 2.0, 4.0, EffectParams::new()),
+        ]);
+        let result = export(&show);
+        let xml = &result.xml;
+
+        assert_eq!(result.effect_count, 2);
+        assert!(result.warnings.is_empty(), "{:?}", result.warnings);
+        assert!(xml.contains(
+            r#"<Element collapsed="0" type="model" name="Roof &amp; Eaves" visible="1"/>"#
+        ));
+        assert!(xml.contains(r#"<Element type="model" name="Tree">"#));
+        assert!(
+            xml.contains(r#"<Effect ref="0" name="On" startTime="0" endTime="1500" palette="0"/>"#)
+        );
+        assert!(xml.contains(
+            r#"<Effect ref="1" name="Twinkle" startTime="2000" endTime="4000" palette="1"/>"#
+        ));
+        assert!(xml.contains("C_BUTTON_Palette1=#FF0000,C_CHECKBOX_Palette1=1,"));
+        assert!(xml.contains("E_SLIDER_Twinkle_Count=30"));
+        assert!(xml.contains(r#"<Effect label="Verse" startTime="2000" endTime="10000"/>"#));
+        assert!(xml.contains("<mediaFile>song.mp3</mediaFile>"));
+
+        // Well-formed all the way through.
+        let mut reader = quick_xml::Reader::from_str(xml);
+        while !matches!(reader.read_event().unwrap(), quick_xml::events::Event::Eof) {}
+    }
+
+    #[test]
+    fn unsupported_effects_warn_and_fades_become_brightness_curves() {
+        let show = show_with(vec![
+            effect(
+                EffectKind::Script("sparkle".into()),
+                0.0,
+                1.0,
+                EffectParams::new(),
+            ),
+            effect(EffectKind::Fade, 1.0, 3.0, EffectParams::new()),
+        ]);
+        let result = export(&show);
+
+        assert_eq!(result.effect_count, 1);
+        assert_eq!(result.warnings.len(), 1);
+        assert!(result.warnings[0].contains("sparkle"));
+        assert!(result
+            .xml
+            .contains(r#"name="On" startTime="1000" endTime="3000""#));
+        // The default triangle fade peaks mid-effect at 100% (0.25 of xLights' 0-400 range).
+        assert!(result
+            .xml
+            .contains("Values=0.00:0.0000;0.50:0.2500;1.00:0.0000|"));
+    }
+}
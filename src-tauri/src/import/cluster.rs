@@ -0,0 +1,190 @@
+//! Propose fixture groups from layout positions.
+//!
+//! Imported setups often arrive with a flat or oddly nested group tree.
+//! Fixtures that sit together in the layout (a mini tree cluster, an arch run,
+//! the roofline) tend to be sequenced together, so fixtures close to each
+//! other are clustered and offered as extra groups.
+
+use std::collections::{BTreeSet, HashMap};
+
+use crate::model::fixture::{FixtureGroup, FixtureId, GroupId, GroupMember};
+use crate::model::show::Layout;
+
+/// Fixtures whose centers are closer than this, in normalized layout units,
+/// end up in the same cluster.
+pub const DEFAULT_CLUSTER_GAP: f32 = 0.1;
+
+/// Cluster fixtures by layout proximity and return one new group per cluster
+/// of two or more fixtures.
+///
+/// Clustering is single-linkage on fixture centers: two fixtures share a
+/// cluster when a chain of neighbors less than `gap` apart connects them.
+/// Clusters that cover every placed fixture, or that match the fixtures of an
+/// existing group, add nothing and are skipped. New group ids continue after
+/// the highest id in `existing`, and groups are numbered left to right.
+#[allow(clippy::cast_precision_loss)]
+pub fn spatial_groups(layout: &Layout, existing: &[FixtureGroup], gap: f32) -> Vec<FixtureGroup> {
+    let centers: Vec<(FixtureId, f32, f32)> = layout
+        .fixtures
+        .iter()
+        .filter(|fl| !fl.pixel_positions.is_empty())
+        .map(|fl| {
+            let n = fl.pixel_positions.len() as f32;
+            let (sx, sy) = fl
+                .pixel_positions
+                .iter()
+                .fold((0.0, 0.0), |(sx, sy), p| (sx + p.x, sy + p.y));
+            (fl.fixture_id, sx / n, sy / n)
+        })
+        .collect();
+
+    let mut parent: Vec<usize> = (0..centers.len()).collect();
+    for (i, &(_, ax, ay)) in centers.iter().enumerate() {
+        for (j, &(_, bx, by)) in centers.iter().enumerate().skip(i + 1) {
+            if (ax - bx).hypot(ay - by) < gap {
+                let (ri, rj) = (find(&mut parent, i), find(&mut parent, j));
+                if ri != rj {
+                    if let Some(slot) = parent.get_mut(rj) {
+                        *slot = ri;
+                    }
+                }
+            }
+        }
+    }
+
+    let mut clusters: HashMap<usize, Vec<usize>> = HashMap::new();
+    for i in 0..centers.len() {
+        let root = find(&mut parent, i);
+        clusters.entry(root).or_default().push(i);
+    }
+
+    let existing_sets: Vec<BTreeSet<u32>> = existing
+        .iter()
+        .map(|g| {
+            g.members
+                .iter()
+                .filter_map(|m| match m {
+                    GroupMember::Fixture(id) => Some(id.0),
+                    GroupMember::Group(_) => None,
+                })
+                .collect()
+        })
+        .collect();
+
+    let mut proposed: Vec<(f32, Vec<FixtureId>)> = clusters
+        .into_values()
+        .filter(|members| members.len() >= 2 && members.len() < centers.len())
+        .filter_map(|members| {
+            let ids: Vec<FixtureId> = members
+                .iter()
+                .filter_map(|&i| centers.get(i).map(|c| c.0))
+                .collect();
+            let set: BTreeSet<u32> = ids.iter().map(|id| id.0).collect();
+            if existing_sets.contains(&set) {
+                return None;
+            }
+            let left = members
+                .iter()
+                .filter_map(|&i| centers.get(i).map(|c| c.1))
+                .fold(f32::INFINITY, f32::min);
+            Some((left, ids))
+        })
+        .collect();
+    proposed.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let next_id = existing.iter().map(|g| g.id.0 + 1).max().unwrap_or(0);
+    (next_id..)
+        .zip(proposed)
+        .enumerate()
+        .map(|(n, (id, (_, ids)))| FixtureGroup {
+            id: GroupId(id),
+            name: format!("Area {}", n + 1),
+            members: ids.into_iter().map(GroupMember::Fixture).collect(),
+        })
+        .collect()
+}
+
+/// Union-find root of `i`, compressing the path on the way.
+fn find(parent: &mut [usize], i: usize) -> usize {
+    let mut root = i;
+    while let Some(&p) = parent.get(root) {
+        if p == root {
+            break;
+        }
+        root = p;
+    }
+    let mut node = i;
+    while node != root {
+        let Some(slot) = parent.get_mut(node) else {
+            break;
+        };
+        node = std::mem::replace(slot, root);
+    }
+    root
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::indexing_slicing)]
+mod tests {
+    use super::*;
+    use crate::model::show::{FixtureLayout, LayoutShape, Position2D};
+
+    fn placed(id: u32, x: f32, y: f32) -> FixtureLayout {
+        FixtureLayout {
+            fixture_id: FixtureId(id),
+            pixel_positions: vec![Position2D { x, y }, Position2D { x: x + 0.02, y }],
+            shape: LayoutShape::default(),
+            preview_gain: None,
+        }
+    }
+
+    fn fixture_ids(group: &FixtureGroup) -> Vec<u32> {
+        let mut ids: Vec<u32> = group
+            .members
+            .iter()
+            .filter_map(|m| match m {
+                GroupMember::Fixture(id) => Some(id.0),
+                GroupMember::Group(_) => None,
+            })
+            .collect();
+        ids.sort_unstable();
+        ids
+    }
+
+    #[test]
+    fn two_separated_clusters_become_two_groups() {
+        let layout = Layout {
+            fixtures: vec![
+                placed(0, 0.80, 0.50),
+                placed(1, 0.10, 0.20),
+                placed(2, 0.85, 0.55),
+                placed(3, 0.15, 0.22),
+                placed(4, 0.12, 0.28),
+            ],
+        };
+        let existing = vec![FixtureGroup {
+            id: GroupId(7),
+            name: "All".into(),
+            members: (0..5).map(|i| GroupMember::Fixture(FixtureId(i))).collect(),
+        }];
+
+        let groups = spatial_groups(&layout, &existing, DEFAULT_CLUSTER_GAP);
+
+        assert_eq!(groups.len(), 2);
+        assert_eq!(
+            (groups[0].id, groups[0].name.as_str()),
+            (GroupId(8), "Area 1")
+        );
+        assert_eq!(fixture_ids(&groups[0]), [1, 3, 4]);
+        assert_eq!(
+            (groups[1].id, groups[1].name.as_str()),
+            (GroupId(9), "Area 2")
+        );
+        assert_eq!(fixture_ids(&groups[1]), [0, 2]);
+
+        // Proposing again against the result adds nothing new.
+        let mut all = existing;
+        all.extend(groups);
+        assert!(spatial_groups(&layout, &all, DEFAULT_CLUSTER_GAP).is_empty());
+    }
+}
@@ -0,0 +1,117 @@
+use crate::model::color::Color;
+use crate::model::color_gradient::ColorGradient;
+use crate::model::curve::{Curve, CurvePoint};
+use crate::model::timeline::{ColorMode, EffectKind, EffectParams, ParamKey, ParamValue};
+
+use super::types::LorEffect;
+
+/// LOR effect type names as they appear in `.lms` files.
+mod lor_effect {
+    pub const INTENSITY: &str = "intensity";
+    pub const TWINKLE: &str = "twinkle";
+    pub const SHIMMER: &str = "shimmer";
+}
+
+/// Strobe rate used for shimmer, which LOR toggles several times a second.
+const SHIMMER_RATE: f64 = 10.0;
+
+/// Map a LOR effect on a channel of the given color to an `EffectKind` +
+/// params. Returns `None` for effect types we can't represent; the caller
+/// reports those.
+pub(super) fn map_lor_effect(
+    effect: &LorEffect,
+    color: Color,
+) -> Option<(EffectKind, EffectParams)> {
+    use lor_effect::{INTENSITY, SHIMMER, TWINKLE};
+
+    let (start, end) = effect.intensity_range();
+    match effect.type_name.as_str() {
+        // ── Intensity at full → Solid ("ON") ────────────────────
+        INTENSITY if start >= 1.0 && end >= 1.0 => Some((
+            EffectKind::Solid,
+            EffectParams::new().set(ParamKey::Color, ParamValue::Color(color)),
+        )),
+
+        // ── Partial or ramped intensity → Fade ──────────────────
+        INTENSITY => {
+            let curve = Curve::new(vec![
+                CurvePoint { x: 0.0, y: start },
+                CurvePoint { x: 1.0, y: end },
+            ])
+            .unwrap_or_else(|| Curve::constant(start));
+            Some((
+                EffectKind::Fade,
+                EffectParams::new()
+                    .set(ParamKey::IntensityCurve, ParamValue::Curve(curve))
+                    .set(
+                        ParamKey::Gradient,
+                        ParamValue::ColorGradient(ColorGradient::solid(color)),
+                    )
+                    .set(
+                        ParamKey::ColorMode,
+                        ParamValue::ColorMode(ColorMode::GradientThroughEffect),
+                    ),
+            ))
+        }
+
+        // ── Twinkle ─────────────────────────────────────────────
+        TWINKLE => Some((
+            EffectKind::Twinkle,
+            EffectParams::new()
+                .set(ParamKey::Color, ParamValue::Color(color.scale(start.max(end))))
+                .set(ParamKey::Density, ParamValue::Float(0.4))
+                .set(ParamKey::Speed, ParamValue::Float(6.0)),
+        )),
+
+        // ── Shimmer → Strobe ────────────────────────────────────
+        SHIMMER => Some((
+            EffectKind::Strobe,
+            EffectParams::new()
+                .set(ParamKey::Color, ParamValue::Color(color.scale(start.max(end))))
+                .set(ParamKey::Rate, ParamValue::Float(SHIMMER_RATE))
+                .set(ParamKey::DutyCycle, ParamValue::Float(0.5)),
+        )),
+
+        _ => None,
+    }
+}
+
+// ── Tests ───────────────────────────────────────────────────────────
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn effect(type_name: &str, start: Option<f64>, end: Option<f64>) -> LorEffect {
+        LorEffect {
+            type_name: type_name.into(),
+            start: 0.0,
+            end: 1.0,
+            intensity: None,
+            start_intensity: start,
+            end_intensity: end,
+        }
+    }
+
+    #[test]
+    fn full_intensity_is_solid_and_ramps_are_fades() {
+        let red = Color::rgb(255, 0, 0);
+        let (kind, _) = map_lor_effect(&effect("intensity", None, None), red).unwrap();
+        assert!(matches!(kind, EffectKind::Solid));
+
+        let (kind, params) =
+            map_lor_effect(&effect("intensity", Some(0.0), Some(100.0)), red).unwrap();
+        assert!(matches!(kind, EffectKind::Fade));
+        assert!(matches!(
+            params.get(&ParamKey::IntensityCurve),
+            Some(ParamValue::Curve(c)) if (c.evaluate(0.5) - 0.5).abs() < 1e-9
+        ));
+
+        let (kind, _) = map_lor_effect(&effect("twinkle", None, None), red).unwrap();
+        assert!(matches!(kind, EffectKind::Twinkle));
+        let (kind, _) = map_lor_effect(&effect("shimmer", None, None), red).unwrap();
+        assert!(matches!(kind, EffectKind::Strobe));
+        assert!(map_lor_effect(&effect("DeviceSpecial", None, None), red).is_none());
+    }
+}
@@ -0,0 +1,479 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+
+use crate::import::ImportError;
+use crate::model::color::Color;
+use crate::model::fixture::{
+    BulbShape, ChannelOrder, ColorModel, EffectTarget, FixtureDef, FixtureGroup, FixtureId,
+    GroupId, GroupMember, PixelType,
+};
+use crate::model::show::{Layout, Show};
+use crate::model::timeline::{BlendMode, EffectInstance, Sequence, TimeRange, Track};
+
+use super::effects::map_lor_effect;
+use super::types::{LorChannel, LorEffect, LorGroup};
+
+/// LOR stores times in hundredths of a second.
+const CENTISECONDS_PER_SECOND: f64 = 100.0;
+/// Upper bound on imported effects, matching the Vixen importer.
+const MAX_TOTAL_EFFECTS: usize = 10_000;
+/// Sequence length used when the file declares none and has no effects.
+const DEFAULT_DURATION: f64 = 30.0;
+
+// ── LorImporter ─────────────────────────────────────────────────────
+
+/// Imports one Light-O-Rama `.lms` sequence or `.lcc` channel configuration.
+///
+/// Channels become single-pixel fixtures, `rgbChannel`s and channel groups
+/// become fixture groups, and each channel's effects land on its own track.
+#[derive(Default)]
+pub struct LorImporter {
+    name: String,
+    channels: Vec<LorChannel>,
+    groups: Vec<LorGroup>,
+    /// Set when the file is a `<sequence>` rather than a channel config.
+    is_sequence: bool,
+    audio_file: Option<String>,
+    /// Longest track length declared in the file, in seconds.
+    duration: f64,
+    effect_count: usize,
+    /// Count of skipped effects per unsupported LOR effect type.
+    unsupported: BTreeMap<String, usize>,
+    /// Set once effects start being dropped for exceeding the cap.
+    capped: bool,
+    warnings: Vec<String>,
+}
+
+impl LorImporter {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Return warnings accumulated during import.
+    #[must_use]
+    pub fn warnings(&self) -> &[String] {
+        &self.warnings
+    }
+
+    /// Count of parsed channels.
+    #[must_use]
+    pub fn channel_count(&self) -> usize {
+        self.channels.len()
+    }
+
+    /// Count of parsed effects that will be imported.
+    #[must_use]
+    pub fn effect_count(&self) -> usize {
+        self.effect_count
+    }
+
+    /// File name of the sequence's music. LOR stores an absolute Windows
+    /// path; only the final component is kept.
+    #[must_use]
+    pub fn audio_file(&self) -> Option<&str> {
+        self.audio_file.as_deref()
+    }
+
+    /// Parse a LOR file from disk. The file stem names the sequence.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ImportError` if the file cannot be read, is not well-formed
+    /// XML, or declares no channels.
+    pub fn parse_file(&mut self, path: &Path) -> Result<(), ImportError> {
+        let name = path
+            .file_stem()
+            .map_or_else(|| "Untitled".to_string(), |s| s.to_string_lossy().to_string());
+        let file = File::open(path)?;
+        self.parse_reader(BufReader::with_capacity(64 * 1024, file), &name)
+    }
+
+    /// Parse LOR XML from any reader.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ImportError` if the XML is malformed or declares no channels.
+    pub fn parse_reader<R: BufRead>(&mut self, reader: R, name: &str) -> Result<(), ImportError> {
+        self.name = name.to_string();
+        let mut xml = Reader::from_reader(reader);
+        xml.config_mut().trim_text(true);
+
+        let mut buf = Vec::with_capacity(4096);
+        // Names of the currently open elements, outermost first.
+        let mut open: Vec<String> = Vec::new();
+        loop {
+            match xml.read_event_into(&mut buf)? {
+                Event::Eof => break,
+                Event::Start(ref e) => {
+                    self.read_element(e, &open);
+                    open.push(element_name(e));
+                }
+                Event::Empty(ref e) => self.read_element(e, &open),
+                Event::End(_) => {
+                    open.pop();
+                }
+                _ => {}
+            }
+            buf.clear();
+        }
+
+        if self.channels.is_empty() {
+            return Err(ImportError::Parse(format!("No channels found in LOR file \"{name}\"")));
+        }
+        self.report_skipped();
+        Ok(())
+    }
+
+    /// Dispatch one opening (or self-closing) element by its position in the
+    /// document. Channel references inside `rgbChannel`s and groups share
+    /// element names with definitions, so the parent chain disambiguates.
+    fn read_element(&mut self, e: &BytesStart, open: &[String]) {
+        let name = element_name(e);
+        let parent = open.last().map(String::as_str);
+        let grandparent = open.len().checked_sub(2).and_then(|i| open.get(i)).map(String::as_str);
+        let top_level = open.len() == 2 && parent == Some("channels");
+
+        match name.as_str() {
+            "sequence" | "channelConfig" if open.is_empty() => {
+                self.is_sequence = name == "sequence";
+                self.audio_file = attributes(e)
+                    .get("musicFilename")
+                    .and_then(|path| path.rsplit(['\\', '/']).next())
+                    .filter(|file| !file.is_empty())
+                    .map(str::to_string);
+            }
+            "channel" if top_level => {
+                let attrs = attributes(e);
+                self.channels.push(LorChannel {
+                    name: attrs.get("name").cloned().unwrap_or_default(),
+                    saved_index: saved_index(&attrs),
+                    color: attrs.get("color").map_or(Color::WHITE, |c| lor_color(c)),
+                    effects: Vec::new(),
+                });
+            }
+            "rgbChannel" | "channelGroupList" if top_level => {
+                let attrs = attributes(e);
+                self.groups.push(LorGroup {
+                    name: attrs.get("name").cloned().unwrap_or_default(),
+                    saved_index: saved_index(&attrs),
+                    members: Vec::new(),
+                });
+            }
+            "channel" if parent == Some("channels") && grandparent == Some("rgbChannel") => {
+                self.add_group_member(e);
+            }
+            "channelGroup" if parent == Some("channelGroups") => self.add_group_member(e),
+            "effect" if open.len() == 3 && parent == Some("channel") => self.read_effect(e),
+            "track" if parent == Some("tracks") => {
+                let attrs = attributes(e);
+                let total = attrs.get("totalCentiseconds").and_then(|v| v.parse::<f64>().ok());
+                if let Some(cs) = total {
+                    self.duration = self.duration.max(cs / CENTISECONDS_PER_SECOND);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn add_group_member(&mut self, e: &BytesStart) {
+        let index = saved_index(&attributes(e));
+        if let Some(group) = self.groups.last_mut() {
+            group.members.push(index);
+        }
+    }
+
+    fn read_effect(&mut self, e: &BytesStart) {
+        let attrs = attributes(e);
+        let number = |key: &str| attrs.get(key).and_then(|v| v.parse::<f64>().ok());
+        let Some(channel) = self.channels.last_mut() else {
+            return;
+        };
+        let (Some(start), Some(end)) = (number("startCentisecond"), number("endCentisecond"))
+        else {
+            let name = &channel.name;
+            self.warnings.push(format!("Effect on channel \"{name}\" has no timing; skipped."));
+            return;
+        };
+        let effect = LorEffect {
+            type_name: attrs.get("type").cloned().unwrap_or_default(),
+            start: start / CENTISECONDS_PER_SECOND,
+            end: end / CENTISECONDS_PER_SECOND,
+            intensity: number("intensity"),
+            start_intensity: number("startIntensity"),
+            end_intensity: number("endIntensity"),
+        };
+
+        if map_lor_effect(&effect, channel.color).is_none() {
+            *self.unsupported.entry(effect.type_name).or_default() += 1;
+            return;
+        }
+        if effect.end <= effect.start {
+            return;
+        }
+        // LOR never overlaps effects on one channel; anything that does is
+        // dropped rather than given an extra lane.
+        if channel.effects.last().is_some_and(|prev| effect.start < prev.end) {
+            self.warnings.push(format!(
+                "Overlapping effect at {:.2}s on channel \"{}\" skipped.",
+                effect.start, channel.name
+            ));
+            return;
+        }
+        if self.effect_count >= MAX_TOTAL_EFFECTS {
+            self.capped = true;
+            return;
+        }
+        self.effect_count += 1;
+        channel.effects.push(effect);
+    }
+
+    /// Summarize skipped effects: one loud warning per unsupported type, plus
+    /// one if the effect cap was hit.
+    fn report_skipped(&mut self) {
+        for (type_name, count) in std::mem::take(&mut self.unsupported) {
+            eprintln!(
+                "\n[VibeLights] !!! UNHANDLED LOR EFFECT TYPE: '{type_name}' !!!\n\
+                 [VibeLights]     Skipped {count} effect(s).\n",
+            );
+            self.warnings.push(format!(
+                "Skipped {count} unsupported LOR \"{type_name}\" effect(s)."
+            ));
+        }
+        if self.capped {
+            self.warnings.push(format!(
+                "Effect cap of {MAX_TOTAL_EFFECTS} reached; later effects were not imported."
+            ));
+        }
+    }
+
+    /// Consume the importer and produce a Show. Sequences get one track per
+    /// channel that has effects; channel configs produce no sequence.
+    #[must_use]
+    pub fn into_show(self) -> Show {
+        let mut members: HashMap<u32, GroupMember> = HashMap::new();
+        let mut next_id = 0u32;
+        let mut fixtures = Vec::with_capacity(self.channels.len());
+        let mut channel_ids = Vec::with_capacity(self.channels.len());
+        for channel in &self.channels {
+            let id = FixtureId(next_id);
+            next_id += 1;
+            members.entry(channel.saved_index).or_insert(GroupMember::Fixture(id));
+            channel_ids.push(id);
+            fixtures.push(FixtureDef {
+                id,
+                name: channel.name.clone(),
+                color_model: ColorModel::Single,
+                pixel_count: 1,
+                pixel_type: PixelType::default(),
+                bulb_shape: BulbShape::default(),
+                display_radius_override: None,
+                channel_order: ChannelOrder::default(),
+                gamma: None,
+            });
+        }
+        let group_ids: Vec<GroupId> = self
+            .groups
+            .iter()
+            .map(|group| {
+                let id = GroupId(next_id);
+                next_id += 1;
+                members.entry(group.saved_index).or_insert(GroupMember::Group(id));
+                id
+            })
+            .collect();
+        let groups = self
+            .groups
+            .iter()
+            .zip(group_ids)
+            .map(|(group, id)| FixtureGroup {
+                id,
+                name: group.name.clone(),
+                members: group
+                    .members
+                    .iter()
+                    .filter_map(|index| members.get(index).cloned())
+                    .filter(|m| !matches!(m, GroupMember::Group(g) if *g == id))
+                    .collect(),
+            })
+            .collect();
+
+        let sequences = if self.is_sequence {
+            let tracks: Vec<Track> = self
+                .channels
+                .iter()
+                .zip(&channel_ids)
+                .filter(|(channel, _)| !channel.effects.is_empty())
+                .map(|(channel, &id)| Track {
+                    name: channel.name.clone(),
+                    target: EffectTarget::Fixtures(vec![id]),
+                    locked: false,
+                    muted: false,
+                    blend_mode: BlendMode::Override,
+                    opacity: 1.0,
+                    effects: channel
+                        .effects
+                        .iter()
+                        .filter_map(|e| {
+                            let (kind, params) = map_lor_effect(e, channel.color)?;
+                            Some(EffectInstance {
+                                kind,
+                                params,
+                                time_range: TimeRange::new(e.start, e.end)?,
+                                blend_mode: BlendMode::Override,
+                                opacity: 1.0,
+                                opacity_curve: None,
+                                fade_in: 0.0,
+                                fade_out: 0.0,
+                            })
+                        })
+                        .collect(),
+                })
+                .collect();
+            let last_end = tracks
+                .iter()
+                .flat_map(|t| &t.effects)
+                .map(|e| e.time_range.end())
+                .fold(0.0, f64::max);
+            let duration = self.duration.max(last_end);
+            vec![Sequence {
+                name: self.name.clone(),
+                duration: if duration > 0.0 { duration } else { DEFAULT_DURATION },
+                frame_rate: 30.0,
+                audio_file: self.audio_file,
+                audio_tracks: Vec::new(),
+                tracks,
+                motion_paths: HashMap::new(),
+                markers: Vec::new(),
+                cues: Vec::new(),
+                region: None,
+                looping: false,
+            }]
+        } else {
+            Vec::new()
+        };
+
+        Show {
+            name: self.name,
+            fixtures,
+            groups,
+            layout: Layout { fixtures: Vec::new() },
+            sequences,
+            patches: Vec::new(),
+            controllers: Vec::new(),
+        }
+    }
+}
+
+fn element_name(e: &BytesStart) -> String {
+    String::from_utf8_lossy(e.name().as_ref()).to_string()
+}
+
+/// Attribute values of `e`, keyed by attribute name.
+fn attributes(e: &BytesStart) -> HashMap<String, String> {
+    e.attributes()
+        .flatten()
+        .map(|attr| {
+            let key = String::from_utf8_lossy(attr.key.as_ref()).to_string();
+            let value = attr.unescape_value().map_or_else(
+                |_| String::from_utf8_lossy(&attr.value).to_string(),
+                std::borrow::Cow::into_owned,
+            );
+            (key, value)
+        })
+        .collect()
+}
+
+fn saved_index(attrs: &HashMap<String, String>) -> u32 {
+    attrs.get("savedIndex").and_then(|v| v.parse().ok()).unwrap_or(u32::MAX)
+}
+
+/// LOR colors are Windows `COLORREF` integers: 0x00BBGGRR.
+fn lor_color(value: &str) -> Color {
+    let bgr: u32 = value.trim().parse().unwrap_or(0x00FF_FFFF);
+    let [r, g, b, _] = bgr.to_le_bytes();
+    Color::rgb(r, g, b)
+}
+
+// ── Tests ───────────────────────────────────────────────────────────
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::indexing_slicing)]
+mod tests {
+    use super::*;
+    use crate::model::timeline::EffectKind;
+
+    const SMALL_LMS: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<sequence saveFileVersion="14" musicFilename="C:\Users\me\Music\Jingle Bells.mp3">
+  <channels>
+    <channel name="Arch Red" color="255" savedIndex="0">
+      <effect type="intensity" startCentisecond="100" endCentisecond="250" intensity="100"/>
+      <effect type="intensity" startCentisecond="250" endCentisecond="400" startIntensity="100" endIntensity="0"/>
+      <effect type="DeviceSpecial" startCentisecond="400" endCentisecond="500"/>
+    </channel>
+    <channel name="Arch Green" color="65280" savedIndex="1">
+      <effect type="twinkle" startCentisecond="0" endCentisecond="50" intensity="80"/>
+      <effect type="shimmer" startCentisecond="50" endCentisecond="75" intensity="100"/>
+    </channel>
+    <channel name="Arch Blue" color="16711680" savedIndex="2"/>
+    <rgbChannel name="Arch" savedIndex="3">
+      <channels>
+        <channel savedIndex="0"/>
+        <channel savedIndex="1"/>
+        <channel savedIndex="2"/>
+      </channels>
+    </rgbChannel>
+  </channels>
+  <tracks>
+    <track totalCentiseconds="6000">
+      <channels><channel savedIndex="3"/></channels>
+    </track>
+  </tracks>
+</sequence>"#;
+
+    #[test]
+    fn small_sequence_maps_channels_to_tracks() {
+        let mut importer = LorImporter::new();
+        importer.parse_reader(SMALL_LMS.as_bytes(), "Jingle").unwrap();
+        assert_eq!(importer.channel_count(), 3);
+        assert_eq!(importer.effect_count(), 4);
+        assert_eq!(importer.audio_file(), Some("Jingle Bells.mp3"));
+        assert!(importer.warnings().iter().any(|w| w.contains("DeviceSpecial")));
+
+        let show = importer.into_show();
+        assert_eq!(show.fixtures.len(), 3);
+        assert_eq!(show.groups.len(), 1);
+        assert_eq!(show.groups[0].name, "Arch");
+        assert_eq!(show.groups[0].members.len(), 3);
+
+        let seq = &show.sequences[0];
+        assert!((seq.duration - 60.0).abs() < 1e-9);
+        assert_eq!(seq.tracks.len(), 2);
+
+        let red = &seq.tracks[0];
+        assert_eq!(red.name, "Arch Red");
+        assert!(matches!(&red.target, EffectTarget::Fixtures(ids) if ids == &[FixtureId(0)]));
+        assert_eq!(red.effects.len(), 2);
+        assert!(matches!(red.effects[0].kind, EffectKind::Solid));
+        assert!((red.effects[0].time_range.start() - 1.0).abs() < 1e-9);
+        assert!((red.effects[0].time_range.end() - 2.5).abs() < 1e-9);
+        assert!(matches!(red.effects[1].kind, EffectKind::Fade));
+        assert!((red.effects[1].time_range.end() - 4.0).abs() < 1e-9);
+
+        let green = &seq.tracks[1];
+        assert!(matches!(green.effects[0].kind, EffectKind::Twinkle));
+        assert!(matches!(green.effects[1].kind, EffectKind::Strobe));
+    }
+
+    #[test]
+    fn lor_colors_are_bgr() {
+        assert_eq!(lor_color("255"), Color::rgb(255, 0, 0));
+        assert_eq!(lor_color("65280"), Color::rgb(0, 255, 0));
+        assert_eq!(lor_color("16711680"), Color::rgb(0, 0, 255));
+    }
+}
@@ -0,0 +1,6 @@
+mod effects;
+mod importer;
+mod types;
+
+pub use importer::LorImporter;
+pub use types::{LorDiscovery, LorFileInfo};
@@ -0,0 +1,68 @@
+use serde::Serialize;
+use ts_rs::TS;
+
+use crate::model::color::Color;
+
+// ── Discovery types (TS-exported) ───────────────────────────────────
+
+/// Discovery result from scanning a directory of Light-O-Rama files.
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct LorDiscovery {
+    pub lor_dir: String,
+    /// Musical sequences (`.lms`).
+    pub sequences: Vec<LorFileInfo>,
+    /// Channel configurations (`.lcc`): channels only, no effects.
+    pub channel_configs: Vec<LorFileInfo>,
+}
+
+/// Info about a discovered LOR file.
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct LorFileInfo {
+    pub filename: String,
+    pub path: String,
+    #[ts(type = "number")]
+    pub size_bytes: u64,
+}
+
+// ── Internal types ──────────────────────────────────────────────────
+
+/// A single LOR output circuit with the effects placed on it.
+#[derive(Debug, Clone)]
+pub(super) struct LorChannel {
+    pub name: String,
+    pub saved_index: u32,
+    pub color: Color,
+    pub effects: Vec<LorEffect>,
+}
+
+/// An `rgbChannel` or `channelGroupList`: a named set of other channels.
+#[derive(Debug, Clone)]
+pub(super) struct LorGroup {
+    pub name: String,
+    pub saved_index: u32,
+    /// `savedIndex` of each member channel or group.
+    pub members: Vec<u32>,
+}
+
+/// One effect on a channel. Times are in seconds, intensities 0-100.
+#[derive(Debug, Clone)]
+pub(super) struct LorEffect {
+    pub type_name: String,
+    pub start: f64,
+    pub end: f64,
+    pub intensity: Option<f64>,
+    pub start_intensity: Option<f64>,
+    pub end_intensity: Option<f64>,
+}
+
+impl LorEffect {
+    /// Intensity (0-1) at the start and end of the effect. Constant effects
+    /// carry a single `intensity`; fades carry a start/end pair.
+    pub fn intensity_range(&self) -> (f64, f64) {
+        let start = self.start_intensity.or(self.intensity).unwrap_or(100.0);
+        let end = self.end_intensity.or(self.intensity).unwrap_or(start);
+        ((start / 100.0).clamp(0.0, 1.0), (end / 100.0).clamp(0.0, 1.0))
+    }
+}
@@ -1,3 +1,5 @@
+pub mod cluster;
+pub mod lor;
 pub mod vixen;
 
 /// Backward-compatible alias: `crate::import::vixen_preview` still works.
@@ -23,6 +25,14 @@ pub const VIXEN_SEQUENCE_EXT: &str = "tim";
 /// Vixen module-store filename (for preview data).
 pub const VIXEN_MODULE_STORE_FILE: &str = "ModuleStore.xml";
 
+// ── Light-O-Rama format constants ──────────────────────────────────
+
+/// LOR musical sequence file extension.
+pub const LOR_SEQUENCE_EXT: &str = "lms";
+
+/// LOR channel configuration file extension.
+pub const LOR_CHANNEL_CONFIG_EXT: &str = "lcc";
+
 // ── Error type (shared across all importers) ────────────────────────
 
 #[derive(Debug)]
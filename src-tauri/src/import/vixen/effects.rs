@@ -2,7 +2,7 @@ use crate::model::color::Color;
 use crate::model::color_gradient::{ColorGradient, ColorStop};
 use crate::model::curve::{Curve, CurvePoint};
 use crate::model::timeline::{
-    ColorMode, EffectKind, EffectParams, ParamKey, ParamValue, WipeDirection,
+    ColorMode, EffectKind, EffectParams, ParamKey, ParamValue, PulseShape, WipeDirection,
 };
 
 use super::constants::{vixen_color_handling, vixen_direction, vixen_effect};
@@ -25,6 +25,26 @@ fn build_curve_param(points: &[(f64, f64)]) -> Option<ParamValue> {
     Curve::new(curve_points).map(ParamValue::Curve)
 }
 
+/// Largest mean squared error at which a Vixen pulse curve is replaced by the
+/// nearest `PulseShape` preset.
+const PULSE_SHAPE_TOLERANCE: f64 = 0.005;
+
+/// Set a chase's pulse profile from a Vixen pulse curve. Curves that closely
+/// match a `PulseShape` become that preset so the pulse stays a simple choice
+/// in the editor; anything else is kept as an exact `PulseCurve`. Without a
+/// curve, Vixen's default is a flat full-level pulse, i.e. `Square`.
+fn set_pulse(params: EffectParams, pulse_curve: Option<&Vec<(f64, f64)>>) -> EffectParams {
+    let shape = match pulse_curve.and_then(|pts| build_curve_param(pts)) {
+        None => PulseShape::Square,
+        Some(value) => match value.as_curve().map(PulseShape::closest) {
+            Some((shape, error)) if error <= PULSE_SHAPE_TOLERANCE => shape,
+            _ => return params.set(ParamKey::PulseCurve, value),
+        },
+    };
+    let name = crate::util::serde_variant_name(&shape).unwrap_or_default();
+    params.set(ParamKey::PulseShape, ParamValue::Text(name))
+}
+
 /// Build a `ColorGradient` `ParamValue` from Vixen gradient stops (positions 0-1).
 fn build_gradient_param(stops: &[(f64, Color)]) -> Option<ParamValue> {
     if stops.is_empty() {
@@ -161,11 +181,7 @@ pub(super) fn map_vixen_effect(effect: &VixenEffect) -> (EffectKind, EffectParam
                     params = params.set(ParamKey::MovementCurve, curve_val);
                 }
             }
-            if let Some(pts) = pulse_curve {
-                if let Some(curve_val) = build_curve_param(pts) {
-                    params = params.set(ParamKey::PulseCurve, curve_val);
-                }
-            }
+            params = set_pulse(params, pulse_curve);
             let color_mode = map_color_handling(color_handling, ColorMode::GradientPerPulse);
             params = params
                 .set(ParamKey::ColorMode, ParamValue::ColorMode(color_mode))
@@ -177,11 +193,7 @@ pub(super) fn map_vixen_effect(effect: &VixenEffect) -> (EffectKind, EffectParam
         // ── Spin → Chase (continuous rotation) ───────────────────
         SPIN => {
             let mut params = set_gradient(EffectParams::new(), effect);
-            if let Some(pts) = pulse_curve {
-                if let Some(curve_val) = build_curve_param(pts) {
-                    params = params.set(ParamKey::PulseCurve, curve_val);
-                }
-            }
+            params = set_pulse(params, pulse_curve);
             let speed = effect.revolution_count.unwrap_or(4.0);
             let pulse_width = effect
                 .pulse_percentage
@@ -403,23 +415,33 @@ pub(super) fn map_vixen_effect(effect: &VixenEffect) -> (EffectKind, EffectParam
             (EffectKind::Fade, params)
         }
 
-        // ── Fireworks → Twinkle (bright random bursts) ──────────
-        // Fireworks are particle bursts — approximated as bright twinkle.
+        // ── Fireworks → Sparkle (bright bursts that burn out) ───
         "Fireworks" => (
-            EffectKind::Twinkle,
+            EffectKind::Sparkle,
             EffectParams::new()
                 .set(ParamKey::Color, ParamValue::Color(base_color))
                 .set(ParamKey::Density, ParamValue::Float(0.3))
-                .set(ParamKey::Speed, ParamValue::Float(10.0)),
+                .set(ParamKey::Speed, ParamValue::Float(10.0))
+                .set(ParamKey::Decay, ParamValue::Float(0.2)),
         ),
 
-        // ── Snowflakes / Meteor → Twinkle ───────────────────────
-        "Snowflakes" | "Meteor" | "Meteors" => (
-            EffectKind::Twinkle,
+        // ── Snowflakes / Meteor → Sparkle ───────────────────────
+        // Snowflakes glint and fade; meteors leave long trails.
+        "Snowflakes" => (
+            EffectKind::Sparkle,
             EffectParams::new()
                 .set(ParamKey::Color, ParamValue::Color(base_color))
                 .set(ParamKey::Density, ParamValue::Float(0.3))
-                .set(ParamKey::Speed, ParamValue::Float(5.0)),
+                .set(ParamKey::Speed, ParamValue::Float(5.0))
+                .set(ParamKey::Decay, ParamValue::Float(0.1)),
+        ),
+        "Meteor" | "Meteors" => (
+            EffectKind::Sparkle,
+            EffectParams::new()
+                .set(ParamKey::Color, ParamValue::Color(base_color))
+                .set(ParamKey::Density, ParamValue::Float(0.15))
+                .set(ParamKey::Speed, ParamValue::Float(5.0))
+                .set(ParamKey::Decay, ParamValue::Float(0.3)),
         ),
 
         // ── Candle → Fade (warm flicker) ────────────────────────
@@ -566,18 +588,19 @@ mod tests {
         let (kind, _) = map_vixen_effect(&test_effect("Butterfly"));
         assert!(matches!(kind, EffectKind::Chase));
 
-        // Random/particle effects → Twinkle
+        // Dissolve switches pixels on/off → Twinkle
         let (kind, _) = map_vixen_effect(&test_effect("Dissolve"));
         assert!(matches!(kind, EffectKind::Twinkle));
 
+        // Particles with fading trails → Sparkle
         let (kind, _) = map_vixen_effect(&test_effect("Fireworks"));
-        assert!(matches!(kind, EffectKind::Twinkle));
+        assert!(matches!(kind, EffectKind::Sparkle));
 
         let (kind, _) = map_vixen_effect(&test_effect("Snowflakes"));
-        assert!(matches!(kind, EffectKind::Twinkle));
+        assert!(matches!(kind, EffectKind::Sparkle));
 
         let (kind, _) = map_vixen_effect(&test_effect("Meteor"));
-        assert!(matches!(kind, EffectKind::Twinkle));
+        assert!(matches!(kind, EffectKind::Sparkle));
 
         // Flame/warm effects → Fade
         let (kind, _) = map_vixen_effect(&test_effect("Fire"));
@@ -598,6 +621,32 @@ mod tests {
         assert!(matches!(kind, EffectKind::Solid));
     }
 
+    #[test]
+    fn chase_pulse_curves_map_to_closest_shape() {
+        let shape_of = |pulse_curve: Option<Vec<(f64, f64)>>| {
+            let mut chase = test_effect("Chase");
+            chase.pulse_curve = pulse_curve;
+            let (_, params) = map_vixen_effect(&chase);
+            (
+                params.get(&ParamKey::PulseShape).and_then(ParamValue::as_pulse_shape),
+                params.get(&ParamKey::PulseCurve).is_some(),
+            )
+        };
+
+        // Vixen's default pulse is flat at full level.
+        assert_eq!(shape_of(None), (Some(PulseShape::Square), false));
+        assert_eq!(
+            shape_of(Some(vec![(0.0, 100.0), (100.0, 100.0)])),
+            (Some(PulseShape::Square), false)
+        );
+        assert_eq!(
+            shape_of(Some(vec![(0.0, 0.0), (50.0, 100.0), (100.0, 0.0)])),
+            (Some(PulseShape::Triangle), false)
+        );
+        // A sawtooth matches no preset, so the exact curve is kept.
+        assert_eq!(shape_of(Some(vec![(0.0, 100.0), (100.0, 0.0)])), (None, true));
+    }
+
     #[test]
     fn test_wipe_direction_reverse() {
         let mut wipe = test_effect("Wipe");
@@ -3,29 +3,46 @@ use std::fs::File;
 use std::io::BufReader;
 use std::path::Path;
 
-use quick_xml::events::Event;
+use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 
 use crate::import::ImportError;
 use crate::model::color::Color;
 use crate::model::fixture::{
-    BulbShape, ChannelOrder, ColorModel, Controller, ControllerId, ControllerProtocol,
-    EffectTarget, FixtureDef, FixtureGroup, FixtureId, GroupId, GroupMember, PixelType,
+    BulbShape, ChannelOrder, ColorCalibration, ColorModel, Controller, ControllerId, ControllerProtocol,
+    DmxAddress, EffectTarget, FixtureDef, FixtureGroup, FixtureId, GroupId, GroupMember,
+    OutputMapping, Patch, PixelType, Universe,
 };
 use crate::model::show::{FixtureLayout, Layout, Show};
 use crate::model::timeline::{
-    BlendMode, EffectInstance, Sequence, TimeRange, Track,
+    BlendMode, EffectInstance, EffectParams, ParamKey, ParamValue, Sequence, TimeRange, Track,
 };
+use crate::model::{ColorGradient, Curve};
 
 use super::effects::map_vixen_effect;
-use super::types::{CurveKind, VixenEffect, VixenNode};
+use super::types::{CurveKind, VixenController, VixenEffect, VixenNode, VixenUniverse};
 use crate::import::parse_iso_duration;
 use crate::import::xyz_to_srgb;
 
+/// Channels per universe Vixen assumes when a universe entry omits its size.
+const VIXEN_UNIVERSE_SIZE: u32 = 510;
+/// Channels per universe when auto-patching.
+const DMX_UNIVERSE_SIZE: u32 = 512;
+/// Default tolerance for simplifying imported curves (normalized units).
+/// Half a percent is below what shows up on a light.
+const DEFAULT_CURVE_EPSILON: f64 = 0.005;
+/// Default cap on effects kept per imported sequence.
+const DEFAULT_MAX_EFFECTS: usize = 10_000;
+/// Progress callback taking a phase name and the fraction done (0.0–1.0).
+type PhaseProgress<'a> = &'a dyn Fn(&str, f64);
+
 // ── VixenImporter ───────────────────────────────────────────────────
 
 pub struct VixenImporter {
     nodes: HashMap<String, VixenNode>,
+    /// Node GUIDs in document order, so fixtures are built (and patched) in
+    /// the order Vixen lists them.
+    node_order: Vec<String>,
     guid_to_id: HashMap<String, u32>,
     next_id: u32,
     fixtures: Vec<FixtureDef>,
@@ -38,6 +55,10 @@ pub struct VixenImporter {
     merged_fixture_ids: HashSet<u32>,
     /// Warnings accumulated during import (orphan targets, unsupported shapes, etc.).
     warnings: Vec<String>,
+    /// Tolerance for simplifying curve params; 0 disables simplification.
+    curve_epsilon: f64,
+    /// Most effects kept per sequence; the rest are dropped with a warning.
+    max_effects: usize,
 }
 
 impl Default for VixenImporter {
@@ -51,6 +72,7 @@ impl VixenImporter {
     pub fn new() -> Self {
         Self {
             nodes: HashMap::new(),
+            node_order: Vec::new(),
             guid_to_id: HashMap::new(),
             next_id: 0,
             fixtures: Vec::new(),
@@ -60,6 +82,8 @@ impl VixenImporter {
             sequences: Vec::new(),
             merged_fixture_ids: HashSet::new(),
             warnings: Vec::new(),
+            curve_epsilon: DEFAULT_CURVE_EPSILON,
+            max_effects: DEFAULT_MAX_EFFECTS,
         }
     }
 
@@ -76,6 +100,7 @@ impl VixenImporter {
         let next_id = guid_map.values().copied().max().map_or(0, |m| m + 1);
         Self {
             nodes: HashMap::new(),
+            node_order: Vec::new(),
             guid_to_id: guid_map,
             next_id,
             fixtures,
@@ -85,9 +110,21 @@ impl VixenImporter {
             sequences: Vec::new(),
             merged_fixture_ids: HashSet::new(),
             warnings: Vec::new(),
+            curve_epsilon: DEFAULT_CURVE_EPSILON,
+            max_effects: DEFAULT_MAX_EFFECTS,
         }
     }
 
+    /// Set the tolerance used to simplify imported curves. 0 keeps every point.
+    pub fn set_curve_epsilon(&mut self, epsilon: f64) {
+        self.curve_epsilon = epsilon.max(0.0);
+    }
+
+    /// Set the most effects kept per imported sequence.
+    pub fn set_max_effects(&mut self, max: usize) {
+        self.max_effects = max;
+    }
+
     /// Return the GUID → ID mapping (for persisting after setup import).
     #[must_use]
     pub fn guid_map(&self) -> &HashMap<String, u32> {
@@ -163,10 +200,22 @@ impl VixenImporter {
 
     /// Parse SystemConfig.xml to extract fixtures, groups, and controllers.
     ///
+    /// An optional `progress_cb` is called with each phase's name and the
+    /// fraction (0.0–1.0) of the parse done when it starts.
+    ///
     /// # Errors
     ///
     /// Returns `ImportError` on I/O or XML parsing failures.
-    pub fn parse_system_config(&mut self, path: &Path) -> Result<(), ImportError> {
+    pub fn parse_system_config(
+        &mut self,
+        path: &Path,
+        progress_cb: Option<PhaseProgress<'_>>,
+    ) -> Result<(), ImportError> {
+        let report = |phase: &str, frac: f64| {
+            if let Some(cb) = progress_cb {
+                cb(phase, frac);
+            }
+        };
         let file = File::open(path)?;
         let reader = BufReader::with_capacity(64 * 1024, file);
         let mut xml = Reader::from_reader(reader);
@@ -175,18 +224,22 @@ impl VixenImporter {
         let mut buf = Vec::with_capacity(4096);
 
         // First pass: collect all nodes
+        report("Parsing nodes...", 0.0);
         self.parse_nodes(&mut xml, &mut buf)?;
 
         // Rewind and parse controllers
+        report("Parsing controllers...", 0.4);
         let file2 = File::open(path)?;
         let reader2 = BufReader::with_capacity(64 * 1024, file2);
         let mut xml2 = Reader::from_reader(reader2);
         xml2.config_mut().trim_text(true);
         buf.clear();
-        self.parse_controllers(&mut xml2, &mut buf)?;
+        let controllers = self.parse_controllers(&mut xml2, &mut buf)?;
 
         // Build fixtures and groups from nodes
+        report("Building fixtures...", 0.8);
         self.build_fixtures_and_groups();
+        self.build_patches(&controllers);
 
         Ok(())
     }
@@ -259,6 +312,7 @@ impl VixenImporter {
                             };
                             if !node.guid.is_empty() {
                                 let guid = node.guid.clone();
+                                self.node_order.push(guid.clone());
                                 self.nodes.insert(guid.clone(), node);
 
                                 // Register as child of parent node (if any)
@@ -297,6 +351,7 @@ impl VixenImporter {
 
                         if !node_id.is_empty() {
                             let guid = node_id.clone();
+                            self.node_order.push(guid.clone());
                             self.nodes.insert(
                                 guid.clone(),
                                 VixenNode {
@@ -327,12 +382,11 @@ impl VixenImporter {
         &mut self,
         xml: &mut Reader<BufReader<File>>,
         buf: &mut Vec<u8>,
-    ) -> Result<(), ImportError> {
+    ) -> Result<Vec<VixenController>, ImportError> {
         let mut in_controllers = false;
-        let mut current_name = String::new();
-        let mut current_outputs: Vec<(String, u16)> = Vec::new(); // (ip, universe)
+        let mut current = VixenController::default();
+        let mut parsed = Vec::new();
         let mut depth = 0u32;
-        let mut controller_id_counter = 0u32;
 
         loop {
             match xml.read_event_into(buf) {
@@ -345,40 +399,18 @@ impl VixenImporter {
                         in_controllers = true;
                     }
 
-                    if in_controllers
-                        && (name == "Controller"
-                            || name == "OutputController"
-                            || name.contains("Controller"))
-                    {
-                        for attr in e.attributes().flatten() {
-                            let key = String::from_utf8_lossy(attr.key.as_ref()).to_string();
-                            let val = String::from_utf8_lossy(&attr.value).to_string();
-                            if key == "name" || key == "Name" {
-                                current_name = val;
-                            }
-                        }
-                    }
-
-                    // Look for universe/IP configuration in output elements
+                    // Controller name/channel count and universe entries
                     if in_controllers {
-                        let mut ip = None;
-                        let mut universe = None;
-                        for attr in e.attributes().flatten() {
-                            let key = String::from_utf8_lossy(attr.key.as_ref()).to_string();
-                            let val = String::from_utf8_lossy(&attr.value).to_string();
-                            match key.as_str() {
-                                "ip" | "IP" | "address" | "Address" | "UnicastAddress" => {
-                                    ip = Some(val);
-                                }
-                                "universe" | "Universe" => {
-                                    universe = val.parse().ok();
-                                }
-                                _ => {}
-                            }
-                        }
-                        if let (Some(ip_addr), Some(uni)) = (ip, universe) {
-                            current_outputs.push((ip_addr, uni));
-                        }
+                        read_controller_attrs(e, &name, &mut current);
+                    }
+                }
+                // Universe entries are usually self-closing
+                Ok(Event::Empty(ref e)) if in_controllers => {
+                    let name = String::from_utf8_lossy(e.name().as_ref()).to_string();
+                    read_controller_attrs(e, &name, &mut current);
+                    if is_controller_element(&name) && !current.name.is_empty() {
+                        self.push_controllers(&current);
+                        parsed.push(std::mem::take(&mut current));
                     }
                 }
                 Ok(Event::End(ref e)) => {
@@ -389,37 +421,9 @@ impl VixenImporter {
                         in_controllers = false;
                     }
 
-                    if in_controllers
-                        && (name == "Controller"
-                            || name == "OutputController"
-                            || name.contains("Controller"))
-                        && !current_name.is_empty()
-                    {
-                        // Create a controller for each unique IP/universe combo
-                        // If no outputs found, create a generic E1.31 controller
-                        if current_outputs.is_empty() {
-                            self.controllers.push(Controller {
-                                id: ControllerId(controller_id_counter),
-                                name: current_name.clone(),
-                                protocol: ControllerProtocol::E131 {
-                                    unicast_address: None,
-                                },
-                            });
-                            controller_id_counter += 1;
-                        } else {
-                            for (ip, _universe) in &current_outputs {
-                                self.controllers.push(Controller {
-                                    id: ControllerId(controller_id_counter),
-                                    name: format!("{current_name} ({ip})"),
-                                    protocol: ControllerProtocol::E131 {
-                                        unicast_address: Some(ip.clone()),
-                                    },
-                                });
-                                controller_id_counter += 1;
-                            }
-                        }
-                        current_name.clear();
-                        current_outputs.clear();
+                    if in_controllers && is_controller_element(&name) && !current.name.is_empty() {
+                        self.push_controllers(&current);
+                        parsed.push(std::mem::take(&mut current));
                     }
                 }
                 Err(e) => return Err(ImportError::Xml(e)),
@@ -428,7 +432,121 @@ impl VixenImporter {
             buf.clear();
         }
 
-        Ok(())
+        Ok(parsed)
+    }
+
+    /// Create a controller for each unique unicast IP of a parsed Vixen
+    /// controller, or a generic E1.31 controller if it has none.
+    fn push_controllers(&mut self, parsed: &VixenController) {
+        let mut ips: Vec<&str> = Vec::new();
+        for ip in parsed.universes.iter().filter_map(|u| u.ip.as_deref()) {
+            if !ips.contains(&ip) {
+                ips.push(ip);
+            }
+        }
+
+        if ips.is_empty() {
+            self.controllers.push(Controller {
+                id: self.next_controller_id(),
+                name: parsed.name.clone(),
+                protocol: ControllerProtocol::E131 {
+                    unicast_address: None,
+                },
+                calibration: ColorCalibration::IDENTITY,
+            });
+        } else {
+            for ip in ips {
+                self.controllers.push(Controller {
+                    id: self.next_controller_id(),
+                    name: format!("{} ({ip})", parsed.name),
+                    protocol: ControllerProtocol::E131 {
+                        unicast_address: Some(ip.to_string()),
+                    },
+                    calibration: ColorCalibration::IDENTITY,
+                });
+            }
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn next_controller_id(&self) -> ControllerId {
+        ControllerId(self.controllers.len() as u32)
+    }
+
+    /// Patch fixtures onto the controllers' channels in document order,
+    /// translating each fixture's first controller channel through that
+    /// controller's universe table. Vixen doesn't record which controller a
+    /// fixture is wired to here, so fixtures fill each controller's channels
+    /// before moving to the next. Fixtures left over, and those a controller
+    /// without a channel count or universes would have carried, are
+    /// auto-patched sequentially into universes after the last one in use.
+    fn build_patches(&mut self, controllers: &[VixenController]) {
+        let next_universe = controllers
+            .iter()
+            .flat_map(|c| &c.universes)
+            .map(|u| u.universe)
+            .max()
+            .unwrap_or(0);
+        let mut fixtures = self.fixtures.iter().peekable();
+
+        for controller in controllers {
+            let ranges = universe_ranges(&controller.universes);
+            let Some(channel_count) = controller.channel_count.filter(|_| !ranges.is_empty())
+            else {
+                self.warnings.push(format!(
+                    "Controller '{}' has no channel count or universes; fixtures were auto-patched",
+                    controller.name
+                ));
+                continue;
+            };
+
+            let mut offset = 0u32;
+            while let Some(fixture) = fixtures.peek() {
+                let end = offset.saturating_add(fixture.total_channels());
+                if end > channel_count {
+                    break;
+                }
+                let Some((universe, start_address)) = locate_channel(&ranges, offset) else {
+                    break;
+                };
+                self.patches.push(Patch {
+                    fixture_id: fixture.id,
+                    output: OutputMapping::Dmx {
+                        universe,
+                        start_address,
+                    },
+                });
+                offset = end;
+                fixtures.next();
+            }
+        }
+
+        let mut auto_patched = 0usize;
+        let mut offset = 0u32;
+        for fixture in fixtures {
+            let universe = u16::try_from(offset / DMX_UNIVERSE_SIZE)
+                .map_or(u16::MAX, |u| next_universe.saturating_add(u));
+            let start_address = u16::try_from(offset % DMX_UNIVERSE_SIZE + 1)
+                .ok()
+                .and_then(DmxAddress::new);
+            if let Some(start_address) = start_address {
+                self.patches.push(Patch {
+                    fixture_id: fixture.id,
+                    output: OutputMapping::Dmx {
+                        universe: Universe(universe),
+                        start_address,
+                    },
+                });
+                auto_patched += 1;
+            }
+            offset = offset.saturating_add(fixture.total_channels());
+        }
+        if auto_patched > 0 {
+            self.warnings.push(format!(
+                "{auto_patched} fixture(s) had no Vixen controller channels and were auto-patched from universe {}",
+                next_universe.saturating_add(1)
+            ));
+        }
     }
 
     fn build_fixtures_and_groups(&mut self) {
@@ -440,8 +558,8 @@ impl VixenImporter {
             .collect();
 
         let root_guids: Vec<String> = self
-            .nodes
-            .keys()
+            .node_order
+            .iter()
             .filter(|guid| !all_child_guids.contains(guid.as_str()))
             .cloned()
             .collect();
@@ -479,6 +597,7 @@ impl VixenImporter {
                 bulb_shape: BulbShape::default(),
                 display_radius_override: None,
                 channel_order: ChannelOrder::default(),
+                gamma: None,
             });
             Some(GroupMember::Fixture(FixtureId(id)))
         } else {
@@ -527,6 +646,7 @@ impl VixenImporter {
                     bulb_shape: BulbShape::default(),
                     display_radius_override: None,
                     channel_order: ChannelOrder::default(),
+                    gamma: None,
                 });
 
                 // Record this as a merged fixture so parent nodes don't re-merge it
@@ -1308,15 +1428,20 @@ impl VixenImporter {
         }
 
         // Build tracks from effects, grouped by target node
-        let tracks = self.build_tracks(effects);
+        let tracks = self.build_tracks(&seq_name, effects);
 
         self.sequences.push(Sequence {
             name: seq_name,
             duration,
             frame_rate: 30.0,
             audio_file,
+            audio_tracks: Vec::new(),
             tracks,
             motion_paths: std::collections::HashMap::new(),
+            markers: Vec::new(),
+            cues: Vec::new(),
+            region: None,
+            looping: false,
         });
 
         Ok(())
@@ -1365,10 +1490,9 @@ impl VixenImporter {
     }
 
     /// Build tracks from parsed Vixen effects, grouped by target node.
+    /// Effects past the cap are dropped with a warning naming the sequence.
     #[allow(clippy::too_many_lines)]
-    fn build_tracks(&self, effects: Vec<VixenEffect>) -> Vec<Track> {
-        const MAX_TOTAL_EFFECTS: usize = 10_000;
-
+    fn build_tracks(&mut self, seq_name: &str, effects: Vec<VixenEffect>) -> Vec<Track> {
         // Group effects by their primary target
         let mut effects_by_target: HashMap<String, Vec<VixenEffect>> = HashMap::new();
 
@@ -1467,12 +1591,16 @@ impl VixenImporter {
                         let end = e.start_time + e.duration;
                         let time_range = TimeRange::new(e.start_time, end)?;
                         let (kind, params) = map_vixen_effect(e);
+                        let params = simplify_curves(params, self.curve_epsilon);
                         Some(EffectInstance {
                             kind,
                             params,
                             time_range,
                             blend_mode: BlendMode::Override,
                             opacity: 1.0,
+                            opacity_curve: None,
+                            fade_in: 0.0,
+                            fade_out: 0.0,
                         })
                     })
                     .collect();
@@ -1490,6 +1618,10 @@ impl VixenImporter {
                     tracks.push(Track {
                         name: format!("{target_name}{lane_suffix}"),
                         target: target.clone(),
+                        locked: false,
+                        muted: false,
+                        blend_mode: BlendMode::Override,
+                        opacity: 1.0,
                         effects: effect_instances,
                     });
                 }
@@ -1497,13 +1629,16 @@ impl VixenImporter {
         }
 
         // Cap total effects
-        if total_effects > MAX_TOTAL_EFFECTS {
-            eprintln!(
-                "[VibeLights] Warning: {total_effects} effects exceed cap of {MAX_TOTAL_EFFECTS}. Truncating tracks.",
-            );
+        let dropped = total_effects.saturating_sub(self.max_effects);
+        if dropped > 0 {
+            self.warnings.push(format!(
+                "Sequence \"{seq_name}\" has {total_effects} effects, over the import cap of {}; \
+                 {dropped} effects were dropped.",
+                self.max_effects,
+            ));
             let mut count = 0usize;
             for track in &mut tracks {
-                let remaining = MAX_TOTAL_EFFECTS.saturating_sub(count);
+                let remaining = self.max_effects.saturating_sub(count);
                 if remaining == 0 {
                     track.effects.clear();
                 } else if track.effects.len() > remaining {
@@ -1518,12 +1653,47 @@ impl VixenImporter {
         tracks
     }
 
+    /// Distinct multi-color gradients and non-flat curves used by imported
+    /// effects, in first-seen order, for seeding the reusable libraries.
+    /// Solid colors and constant curves are left out; they'd only clutter
+    /// the library.
+    #[must_use]
+    pub fn library_items(&self) -> (Vec<ColorGradient>, Vec<Curve>) {
+        let mut gradients: Vec<ColorGradient> = Vec::new();
+        let mut curves: Vec<Curve> = Vec::new();
+        let mut seen = HashSet::new();
+        let values = self
+            .sequences
+            .iter()
+            .flat_map(|s| &s.tracks)
+            .flat_map(|t| &t.effects)
+            .flat_map(|e| e.params.inner().values());
+        for value in values {
+            match value {
+                ParamValue::ColorGradient(g) if is_multicolor(g) && first_seen(&mut seen, g) => {
+                    gradients.push(g.clone());
+                }
+                ParamValue::Curve(c) if !is_flat(c) && first_seen(&mut seen, c) => {
+                    curves.push(c.clone());
+                }
+                _ => {}
+            }
+        }
+        (gradients, curves)
+    }
+
     /// Extract just the sequences (for sequence-only imports).
     #[must_use]
     pub fn into_sequences(self) -> Vec<Sequence> {
         self.sequences
     }
 
+    /// Remove and return the sequences parsed so far, keeping the rest of the
+    /// importer state for further files.
+    pub fn take_sequences(&mut self) -> Vec<Sequence> {
+        std::mem::take(&mut self.sequences)
+    }
+
     /// Consume the importer and produce a Show.
     #[must_use]
     pub fn into_show(self) -> Show {
@@ -1541,6 +1711,117 @@ impl VixenImporter {
     }
 }
 
+/// Whether an element in the controllers section opens a controller.
+/// Simplify every curve param so dense Vixen curves don't bloat sequence files.
+fn simplify_curves(mut params: EffectParams, epsilon: f64) -> EffectParams {
+    if epsilon <= 0.0 {
+        return params;
+    }
+    let simplified: Vec<(ParamKey, ParamValue)> = params
+        .inner()
+        .iter()
+        .filter_map(|(key, value)| match value {
+            ParamValue::Curve(c) => Some((key.clone(), ParamValue::Curve(c.simplified(epsilon)))),
+            _ => None,
+        })
+        .collect();
+    for (key, value) in simplified {
+        params.set_mut(key, value);
+    }
+    params
+}
+
+fn is_controller_element(name: &str) -> bool {
+    name == "Controller" || name == "OutputController" || name.contains("Controller")
+}
+
+/// Fold a controllers-section element's attributes into `controller`: the
+/// name and channel count from the controller element itself, and a universe
+/// entry from any element carrying a universe number.
+fn read_controller_attrs(e: &BytesStart, element: &str, controller: &mut VixenController) {
+    let is_controller = is_controller_element(element);
+    let mut ip = None;
+    let mut universe = None;
+    let mut start = None;
+    let mut size = None;
+    for attr in e.attributes().flatten() {
+        let key = String::from_utf8_lossy(attr.key.as_ref()).to_string();
+        let val = String::from_utf8_lossy(&attr.value).to_string();
+        match key.as_str() {
+            "name" | "Name" if is_controller => controller.name = val,
+            "outputCount" | "OutputCount" | "channelCount" | "ChannelCount" if is_controller => {
+                controller.channel_count = val.parse().ok();
+            }
+            "ip" | "IP" | "address" | "Address" | "UnicastAddress" | "Unicast" => {
+                ip = Some(val);
+            }
+            "universe" | "Universe" => universe = val.parse().ok(),
+            "start" | "Start" => start = val.parse().ok(),
+            "size" | "Size" => size = val.parse().ok(),
+            _ => {}
+        }
+    }
+    if let Some(universe) = universe {
+        controller.universes.push(VixenUniverse {
+            ip,
+            universe,
+            start,
+            size,
+        });
+    }
+}
+
+/// A controller universe's span of 0-based controller channel offsets.
+struct UniverseRange {
+    universe: Universe,
+    start: u32,
+    size: u32,
+}
+
+/// Resolve universe entries to channel spans. Entries without a start follow
+/// the previous one; entries without a size get Vixen's default.
+fn universe_ranges(universes: &[VixenUniverse]) -> Vec<UniverseRange> {
+    let mut next_start = 0;
+    universes
+        .iter()
+        .map(|u| {
+            // Vixen's start channel is 1-based; ranges hold 0-based offsets.
+            let start = u.start.map_or(next_start, |s| s.saturating_sub(1));
+            let size = u.size.unwrap_or(VIXEN_UNIVERSE_SIZE).clamp(1, DMX_UNIVERSE_SIZE);
+            next_start = start.saturating_add(size);
+            UniverseRange {
+                universe: Universe(u.universe.saturating_sub(1)),
+                start,
+                size,
+            }
+        })
+        .collect()
+}
+
+/// Universe and DMX address of a 0-based controller channel offset.
+fn locate_channel(ranges: &[UniverseRange], offset: u32) -> Option<(Universe, DmxAddress)> {
+    let range = ranges
+        .iter()
+        .find(|r| offset >= r.start && offset - r.start < r.size)?;
+    let address = u16::try_from(offset - range.start + 1).ok().and_then(DmxAddress::new)?;
+    Some((range.universe, address))
+}
+
+/// Record `value` by its serialized form; true the first time it's seen.
+fn first_seen<T: serde::Serialize>(seen: &mut HashSet<String>, value: &T) -> bool {
+    serde_json::to_string(value).is_ok_and(|k| seen.insert(k))
+}
+
+fn is_multicolor(gradient: &ColorGradient) -> bool {
+    let mut stops = gradient.stops().iter();
+    stops.next().is_some_and(|first| stops.any(|s| s.color != first.color))
+}
+
+fn is_flat(curve: &Curve) -> bool {
+    let mut points = curve.points().iter();
+    points.next().is_none_or(|first| points.all(|p| (p.y - first.y).abs() < f64::EPSILON))
+}
+
 // ── Tests ───────────────────────────────────────────────────────────
 
 #[cfg(test)]
@@ -1555,11 +1836,31 @@ impl VixenImporter {
     clippy::bool_assert_comparison,
     clippy::match_same_arms,
     clippy::option_map_or_none,
+    clippy::panic,
 )]
 mod tests {
     use super::*;
     use crate::import::{parse_iso_duration, xyz_to_srgb};
 
+    #[test]
+    fn universe_start_channel_is_one_based() {
+        let universe = |universe, start| VixenUniverse {
+            ip: None,
+            universe,
+            start,
+            size: Some(510),
+        };
+        let ranges = universe_ranges(&[universe(1, Some(1)), universe(2, Some(511))]);
+        let located = |offset| locate_channel(&ranges, offset).map(|(u, a)| (u.0, a.get()));
+        assert_eq!(located(0), Some((0, 1)));
+        assert_eq!(located(509), Some((0, 510)));
+        assert_eq!(located(510), Some((1, 1)));
+
+        // Entries without a start follow on from the previous one.
+        let ranges = universe_ranges(&[universe(1, Some(1)), universe(2, None)]);
+        assert_eq!(ranges[1].start, 510);
+    }
+
     #[test]
     fn test_parse_iso_duration_simple() {
         assert!((parse_iso_duration("PT1M53.606S").unwrap() - 113.606).abs() < 0.001);
@@ -1671,4 +1972,158 @@ mod tests {
             Some(parent_id)
         );
     }
+
+    #[test]
+    fn controllers_patch_fixtures_through_universe_table() {
+        let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+<SystemConfig>
+  <Nodes>
+    <Node name="Arch" id="arch">
+      <Node name="A1" id="a1" channelId="c1" />
+      <Node name="A2" id="a2" channelId="c2" />
+    </Node>
+    <Node name="Star" id="star" channelId="c3" />
+    <Node name="Flood" id="flood" channelId="c4" />
+  </Nodes>
+  <Controllers>
+    <Controller name="Pixel" outputCount="9">
+      <Universes>
+        <UniverseEntry Universe="5" Start="1" Size="6" Unicast="10.0.0.5" />
+        <UniverseEntry Universe="6" Start="7" Size="510" Unicast="10.0.0.5" />
+      </Universes>
+    </Controller>
+    <Controller name="Spare" />
+  </Controllers>
+</SystemConfig>"#;
+        let dir = std::env::temp_dir().join("vibelights_test_vixen_patch");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("SystemConfig.xml");
+        std::fs::write(&path, xml).unwrap();
+
+        let mut importer = VixenImporter::new();
+        importer.parse_system_config(&path, None).unwrap();
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert_eq!(importer.controller_count(), 2);
+        assert_eq!(importer.warnings().len(), 2, "{:?}", importer.warnings());
+
+        let address = |name: &str| {
+            let fixture = importer.fixtures.iter().find(|f| f.name == name).unwrap();
+            let patch = importer
+                .patches
+                .iter()
+                .find(|p| p.fixture_id == fixture.id)
+                .unwrap();
+            match &patch.output {
+                OutputMapping::Dmx { universe, start_address, .. } => {
+                    (universe.0, start_address.get())
+                }
+                OutputMapping::PixelPort { .. } => panic!("expected DMX patch"),
+            }
+        };
+        // Arch fills universe 5; Star starts universe 6 at controller channel 7.
+        assert_eq!(address("Arch"), (4, 1));
+        assert_eq!(address("Star"), (5, 1));
+        // Flood doesn't fit the controller's 9 channels, so it's auto-patched
+        // into the next unused universe.
+        assert_eq!(address("Flood"), (6, 1));
+    }
+
+    #[test]
+    fn library_items_dedup_gradients_and_get_unique_names() {
+        use crate::model::{EffectKind, EffectParams, ParamKey};
+        use crate::setup::LibrariesFile;
+
+        let fade = |gradient: ColorGradient, start: f64| EffectInstance {
+            kind: EffectKind::Fade,
+            params: EffectParams::new().set(ParamKey::Gradient, ParamValue::ColorGradient(gradient)),
+            time_range: TimeRange::new(start, start + 1.0).unwrap(),
+            blend_mode: BlendMode::Override,
+            opacity: 1.0,
+            opacity_curve: None,
+            fade_in: 0.0,
+            fade_out: 0.0,
+        };
+        let red_blue = ColorGradient::two_color(Color::rgb(255, 0, 0), Color::rgb(0, 0, 255));
+        let green_white = ColorGradient::two_color(Color::rgb(0, 255, 0), Color::WHITE);
+
+        let mut importer = VixenImporter::new();
+        importer.sequences.push(Sequence::for_test(
+            "Song",
+            10.0,
+            vec![Track {
+                name: "Roof".into(),
+                target: EffectTarget::All,
+                locked: false,
+                muted: false,
+                blend_mode: BlendMode::Override,
+                opacity: 1.0,
+                effects: vec![
+                    fade(red_blue.clone(), 0.0),
+                    fade(green_white, 1.0),
+                    fade(red_blue, 2.0),
+                    fade(ColorGradient::solid(Color::WHITE), 3.0),
+                ],
+            }],
+        ));
+
+        let (gradients, curves) = importer.library_items();
+        assert_eq!(gradients.len(), 2);
+        assert!(curves.is_empty());
+
+        let mut libs = LibrariesFile::default();
+        libs.gradients.insert(
+            "Vixen Gradient 1".into(),
+            ColorGradient::two_color(Color::BLACK, Color::WHITE),
+        );
+        let added = libs.absorb_imported("Vixen", gradients.clone(), curves);
+        assert_eq!(added, (2, 0));
+        assert_eq!(libs.gradients.len(), 3);
+        assert!(libs.gradients.contains_key("Vixen Gradient 2"));
+        assert!(libs.gradients.contains_key("Vixen Gradient 3"));
+
+        // Re-importing the same gradients adds nothing.
+        assert_eq!(libs.absorb_imported("Vixen", gradients, vec![]), (0, 0));
+        assert_eq!(libs.gradients.len(), 3);
+    }
+
+    #[test]
+    fn effects_past_the_cap_are_dropped_with_a_warning() {
+        let effect = |i: u32| VixenEffect {
+            type_name: "SetLevel".into(),
+            start_time: f64::from(i) * 2.0,
+            duration: 1.0,
+            target_node_guids: Vec::new(),
+            color: None,
+            movement_curve: None,
+            pulse_curve: None,
+            intensity_curve: None,
+            gradient_colors: None,
+            color_handling: None,
+            level: None,
+            revolution_count: None,
+            pulse_percentage: None,
+            pulse_time_ms: None,
+            reverse_spin: None,
+            direction: None,
+        };
+
+        let mut importer = VixenImporter::new();
+        importer.set_max_effects(5);
+        let tracks = importer.build_tracks("Big Song", (0..8).map(effect).collect());
+
+        let kept: usize = tracks.iter().map(|t| t.effects.len()).sum();
+        assert_eq!(kept, 5);
+        assert_eq!(
+            importer.warnings(),
+            ["Sequence \"Big Song\" has 8 effects, over the import cap of 5; \
+              3 effects were dropped."]
+        );
+
+        // Under the cap nothing is dropped or reported.
+        let mut importer = VixenImporter::new();
+        let tracks = importer.build_tracks("Song", (0..8).map(effect).collect());
+        assert_eq!(tracks.iter().map(|t| t.effects.len()).sum::<usize>(), 8);
+        assert!(importer.warnings().is_empty());
+    }
 }
@@ -7,7 +7,8 @@ mod types;
 // Re-export public API — all existing `crate::import::vixen::*` paths continue to work.
 pub use importer::VixenImporter;
 pub use types::{
-    VixenDiscovery, VixenImportConfig, VixenImportResult, VixenMediaInfo, VixenSequenceInfo,
+    VixenDiscovery, VixenImportConfig, VixenImportResult, VixenMediaInfo, VixenSequenceImport,
+    VixenSequenceInfo,
 };
 
 // Backward compatibility: ImportError lives in the parent module now,
@@ -819,6 +819,7 @@ pub fn build_fixture_layouts(
             fixture_id: FixtureId(fixture_id),
             pixel_positions,
             shape,
+            preview_gain: None,
         });
     }
 
@@ -54,6 +54,18 @@ pub struct VixenImportConfig {
     pub preview_file_override: Option<String>,
     pub sequence_paths: Vec<String>,
     pub media_filenames: Vec<String>,
+    /// Tolerance for simplifying imported curves, in normalized curve units.
+    /// `None` uses the importer default; `0` keeps every point.
+    #[serde(default)]
+    pub curve_epsilon: Option<f64>,
+    /// Most effects kept per imported sequence; the rest are dropped with a
+    /// warning. `None` uses the importer default.
+    #[serde(default)]
+    pub max_effects: Option<usize>,
+    /// Add groups of fixtures that sit close together in the imported layout,
+    /// alongside the groups from the Vixen project.
+    #[serde(default)]
+    pub group_by_position: bool,
 }
 
 /// Result returned after full import.
@@ -70,6 +82,16 @@ pub struct VixenImportResult {
     pub warnings: Vec<String>,
 }
 
+/// Outcome for one `.tim` file of a batch sequence import. Exactly one of
+/// `sequence` and `error` is set.
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct VixenSequenceImport {
+    pub path: String,
+    pub sequence: Option<crate::setup::SequenceSummary>,
+    pub error: Option<String>,
+}
+
 // ── Internal types ──────────────────────────────────────────────────
 
 #[derive(Debug, Clone)]
@@ -81,6 +103,27 @@ pub(super) struct VixenNode {
     pub channel_id: Option<String>,
 }
 
+/// A controller's output configuration from SystemConfig.xml.
+#[derive(Debug, Clone, Default)]
+pub(super) struct VixenController {
+    pub name: String,
+    /// Total output channels (`outputCount`), if declared.
+    pub channel_count: Option<u32>,
+    pub universes: Vec<VixenUniverse>,
+}
+
+/// One E1.31 universe entry on a controller.
+#[derive(Debug, Clone)]
+pub(super) struct VixenUniverse {
+    pub ip: Option<String>,
+    /// Universe number as shown in Vixen (1-based).
+    pub universe: u16,
+    /// First controller channel (1-based) carried by this universe.
+    pub start: Option<u32>,
+    /// Channels carried by this universe.
+    pub size: Option<u32>,
+}
+
 #[derive(Debug, Clone)]
 pub(super) struct VixenEffect {
     pub type_name: String,
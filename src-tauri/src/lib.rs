@@ -15,16 +15,21 @@ pub mod effects;
 pub mod events;
 pub mod engine;
 pub mod error;
+pub mod export;
 pub mod import;
 pub mod model;
+pub mod output;
 pub mod paths;
 pub mod setup;
 #[cfg(feature = "tauri-app")]
 pub mod progress;
 pub mod project;
 pub mod registry;
+pub mod render;
 #[cfg(feature = "tauri-app")]
 pub mod python;
 pub mod settings;
 pub mod state;
+pub mod template;
 pub mod util;
+pub mod waveform;
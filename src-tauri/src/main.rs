@@ -9,10 +9,12 @@ use parking_lot::Mutex;
 use tauri::Manager;
 
 use vibe_lights::commands;
-use vibe_lights::dispatcher::CommandDispatcher;
+use vibe_lights::dispatcher::{CommandDispatcher, DEFAULT_MAX_UNDO_LEVELS};
 use vibe_lights::model::Show;
 use vibe_lights::settings;
-use vibe_lights::state::{AppState, CancellationRegistry, PlaybackState};
+use vibe_lights::state::{
+    AnalysisCache, AppState, CancellationRegistry, FrameCache, PlaybackState, ThumbnailCache,
+};
 
 #[allow(clippy::expect_used)] // app cannot start without config dir / Tauri runtime
 fn main() {
@@ -45,12 +47,18 @@ fn main() {
                 playback: Mutex::new(PlaybackState {
                     playing: false,
                     current_time: 0.0,
+                    frame_index: 0,
                     sequence_index: 0,
-                    last_tick: None,
+                    clock_anchor: None,
                     region: None,
                     looping: false,
+                    solo_tracks: std::collections::HashSet::new(),
                 }),
-                dispatcher: Mutex::new(CommandDispatcher::new()),
+                dispatcher: Mutex::new(CommandDispatcher::with_max_undo_levels(
+                    loaded_settings
+                        .as_ref()
+                        .map_or(DEFAULT_MAX_UNDO_LEVELS, |s| s.max_undo_levels),
+                )),
                 app_config_dir: app_config_dir.clone(),
                 settings: Mutex::new(loaded_settings),
                 current_setup: Mutex::new(None),
@@ -58,7 +66,8 @@ fn main() {
                 script_cache: Mutex::new(std::collections::HashMap::new()),
                 python_sidecar: Mutex::new(None),
                 python_port: AtomicU16::new(0),
-                analysis_cache: Mutex::new(indexmap::IndexMap::new()),
+                analysis_cache: Mutex::new(AnalysisCache::new()),
+                waveform_cache: Mutex::new(indexmap::IndexMap::new()),
                 agent_sidecar: Mutex::new(None),
                 agent_port: AtomicU16::new(0),
                 agent_session_id: Mutex::new(None),
@@ -67,6 +76,10 @@ fn main() {
                 global_libraries: Mutex::new(global_libs),
                 cancellation: CancellationRegistry::new(),
                 api_port: AtomicU16::new(0),
+                thumbnail_cache: ThumbnailCache::new(),
+                frame_cache: FrameCache::new(),
+                tick_baselines: Mutex::new(std::collections::HashMap::new()),
+                live_output: Mutex::new(None),
             });
 
             // Load agent chat history
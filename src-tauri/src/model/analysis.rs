@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "tauri-app")]
@@ -64,6 +65,27 @@ impl Default for AnalysisFeatures {
     }
 }
 
+impl AnalysisFeatures {
+    /// True if every feature enabled in `requested` is enabled here too.
+    pub fn covers(&self, requested: &Self) -> bool {
+        let flags = |f: &Self| {
+            [
+                f.beats,
+                f.structure,
+                f.stems,
+                f.lyrics,
+                f.mood,
+                f.harmony,
+                f.low_level,
+                f.pitch,
+                f.drums,
+                f.vocal_presence,
+            ]
+        };
+        flags(self).into_iter().zip(flags(requested)).all(|(have, want)| have || !want)
+    }
+}
+
 // ── Beat analysis ─────────────────────────────────────────────────
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -84,6 +106,75 @@ pub struct BeatAnalysis {
     pub tempo_confidence: f64,
 }
 
+/// Note value of one snap/quantize grid step, counting the beat as a quarter note.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "tauri-app", derive(TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub enum GridDivision {
+    Whole,
+    Half,
+    #[default]
+    Quarter,
+    Eighth,
+    Sixteenth,
+    QuarterTriplet,
+    EighthTriplet,
+}
+
+impl GridDivision {
+    /// Length of one grid step in beats.
+    pub fn beats(self) -> f64 {
+        match self {
+            Self::Whole => 4.0,
+            Self::Half => 2.0,
+            Self::Quarter => 1.0,
+            Self::Eighth => 0.5,
+            Self::Sixteenth => 0.25,
+            Self::QuarterTriplet => 2.0 / 3.0,
+            Self::EighthTriplet => 1.0 / 3.0,
+        }
+    }
+}
+
+impl BeatAnalysis {
+    /// Seconds per grid step at the analyzed tempo, or `None` if the tempo
+    /// is unusable.
+    pub fn grid_step(&self, division: GridDivision) -> Option<f64> {
+        (self.tempo.is_finite() && self.tempo > 0.0).then(|| 60.0 / self.tempo * division.beats())
+    }
+
+    /// Where the grid is phase-locked: the first downbeat, else the first beat.
+    fn grid_origin(&self) -> f64 {
+        self.downbeats
+            .first()
+            .or(self.beats.first())
+            .copied()
+            .filter(|t| t.is_finite())
+            .unwrap_or(0.0)
+    }
+
+    /// Every gridline in `[0, duration]`, ascending.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn grid(&self, division: GridDivision, duration: f64) -> Vec<f64> {
+        let Some(step) = self.grid_step(division) else {
+            return Vec::new();
+        };
+        let first = self.grid_origin().rem_euclid(step);
+        if !duration.is_finite() || first > duration {
+            return Vec::new();
+        }
+        let count = ((duration - first) / step).floor() as u32;
+        (0..=count).map(|i| first + f64::from(i) * step).collect()
+    }
+
+    /// The gridline nearest to `time`, or `None` if the tempo is unusable.
+    pub fn snap(&self, division: GridDivision, time: f64) -> Option<f64> {
+        let step = self.grid_step(division)?;
+        let first = self.grid_origin().rem_euclid(step);
+        Some(first + ((time - first) / step).round() * step)
+    }
+}
+
 // ── Song structure ────────────────────────────────────────────────
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -121,6 +212,30 @@ pub struct StemAnalysis {
     pub bass: String,
     /// Relative path to other/accompaniment stem WAV
     pub other: String,
+    /// RMS energy envelope per stem name (e.g. "drums"), normalized to 0-1.
+    /// Absent in older analyses.
+    #[serde(default)]
+    pub energy: HashMap<String, Vec<f64>>,
+    /// Time step between energy samples in seconds
+    #[serde(default)]
+    pub energy_time_step: f64,
+}
+
+impl StemAnalysis {
+    /// Energy of `stem` at time `t`, linearly interpolated between samples and
+    /// held at the ends. `None` when the stem has no envelope or `t` is NaN.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss, clippy::cast_precision_loss)]
+    pub fn energy_at(&self, stem: &str, t: f64) -> Option<f64> {
+        let envelope = self.energy.get(stem).filter(|e| !e.is_empty())?;
+        if self.energy_time_step <= 0.0 || t.is_nan() {
+            return None;
+        }
+        let last = envelope.len() - 1;
+        let pos = (t / self.energy_time_step).clamp(0.0, last as f64);
+        let i = (pos as usize).min(last);
+        let (a, b) = (envelope.get(i)?, envelope.get((i + 1).min(last))?);
+        Some(a + (b - a) * (pos - i as f64))
+    }
 }
 
 // ── Lyrics (speech-to-text) ───────────────────────────────────────
@@ -36,7 +36,9 @@ impl Color {
     #[must_use]
     #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss, clippy::many_single_char_names)]
     pub fn from_hsv(h: f64, s: f64, v: f64) -> Self {
-        let h = h % 360.0;
+        // Non-finite inputs (e.g. script math gone wrong) count as 0.
+        let finite = |x: f64| if x.is_finite() { x } else { 0.0 };
+        let (h, s, v) = (finite(h) % 360.0, finite(s), finite(v));
         let c = v * s;
         let x = c * (1.0 - ((h / 60.0) % 2.0 - 1.0).abs());
         let m = v - c;
@@ -57,6 +59,47 @@ impl Color {
         )
     }
 
+    /// Create from HSL (hue 0-360, saturation 0-1, lightness 0-1).
+    #[must_use]
+    pub fn from_hsl(h: f64, s: f64, l: f64) -> Self {
+        let s = s.clamp(0.0, 1.0);
+        let l = l.clamp(0.0, 1.0);
+        // HSL → HSV: v = l + s·min(l, 1-l), s_v = 2(1 - l/v).
+        let v = l + s * l.min(1.0 - l);
+        let s_v = if v > 0.0 { 2.0 * (1.0 - l / v) } else { 0.0 };
+        Self::from_hsv(h.rem_euclid(360.0), s_v, v)
+    }
+
+    /// Approximate sRGB color of a black-body radiator at `kelvin` (clamped to
+    /// 1000–40000 K). Uses Tanner Helland's curve fit to the Planckian locus;
+    /// 6500 K is close to neutral white, lower values are warmer.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn from_kelvin(kelvin: f64) -> Self {
+        let temp = kelvin.clamp(1000.0, 40000.0) / 100.0;
+        let to_u8 = |x: f64| x.clamp(0.0, 255.0).round() as u8;
+
+        let r = if temp <= 66.0 {
+            255.0
+        } else {
+            329.698_727_446 * (temp - 60.0).powf(-0.133_204_759_2)
+        };
+        let g = if temp <= 66.0 {
+            99.470_802_586_1 * temp.ln() - 161.119_568_166_1
+        } else {
+            288.122_169_528_3 * (temp - 60.0).powf(-0.075_514_849_2)
+        };
+        let b = if temp >= 66.0 {
+            255.0
+        } else if temp <= 19.0 {
+            0.0
+        } else {
+            138.517_731_223_1 * (temp - 10.0).ln() - 305.044_792_730_7
+        };
+
+        Self::rgb(to_u8(r), to_u8(g), to_u8(b))
+    }
+
     /// Linear interpolation between two colors. t is clamped to [0, 1].
     #[must_use]
     #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
@@ -184,6 +227,76 @@ impl Color {
         (0.2126 * f64::from(self.r) + 0.7152 * f64::from(self.g) + 0.0722 * f64::from(self.b)) / 255.0
     }
 
+    /// How this color looks to a viewer with the given color vision
+    /// deficiency. Alpha is kept.
+    ///
+    /// The transform works on linear RGB with the full-severity matrices of
+    /// Machado, Oliveira & Fernandes (2009), which project onto the colors
+    /// the remaining two cone types (LMS) can tell apart.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn simulate_cvd(self, kind: ColorVisionDeficiency) -> Self {
+        fn to_srgb(c: f64) -> u8 {
+            let c = c.clamp(0.0, 1.0);
+            let c = if c <= 0.003_130_8 {
+                c * 12.92
+            } else {
+                1.055 * c.powf(1.0 / 2.4) - 0.055
+            };
+            (c * 255.0).round() as u8
+        }
+
+        let [m0, m1, m2] = kind.matrix();
+        let rgb = self.to_linear();
+        let dot = |row: [f64; 3]| row[0] * rgb[0] + row[1] * rgb[1] + row[2] * rgb[2];
+        Self {
+            r: to_srgb(dot(m0)),
+            g: to_srgb(dot(m1)),
+            b: to_srgb(dot(m2)),
+            a: self.a,
+        }
+    }
+
+    /// Linear-light RGB channels (0-1), undoing the sRGB transfer curve.
+    fn to_linear(self) -> [f64; 3] {
+        fn channel(c: u8) -> f64 {
+            let c = f64::from(c) / 255.0;
+            if c <= 0.040_45 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        }
+        [channel(self.r), channel(self.g), channel(self.b)]
+    }
+
+    /// Oklab coordinates `[L, a, b]` (Ottosson, 2020), where straight-line
+    /// distance tracks perceived color difference. Alpha is ignored.
+    #[must_use]
+    pub fn to_oklab(self) -> [f64; 3] {
+        let [r, g, b] = self.to_linear();
+        let l = (0.412_221_470_8 * r + 0.536_332_536_3 * g + 0.051_445_992_9 * b).cbrt();
+        let m = (0.211_903_498_2 * r + 0.680_699_545_1 * g + 0.107_396_956_6 * b).cbrt();
+        let s = (0.088_302_461_9 * r + 0.281_718_837_6 * g + 0.629_978_700_5 * b).cbrt();
+        [
+            0.210_454_255_3 * l + 0.793_617_785_0 * m - 0.004_072_046_8 * s,
+            1.977_998_495_1 * l - 2.428_592_205_0 * m + 0.450_593_709_9 * s,
+            0.025_904_037_1 * l + 0.782_771_766_2 * m - 0.808_675_766_0 * s,
+        ]
+    }
+
+    /// The `palette` entry that looks closest to this color (nearest in
+    /// Oklab), or `None` for an empty palette.
+    #[must_use]
+    pub fn nearest(self, palette: &[Color]) -> Option<Self> {
+        let [l, a, b] = self.to_oklab();
+        let distance = |c: &Color| {
+            let [l2, a2, b2] = c.to_oklab();
+            (l - l2).powi(2) + (a - a2).powi(2) + (b - b2).powi(2)
+        };
+        palette.iter().copied().min_by(|x, y| distance(x).total_cmp(&distance(y)))
+    }
+
     /// Alpha-composite `self` over `other` (self is foreground).
     #[must_use]
     #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
@@ -226,6 +339,56 @@ impl Color {
             BlendMode::IntensityOverlay => self.scale(fg.brightness()),
         }
     }
+
+    /// Blend `fg` onto `self` at `opacity`. `Override` crossfades from the
+    /// background to `fg`; other modes blend `fg` scaled by `opacity`.
+    #[inline]
+    #[must_use]
+    pub fn blend_at(self, fg: Self, mode: BlendMode, opacity: f64) -> Self {
+        if opacity >= 1.0 {
+            self.blend(fg, mode)
+        } else if mode == BlendMode::Override {
+            self.lerp(fg, opacity)
+        } else {
+            self.blend(fg.scale(opacity), mode)
+        }
+    }
+}
+
+/// A dichromatic color vision deficiency: one cone type is missing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum ColorVisionDeficiency {
+    /// No long-wavelength (red) cones.
+    Protanopia,
+    /// No medium-wavelength (green) cones; the most common form.
+    Deuteranopia,
+    /// No short-wavelength (blue) cones.
+    Tritanopia,
+}
+
+impl ColorVisionDeficiency {
+    /// Linear-RGB simulation matrix, row-major.
+    const fn matrix(self) -> [[f64; 3]; 3] {
+        match self {
+            Self::Protanopia => [
+                [0.152_286, 1.052_583, -0.204_868],
+                [0.114_503, 0.786_281, 0.099_216],
+                [-0.003_882, -0.048_116, 1.051_998],
+            ],
+            Self::Deuteranopia => [
+                [0.367_322, 0.860_646, -0.227_968],
+                [0.280_085, 0.672_501, 0.047_413],
+                [-0.011_820, 0.042_940, 0.968_881],
+            ],
+            Self::Tritanopia => [
+                [1.255_528, -0.076_749, -0.178_779],
+                [-0.078_411, 0.930_809, 0.147_602],
+                [0.004_733, 0.691_367, 0.303_900],
+            ],
+        }
+    }
 }
 
 impl Default for Color {
@@ -248,6 +411,150 @@ impl ops::Add for Color {
     }
 }
 
+/// RGBA color with `f32` channels on the same 0-255 scale as [`Color`].
+/// Compositing in float keeps long chains of low-opacity blends from
+/// rounding at every step; quantize once at the end with [`ColorF::to_color`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColorF {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32,
+}
+
+impl ColorF {
+    pub const BLACK: ColorF = ColorF { r: 0.0, g: 0.0, b: 0.0, a: 255.0 };
+
+    const fn opaque(r: f32, g: f32, b: f32) -> Self {
+        Self { r, g, b, a: 255.0 }
+    }
+
+    /// Round back to 8-bit channels.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn to_color(self) -> Color {
+        let q = |c: f32| c.clamp(0.0, 255.0).round() as u8;
+        Color { r: q(self.r), g: q(self.g), b: q(self.b), a: q(self.a) }
+    }
+
+    /// Linearly interpolate between two colors. t=0 returns self, t=1 returns other.
+    #[must_use]
+    pub fn lerp(self, other: Self, t: f32) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let mix = |a: f32, b: f32| a + (b - a) * t;
+        Self {
+            r: mix(self.r, other.r),
+            g: mix(self.g, other.g),
+            b: mix(self.b, other.b),
+            a: mix(self.a, other.a),
+        }
+    }
+
+    /// Scale RGB by a factor (0.0-1.0). Alpha is preserved.
+    #[must_use]
+    pub fn scale(self, factor: f32) -> Self {
+        let f = factor.clamp(0.0, 1.0);
+        Self { r: self.r * f, g: self.g * f, b: self.b * f, a: self.a }
+    }
+
+    /// Rec. 709 luma, returns 0.0..1.0.
+    #[must_use]
+    pub fn brightness(self) -> f32 {
+        (0.2126 * self.r + 0.7152 * self.g + 0.0722 * self.b) / 255.0
+    }
+
+    /// Alpha-composite `self` over `other` (self is foreground).
+    #[must_use]
+    pub fn over(self, other: Self) -> Self {
+        let fa = self.a / 255.0;
+        let ba = other.a / 255.0;
+        let out_a = fa + ba * (1.0 - fa);
+        if out_a <= 0.0 {
+            return Self { r: 0.0, g: 0.0, b: 0.0, a: 0.0 };
+        }
+        let mix = |f: f32, b: f32| (f * fa + b * ba * (1.0 - fa)) / out_a;
+        Self {
+            r: mix(self.r, other.r),
+            g: mix(self.g, other.g),
+            b: mix(self.b, other.b),
+            a: out_a * 255.0,
+        }
+    }
+
+    /// Float counterpart of [`Color::blend`].
+    #[must_use]
+    pub fn blend(self, fg: Self, mode: BlendMode) -> Self {
+        match mode {
+            BlendMode::Override => fg,
+            BlendMode::Add => Self::opaque(
+                (self.r + fg.r).min(255.0),
+                (self.g + fg.g).min(255.0),
+                (self.b + fg.b).min(255.0),
+            ),
+            BlendMode::Multiply => Self::opaque(
+                self.r * fg.r / 255.0,
+                self.g * fg.g / 255.0,
+                self.b * fg.b / 255.0,
+            ),
+            BlendMode::Max => Self {
+                r: self.r.max(fg.r),
+                g: self.g.max(fg.g),
+                b: self.b.max(fg.b),
+                a: self.a.max(fg.a),
+            },
+            BlendMode::Alpha => fg.over(self),
+            BlendMode::Subtract => Self::opaque(
+                (self.r - fg.r).max(0.0),
+                (self.g - fg.g).max(0.0),
+                (self.b - fg.b).max(0.0),
+            ),
+            BlendMode::Min => Self {
+                r: self.r.min(fg.r),
+                g: self.g.min(fg.g),
+                b: self.b.min(fg.b),
+                a: self.a.min(fg.a),
+            },
+            BlendMode::Average => Self::opaque(
+                f32::midpoint(self.r, fg.r),
+                f32::midpoint(self.g, fg.g),
+                f32::midpoint(self.b, fg.b),
+            ),
+            BlendMode::Screen => {
+                let screen = |a: f32, b: f32| 255.0 - (255.0 - a) * (255.0 - b) / 255.0;
+                Self::opaque(screen(self.r, fg.r), screen(self.g, fg.g), screen(self.b, fg.b))
+            }
+            BlendMode::Mask => {
+                if fg.r > 0.0 || fg.g > 0.0 || fg.b > 0.0 {
+                    Self::BLACK
+                } else {
+                    self
+                }
+            }
+            BlendMode::IntensityOverlay => self.scale(fg.brightness()),
+        }
+    }
+
+    /// Float counterpart of [`Color::blend_at`].
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn blend_at(self, fg: Self, mode: BlendMode, opacity: f64) -> Self {
+        let opacity = opacity as f32;
+        if opacity >= 1.0 {
+            self.blend(fg, mode)
+        } else if mode == BlendMode::Override {
+            self.lerp(fg, opacity)
+        } else {
+            self.blend(fg.scale(opacity), mode)
+        }
+    }
+}
+
+impl From<Color> for ColorF {
+    fn from(c: Color) -> Self {
+        Self { r: f32::from(c.r), g: f32::from(c.g), b: f32::from(c.b), a: f32::from(c.a) }
+    }
+}
+
 #[cfg(test)]
 #[allow(
     clippy::unwrap_used,
@@ -258,6 +565,40 @@ impl ops::Add for Color {
 mod tests {
     use super::*;
 
+    #[test]
+    fn hsl_primaries_and_grays() {
+        assert_eq!(Color::from_hsl(0.0, 1.0, 0.5), Color::rgb(255, 0, 0));
+        assert_eq!(Color::from_hsl(120.0, 1.0, 0.5), Color::rgb(0, 255, 0));
+        assert_eq!(Color::from_hsl(0.0, 0.0, 1.0), Color::WHITE);
+        assert_eq!(Color::from_hsl(200.0, 1.0, 0.0), Color::BLACK);
+    }
+
+    #[test]
+    fn kelvin_white_point_and_warmth() {
+        let daylight = Color::from_kelvin(6500.0);
+        assert!(daylight.r >= 250 && daylight.g >= 240 && daylight.b >= 240, "{daylight:?}");
+
+        let tungsten = Color::from_kelvin(2700.0);
+        assert_eq!(tungsten.r, 255);
+        assert!(tungsten.b < tungsten.g && tungsten.g < tungsten.r, "{tungsten:?}");
+    }
+
+    #[test]
+    fn deuteranopia_brings_red_and_green_closer() {
+        let distance = |a: Color, b: Color| {
+            let d = |x: u8, y: u8| (f64::from(x) - f64::from(y)).powi(2);
+            (d(a.r, b.r) + d(a.g, b.g) + d(a.b, b.b)).sqrt()
+        };
+        let (red, green) = (Color::rgb(220, 40, 40), Color::rgb(40, 180, 40));
+        let kind = ColorVisionDeficiency::Deuteranopia;
+
+        let seen = distance(red.simulate_cvd(kind), green.simulate_cvd(kind));
+        assert!(seen < distance(red, green) / 2.0, "{seen}");
+        // Grays have nothing to lose.
+        assert_eq!(Color::WHITE.simulate_cvd(kind), Color::WHITE);
+        assert_eq!(Color::BLACK.simulate_cvd(kind), Color::BLACK);
+    }
+
     #[test]
     fn additive_saturates_at_255() {
         let a = Color::rgb(200, 200, 200);
@@ -5,7 +5,7 @@ use ts_rs::TS;
 use super::color::Color;
 
 /// A color stop at a position along the gradient [0, 1].
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, TS, JsonSchema)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, TS, JsonSchema)]
 #[ts(export)]
 pub struct ColorStop {
     pub position: f64,
@@ -14,7 +14,7 @@ pub struct ColorStop {
 
 /// A color gradient defined by stops with linear RGB interpolation.
 /// Stops are always sorted by position.
-#[derive(Debug, Clone, Serialize, Deserialize, TS, JsonSchema)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS, JsonSchema)]
 #[serde(try_from = "ColorGradientRaw")]
 #[ts(export)]
 pub struct ColorGradient {
@@ -29,27 +29,42 @@ struct ColorGradientRaw {
 impl TryFrom<ColorGradientRaw> for ColorGradient {
     type Error = String;
     fn try_from(raw: ColorGradientRaw) -> Result<Self, String> {
-        ColorGradient::new(raw.stops)
-            .ok_or_else(|| "ColorGradient requires at least 1 stop".to_string())
+        ColorGradient::new(raw.stops).ok_or_else(|| {
+            "ColorGradient requires at least 1 stop, and stop positions must be numbers"
+                .to_string()
+        })
     }
 }
 
 impl ColorGradient {
-    /// Create a gradient from stops. Requires at least 1 stop.
-    /// Positions are clamped to [0, 1] and sorted.
+    /// Create a gradient from stops. Requires at least 1 stop and no NaN
+    /// positions. Positions are clamped to [0, 1] and sorted, and stops that
+    /// could never be seen are dropped: at a shared position only the first
+    /// and last stop matter (a hard edge), and a pair of the same color is
+    /// one stop.
     pub fn new(mut stops: Vec<ColorStop>) -> Option<Self> {
-        if stops.is_empty() {
+        if stops.is_empty() || stops.iter().any(|s| s.position.is_nan()) {
             return None;
         }
         for s in &mut stops {
             s.position = s.position.clamp(0.0, 1.0);
         }
-        stops.sort_by(|a, b| {
-            a.position
-                .partial_cmp(&b.position)
-                .unwrap_or(std::cmp::Ordering::Equal)
-        });
-        Some(Self { stops })
+        // Stable, so stops sharing a position keep the order they were given.
+        stops.sort_by(|a, b| a.position.total_cmp(&b.position));
+
+        let mut deduped: Vec<ColorStop> = Vec::with_capacity(stops.len());
+        for stop in stops {
+            let shared = |s: &ColorStop| s.position.total_cmp(&stop.position).is_eq();
+            let run = deduped.iter().rev().take_while(|s| shared(s)).count();
+            if run >= 2 {
+                // Replace the old closing stop; the opening one stays.
+                deduped.pop();
+            } else if run == 1 && deduped.last().is_some_and(|s| s.color == stop.color) {
+                continue;
+            }
+            deduped.push(stop);
+        }
+        Some(Self { stops: deduped })
     }
 
     /// Single solid color at positions 0 and 1.
@@ -114,13 +129,83 @@ impl ColorGradient {
         let a = &self.stops[idx - 1];
         let b = &self.stops[idx];
         let dp = b.position - a.position;
-        if dp <= 0.0 {
+        // Equal neighbours bound a solid block; lerping them would round
+        // 255 down to 254.
+        if dp <= 0.0 || a.color == b.color {
             return a.color;
         }
 
         let t = (pos - a.position) / dp;
         a.color.lerp(b.color, t)
     }
+
+    /// The same gradient running from 1 to 0.
+    #[must_use]
+    pub fn reversed(&self) -> Self {
+        let stops = self
+            .stops
+            .iter()
+            .rev()
+            .map(|s| ColorStop { position: 1.0 - s.position, color: s.color })
+            .collect();
+        Self { stops }
+    }
+
+    /// Shift every stop forward by `offset`, wrapping past 1 back to 0.
+    /// Boundary stops are added at 0 and 1 with the color that wraps across the
+    /// seam, so `rotated(o).evaluate(x) == evaluate((x - o) mod 1)`.
+    #[must_use]
+    pub fn rotated(&self, offset: f64) -> Self {
+        let offset = offset.rem_euclid(1.0);
+        if offset <= 0.0 {
+            return self.clone();
+        }
+        let seam = self.evaluate(1.0 - offset);
+        // Stops that wrap land below `offset`, the rest at or above it, so
+        // emitting the wrapped ones first keeps the list sorted.
+        let (wrapped, unwrapped): (Vec<ColorStop>, Vec<ColorStop>) = self
+            .stops
+            .iter()
+            .map(|s| ColorStop { position: s.position + offset, color: s.color })
+            .partition(|s| s.position >= 1.0);
+        let mut stops = Vec::with_capacity(self.stops.len() + 2);
+        stops.push(ColorStop { position: 0.0, color: seam });
+        stops.extend(wrapped.into_iter().map(|s| ColorStop { position: s.position - 1.0, ..s }));
+        stops.extend(unwrapped);
+        stops.push(ColorStop { position: 1.0, color: seam });
+        Self { stops }
+    }
+
+    /// Posterize onto `palette` for props that only show a few colors: the
+    /// gradient is split into `steps` equal bands, each a solid block of the
+    /// palette color nearest (in Oklab) to the gradient at the band's center,
+    /// with hard edges between blocks. `None` if `palette` is empty or
+    /// `steps` is 0.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn quantized(&self, palette: &[Color], steps: usize) -> Option<Self> {
+        if steps == 0 {
+            return None;
+        }
+        let mut stops = Vec::new();
+        let mut current: Option<Color> = None;
+        for i in 0..steps {
+            let start = i as f64 / steps as f64;
+            let color = self.evaluate((i as f64 + 0.5) / steps as f64).nearest(palette)?;
+            if current == Some(color) {
+                continue;
+            }
+            if let Some(prev) = current {
+                stops.push(ColorStop { position: start, color: prev });
+            }
+            stops.push(ColorStop { position: start, color });
+            current = Some(color);
+        }
+        if let Some(last) = current {
+            stops.push(ColorStop { position: 1.0, color: last });
+        }
+        Self::new(stops)
+    }
 }
 
 impl Default for ColorGradient {
@@ -208,4 +293,106 @@ mod tests {
         assert!((stops[1].position - 1.0).abs() < 1e-10);
         assert_eq!(stops[1].color.b, 255);
     }
+
+    #[test]
+    fn test_new_dedups_shared_positions() {
+        let stop = |position, color| ColorStop { position, color };
+        let red = Color::rgb(255, 0, 0);
+        let green = Color::rgb(0, 255, 0);
+        let blue = Color::rgb(0, 0, 255);
+        let g = ColorGradient::new(vec![
+            stop(0.5, red),
+            stop(0.0, red),
+            stop(0.5, green),
+            stop(0.5, blue),
+            stop(1.0, blue),
+            stop(1.0, blue),
+        ])
+        .unwrap();
+        let colors: Vec<Color> = g.stops().iter().map(|s| s.color).collect();
+        // The hard edge at 0.5 keeps its outer colors; the repeated end collapses.
+        assert_eq!(colors, [red, red, blue, blue]);
+        assert_eq!(g.evaluate(0.5), blue);
+
+        assert!(ColorGradient::new(vec![stop(f64::NAN, red)]).is_none());
+    }
+
+    fn assert_sorted_in_range(g: &ColorGradient) {
+        let stops = g.stops();
+        assert!(stops.iter().all(|s| (0.0..=1.0).contains(&s.position)));
+        assert!(stops.windows(2).all(|w| w[0].position <= w[1].position));
+    }
+
+    #[test]
+    fn test_reversed_mirrors_stops() {
+        let g = ColorGradient::new(vec![
+            ColorStop { position: 0.0, color: Color::rgb(255, 0, 0) },
+            ColorStop { position: 0.3, color: Color::rgb(0, 255, 0) },
+            ColorStop { position: 1.0, color: Color::rgb(0, 0, 255) },
+        ])
+        .unwrap();
+        let r = g.reversed();
+        assert_sorted_in_range(&r);
+        let positions: Vec<f64> = r.stops().iter().map(|s| s.position).collect();
+        assert!((positions[0] - 0.0).abs() < 1e-10);
+        assert!((positions[1] - 0.7).abs() < 1e-10);
+        assert!((positions[2] - 1.0).abs() < 1e-10);
+        assert_eq!(r.stops()[0].color, Color::rgb(0, 0, 255));
+        assert_eq!(r.stops()[1].color, Color::rgb(0, 255, 0));
+        assert_eq!(r.stops()[2].color, Color::rgb(255, 0, 0));
+    }
+
+    #[test]
+    fn test_rotated_wraps_quarter_turn() {
+        let g = ColorGradient::two_color(Color::rgb(255, 0, 0), Color::rgb(0, 0, 255));
+        let r = g.rotated(0.25);
+        assert_sorted_in_range(&r);
+        // The original start now sits at 0.25, and the seam color is the
+        // original's value at 0.75.
+        assert_eq!(r.evaluate(0.25), Color::rgb(255, 0, 0));
+        assert_eq!(r.evaluate(0.0), g.evaluate(0.75));
+        assert_eq!(r.evaluate(1.0), g.evaluate(0.75));
+        for x in [0.1, 0.2, 0.4, 0.6, 0.9] {
+            let expected = g.evaluate((x - 0.25f64).rem_euclid(1.0));
+            let got = r.evaluate(x);
+            assert!((i16::from(got.r) - i16::from(expected.r)).abs() <= 1, "x={x}");
+            assert!((i16::from(got.b) - i16::from(expected.b)).abs() <= 1, "x={x}");
+        }
+        // Full turns are a no-op
+        assert_eq!(g.rotated(1.0).stops().len(), 2);
+    }
+
+    #[test]
+    fn test_quantized_rainbow_uses_only_palette_colors() {
+        let hues = [0.0, 60.0, 120.0, 180.0, 240.0, 300.0, 360.0];
+        let rainbow = ColorGradient::new(
+            hues
+                .iter()
+                .map(|&h| ColorStop { position: h / 360.0, color: Color::from_hsv(h, 1.0, 1.0) })
+                .collect(),
+        )
+        .unwrap();
+        let palette = [
+            Color::rgb(255, 0, 0),
+            Color::rgb(0, 255, 0),
+            Color::rgb(0, 0, 255),
+            Color::rgb(255, 255, 255),
+        ];
+
+        let q = rainbow.quantized(&palette, 24).unwrap();
+        assert_sorted_in_range(&q);
+        for i in 0..=200 {
+            let c = q.evaluate(f64::from(i) / 200.0);
+            assert!(palette.contains(&c), "{c:?} at {i} is not in the palette");
+        }
+        // Every stop is a palette color, so blocks never blend into each other.
+        assert!(q.stops().iter().all(|s| palette.contains(&s.color)));
+        assert!(palette.iter().all(|c| q.stops().iter().any(|s| s.color == *c)));
+        assert_eq!(q.evaluate(0.0), Color::rgb(255, 0, 0));
+        assert_eq!(q.evaluate(0.35), Color::rgb(0, 255, 0));
+        assert_eq!(q.evaluate(0.67), Color::rgb(0, 0, 255));
+
+        assert!(rainbow.quantized(&[], 24).is_none());
+        assert!(rainbow.quantized(&palette, 0).is_none());
+    }
 }
@@ -2,32 +2,65 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
+use super::easing::EasingFunction;
+
+/// Number of evenly spaced points sampled for an easing preset.
+/// Odd so that x = 0.5 lands exactly on a sample.
+const PRESET_SAMPLES: usize = 33;
+
 /// A single point on a curve, both axes normalized to [0, 1].
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, TS, JsonSchema)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, TS, JsonSchema)]
 #[ts(export)]
 pub struct CurvePoint {
     pub x: f64,
     pub y: f64,
 }
 
-/// Piecewise-linear curve mapping time (x) to value (y), both normalized [0, 1].
-/// Points are always sorted by x. Evaluate via binary search + linear interpolation.
-#[derive(Debug, Clone, Serialize, Deserialize, TS, JsonSchema)]
+/// How a curve fills in between its points.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, TS, JsonSchema)]
+#[ts(export)]
+pub enum CurveInterp {
+    /// Straight segments between points.
+    #[default]
+    Linear,
+    /// Smooth spline through every point (Catmull-Rom tangents, cubic
+    /// Hermite segments). Overshoot is clamped to [0, 1].
+    CatmullRom,
+}
+
+impl CurveInterp {
+    // By reference for `skip_serializing_if`.
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    fn is_linear(&self) -> bool {
+        *self == Self::Linear
+    }
+}
+
+/// Curve mapping time (x) to value (y), both normalized [0, 1]. Points are
+/// always sorted by x. Evaluate via binary search, then interpolate the
+/// segment per `interp` (linear unless set).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS, JsonSchema)]
 #[serde(try_from = "CurveRaw")]
 #[ts(export)]
 pub struct Curve {
     points: Vec<CurvePoint>,
+    #[serde(default, skip_serializing_if = "CurveInterp::is_linear")]
+    interp: CurveInterp,
 }
 
 #[derive(Deserialize, JsonSchema)]
 struct CurveRaw {
     points: Vec<CurvePoint>,
+    #[serde(default)]
+    interp: CurveInterp,
 }
 
 impl TryFrom<CurveRaw> for Curve {
     type Error = String;
     fn try_from(raw: CurveRaw) -> Result<Self, String> {
-        Curve::new(raw.points).ok_or_else(|| "Curve requires at least 2 points".to_string())
+        Curve::new(raw.points)
+            .map(|c| c.with_interp(raw.interp))
+            .ok_or_else(|| "Curve requires at least 2 points".to_string())
     }
 }
 
@@ -43,7 +76,18 @@ impl Curve {
             p.y = p.y.clamp(0.0, 1.0);
         }
         points.sort_by(|a, b| a.x.partial_cmp(&b.x).unwrap_or(std::cmp::Ordering::Equal));
-        Some(Self { points })
+        Some(Self { points, interp: CurveInterp::Linear })
+    }
+
+    /// The same points, interpolated with `interp`.
+    #[must_use]
+    pub fn with_interp(mut self, interp: CurveInterp) -> Self {
+        self.interp = interp;
+        self
+    }
+
+    pub fn interp(&self) -> CurveInterp {
+        self.interp
     }
 
     /// Linear ramp from (0,0) to (1,1).
@@ -53,6 +97,7 @@ impl Curve {
                 CurvePoint { x: 0.0, y: 0.0 },
                 CurvePoint { x: 1.0, y: 1.0 },
             ],
+            interp: CurveInterp::Linear,
         }
     }
 
@@ -61,6 +106,7 @@ impl Curve {
         let y = y.clamp(0.0, 1.0);
         Self {
             points: vec![CurvePoint { x: 0.0, y }, CurvePoint { x: 1.0, y }],
+            interp: CurveInterp::Linear,
         }
     }
 
@@ -72,16 +118,49 @@ impl Curve {
                 CurvePoint { x: 0.5, y: 1.0 },
                 CurvePoint { x: 1.0, y: 0.0 },
             ],
+            interp: CurveInterp::Linear,
         }
     }
 
+    /// Standard easing shape sampled into points. Back and Elastic easings
+    /// overshoot [0, 1], so their overshoot is clamped like any other point.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn preset(kind: EasingFunction) -> Self {
+        let last = (PRESET_SAMPLES - 1) as f64;
+        let points = (0..PRESET_SAMPLES)
+            .map(|i| {
+                let x = i as f64 / last;
+                CurvePoint { x, y: kind.evaluate(x).clamp(0.0, 1.0) }
+            })
+            .collect();
+        Self { points, interp: CurveInterp::Linear }
+    }
+
+    /// Ramer–Douglas–Peucker simplification: drop points that lie within
+    /// `epsilon` (perpendicular distance, normalized units) of the line through
+    /// their neighbours. Endpoints are always kept.
+    pub fn simplified(&self, epsilon: f64) -> Self {
+        let mut keep = vec![false; self.points.len()];
+        if let Some(first) = keep.first_mut() {
+            *first = true;
+        }
+        rdp_mark(&self.points, 0, self.points.len().saturating_sub(1), epsilon, &mut keep);
+        let points = self
+            .points
+            .iter()
+            .zip(&keep)
+            .filter_map(|(p, &k)| k.then_some(*p))
+            .collect();
+        Self { points, interp: self.interp }
+    }
+
     /// Access the underlying points.
     pub fn points(&self) -> &[CurvePoint] {
         &self.points
     }
 
     /// Evaluate the curve at position x (clamped to [0, 1]).
-    /// Uses binary search for O(log n) lookup with linear interpolation.
+    /// Uses binary search for O(log n) lookup, then interpolates per `interp`.
     // Indexing is safe: points always has >= 2 entries (constructor returns None
     // otherwise), and idx bounds are checked before each access.
     #[allow(clippy::indexing_slicing)]
@@ -106,7 +185,69 @@ impl Curve {
         }
 
         let t = (x - a.x) / dx;
-        a.y + (b.y - a.y) * t
+        match self.interp {
+            CurveInterp::Linear => a.y + (b.y - a.y) * t,
+            CurveInterp::CatmullRom => {
+                let m_a = self.slope_at(idx - 1);
+                let m_b = self.slope_at(idx);
+                hermite(a.y, b.y, m_a * dx, m_b * dx, t).clamp(0.0, 1.0)
+            }
+        }
+    }
+
+    /// Catmull-Rom tangent (dy/dx) at point `i`: the slope between its
+    /// neighbours, or one-sided at the ends.
+    fn slope_at(&self, i: usize) -> f64 {
+        let prev = self.points.get(i.saturating_sub(1));
+        let next = self.points.get(i + 1).or_else(|| self.points.get(i));
+        match (prev, next) {
+            (Some(p), Some(n)) if n.x > p.x => (n.y - p.y) / (n.x - p.x),
+            _ => 0.0,
+        }
+    }
+}
+
+/// Cubic Hermite interpolation from `y0` to `y1` with end tangents `m0`, `m1`
+/// (already scaled to the segment width), at `t` in [0, 1].
+fn hermite(y0: f64, y1: f64, m0: f64, m1: f64, t: f64) -> f64 {
+    let t2 = t * t;
+    let t3 = t2 * t;
+    (2.0 * t3 - 3.0 * t2 + 1.0) * y0
+        + (t3 - 2.0 * t2 + t) * m0
+        + (-2.0 * t3 + 3.0 * t2) * y1
+        + (t3 - t2) * m1
+}
+
+/// Mark the points of `points[first..=last]` that RDP keeps, including `last`.
+fn rdp_mark(points: &[CurvePoint], first: usize, last: usize, epsilon: f64, keep: &mut [bool]) {
+    if let Some(k) = keep.get_mut(last) {
+        *k = true;
+    }
+    let (Some(a), Some(b)) = (points.get(first), points.get(last)) else {
+        return;
+    };
+    let (dx, dy) = (b.x - a.x, b.y - a.y);
+    let len = dx.hypot(dy);
+    let distance = |p: &CurvePoint| {
+        if len > 0.0 {
+            (dy * (p.x - a.x) - dx * (p.y - a.y)).abs() / len
+        } else {
+            (p.x - a.x).hypot(p.y - a.y)
+        }
+    };
+
+    let mut farthest = None;
+    let mut max_distance = epsilon;
+    for (i, p) in points.iter().enumerate().take(last).skip(first + 1) {
+        let d = distance(p);
+        if d > max_distance {
+            max_distance = d;
+            farthest = Some(i);
+        }
+    }
+    if let Some(split) = farthest {
+        rdp_mark(points, first, split, epsilon, keep);
+        rdp_mark(points, split, last, epsilon, keep);
     }
 }
 
@@ -178,4 +319,91 @@ mod tests {
         assert!((pts[1].x - 1.0).abs() < 1e-10);
         assert!((pts[1].y - 0.0).abs() < 1e-10);
     }
+
+    #[test]
+    fn test_preset_matches_vm_easing() {
+        let c = Curve::preset(EasingFunction::EaseInOutCubic);
+        let color = crate::dsl::vm::execute(
+            &crate::dsl::compile_source("let e = ease_in_out_cubic(0.5);\nrgb(e, e, e)").unwrap(),
+            &crate::dsl::vm::VmContext {
+                t: 0.0,
+                pixel: 0,
+                pixels: 1,
+                pos: 0.0,
+                pos2d: (0.0, 0.0),
+                abs_t: 0.0,
+                param_values: &[],
+                gradients: &[],
+                curves: &[],
+                colors: &[],
+                paths: &[],
+                positions: &[],
+            },
+        );
+        let y = c.evaluate(0.5);
+        assert!((y - 0.5).abs() < 1e-10);
+        assert!((f64::from(color.r) / 255.0 - y).abs() <= 0.5 / 255.0);
+    }
+
+    #[test]
+    fn test_preset_round_trips_as_plain_curve() {
+        let c = Curve::preset(EasingFunction::EaseOutBounce);
+        let json = serde_json::to_string(&c).unwrap();
+        let back: Curve = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.points().len(), c.points().len());
+        assert!((back.evaluate(0.3) - c.evaluate(0.3)).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_simplify_near_linear_collapses() {
+        let points = (0..100)
+            .map(|i| {
+                let x = f64::from(i) / 99.0;
+                let wobble = if i % 2 == 0 { 0.0005 } else { -0.0005 };
+                CurvePoint { x, y: x + wobble }
+            })
+            .collect();
+        let c = Curve::new(points).unwrap().simplified(0.005);
+        assert_eq!(c.points().len(), 2);
+        assert!((c.points()[0].x - 0.0).abs() < 1e-10);
+        assert!((c.points()[1].x - 1.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_simplify_keeps_sharp_corner() {
+        // Ramp up to a peak at x = 0.3, then down, sampled densely.
+        let points = (0..=100)
+            .map(|i| {
+                let x = f64::from(i) / 100.0;
+                let y = if x <= 0.3 { x / 0.3 } else { (1.0 - x) / 0.7 };
+                CurvePoint { x, y }
+            })
+            .collect();
+        let c = Curve::new(points).unwrap().simplified(0.005);
+        assert_eq!(c.points().len(), 3);
+        assert!((c.points()[1].x - 0.3).abs() < 1e-10);
+        assert!((c.points()[1].y - 1.0).abs() < 1e-10);
+        assert!((c.evaluate(0.65) - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_catmull_rom_rounds_off_a_peak() {
+        let linear = Curve::triangle();
+        let smooth = Curve::triangle().with_interp(CurveInterp::CatmullRom);
+
+        // Halfway up the rise: the spline leaves the start at the rise's slope
+        // and flattens into the peak, so it sits above the straight line.
+        assert!((linear.evaluate(0.25) - 0.5).abs() < 1e-10);
+        assert!((smooth.evaluate(0.25) - 0.625).abs() < 1e-10);
+        // Both still pass through every point.
+        for (x, y) in [(0.0, 0.0), (0.5, 1.0), (1.0, 0.0)] {
+            assert!((smooth.evaluate(x) - y).abs() < 1e-10);
+        }
+
+        let json = serde_json::to_string(&smooth).unwrap();
+        let back: Curve = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.interp(), CurveInterp::CatmullRom);
+        // Linear curves serialize as before and load as linear.
+        assert!(!serde_json::to_string(&linear).unwrap().contains("interp"));
+    }
 }
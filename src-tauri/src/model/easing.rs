@@ -54,6 +54,39 @@ pub enum EasingFunction {
 }
 
 impl EasingFunction {
+    /// Every easing function, in declaration order.
+    pub const ALL: [Self; 29] = [
+        Self::Hold,
+        Self::Linear,
+        Self::EaseIn,
+        Self::EaseOut,
+        Self::EaseInOut,
+        Self::EaseInCubic,
+        Self::EaseOutCubic,
+        Self::EaseInOutCubic,
+        Self::EaseInQuart,
+        Self::EaseOutQuart,
+        Self::EaseInOutQuart,
+        Self::EaseInSine,
+        Self::EaseOutSine,
+        Self::EaseInOutSine,
+        Self::EaseInExpo,
+        Self::EaseOutExpo,
+        Self::EaseInOutExpo,
+        Self::EaseInBack,
+        Self::EaseOutBack,
+        Self::EaseInOutBack,
+        Self::EaseInElastic,
+        Self::EaseOutElastic,
+        Self::EaseInOutElastic,
+        Self::EaseInCirc,
+        Self::EaseOutCirc,
+        Self::EaseInOutCirc,
+        Self::EaseInBounce,
+        Self::EaseOutBounce,
+        Self::EaseInOutBounce,
+    ];
+
     /// Evaluate the easing function at normalized time `t` (clamped to [0,1]).
     /// Returns the eased value, typically in [0,1] but may overshoot for
     /// Back and Elastic easings.
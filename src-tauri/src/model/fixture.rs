@@ -62,6 +62,21 @@ pub enum ChannelOrder {
     Bgr,
 }
 
+impl ChannelOrder {
+    /// Reorder an `[r, g, b]` triple into this wire order. Every output path
+    /// packs through here so what reaches the hardware matches the fixture setting.
+    pub const fn apply<T: Copy>(self, [r, g, b]: [T; 3]) -> [T; 3] {
+        match self {
+            ChannelOrder::Rgb => [r, g, b],
+            ChannelOrder::Grb => [g, r, b],
+            ChannelOrder::Brg => [b, r, g],
+            ChannelOrder::Rbg => [r, b, g],
+            ChannelOrder::Gbr => [g, b, r],
+            ChannelOrder::Bgr => [b, g, r],
+        }
+    }
+}
+
 // ── DMX Addressing ──────────────────────────────────────────────────
 
 /// DMX universe number (0-indexed internally, shown as 1-indexed to users).
@@ -118,7 +133,9 @@ pub struct Patch {
     pub output: OutputMapping,
 }
 
-/// Where a fixture's channel data is sent.
+/// Where a fixture's channel data is sent. Channel order is a property of the
+/// fixture (`FixtureDef::channel_order`), not of where it's patched; a
+/// per-patch order in an older setup file is moved onto its fixture on load.
 #[derive(Debug, Clone, Serialize, Deserialize, TS, JsonSchema)]
 #[ts(export)]
 pub enum OutputMapping {
@@ -126,14 +143,12 @@ pub enum OutputMapping {
     Dmx {
         universe: Universe,
         start_address: DmxAddress,
-        channel_order: ChannelOrder,
     },
     /// Future: direct pixel protocol output (e.g. WS2811 via a pixel controller).
     /// The controller handles the protocol; we just need to know which output port.
     PixelPort {
         controller_id: ControllerId,
         port: u16,
-        channel_order: ChannelOrder,
     },
 }
 
@@ -149,6 +164,9 @@ pub enum ControllerProtocol {
     ArtNet { address: Option<String> },
     /// Serial (USB) for direct pixel output.
     Serial { port: String, baud_rate: u32 },
+    /// DDP over network (WLED and most ESP-based controllers).
+    /// `address` is a host or `host:port`; the port defaults to 4048.
+    Ddp { address: String },
 }
 
 /// A physical controller that drives one or more outputs.
@@ -159,6 +177,41 @@ pub struct Controller {
     pub id: ControllerId,
     pub name: String,
     pub protocol: ControllerProtocol,
+    /// Color correction for everything this controller sends. Absent in
+    /// older files, meaning identity.
+    #[serde(default, skip_serializing_if = "ColorCalibration::is_identity")]
+    pub calibration: ColorCalibration,
+}
+
+/// Color correction matrix for a controller's output, so pixel batches that
+/// render the same color differently can be matched to each other.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, TS, JsonSchema)]
+#[ts(export)]
+pub struct ColorCalibration {
+    /// Row-major 3×3 matrix: output channel `i` is `matrix[i] · (r, g, b)`.
+    pub matrix: [[f32; 3]; 3],
+}
+
+impl ColorCalibration {
+    pub const IDENTITY: Self = Self {
+        matrix: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+    };
+
+    pub fn is_identity(&self) -> bool {
+        *self == Self::IDENTITY
+    }
+
+    /// Correct one pixel's RGB levels (0–255), clamped to that range.
+    pub fn apply(&self, rgb: [f32; 3]) -> [f32; 3] {
+        self.matrix
+            .map(|row| (row[0] * rgb[0] + row[1] * rgb[1] + row[2] * rgb[2]).clamp(0.0, 255.0))
+    }
+}
+
+impl Default for ColorCalibration {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
 }
 
 // ── Pixel & Bulb Types ──────────────────────────────────────────────
@@ -201,6 +254,24 @@ impl BulbShape {
             BulbShape::Flood => 3.0,
         }
     }
+
+    /// Outline drawn for one pixel of this bulb shape. Floods are panels;
+    /// every other bulb reads as a round dot.
+    pub fn footprint(self) -> BulbFootprint {
+        match self {
+            BulbShape::Flood => BulbFootprint::Square,
+            _ => BulbFootprint::Round,
+        }
+    }
+}
+
+/// Outline of a single bulb when rasterized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BulbFootprint {
+    /// A disc of the display radius.
+    Round,
+    /// A square with sides of twice the display radius.
+    Square,
 }
 
 // ── Fixtures ────────────────────────────────────────────────────────
@@ -224,6 +295,10 @@ pub struct FixtureDef {
     pub display_radius_override: Option<f32>,
     #[serde(default)]
     pub channel_order: ChannelOrder,
+    /// Output gamma for this fixture, overriding the show-wide default in
+    /// output settings. Only applied when packing output, never to frames.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gamma: Option<f32>,
 }
 
 impl FixtureDef {
@@ -260,11 +335,14 @@ pub struct FixtureGroup {
 
 impl FixtureGroup {
     /// Recursively resolve all fixture IDs in this group, with cycle detection.
+    /// Each fixture appears once, in first-reached order, even when several
+    /// subgroups share it.
     pub fn resolve_fixture_ids(&self, all_groups: &[FixtureGroup]) -> Vec<FixtureId> {
         let mut result = Vec::new();
         let mut visited = HashSet::new();
+        let mut seen = HashSet::new();
         visited.insert(self.id);
-        Self::resolve_recursive(&self.members, all_groups, &mut visited, &mut result);
+        Self::resolve_recursive(&self.members, all_groups, &mut visited, &mut seen, &mut result);
         result
     }
 
@@ -272,11 +350,16 @@ impl FixtureGroup {
         members: &[GroupMember],
         all_groups: &[FixtureGroup],
         visited: &mut HashSet<GroupId>,
+        seen: &mut HashSet<FixtureId>,
         result: &mut Vec<FixtureId>,
     ) {
         for member in members {
             match member {
-                GroupMember::Fixture(id) => result.push(*id),
+                GroupMember::Fixture(id) => {
+                    if seen.insert(*id) {
+                        result.push(*id);
+                    }
+                }
                 GroupMember::Group(gid) => {
                     if visited.insert(*gid) {
                         if let Some(group) = all_groups.iter().find(|g| g.id == *gid) {
@@ -284,6 +367,7 @@ impl FixtureGroup {
                                 &group.members,
                                 all_groups,
                                 visited,
+                                seen,
                                 result,
                             );
                         }
@@ -316,6 +400,28 @@ mod tests {
         }
     }
 
+    #[test]
+    fn patch_with_legacy_channel_order_still_loads() {
+        let patch: Patch = serde_json::from_str(
+            r#"{"fixture_id":3,"output":{"PixelPort":{"controller_id":1,"port":2,"channel_order":"Grb"}}}"#,
+        )
+        .unwrap();
+        assert!(matches!(patch.output, OutputMapping::PixelPort { port: 2, .. }));
+        let json = serde_json::to_string(&patch).unwrap();
+        assert!(!json.contains("channel_order"), "{json}");
+    }
+
+    #[test]
+    fn channel_order_maps_rgb_to_wire_bytes() {
+        let rgb = [0x11u8, 0x22, 0x33];
+        assert_eq!(ChannelOrder::Rgb.apply(rgb), [0x11, 0x22, 0x33]);
+        assert_eq!(ChannelOrder::Grb.apply(rgb), [0x22, 0x11, 0x33]);
+        assert_eq!(ChannelOrder::Brg.apply(rgb), [0x33, 0x11, 0x22]);
+        assert_eq!(ChannelOrder::Rbg.apply(rgb), [0x11, 0x33, 0x22]);
+        assert_eq!(ChannelOrder::Gbr.apply(rgb), [0x22, 0x33, 0x11]);
+        assert_eq!(ChannelOrder::Bgr.apply(rgb), [0x33, 0x22, 0x11]);
+    }
+
     #[test]
     fn flat_group_resolves_to_its_fixtures() {
         let g = group(1, vec![
@@ -436,4 +542,20 @@ mod tests {
         let ids = g1.resolve_fixture_ids(&all);
         assert_eq!(ids, vec![FixtureId(100)]);
     }
+
+    #[test]
+    fn fixtures_shared_by_subgroups_resolve_once() {
+        let left = group(2, vec![
+            GroupMember::Fixture(FixtureId(10)),
+            GroupMember::Fixture(FixtureId(20)),
+        ]);
+        let right = group(3, vec![
+            GroupMember::Fixture(FixtureId(20)),
+            GroupMember::Fixture(FixtureId(30)),
+        ]);
+        let top = group(1, vec![GroupMember::Group(GroupId(2)), GroupMember::Group(GroupId(3))]);
+        let all = vec![top.clone(), left, right];
+        let ids = top.resolve_fixture_ids(&all);
+        assert_eq!(ids, vec![FixtureId(10), FixtureId(20), FixtureId(30)]);
+    }
 }
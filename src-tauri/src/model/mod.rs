@@ -9,22 +9,25 @@ pub mod show;
 pub mod timeline;
 
 // Re-export commonly used types at the model level.
-pub use color::Color;
+pub use color::{Color, ColorF, ColorVisionDeficiency};
 pub use color_gradient::{ColorGradient, ColorStop};
-pub use curve::{Curve, CurvePoint};
+pub use curve::{Curve, CurveInterp, CurvePoint};
 pub use easing::EasingFunction;
 pub use motion_path::{LoopMode, MotionPath, Waypoint};
 pub use fixture::{
-    BulbShape, ChannelOrder, Controller, ControllerId, EffectTarget, FixtureDef, FixtureGroup,
+    BulbShape, ChannelOrder, ColorCalibration, Controller, ControllerId, EffectTarget, FixtureDef,
+    FixtureGroup,
     FixtureId, GroupId, GroupMember, OutputMapping, Patch, PixelType,
 };
 pub use show::{Layout, LayoutShape, Show};
 pub use timeline::{
-    BlendMode, ColorMode, EffectInstance, EffectKind, EffectParams, ParamKey, ParamSchema,
-    ParamType, ParamValue, Sequence, TimeRange, Track, WipeDirection,
+    AudioTrackRef, BlendMode, ColorMode, Cue, EffectInstance, EffectKind, EffectParams, EffectPreset,
+    Marker, ParamKey, ParamSchema, ParamType, ParamValue, PulseShape, Sequence, TimeRange,
+    Track, WipeDirection,
 };
 pub use analysis::{
-    AnalysisFeatures, AudioAnalysis, BeatAnalysis, ChordEvent, DrumAnalysis, HarmonyAnalysis,
-    LowLevelFeatures, LyricWord, LyricsAnalysis, MoodAnalysis, NoteEvent, PitchAnalysis,
-    PythonEnvStatus, SongSection, StemAnalysis, StructureAnalysis, VocalPresence, VocalSegment,
+    AnalysisFeatures, AudioAnalysis, BeatAnalysis, ChordEvent, DrumAnalysis, GridDivision,
+    HarmonyAnalysis, LowLevelFeatures, LyricWord, LyricsAnalysis, MoodAnalysis, NoteEvent,
+    PitchAnalysis, PythonEnvStatus, SongSection, StemAnalysis, StructureAnalysis, VocalPresence,
+    VocalSegment,
 };
@@ -180,9 +180,20 @@ pub struct FixtureLayout {
     pub pixel_positions: Vec<Position2D>,
     #[serde(default)]
     pub shape: LayoutShape,
+    /// Preview-only brightness multiplier, so props that are much brighter or
+    /// dimmer in person look right on screen. Never applied to output.
+    /// Absent means 1.0.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub preview_gain: Option<f32>,
 }
 
 impl FixtureLayout {
+    /// The preview brightness multiplier, defaulting to 1.0.
+    pub fn preview_gain(&self) -> f32 {
+        self.preview_gain.unwrap_or(1.0).max(0.0)
+    }
+
     /// Check if `pixel_positions` length matches the expected pixel count for this fixture.
     /// The evaluator handles mismatches gracefully (falls back to evenly-spaced positions),
     /// but callers can use this to detect and warn about inconsistencies.
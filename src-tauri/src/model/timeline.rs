@@ -36,6 +36,9 @@ impl TryFrom<TimeRangeRaw> for TimeRange {
 
 const TIME_EPSILON: f64 = 1e-9;
 
+/// Shortest effect an edit may leave behind, in seconds.
+pub const MIN_EFFECT_DURATION: f64 = 0.01;
+
 impl TimeRange {
     /// Create a time range. Returns None if start >= end, either is negative, or either is non-finite.
     pub fn new(start: f64, end: f64) -> Option<Self> {
@@ -46,6 +49,25 @@ impl TimeRange {
         }
     }
 
+    /// Clamp an effect's range into `[0, duration]`. Inverted ranges, and
+    /// ranges that end up shorter than `MIN_EFFECT_DURATION` once clamped,
+    /// are rejected with the reason.
+    pub fn clamped(start: f64, end: f64, duration: f64) -> Result<Self, String> {
+        if !start.is_finite() || !end.is_finite() {
+            return Err("start and end must be finite".into());
+        }
+        if start >= end {
+            return Err("start must be before end".into());
+        }
+        let (clamped_start, clamped_end) = (start.max(0.0), end.min(duration));
+        if clamped_end - clamped_start < MIN_EFFECT_DURATION {
+            return Err(format!(
+                "must cover at least {MIN_EFFECT_DURATION}s within the {duration:.3}s sequence"
+            ));
+        }
+        Self::new(clamped_start, clamped_end).ok_or_else(|| "out of sequence bounds".into())
+    }
+
     pub fn start(&self) -> f64 {
         self.start
     }
@@ -77,10 +99,11 @@ impl TimeRange {
 }
 
 /// How multiple effect layers combine their output.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS, JsonSchema)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, TS, JsonSchema)]
 #[ts(export)]
 pub enum BlendMode {
     /// Top layer fully replaces the layer below.
+    #[default]
     Override,
     /// Additive blend (clamped at 255 per channel).
     Add,
@@ -167,6 +190,14 @@ pub enum ParamKey {
     CenterY,
     PassCount,
     WipeOn,
+    BeatSync,
+    BeatDivision,
+    /// Mixed into randomized effects' hashing for distinct-but-stable patterns.
+    Seed,
+    /// Chase head falloff; see `PulseShape`.
+    PulseShape,
+    /// How long a sparkle takes to fade out, in effect-normalized time.
+    Decay,
     /// Custom parameter key for DSL-defined effects.
     Custom(String),
 }
@@ -198,6 +229,11 @@ impl ParamKey {
             "CenterY" => Self::CenterY,
             "PassCount" => Self::PassCount,
             "WipeOn" => Self::WipeOn,
+            "BeatSync" => Self::BeatSync,
+            "BeatDivision" => Self::BeatDivision,
+            "Seed" => Self::Seed,
+            "PulseShape" => Self::PulseShape,
+            "Decay" => Self::Decay,
             other => Self::Custom(other.to_string()),
         }
     }
@@ -245,6 +281,64 @@ impl WipeDirection {
     }
 }
 
+/// Brightness profile across a chase pulse, from head to tail.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, TS, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum PulseShape {
+    /// Full brightness across the whole pulse, hard edges.
+    Square,
+    /// Linear ramp up to the middle and back down.
+    #[default]
+    Triangle,
+    /// Bell curve: a bright core with soft tails.
+    Gaussian,
+}
+
+impl PulseShape {
+    /// Standard deviation of the gaussian profile, in pulse widths. At three
+    /// sigma the pulse edges are dark enough to meet the background cleanly.
+    const GAUSSIAN_SIGMA: f64 = 1.0 / 6.0;
+
+    pub const fn all() -> &'static [PulseShape] {
+        &[PulseShape::Square, PulseShape::Triangle, PulseShape::Gaussian]
+    }
+
+    /// Brightness at `x` (0 = head, 1 = tail) across the pulse.
+    pub fn evaluate(self, x: f64) -> f64 {
+        let x = x.clamp(0.0, 1.0);
+        match self {
+            PulseShape::Square => 1.0,
+            PulseShape::Triangle => 1.0 - (2.0 * x - 1.0).abs(),
+            PulseShape::Gaussian => {
+                let d = (x - 0.5) / Self::GAUSSIAN_SIGMA;
+                (-0.5 * d * d).exp()
+            }
+        }
+    }
+
+    /// The shape whose profile best matches `curve`, with the mean squared
+    /// error of the match.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn closest(curve: &Curve) -> (PulseShape, f64) {
+        const SAMPLES: usize = 32;
+        let error = |shape: PulseShape| {
+            (0..=SAMPLES)
+                .map(|i| {
+                    let x = i as f64 / SAMPLES as f64;
+                    (curve.evaluate(x) - shape.evaluate(x)).powi(2)
+                })
+                .sum::<f64>()
+                / (SAMPLES + 1) as f64
+        };
+        Self::all()
+            .iter()
+            .map(|&shape| (shape, error(shape)))
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .unwrap_or((PulseShape::Triangle, 0.0))
+    }
+}
+
 /// How gradient colors are applied across time/space.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS, JsonSchema)]
 #[serde(rename_all = "snake_case")]
@@ -376,6 +470,14 @@ impl ParamValue {
             _ => None,
         }
     }
+
+    /// Extract a `PulseShape`, stored as its snake_case name in `Text`.
+    pub fn as_pulse_shape(&self) -> Option<PulseShape> {
+        match self {
+            ParamValue::Text(s) => crate::util::from_serde_str(s),
+            _ => None,
+        }
+    }
 }
 
 /// Describes the type and constraints for an effect parameter, used to drive UI generation.
@@ -410,6 +512,89 @@ pub struct ParamSchema {
     pub default: ParamValue,
 }
 
+impl ParamSchema {
+    /// Check that `value` has a type this param's effect can read and lies
+    /// within the schema's range or options. Library references count as the
+    /// gradient or curve they name.
+    pub fn check(&self, value: &ParamValue) -> Result<(), String> {
+        let wrong_type = || Err(format!("expected {}, got {value:?}", self.param_type.describe()));
+        let one_of = |v: &str, options: &[String]| {
+            if options.is_empty() || options.iter().any(|o| o == v) {
+                Ok(())
+            } else {
+                Err(format!("\"{v}\" is not one of {}", options.join(", ")))
+            }
+        };
+        let in_range = |n: f64, min: f64, max: f64| {
+            if (min..=max).contains(&n) {
+                Ok(())
+            } else {
+                Err(format!("{n} is outside {min}..={max}"))
+            }
+        };
+        let count_in = |n: usize, min: usize, max: usize, what: &str| {
+            if (min..=max).contains(&n) {
+                Ok(())
+            } else {
+                Err(format!("{n} {what} (needs {min} to {max})"))
+            }
+        };
+
+        match (&self.param_type, value) {
+            (ParamType::Float { min, max, .. }, v) => match v.as_float() {
+                Some(n) => in_range(n, *min, *max),
+                None => wrong_type(),
+            },
+            (ParamType::Int { min, max }, v) => match v.as_float() {
+                Some(n) => in_range(n, f64::from(*min), f64::from(*max)),
+                None => wrong_type(),
+            },
+            (ParamType::Bool, ParamValue::Bool(_))
+            | (ParamType::Color, ParamValue::Color(_))
+            | (ParamType::Curve, ParamValue::Curve(_) | ParamValue::CurveRef(_))
+            | (ParamType::ColorGradient { .. }, ParamValue::GradientRef(_))
+            | (ParamType::ColorMode { .. }, ParamValue::ColorMode(_))
+            | (ParamType::Path, ParamValue::PathRef(_)) => Ok(()),
+            (ParamType::ColorList { min_colors, max_colors }, ParamValue::ColorList(colors)) => {
+                count_in(colors.len(), *min_colors, *max_colors, "colors")
+            }
+            (ParamType::ColorGradient { min_stops, max_stops }, ParamValue::ColorGradient(g)) => {
+                count_in(g.stops().len(), *min_stops, *max_stops, "stops")
+            }
+            (ParamType::WipeDirection { .. }, v) if v.as_wipe_direction().is_some() => Ok(()),
+            (ParamType::Text { options }, ParamValue::Text(t))
+            | (ParamType::Enum { options }, ParamValue::EnumVariant(t)) => {
+                one_of(t.as_str(), options.as_slice())
+            }
+            (ParamType::Flags { options }, ParamValue::FlagSet(flags)) => {
+                flags.iter().try_for_each(|f| one_of(f.as_str(), options.as_slice()))
+            }
+            _ => wrong_type(),
+        }
+    }
+}
+
+impl ParamType {
+    /// Short human-readable name for error messages.
+    fn describe(&self) -> &'static str {
+        match self {
+            ParamType::Float { .. } => "a number",
+            ParamType::Int { .. } => "an integer",
+            ParamType::Bool => "a bool",
+            ParamType::Color => "a color",
+            ParamType::ColorList { .. } => "a color list",
+            ParamType::Curve => "a curve",
+            ParamType::ColorGradient { .. } => "a gradient",
+            ParamType::ColorMode { .. } => "a color mode",
+            ParamType::WipeDirection { .. } => "a wipe direction",
+            ParamType::Text { .. } => "text",
+            ParamType::Enum { .. } => "an enum variant",
+            ParamType::Flags { .. } => "a flag set",
+            ParamType::Path => "a motion path reference",
+        }
+    }
+}
+
 /// Named, typed parameters for an effect instance.
 /// Serializes as a flat JSON object (transparent over the inner HashMap).
 #[derive(Debug, Clone, Serialize, Deserialize, Default, TS, JsonSchema)]
@@ -467,6 +652,7 @@ impl EffectParams {
     owned_or!(color_or, as_color, Color);
     owned_or!(color_mode_or, as_color_mode, ColorMode);
     owned_or!(wipe_direction_or, as_wipe_direction, WipeDirection);
+    owned_or!(pulse_shape_or, as_pulse_shape, PulseShape);
 
     ref_or!(color_list_or, as_color_list, [Color]);
     ref_or!(curve_or, as_curve, Curve);
@@ -529,6 +715,8 @@ pub enum EffectKind {
     Twinkle,
     Fade,
     Wipe,
+    /// Random flashes with fading tails.
+    Sparkle,
     /// A DSL-scripted effect. The string is the script name (key into Show::scripts).
     Script(String),
 }
@@ -545,6 +733,7 @@ impl EffectKind {
             EffectKind::Twinkle,
             EffectKind::Fade,
             EffectKind::Wipe,
+            EffectKind::Sparkle,
         ]
     }
 }
@@ -561,18 +750,149 @@ pub struct EffectInstance {
     /// Values outside [0.0, 1.0] are safe: `Color::scale()` clamps the factor,
     /// and the evaluator uses opacity only via `scale()`.
     pub opacity: f64,
+    /// Optional envelope over the effect's normalized time that scales
+    /// `opacity`, e.g. a crossfade ramp. `None` keeps opacity constant.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub opacity_curve: Option<Curve>,
+    /// Seconds over which opacity ramps up from zero at the start.
+    /// Applied on top of `opacity_curve`; zero means no fade.
+    #[serde(default)]
+    pub fade_in: f64,
+    /// Seconds over which opacity ramps down to zero at the end.
+    #[serde(default)]
+    pub fade_out: f64,
+}
+
+impl EffectInstance {
+    /// Effective opacity at normalized effect time `t` (0.0-1.0).
+    pub fn opacity_at(&self, t: f64) -> f64 {
+        let opacity = self
+            .opacity_curve
+            .as_ref()
+            .map_or(self.opacity, |curve| self.opacity * curve.evaluate(t));
+        opacity * self.fade_envelope(t * self.time_range.duration())
+    }
+
+    /// Linear fade-handle ramp `elapsed` seconds into the effect: 0.0 at a
+    /// faded edge, 1.0 across the body.
+    fn fade_envelope(&self, elapsed: f64) -> f64 {
+        let ramp = |distance: f64, fade: f64| {
+            if fade > 0.0 { (distance / fade).clamp(0.0, 1.0) } else { 1.0 }
+        };
+        ramp(elapsed, self.fade_in) * ramp(self.time_range.duration() - elapsed, self.fade_out)
+    }
+}
+
+/// A reusable effect configuration: the kind plus a snapshot of its params.
+/// Stored in the global library and instantiated onto tracks.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct EffectPreset {
+    pub kind: EffectKind,
+    pub params: EffectParams,
 }
 
 /// A track targets a set of fixtures and contains a list of non-overlapping effect instances.
-/// Tracks are layered bottom-to-top; blend mode lives on each EffectInstance.
+/// Tracks are layered bottom-to-top. Each effect blends onto the track's layer with its own
+/// blend mode; the track's `blend_mode` and `opacity` then combine that layer with the
+/// tracks below.
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 #[ts(export)]
 pub struct Track {
     pub name: String,
     pub target: EffectTarget,
+    /// Locked tracks reject effect edits. Absent in older files, meaning unlocked.
+    #[serde(default)]
+    pub locked: bool,
+    /// Muted tracks are skipped during evaluation unless another track is soloed.
+    #[serde(default)]
+    pub muted: bool,
+    /// How the track's composited effects combine with the tracks below.
+    /// Absent in older files, meaning `Override`.
+    #[serde(default)]
+    pub blend_mode: BlendMode,
+    /// Master opacity of the whole track (0.0-1.0). Absent in older files, meaning 1.0.
+    #[serde(default = "Track::default_opacity")]
+    pub opacity: f64,
     pub effects: Vec<EffectInstance>,
 }
 
+impl Track {
+    fn default_opacity() -> f64 {
+        1.0
+    }
+
+    /// Whether the track's effects can be written straight into the frame:
+    /// a fully opaque `Override` track looks the same either way.
+    pub fn blends_directly(&self) -> bool {
+        self.blend_mode == BlendMode::Override && self.opacity >= 1.0
+    }
+}
+
+/// A labelled point on the timeline, used for annotation, navigation and snapping.
+/// Independent of analysis `SongSection`s, which are derived from the audio.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct Marker {
+    /// Time in seconds.
+    pub time: f64,
+    pub label: String,
+    #[serde(default = "Marker::default_color")]
+    pub color: Color,
+}
+
+impl Marker {
+    /// Amber, distinct from the playhead and selection colors.
+    pub const DEFAULT_COLOR: Color = Color::rgb(255, 190, 40);
+
+    fn default_color() -> Color {
+        Self::DEFAULT_COLOR
+    }
+}
+
+/// A named transport position, optionally spanning a range, for jumping
+/// around a show during rehearsal.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct Cue {
+    pub name: String,
+    /// Start time in seconds.
+    pub time: f64,
+    /// End time in seconds, if the cue covers a range.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub end: Option<f64>,
+}
+
+/// One audio layer of a sequence: the song, a click track, a stem.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct AudioTrackRef {
+    /// File name in the setup's media directory.
+    pub path: String,
+    /// Linear playback gain; 1.0 plays the file as recorded.
+    #[serde(default = "AudioTrackRef::default_gain")]
+    pub gain: f64,
+    #[serde(default)]
+    pub muted: bool,
+}
+
+impl AudioTrackRef {
+    /// A layer playing `path` at full volume.
+    pub fn new(path: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            gain: Self::default_gain(),
+            muted: false,
+        }
+    }
+
+    fn default_gain() -> f64 {
+        1.0
+    }
+}
+
 /// A sequence is the top-level timeline container. One sequence per song/show.
 /// Deserialization runs `validated()` automatically via `#[serde(from = "SequenceRaw")]`.
 #[derive(Debug, Clone, Serialize, TS)]
@@ -583,12 +903,25 @@ pub struct Sequence {
     pub duration: f64,
     /// Target frames per second for evaluation.
     pub frame_rate: f64,
-    /// Audio file path, if any.
+    /// Primary audio file, if any: the one analysis runs on. It is also one
+    /// of `audio_tracks`.
     pub audio_file: Option<String>,
+    /// Every audio layer played with the sequence, the primary included.
+    /// Older files only have `audio_file`, which becomes the single layer on load.
+    pub audio_tracks: Vec<AudioTrackRef>,
     /// Tracks layered bottom (index 0) to top.
     pub tracks: Vec<Track>,
     /// Named motion paths. Key = path name.
     pub motion_paths: HashMap<String, MotionPath>,
+    /// Timeline markers, sorted by time. Absent in older files.
+    pub markers: Vec<Marker>,
+    /// Named cues, sorted by time. Absent in older files.
+    pub cues: Vec<Cue>,
+    /// Last-used playback region (start, end) in seconds, restored on open.
+    /// Absent in older files.
+    pub region: Option<(f64, f64)>,
+    /// Whether playback was looping the region when last saved.
+    pub looping: bool,
 }
 
 #[derive(Deserialize)]
@@ -597,24 +930,39 @@ struct SequenceRaw {
     duration: f64,
     frame_rate: f64,
     audio_file: Option<String>,
+    #[serde(default)]
+    audio_tracks: Vec<AudioTrackRef>,
     tracks: Vec<Track>,
     #[serde(default)]
     motion_paths: HashMap<String, MotionPath>,
+    #[serde(default)]
+    markers: Vec<Marker>,
+    #[serde(default)]
+    cues: Vec<Cue>,
+    #[serde(default)]
+    region: Option<(f64, f64)>,
+    #[serde(default)]
+    looping: bool,
 }
 
 impl<'de> Deserialize<'de> for Sequence {
     fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         let raw = SequenceRaw::deserialize(deserializer)?;
-        Sequence {
+        let mut sequence = Sequence {
             name: raw.name,
             duration: raw.duration,
             frame_rate: raw.frame_rate,
             audio_file: raw.audio_file,
+            audio_tracks: raw.audio_tracks,
             tracks: raw.tracks,
             motion_paths: raw.motion_paths,
-        }
-        .validated()
-        .map_err(serde::de::Error::custom)
+            markers: raw.markers,
+            cues: raw.cues,
+            region: raw.region,
+            looping: raw.looping,
+        };
+        sequence.adopt_legacy_audio();
+        sequence.validated().map_err(serde::de::Error::custom)
     }
 }
 
@@ -636,6 +984,92 @@ impl Sequence {
         }
         Ok(self)
     }
+
+    /// Give a sequence that predates `audio_tracks` a single layer for its
+    /// primary audio file.
+    fn adopt_legacy_audio(&mut self) {
+        if self.audio_tracks.is_empty() {
+            if let Some(path) = &self.audio_file {
+                self.audio_tracks.push(AudioTrackRef::new(path.clone()));
+            }
+        }
+    }
+
+    /// Add an audio layer and return its index. It becomes the primary file
+    /// when `primary` is set or the sequence has no primary yet.
+    pub fn add_audio_track(&mut self, track: AudioTrackRef, primary: bool) -> usize {
+        self.adopt_legacy_audio();
+        if primary || self.audio_file.is_none() {
+            self.audio_file = Some(track.path.clone());
+        }
+        self.audio_tracks.push(track);
+        self.audio_tracks.len() - 1
+    }
+
+    /// Remove the audio layer at `index`, or `None` if there is no such layer.
+    /// Removing the primary promotes the first remaining layer, so analysis
+    /// keeps a target while any audio is left.
+    pub fn remove_audio_track(&mut self, index: usize) -> Option<AudioTrackRef> {
+        self.adopt_legacy_audio();
+        if index >= self.audio_tracks.len() {
+            return None;
+        }
+        let removed = self.audio_tracks.remove(index);
+        let primary_gone = self.audio_file.as_ref() == Some(&removed.path)
+            && !self.audio_tracks.iter().any(|t| t.path == removed.path);
+        if primary_gone {
+            self.audio_file = self.audio_tracks.first().map(|t| t.path.clone());
+        }
+        Some(removed)
+    }
+
+    /// Make `path` the primary audio file, or clear it. A path that is already
+    /// a layer is promoted as-is; otherwise the primary layer is repointed and
+    /// keeps its gain and mute. Clearing drops the primary layer only.
+    pub fn set_primary_audio(&mut self, path: Option<String>) {
+        self.adopt_legacy_audio();
+        let old_layer = self
+            .audio_file
+            .take()
+            .and_then(|old| self.audio_tracks.iter().position(|t| t.path == old));
+        match path {
+            Some(path) => {
+                if !self.audio_tracks.iter().any(|t| t.path == path) {
+                    match old_layer.and_then(|i| self.audio_tracks.get_mut(i)) {
+                        Some(layer) => layer.path.clone_from(&path),
+                        None => self.audio_tracks.push(AudioTrackRef::new(path.clone())),
+                    }
+                }
+                self.audio_file = Some(path);
+            }
+            None => {
+                if let Some(i) = old_layer {
+                    self.audio_tracks.remove(i);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+impl Sequence {
+    /// A 30 fps sequence with the given tracks and nothing else: no audio,
+    /// motion paths, markers, cues or region.
+    pub(crate) fn for_test(name: &str, duration: f64, tracks: Vec<Track>) -> Self {
+        Self {
+            name: name.to_string(),
+            duration,
+            frame_rate: 30.0,
+            audio_file: None,
+            audio_tracks: Vec::new(),
+            tracks,
+            motion_paths: HashMap::new(),
+            markers: Vec::new(),
+            cues: Vec::new(),
+            region: None,
+            looping: false,
+        }
+    }
 }
 
 // ── Display impls ──────────────────────────────────────────────────
@@ -653,6 +1087,7 @@ impl fmt::Display for EffectKind {
             Self::Twinkle => f.write_str("Twinkle"),
             Self::Fade => f.write_str("Fade"),
             Self::Wipe => f.write_str("Wipe"),
+            Self::Sparkle => f.write_str("Sparkle"),
             Self::Script(name) => write!(f, "Script({name})"),
         }
     }
@@ -684,6 +1119,11 @@ impl fmt::Display for ParamKey {
             Self::CenterY => f.write_str("CenterY"),
             Self::PassCount => f.write_str("PassCount"),
             Self::WipeOn => f.write_str("WipeOn"),
+            Self::BeatSync => f.write_str("BeatSync"),
+            Self::BeatDivision => f.write_str("BeatDivision"),
+            Self::Seed => f.write_str("Seed"),
+            Self::PulseShape => f.write_str("PulseShape"),
+            Self::Decay => f.write_str("Decay"),
             Self::Custom(name) => write!(f, "{name}"),
         }
     }
@@ -929,29 +1369,36 @@ mod tests {
         assert!(err.contains("frame_rate"), "error should mention frame_rate: {err}");
     }
 
+    #[test]
+    fn sequence_deser_migrates_single_audio_file() {
+        let json = r#"{"name":"test","duration":10.0,"frame_rate":30.0,"audio_file":"song.mp3","tracks":[]}"#;
+        let mut seq: Sequence = serde_json::from_str(json).expect("deserialize");
+        assert_eq!(seq.audio_tracks, vec![AudioTrackRef::new("song.mp3")]);
+
+        // A click track layers on without taking over as the analysis target.
+        assert_eq!(seq.add_audio_track(AudioTrackRef::new("click.wav"), false), 1);
+        assert_eq!(seq.audio_file.as_deref(), Some("song.mp3"));
+        let json = serde_json::to_string(&seq).expect("serialize");
+        let back: Sequence = serde_json::from_str(&json).expect("deserialize");
+        assert_eq!(back.audio_tracks, seq.audio_tracks);
+
+        // Removing the song promotes the click track.
+        seq.remove_audio_track(0);
+        assert_eq!(seq.audio_file.as_deref(), Some("click.wav"));
+    }
+
     #[test]
     fn sequence_validated_rejects_infinity() {
         let result = Sequence {
-            name: "test".to_string(),
-            duration: f64::INFINITY,
             frame_rate: f64::NEG_INFINITY,
-            audio_file: None,
-            tracks: vec![],
-            motion_paths: HashMap::new(),
+            ..Sequence::for_test("test", f64::INFINITY, Vec::new())
         }.validated();
         assert!(result.is_err());
     }
 
     #[test]
     fn sequence_validated_accepts_valid() {
-        let result = Sequence {
-            name: "test".to_string(),
-            duration: 60.0,
-            frame_rate: 30.0,
-            audio_file: None,
-            tracks: vec![],
-            motion_paths: HashMap::new(),
-        }.validated();
+        let result = Sequence::for_test("test", 60.0, Vec::new()).validated();
         assert!(result.is_ok());
         let seq = result.unwrap();
         assert_eq!(seq.duration, 60.0);
@@ -0,0 +1,301 @@
+//! DDP (Distributed Display Protocol) sender, as spoken by WLED and most
+//! ESP-based pixel controllers.
+//!
+//! Each packet is a 10-byte header followed by raw channel data:
+//!
+//! | byte | field                                             |
+//! |------|---------------------------------------------------|
+//! | 0    | flags: version 1 (`0x40`), push (`0x01`) on last  |
+//! | 1    | sequence number (low 4 bits, 1–15)                |
+//! | 2    | data type (`0x0B` = RGB, 8 bits per channel)      |
+//! | 3    | destination id (`0x01` = default output)          |
+//! | 4–7  | byte offset of this packet's data (big-endian)    |
+//! | 8–9  | data length in bytes (big-endian)                 |
+//!
+//! Unlike DMX there is no 512-channel universe limit, so all fixtures on a
+//! controller pack contiguously into one buffer, split across packets only
+//! to stay under a typical MTU.
+
+use std::net::{SocketAddr, ToSocketAddrs, UdpSocket};
+
+use crate::engine::Frame;
+use crate::model::fixture::Controller;
+use crate::model::Show;
+
+use super::{controller_rgb, OutputSender, Packing};
+
+/// Default UDP port for DDP.
+pub const DDP_PORT: u16 = 4048;
+
+pub const HEADER_LEN: usize = 10;
+
+/// Maximum payload per packet: 480 RGB pixels keeps packets under a 1500-byte MTU.
+pub const MAX_PAYLOAD: usize = 1440;
+
+const FLAG_VERSION_1: u8 = 0x40;
+const FLAG_PUSH: u8 = 0x01;
+const DATA_TYPE_RGB8: u8 = 0x0B;
+const DEST_DEFAULT_OUTPUT: u8 = 0x01;
+
+/// Split one frame's channel data into DDP packets. The last packet carries
+/// the push flag so the device latches the whole frame at once.
+pub fn packets(sequence: u8, data: &[u8]) -> Vec<Vec<u8>> {
+    let chunk_count = data.len().div_ceil(MAX_PAYLOAD).max(1);
+    let mut out = Vec::with_capacity(chunk_count);
+
+    for i in 0..chunk_count {
+        let start = i * MAX_PAYLOAD;
+        let chunk = data.get(start..data.len().min(start + MAX_PAYLOAD)).unwrap_or(&[]);
+        let is_last = i + 1 == chunk_count;
+
+        let mut packet = Vec::with_capacity(HEADER_LEN + chunk.len());
+        packet.push(if is_last { FLAG_VERSION_1 | FLAG_PUSH } else { FLAG_VERSION_1 });
+        packet.push(sequence & 0x0F);
+        packet.push(DATA_TYPE_RGB8);
+        packet.push(DEST_DEFAULT_OUTPUT);
+        packet.extend_from_slice(&u32::try_from(start).unwrap_or(u32::MAX).to_be_bytes());
+        packet.extend_from_slice(&u16::try_from(chunk.len()).unwrap_or(u16::MAX).to_be_bytes());
+        packet.extend_from_slice(chunk);
+        out.push(packet);
+    }
+    out
+}
+
+/// Streams frames to one DDP controller over UDP.
+pub struct DdpSender {
+    controller: Controller,
+    socket: UdpSocket,
+    target: SocketAddr,
+    sequence: u8,
+}
+
+impl DdpSender {
+    /// Bind a local socket for `controller` targeting `address` (`host` or
+    /// `host:port`; the port defaults to 4048).
+    pub fn new(controller: Controller, address: &str) -> std::io::Result<Self> {
+        let target = if address.contains(':') {
+            address.to_socket_addrs()?
+        } else {
+            (address, DDP_PORT).to_socket_addrs()?
+        }
+        .next()
+        .ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("Could not resolve DDP address '{address}'"),
+            )
+        })?;
+        let socket = UdpSocket::bind(("0.0.0.0", 0))?;
+        Ok(Self {
+            controller,
+            socket,
+            target,
+            sequence: 0,
+        })
+    }
+
+    /// Send raw RGB data as one DDP frame, returning the packet count.
+    pub fn send_rgb(&mut self, data: &[u8]) -> std::io::Result<usize> {
+        // Sequence numbers cycle 1..=15; 0 means "not used" to the receiver.
+        self.sequence = self.sequence % 15 + 1;
+        let packets = packets(self.sequence, data);
+        for packet in &packets {
+            self.socket.send_to(packet, self.target)?;
+        }
+        Ok(packets.len())
+    }
+}
+
+impl OutputSender for DdpSender {
+    fn controller_name(&self) -> &str {
+        &self.controller.name
+    }
+
+    fn pack_frame(&self, show: &Show, frame: &Frame, packing: &Packing) -> Vec<u8> {
+        controller_rgb(show, frame, &self.controller, packing)
+    }
+
+    fn send_packed(&mut self, data: &[u8]) -> std::io::Result<usize> {
+        self.send_rgb(data)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::indexing_slicing)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::model::fixture::{
+        BulbShape, ChannelOrder, ColorCalibration, ColorModel, ControllerId, ControllerProtocol, FixtureDef,
+        FixtureId, OutputMapping, Patch, PixelType,
+    };
+    use crate::model::Color;
+    use crate::util::base64_encode;
+
+    fn ddp_controller() -> Controller {
+        Controller {
+            id: ControllerId(1),
+            name: "WLED".into(),
+            protocol: ControllerProtocol::Ddp {
+                address: "127.0.0.1".into(),
+            },
+            calibration: ColorCalibration::IDENTITY,
+        }
+    }
+
+    fn ten_pixel_show() -> Show {
+        let mut show = Show::empty();
+        show.fixtures.push(FixtureDef {
+            id: FixtureId(7),
+            name: "Arch".into(),
+            color_model: ColorModel::Rgb,
+            pixel_count: 10,
+            pixel_type: PixelType::Smart,
+            bulb_shape: BulbShape::LED,
+            display_radius_override: None,
+            channel_order: ChannelOrder::Rgb,
+            gamma: None,
+        });
+        show.patches.push(Patch {
+            fixture_id: FixtureId(7),
+            output: OutputMapping::PixelPort {
+                controller_id: ControllerId(1),
+                port: 1,
+            },
+        });
+        show
+    }
+
+    /// Pixel `i` is (i, 2i, 3i) so byte positions are easy to check.
+    fn ramp_frame() -> Frame {
+        let rgba: Vec<u8> = (0u8..10)
+            .flat_map(|i| {
+                let c = Color::rgb(i, i * 2, i * 3);
+                [c.r, c.g, c.b, c.a]
+            })
+            .collect();
+        Frame {
+            fixtures: HashMap::from([(7, base64_encode(&rgba))]),
+            warnings: None,
+        }
+    }
+
+    #[test]
+    fn ten_pixel_fixture_fits_one_pushed_packet() {
+        let show = ten_pixel_show();
+        let data = controller_rgb(&show, &ramp_frame(), &ddp_controller(), &Packing::default());
+        let packets = packets(3, &data);
+        assert_eq!(packets.len(), 1);
+
+        let p = &packets[0];
+        assert_eq!(p[0], FLAG_VERSION_1 | FLAG_PUSH);
+        assert_eq!(p[1], 3);
+        assert_eq!(p[2], DATA_TYPE_RGB8);
+        assert_eq!(p[3], DEST_DEFAULT_OUTPUT);
+        assert_eq!(u32::from_be_bytes(p[4..8].try_into().unwrap()), 0);
+        assert_eq!(u16::from_be_bytes(p[8..10].try_into().unwrap()), 30);
+
+        let payload = &p[HEADER_LEN..];
+        assert_eq!(payload.len(), 30);
+        for i in 0..10u8 {
+            let px = &payload[usize::from(i) * 3..usize::from(i) * 3 + 3];
+            assert_eq!(px, [i, i * 2, i * 3], "pixel {i}");
+        }
+    }
+
+    #[test]
+    fn large_buffers_split_with_offsets_and_single_push() {
+        let data = vec![0xAB; MAX_PAYLOAD * 2 + 30];
+        let packets = packets(1, &data);
+        assert_eq!(packets.len(), 3);
+
+        let offsets: Vec<u32> = packets
+            .iter()
+            .map(|p| u32::from_be_bytes(p[4..8].try_into().unwrap()))
+            .collect();
+        assert_eq!(offsets, [0, 1440, 2880]);
+        assert_eq!(packets[0][0], FLAG_VERSION_1);
+        assert_eq!(packets[1][0], FLAG_VERSION_1);
+        assert_eq!(packets[2][0], FLAG_VERSION_1 | FLAG_PUSH);
+        assert_eq!(u16::from_be_bytes(packets[2][8..10].try_into().unwrap()), 30);
+    }
+
+    #[test]
+    fn unlit_fixture_packs_as_black() {
+        let show = ten_pixel_show();
+        let data = controller_rgb(
+            &show,
+            &Frame::default(),
+            &ddp_controller(),
+            &Packing::default(),
+        );
+        assert_eq!(data, vec![0; 30]);
+    }
+
+    #[test]
+    fn calibration_applies_only_to_its_controllers_fixtures() {
+        let mut show = ten_pixel_show();
+        let mut other = show.fixtures[0].clone();
+        other.id = FixtureId(8);
+        show.fixtures.push(other);
+        show.patches.push(Patch {
+            fixture_id: FixtureId(8),
+            output: OutputMapping::PixelPort {
+                controller_id: ControllerId(2),
+                port: 1,
+            },
+        });
+        let mut frame = ramp_frame();
+        let ramp = frame.fixtures[&7].clone();
+        frame.fixtures.insert(8, ramp);
+
+        let plain = ddp_controller();
+        let swapped = Controller {
+            id: ControllerId(2),
+            calibration: ColorCalibration {
+                matrix: [[0.0, 1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]],
+            },
+            ..ddp_controller()
+        };
+        show.controllers = vec![plain.clone(), swapped.clone()];
+
+        let packing = Packing::default();
+        let plain_data = controller_rgb(&show, &frame, &plain, &packing);
+        let swapped_data = controller_rgb(&show, &frame, &swapped, &packing);
+        for i in 0..10u8 {
+            let at = usize::from(i) * 3..usize::from(i) * 3 + 3;
+            assert_eq!(plain_data[at.clone()], [i, i * 2, i * 3], "pixel {i}");
+            assert_eq!(swapped_data[at], [i * 2, i, i * 3], "pixel {i}");
+        }
+    }
+
+    #[test]
+    fn failed_sends_are_counted_with_the_last_error() {
+        use std::sync::Arc;
+        use std::time::{Duration, Instant};
+
+        use crate::output::LiveOutputSession;
+        use crate::state::AppState;
+
+        // Broadcast without SO_BROADCAST is refused by the OS on every send.
+        let sender = DdpSender::new(ddp_controller(), "255.255.255.255").unwrap();
+        let state = Arc::new(AppState::for_test(ten_pixel_show()));
+        let session = LiveOutputSession::start(state, vec![Box::new(sender)], Some(120.0));
+
+        let deadline = Instant::now() + Duration::from_secs(2);
+        let stats = loop {
+            let stats = session.stats();
+            if stats[0].send_errors > 0 || Instant::now() > deadline {
+                break stats;
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        };
+        session.stop();
+
+        assert_eq!(stats[0].controller, "WLED");
+        assert!(stats[0].send_errors > 0);
+        assert_eq!(stats[0].packets_sent, 0);
+        assert!(stats[0].last_error.is_some());
+    }
+}
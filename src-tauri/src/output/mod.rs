@@ -0,0 +1,765 @@
+//! Live output: packing evaluated frames into controller packets.
+//!
+//! The engine produces a [`Frame`] per tick; a live-output session evaluates
+//! the frame at the current playhead and hands it to one [`OutputSender`] per
+//! controller. Senders only translate pixels to wire format — which fixtures
+//! they drive comes from the show's patches.
+//!
+//! Channels are packed as floats and quantized to bytes last, so output-stage
+//! corrections such as gamma keep their precision until the final (optionally
+//! dithered) rounding.
+//!
+//! A session may update controllers faster than the sequence's frame rate; the
+//! frames in between are blended from the two authored frames around them.
+//!
+//! While an [`Identify`] override is active, the session sends a frame that
+//! lights only one fixture instead of the timeline, so it can be found on the
+//! hardware.
+//!
+//! Each sender's packet and error counts are kept as [`OutputStats`], so flaky
+//! network output can be diagnosed while the show runs.
+
+pub mod ddp;
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+use std::collections::{HashMap, HashSet};
+
+use parking_lot::Mutex;
+use serde::Serialize;
+use ts_rs::TS;
+
+use crate::engine::{self, Frame};
+use crate::model::fixture::{
+    ColorCalibration, Controller, ControllerProtocol, FixtureDef, FixtureId, OutputMapping,
+};
+use crate::model::{Color, Show};
+use crate::settings::OutputSettings;
+use crate::state::{sequence_beats, AppState};
+use crate::util::{base64_decode, base64_encode};
+
+pub use ddp::DdpSender;
+
+/// Update rate used when the active sequence has no usable frame rate.
+const DEFAULT_OUTPUT_FPS: f64 = 30.0;
+
+/// Speed of the identify chase, in pixels per second.
+const IDENTIFY_CHASE_SPEED: f64 = 10.0;
+
+/// 4×4 Bayer matrix of rounding thresholds, in sixteenths.
+const BAYER_4X4: [[u8; 4]; 4] = [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+
+/// Per-frame packing options handed to every sender.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Packing {
+    pub settings: OutputSettings,
+    /// Output frame counter. Cycles the dither pattern over time.
+    pub frame_number: u64,
+}
+
+/// Sends one frame to one physical controller.
+pub trait OutputSender: Send {
+    /// Name of the controller this sender drives (for diagnostics).
+    fn controller_name(&self) -> &str;
+
+    /// Pack one frame into this controller's channel data. Called with the
+    /// show locked, so it must not do any I/O.
+    fn pack_frame(&self, show: &Show, frame: &Frame, packing: &Packing) -> Vec<u8>;
+
+    /// Send data from [`pack_frame`](Self::pack_frame), returning how many
+    /// packets went out. Called after the show lock is released.
+    fn send_packed(&mut self, data: &[u8]) -> std::io::Result<usize>;
+}
+
+/// Send counters for one controller in a live-output session.
+#[derive(Debug, Clone, Default, Serialize, TS)]
+#[ts(export)]
+pub struct OutputStats {
+    pub controller: String,
+    #[ts(type = "number")]
+    pub packets_sent: u64,
+    /// Frames whose send failed part way or entirely.
+    #[ts(type = "number")]
+    pub send_errors: u64,
+    pub last_error: Option<String>,
+}
+
+impl OutputStats {
+    fn record(&mut self, result: &std::io::Result<usize>) {
+        match result {
+            Ok(packets) => self.packets_sent += *packets as u64,
+            Err(e) => {
+                self.send_errors += 1;
+                self.last_error = Some(e.to_string());
+            }
+        }
+    }
+}
+
+/// Round a channel level (0.0–255.0) to a byte.
+///
+/// Without dithering this rounds to nearest. With dithering the rounding
+/// threshold comes from a 4×4 Bayer matrix indexed by pixel (column) and frame
+/// (row), so a fractional level alternates between its two neighbouring bytes
+/// in proportion to the fraction and averages out to the exact level over
+/// space and time instead of banding. Whole levels are never altered.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn quantize(level: f32, pixel_index: usize, frame_number: u64, dither: bool) -> u8 {
+    let threshold = if dither {
+        let row = BAYER_4X4.get((frame_number % 4) as usize);
+        let cell = row
+            .and_then(|r| r.get(pixel_index % 4))
+            .copied()
+            .unwrap_or(8);
+        (f32::from(cell) + 0.5) / 16.0
+    } else {
+        0.5
+    };
+    (level + threshold).floor().clamp(0.0, 255.0) as u8
+}
+
+/// Gamma-correct a channel level on the 0–255 scale: `255 · (level / 255)^gamma`.
+///
+/// Gamma above 1 darkens mid levels to compensate for LEDs' roughly linear
+/// light output looking too bright to the eye; 0 and 255 are fixed points.
+/// The result stays fractional for [`quantize`]. Non-finite or non-positive
+/// gamma is treated as linear.
+pub fn gamma_correct(level: f32, gamma: f32) -> f32 {
+    if !gamma.is_finite() || gamma <= 0.0 {
+        return level;
+    }
+    255.0 * (level / 255.0).powf(gamma)
+}
+
+/// Color bytes (3 per pixel) for one fixture, gamma-corrected with the
+/// fixture's gamma (or the show-wide default) and packed in the fixture's
+/// channel order. Fixtures missing from the frame are all-black, so they
+/// produce zeros rather than being skipped.
+pub fn fixture_rgb(frame: &Frame, fixture: &FixtureDef, packing: &Packing) -> Vec<u8> {
+    calibrated_fixture_rgb(frame, fixture, packing, &ColorCalibration::IDENTITY)
+}
+
+/// Like [`fixture_rgb`], with each pixel color-corrected by `calibration`
+/// before gamma.
+pub fn calibrated_fixture_rgb(
+    frame: &Frame,
+    fixture: &FixtureDef,
+    packing: &Packing,
+    calibration: &ColorCalibration,
+) -> Vec<u8> {
+    let pixel_count = fixture.pixel_count as usize;
+    let mut rgb = vec![0u8; pixel_count * 3];
+    let gamma = fixture.gamma.or(packing.settings.gamma);
+    if let Some(encoded) = frame.fixtures.get(&fixture.id.0) {
+        let rgba = base64_decode(encoded);
+        for (pixel, (dst, src)) in rgb
+            .chunks_exact_mut(3)
+            .zip(rgba.chunks_exact(4))
+            .enumerate()
+        {
+            let &[r, g, b, _] = src else {
+                continue;
+            };
+            let mut levels = [0u8; 3];
+            let corrected = calibration.apply([f32::from(r), f32::from(g), f32::from(b)]);
+            for (out, level) in levels.iter_mut().zip(corrected) {
+                let level = gamma.map_or(level, |g| gamma_correct(level, g));
+                *out = quantize(level, pixel, packing.frame_number, packing.settings.dither);
+            }
+            dst.copy_from_slice(&fixture.channel_order.apply(levels));
+        }
+    }
+    rgb
+}
+
+/// Calibration of the controller `fixture` is pixel-patched to, or identity
+/// when it isn't patched to one.
+pub fn fixture_calibration(show: &Show, fixture: FixtureId) -> ColorCalibration {
+    show.patches
+        .iter()
+        .filter(|patch| patch.fixture_id == fixture)
+        .find_map(|patch| match patch.output {
+            OutputMapping::PixelPort { controller_id, .. } => {
+                show.controllers.iter().find(|c| c.id == controller_id)
+            }
+            OutputMapping::Dmx { .. } => None,
+        })
+        .map_or(ColorCalibration::IDENTITY, |c| c.calibration)
+}
+
+/// Concatenated RGB data for every fixture patched to `controller` through a
+/// pixel port, ordered by port and then patch order.
+pub fn controller_rgb(
+    show: &Show,
+    frame: &Frame,
+    controller: &Controller,
+    packing: &Packing,
+) -> Vec<u8> {
+    let mut patched: Vec<(u16, &FixtureDef)> = show
+        .patches
+        .iter()
+        .filter_map(|patch| match patch.output {
+            OutputMapping::PixelPort {
+                controller_id,
+                port,
+                ..
+            } if controller_id == controller.id => show
+                .fixtures
+                .iter()
+                .find(|f| f.id == patch.fixture_id)
+                .map(|f| (port, f)),
+            _ => None,
+        })
+        .collect();
+    patched.sort_by_key(|&(port, _)| port);
+
+    patched
+        .into_iter()
+        .flat_map(|(_, fixture)| {
+            calibrated_fixture_rgb(frame, fixture, packing, &controller.calibration)
+        })
+        .collect()
+}
+
+/// Build a sender for a controller. Returns `Ok(None)` for protocols that have
+/// no live-output implementation yet.
+pub fn sender_for(controller: &Controller) -> std::io::Result<Option<Box<dyn OutputSender>>> {
+    match &controller.protocol {
+        ControllerProtocol::Ddp { address } => {
+            Ok(Some(Box::new(DdpSender::new(controller.clone(), address)?)))
+        }
+        ControllerProtocol::E131 { .. }
+        | ControllerProtocol::ArtNet { .. }
+        | ControllerProtocol::Serial { .. } => Ok(None),
+    }
+}
+
+/// Blend two frames channel by channel: `amount` 0 gives `from`, 1 gives
+/// `to`. A fixture present in only one frame blends against black.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn interpolate_frames(from: &Frame, to: &Frame, amount: f64) -> Frame {
+    let amount = amount.clamp(0.0, 1.0);
+    let ids: HashSet<u32> = from.fixtures.keys().chain(to.fixtures.keys()).copied().collect();
+    let fixtures = ids
+        .into_iter()
+        .map(|id| {
+            let a = from.fixtures.get(&id).map(|b| base64_decode(b)).unwrap_or_default();
+            let b = to.fixtures.get(&id).map(|b| base64_decode(b)).unwrap_or_default();
+            let blended: Vec<u8> = (0..a.len().max(b.len()))
+                .map(|i| {
+                    let x = f64::from(a.get(i).copied().unwrap_or(0));
+                    let y = f64::from(b.get(i).copied().unwrap_or(0));
+                    (x + (y - x) * amount).round() as u8
+                })
+                .collect();
+            (id, base64_encode(&blended))
+        })
+        .collect();
+    Frame {
+        fixtures,
+        warnings: from.warnings.clone(),
+    }
+}
+
+/// Evaluate one authored frame time for live output.
+fn evaluate_at(
+    state: &AppState,
+    show: &Show,
+    sequence_index: usize,
+    solo_tracks: &HashSet<usize>,
+    time: f64,
+) -> Frame {
+    let scripts = state.script_cache.lock();
+    let libs = state.global_libraries.lock();
+    let sequence_slug = state.current_sequence.lock().clone().unwrap_or_default();
+    let analysis = state.analysis_cache.lock();
+    engine::evaluate(
+        show,
+        sequence_index,
+        time,
+        None,
+        Some(&scripts),
+        &libs.gradients,
+        &libs.curves,
+        solo_tracks,
+        sequence_beats(show, sequence_index, &sequence_slug, &analysis),
+    )
+}
+
+/// The authored frames an interpolating session blends between, kept across
+/// output ticks so each is evaluated once. Keyed by sequence and frame-cache
+/// generation, so edits (which bump the generation) force a re-evaluation.
+#[derive(Default)]
+struct AuthoredFrames {
+    key: (usize, u64),
+    frames: HashMap<u64, Frame>,
+}
+
+impl AuthoredFrames {
+    /// The frame at `frame_index`, evaluating it on a miss. Frames outside
+    /// `keep` are dropped.
+    #[allow(clippy::cast_precision_loss, clippy::too_many_arguments)]
+    fn get(
+        &mut self,
+        state: &AppState,
+        show: &Show,
+        sequence_index: usize,
+        solo_tracks: &HashSet<usize>,
+        frame_rate: f64,
+        frame_index: u64,
+        keep: std::ops::RangeInclusive<u64>,
+    ) -> Frame {
+        let key = (sequence_index, state.frame_cache.generation());
+        if self.key != key {
+            self.key = key;
+            self.frames.clear();
+        }
+        self.frames.retain(|i, _| keep.contains(i));
+        self.frames
+            .entry(frame_index)
+            .or_insert_with(|| {
+                let time = frame_index as f64 / frame_rate;
+                evaluate_at(state, show, sequence_index, solo_tracks, time)
+            })
+            .clone()
+    }
+}
+
+// ── Identify ─────────────────────────────────────────────────────
+
+/// A transient override that lights one fixture, bypassing the timeline, so it
+/// can be found while wiring hardware.
+#[derive(Debug, Clone, Copy)]
+pub struct Identify {
+    pub fixture_id: FixtureId,
+    /// Run a single white pixel along the fixture instead of lighting it all.
+    pub chase: bool,
+    pub started: Instant,
+    pub until: Instant,
+}
+
+impl Identify {
+    pub fn new(fixture_id: FixtureId, chase: bool, duration: Duration) -> Self {
+        let started = Instant::now();
+        Self {
+            fixture_id,
+            chase,
+            started,
+            until: started + duration,
+        }
+    }
+
+    /// The frame to send at `now`: the target fixture lit white (or its chase
+    /// pixel), everything else dark.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn frame(&self, show: &Show, now: Instant) -> Frame {
+        let pixel_count = show
+            .fixtures
+            .iter()
+            .find(|f| f.id == self.fixture_id)
+            .map_or(0, |f| f.pixel_count as usize);
+        let lit = if self.chase && pixel_count > 0 {
+            let elapsed = now.saturating_duration_since(self.started).as_secs_f64();
+            Some((elapsed * IDENTIFY_CHASE_SPEED) as usize % pixel_count)
+        } else {
+            None
+        };
+        let rgba: Vec<u8> = (0..pixel_count)
+            .flat_map(|i| {
+                let c = if lit.is_none_or(|l| l == i) { Color::WHITE } else { Color::BLACK };
+                [c.r, c.g, c.b, c.a]
+            })
+            .collect();
+        Frame {
+            fixtures: HashMap::from([(self.fixture_id.0, base64_encode(&rgba))]),
+            warnings: None,
+        }
+    }
+}
+
+// ── Live session ─────────────────────────────────────────────────
+
+/// A running live-output thread. Dropping the session stops the thread.
+pub struct LiveOutputSession {
+    stop: Arc<AtomicBool>,
+    identify: Arc<Mutex<Option<Identify>>>,
+    stats: Arc<Mutex<Vec<OutputStats>>>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl LiveOutputSession {
+    /// Spawn the output loop. Each iteration evaluates the frame at the
+    /// current playhead and sends it to every sender, paced to `output_fps`
+    /// or, when that is `None`, the active sequence's frame rate. With an
+    /// output rate set, frames between the sequence's own are interpolated.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn start(
+        state: Arc<AppState>,
+        mut senders: Vec<Box<dyn OutputSender>>,
+        output_fps: Option<f64>,
+    ) -> Self {
+        let stop = Arc::new(AtomicBool::new(false));
+        let stop_flag = Arc::clone(&stop);
+        let identify = Arc::new(Mutex::new(None::<Identify>));
+        let identify_override = Arc::clone(&identify);
+        let stats = Arc::new(Mutex::new(
+            senders
+                .iter()
+                .map(|s| OutputStats {
+                    controller: s.controller_name().to_string(),
+                    ..OutputStats::default()
+                })
+                .collect::<Vec<_>>(),
+        ));
+        let send_stats = Arc::clone(&stats);
+
+        let handle = std::thread::spawn(move || {
+            let mut frame_number = 0u64;
+            let mut authored = AuthoredFrames::default();
+            while !stop_flag.load(Ordering::Relaxed) {
+                let started = Instant::now();
+                let packing = Packing {
+                    settings: state
+                        .settings
+                        .lock()
+                        .as_ref()
+                        .map(|s| s.output)
+                        .unwrap_or_default(),
+                    frame_number,
+                };
+                frame_number = frame_number.wrapping_add(1);
+
+                // Copy playback out before taking `show`: `tick` locks them
+                // in the other order, so never hold both.
+                let playback = state.playback.lock().clone();
+                let show = state.show.lock();
+                let sequence_index = playback.sequence_index;
+                let frame_rate = show
+                    .sequences
+                    .get(sequence_index)
+                    .map_or(DEFAULT_OUTPUT_FPS, |s| s.frame_rate);
+                let time = if output_fps.is_some() {
+                    playback.clock_time(started, frame_rate)
+                } else {
+                    playback.current_time
+                };
+                let solo_tracks = playback.solo_tracks;
+
+                let identifying = {
+                    let mut slot = identify_override.lock();
+                    if slot.is_some_and(|id| started >= id.until) {
+                        *slot = None;
+                    }
+                    *slot
+                };
+
+                let frame = if let Some(id) = identifying {
+                    id.frame(&show, started)
+                } else if output_fps.is_some() && frame_rate > 0.0 {
+                    let position = time.max(0.0) * frame_rate;
+                    let index = position.floor() as u64;
+                    let amount = position - position.floor();
+                    let mut authored_frame = |i: u64| {
+                        authored.get(
+                            &state,
+                            &show,
+                            sequence_index,
+                            &solo_tracks,
+                            frame_rate,
+                            i,
+                            index..=index + 1,
+                        )
+                    };
+                    let from = authored_frame(index);
+                    if amount > 0.0 {
+                        interpolate_frames(&from, &authored_frame(index + 1), amount)
+                    } else {
+                        from
+                    }
+                } else {
+                    evaluate_at(&state, &show, sequence_index, &solo_tracks, time)
+                };
+
+                let packed: Vec<Vec<u8>> = senders
+                    .iter()
+                    .map(|sender| sender.pack_frame(&show, &frame, &packing))
+                    .collect();
+                // Don't hold up edits while packets go out.
+                drop(show);
+
+                for (i, (sender, data)) in senders.iter_mut().zip(&packed).enumerate() {
+                    let result = sender.send_packed(data);
+                    if let Some(stats) = send_stats.lock().get_mut(i) {
+                        // Log each distinct error once; the stats keep the count.
+                        if let Err(e) = &result {
+                            let message = e.to_string();
+                            if stats.last_error.as_deref() != Some(message.as_str()) {
+                                eprintln!(
+                                    "[VibeLights] Live output to {} failed: {message}",
+                                    sender.controller_name()
+                                );
+                            }
+                        }
+                        stats.record(&result);
+                    }
+                }
+
+                let fps = match output_fps {
+                    Some(fps) => fps,
+                    None if frame_rate.is_finite() && frame_rate > 0.0 => frame_rate,
+                    None => DEFAULT_OUTPUT_FPS,
+                };
+                let period = Duration::from_secs_f64(1.0 / fps);
+                if let Some(remaining) = period.checked_sub(started.elapsed()) {
+                    std::thread::sleep(remaining);
+                }
+            }
+        });
+
+        Self {
+            stop,
+            identify,
+            stats,
+            handle: Some(handle),
+        }
+    }
+
+    /// Packet and error counts so far, one entry per controller.
+    pub fn stats(&self) -> Vec<OutputStats> {
+        self.stats.lock().clone()
+    }
+
+    /// Light only one fixture until the override expires, then resume the
+    /// timeline. Replaces any identify already running.
+    pub fn identify(&self, identify: Identify) {
+        *self.identify.lock() = Some(identify);
+    }
+
+    /// Signal the output loop to stop and wait for it to exit.
+    pub fn stop(mut self) {
+        self.shutdown();
+    }
+
+    fn shutdown(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+impl Drop for LiveOutputSession {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::indexing_slicing, clippy::cast_precision_loss)]
+mod tests {
+    use super::*;
+
+    /// Distinct per-pixel output levels of a dim ramp (0–2) averaged over one
+    /// dither cycle of frames — what the eye integrates.
+    fn perceived_levels(dither: bool) -> usize {
+        (0..64)
+            .map(|pixel| {
+                let level = pixel as f32 / 32.0;
+                let sum: u32 = (0..4)
+                    .map(|frame| u32::from(quantize(level, pixel, frame, dither)))
+                    .sum();
+                sum
+            })
+            .collect::<HashSet<_>>()
+            .len()
+    }
+
+    #[test]
+    fn dithering_resolves_more_levels_on_dim_ramp() {
+        assert_eq!(perceived_levels(false), 3);
+        assert!(perceived_levels(true) > 3, "{}", perceived_levels(true));
+    }
+
+    #[test]
+    fn gamma_darkens_mid_gray() {
+        use crate::model::fixture::{BulbShape, ChannelOrder, ColorModel, FixtureId, PixelType};
+        use crate::model::Color;
+
+        let mut fixture = FixtureDef {
+            id: FixtureId(1),
+            name: "Bulb".into(),
+            color_model: ColorModel::Rgb,
+            pixel_count: 1,
+            pixel_type: PixelType::Smart,
+            bulb_shape: BulbShape::LED,
+            display_radius_override: None,
+            channel_order: ChannelOrder::Rgb,
+            gamma: None,
+        };
+        let gray = Color::rgb(128, 128, 128);
+        let frame = Frame {
+            fixtures: HashMap::from([(
+                1,
+                crate::util::base64_encode(&[gray.r, gray.g, gray.b, gray.a]),
+            )]),
+            warnings: None,
+        };
+        let mut packing = Packing::default();
+        assert_eq!(fixture_rgb(&frame, &fixture, &packing), vec![128, 128, 128]);
+
+        // 255 * (128 / 255)^2.2 = 55.98
+        fixture.gamma = Some(2.2);
+        assert_eq!(fixture_rgb(&frame, &fixture, &packing), vec![56, 56, 56]);
+
+        // The show-wide default applies only to fixtures without their own.
+        fixture.gamma = None;
+        packing.settings.gamma = Some(2.2);
+        assert_eq!(fixture_rgb(&frame, &fixture, &packing), vec![56, 56, 56]);
+        fixture.gamma = Some(1.0);
+        assert_eq!(fixture_rgb(&frame, &fixture, &packing), vec![128, 128, 128]);
+    }
+
+    #[test]
+    fn packing_follows_fixture_channel_order() {
+        use crate::model::fixture::{BulbShape, ChannelOrder, ColorModel, FixtureId, PixelType};
+
+        let mut fixture = FixtureDef {
+            id: FixtureId(1),
+            name: "Strip".into(),
+            color_model: ColorModel::Rgb,
+            pixel_count: 2,
+            pixel_type: PixelType::Smart,
+            bulb_shape: BulbShape::LED,
+            display_radius_override: None,
+            channel_order: ChannelOrder::Rgb,
+            gamma: None,
+        };
+        let frame = Frame {
+            fixtures: HashMap::from([(
+                1,
+                crate::util::base64_encode(&[10, 20, 30, 255, 40, 50, 60, 255]),
+            )]),
+            warnings: None,
+        };
+        let packing = Packing::default();
+        assert_eq!(fixture_rgb(&frame, &fixture, &packing), vec![10, 20, 30, 40, 50, 60]);
+
+        fixture.channel_order = ChannelOrder::Grb;
+        assert_eq!(fixture_rgb(&frame, &fixture, &packing), vec![20, 10, 30, 50, 40, 60]);
+        fixture.channel_order = ChannelOrder::Bgr;
+        assert_eq!(fixture_rgb(&frame, &fixture, &packing), vec![30, 20, 10, 60, 50, 40]);
+    }
+
+    #[test]
+    fn midpoint_between_black_and_white_is_mid_gray() {
+        let frame = |level: u8| Frame {
+            fixtures: HashMap::from([(1, base64_encode(&[level, level, level, 255].repeat(2)))]),
+            warnings: None,
+        };
+
+        let mid = interpolate_frames(&frame(0), &frame(255), 0.5);
+
+        let rgba = base64_decode(&mid.fixtures[&1]);
+        assert_eq!(rgba, [128, 128, 128, 255, 128, 128, 128, 255]);
+        let quarter = interpolate_frames(&frame(0), &frame(255), 0.25);
+        assert_eq!(base64_decode(&quarter.fixtures[&1]).first(), Some(&64));
+    }
+
+    /// Hands every frame it is sent back to the test, as the RGB data of
+    /// each fixture in show order.
+    struct Capture(std::sync::mpsc::Sender<Vec<u8>>);
+
+    impl OutputSender for Capture {
+        fn controller_name(&self) -> &'static str {
+            "capture"
+        }
+
+        fn pack_frame(&self, show: &Show, frame: &Frame, packing: &Packing) -> Vec<u8> {
+            show.fixtures.iter().flat_map(|f| fixture_rgb(frame, f, packing)).collect()
+        }
+
+        fn send_packed(&mut self, data: &[u8]) -> std::io::Result<usize> {
+            let _ = self.0.send(data.to_vec());
+            Ok(1)
+        }
+    }
+
+    #[test]
+    fn identify_lights_only_the_target_fixture() {
+        use crate::model::fixture::{BulbShape, ChannelOrder, ColorModel, EffectTarget, PixelType};
+        use crate::model::timeline::{
+            BlendMode, EffectInstance, EffectKind, EffectParams, ParamKey, ParamValue, Sequence,
+            TimeRange, Track,
+        };
+
+        let fixture = |id: u32| FixtureDef {
+            id: FixtureId(id),
+            name: format!("F{id}"),
+            color_model: ColorModel::Rgb,
+            pixel_count: 3,
+            pixel_type: PixelType::Smart,
+            bulb_shape: BulbShape::LED,
+            display_radius_override: None,
+            channel_order: ChannelOrder::Rgb,
+            gamma: None,
+        };
+        let mut show = Show::empty();
+        show.fixtures = vec![fixture(1), fixture(2)];
+        show.sequences.push(Sequence::for_test(
+            "Seq",
+            10.0,
+            vec![Track {
+                name: "Red".into(),
+                target: EffectTarget::All,
+                locked: false,
+                muted: false,
+                blend_mode: BlendMode::Override,
+                opacity: 1.0,
+                effects: vec![EffectInstance {
+                    kind: EffectKind::Solid,
+                    params: EffectParams::new()
+                        .set(ParamKey::Color, ParamValue::Color(Color::rgb(255, 0, 0))),
+                    time_range: TimeRange::new(0.0, 10.0).unwrap(),
+                    blend_mode: BlendMode::Override,
+                    opacity: 1.0,
+                    opacity_curve: None,
+                    fade_in: 0.0,
+                    fade_out: 0.0,
+                }],
+            }],
+        ));
+        let state = Arc::new(AppState::for_test(show));
+        let (tx, rx) = std::sync::mpsc::channel();
+        let session = LiveOutputSession::start(state, vec![Box::new(Capture(tx))], Some(120.0));
+        let timeout = Duration::from_secs(2);
+
+        // Two 3-pixel fixtures, 9 bytes each.
+        let red: Vec<u8> = [255, 0, 0].repeat(6);
+        assert_eq!(rx.recv_timeout(timeout).unwrap(), red);
+
+        session.identify(Identify::new(FixtureId(2), false, Duration::from_secs(60)));
+        // Frames already in flight may predate the override.
+        let identified = (0..20)
+            .map(|_| rx.recv_timeout(timeout).unwrap())
+            .find(|data| *data != red)
+            .unwrap();
+        assert_eq!(identified[..9], [0; 9]);
+        assert_eq!(identified[9..], [255; 9]);
+
+        session.stop();
+    }
+
+    #[test]
+    fn dithering_leaves_whole_levels_unchanged() {
+        for level in [0u8, 1, 128, 254, 255] {
+            for pixel in 0..4 {
+                for frame in 0..4 {
+                    assert_eq!(quantize(f32::from(level), pixel, frame, true), level);
+                }
+            }
+        }
+    }
+}
@@ -319,6 +319,7 @@ mod tests {
                 bulb_shape: BulbShape::C9,
                 display_radius_override: None,
                 channel_order: ChannelOrder::Grb,
+                gamma: None,
             }],
             groups: vec![FixtureGroup {
                 id: GroupId(10),
@@ -330,26 +331,34 @@ mod tests {
                     fixture_id: FixtureId(1),
                     pixel_positions: vec![Position2D { x: 0.0, y: 0.5 }],
                     shape: LayoutShape::Custom,
+                    preview_gain: None,
                 }],
             },
             sequences: vec![Sequence {
-                name: "Main".into(),
-                duration: 60.0,
-                frame_rate: 30.0,
                 audio_file: Some("song.mp3".into()),
-                tracks: vec![Track {
-                    name: "Track 1".into(),
-                    target: EffectTarget::All,
-                    effects: vec![EffectInstance {
-                        kind: EffectKind::Solid,
-                        params: EffectParams::new()
-                            .set(ParamKey::Color, ParamValue::Color(Color::rgb(255, 0, 0))),
-                        time_range: TimeRange::new(0.0, 10.0).unwrap(),
+                ..Sequence::for_test(
+                    "Main",
+                    60.0,
+                    vec![Track {
+                        name: "Track 1".into(),
+                        target: EffectTarget::All,
+                        locked: false,
+                        muted: false,
                         blend_mode: BlendMode::Override,
                         opacity: 1.0,
+                        effects: vec![EffectInstance {
+                            kind: EffectKind::Solid,
+                            params: EffectParams::new()
+                                .set(ParamKey::Color, ParamValue::Color(Color::rgb(255, 0, 0))),
+                            time_range: TimeRange::new(0.0, 10.0).unwrap(),
+                            blend_mode: BlendMode::Override,
+                            opacity: 1.0,
+                            opacity_curve: None,
+                            fade_in: 0.0,
+                            fade_out: 0.0,
+                        }],
                     }],
-                }],
-                motion_paths: std::collections::HashMap::new(),
+                )
             }],
             patches: vec![],
             controllers: vec![],
@@ -388,22 +397,8 @@ mod tests {
         let mut show = test_show();
         // Add sequences that sort differently by slug than insertion order
         show.sequences = vec![
-            Sequence {
-                name: "Zebra".into(),
-                duration: 10.0,
-                frame_rate: 30.0,
-                audio_file: None,
-                tracks: vec![],
-                motion_paths: std::collections::HashMap::new(),
-            },
-            Sequence {
-                name: "Alpha".into(),
-                duration: 20.0,
-                frame_rate: 30.0,
-                audio_file: None,
-                tracks: vec![],
-                motion_paths: std::collections::HashMap::new(),
-            },
+            Sequence::for_test("Zebra", 10.0, Vec::new()),
+            Sequence::for_test("Alpha", 20.0, Vec::new()),
         ];
 
         save_project(&show, &dir).expect("save failed");
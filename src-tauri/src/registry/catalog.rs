@@ -15,6 +15,8 @@ pub struct CommandRegistryEntry {
     pub undoable: bool,
     pub llm_hidden: bool,
     pub param_schema: Value,
+    /// Rust/TypeScript name of the result payload, if the command returns one.
+    pub result_type: Option<&'static str>,
 }
 
 pub(super) fn empty_object_schema() -> Value {
@@ -26,7 +28,11 @@ pub(super) fn schema_value<T: schemars::JsonSchema>() -> Value {
     serde_json::to_value(root).unwrap_or(empty_object_schema())
 }
 
-pub(super) fn entry(info: CommandInfo, param_schema: Value) -> CommandRegistryEntry {
+pub(super) fn entry(
+    info: CommandInfo,
+    param_schema: Value,
+    result_type: Option<&'static str>,
+) -> CommandRegistryEntry {
     CommandRegistryEntry {
         name: info.name,
         description: info.description,
@@ -34,6 +40,7 @@ pub(super) fn entry(info: CommandInfo, param_schema: Value) -> CommandRegistryEn
         undoable: info.undoable,
         llm_hidden: info.llm_hidden,
         param_schema,
+        result_type,
     }
 }
 
@@ -126,12 +133,87 @@ pub fn to_json_schema() -> Value {
     )
 }
 
+/// Assemble an OpenAPI 3.1 document covering every command, so external
+/// integrators can generate clients for `POST /api/tools/{name}`. Definitions
+/// shared between param schemas are hoisted into `components.schemas`; result
+/// payloads are named by `x-result-type` and match the exported TS bindings.
+pub fn openapi_document() -> Value {
+    let mut schemas = serde_json::Map::new();
+    let mut paths = serde_json::Map::new();
+    for e in command_registry() {
+        let mut param_schema = e.param_schema;
+        hoist_definitions(&mut param_schema, &mut schemas);
+        let operation = serde_json::json!({
+            "operationId": e.name,
+            "summary": e.description,
+            "tags": [e.category.slug()],
+            "x-category": e.category,
+            "x-undoable": e.undoable,
+            "x-llm-hidden": e.llm_hidden,
+            "requestBody": {
+                "required": true,
+                "content": { "application/json": { "schema": param_schema } },
+            },
+            "responses": {
+                "200": {
+                    "description": "Command executed.",
+                    "x-result-type": e.result_type,
+                },
+            },
+        });
+        paths.insert(
+            format!("/api/tools/{}", e.name),
+            serde_json::json!({ "post": operation }),
+        );
+    }
+    serde_json::json!({
+        "openapi": "3.1.0",
+        "info": {
+            "title": "VibeLights command API",
+            "version": env!("CARGO_PKG_VERSION"),
+        },
+        "paths": paths,
+        "components": { "schemas": schemas },
+    })
+}
+
+/// Move a root schema's `definitions` into the shared component map and point
+/// its `$ref`s there.
+fn hoist_definitions(schema: &mut Value, components: &mut serde_json::Map<String, Value>) {
+    if let Some(obj) = schema.as_object_mut() {
+        obj.remove("$schema");
+        if let Some(Value::Object(definitions)) = obj.remove("definitions") {
+            for (name, mut definition) in definitions {
+                rewrite_refs(&mut definition);
+                components.entry(name).or_insert(definition);
+            }
+        }
+    }
+    rewrite_refs(schema);
+}
+
+fn rewrite_refs(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            if let Some(Value::String(target)) = map.get_mut("$ref") {
+                if let Some(name) = target.strip_prefix("#/definitions/") {
+                    *target = format!("#/components/schemas/{name}");
+                }
+            }
+            map.values_mut().for_each(rewrite_refs);
+        }
+        Value::Array(items) => items.iter_mut().for_each(rewrite_refs),
+        _ => {}
+    }
+}
+
 /// Deserialize a tool call (name + JSON input) into a Command.
 pub fn deserialize_from_tool_call(name: &str, input: &Value) -> Result<super::Command, String> {
     super::Command::from_tool_call(name, input)
 }
 
 #[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::indexing_slicing)]
 mod tests {
     use super::*;
 
@@ -166,4 +248,23 @@ mod tests {
         );
         assert!(output.contains("send_agent_message"));
     }
+
+    #[test]
+    fn openapi_document_describes_add_effect() {
+        let doc = openapi_document();
+        let op = &doc["paths"]["/api/tools/add_effect"]["post"];
+        assert_eq!(op["x-category"], "Edit");
+        assert_eq!(op["x-undoable"], true);
+        assert_eq!(op["responses"]["200"]["x-result-type"], "usize");
+
+        let schema = &op["requestBody"]["content"]["application/json"]["schema"];
+        let properties = schema["properties"].as_object().unwrap();
+        assert!(properties.contains_key("track_index"));
+        assert!(properties.contains_key("kind"));
+
+        // Every ref resolves against the hoisted components.
+        let text = doc.to_string();
+        assert!(!text.contains("#/definitions/"));
+        assert!(doc["components"]["schemas"]["EffectKind"].is_object());
+    }
 }
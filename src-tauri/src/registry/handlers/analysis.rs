@@ -7,8 +7,11 @@ use serde_json::Value;
 use ts_rs::TS;
 
 use crate::error::AppError;
-use crate::model::analysis::AudioAnalysis;
-use crate::registry::params::{GetAnalysisDetailParams, GetBeatsInRangeParams};
+use crate::model::analysis::{AudioAnalysis, ChordEvent};
+use crate::registry::params::{
+    ExportAnalysisParams, GetAnalysisDetailParams, GetBeatsInRangeParams, GetChordsParams,
+    GetStemEnergyParams, ImportAnalysisParams,
+};
 use crate::registry::{CommandOutput, CommandResult, JsonValue};
 use crate::state::AppState;
 
@@ -50,11 +53,10 @@ pub struct BeatsInRange {
     pub tempo: f64,
 }
 
-fn current_analysis(state: &Arc<AppState>) -> Option<AudioAnalysis> {
-    let show = state.show.lock();
-    let audio_file = show.sequences.first()?.audio_file.as_ref()?;
-    let cache = state.analysis_cache.lock();
-    cache.get(audio_file).cloned()
+pub(crate) fn current_analysis(state: &Arc<AppState>) -> Option<AudioAnalysis> {
+    let audio_file =
+        state.with_show(|show| show.sequences.first().and_then(|s| s.audio_file.clone()))?;
+    state.cached_analysis(&audio_file)
 }
 
 pub fn get_analysis_summary(state: &Arc<AppState>) -> Result<CommandOutput, AppError> {
@@ -128,6 +130,49 @@ pub fn get_beats_in_range(
     ))
 }
 
+pub fn get_chords(state: &Arc<AppState>, p: GetChordsParams) -> Result<CommandOutput, AppError> {
+    let analysis = current_analysis(state).ok_or(AppError::ValidationError {
+        message: "No audio analysis available.".into(),
+    })?;
+    let chords = chords_in_range(&analysis, p.start, p.end);
+    Ok(CommandOutput::new(
+        serde_json::to_string(&chords).unwrap_or_default(),
+        CommandResult::GetChords(chords),
+    ))
+}
+
+/// Chords overlapping `start..end`. Analyses without harmony data have none.
+fn chords_in_range(analysis: &AudioAnalysis, start: f64, end: f64) -> Vec<ChordEvent> {
+    analysis.harmony.as_ref().map_or_else(Vec::new, |h| {
+        h.chords.iter().filter(|c| c.end > start && c.start < end).cloned().collect()
+    })
+}
+
+pub fn get_stem_energy(
+    state: &Arc<AppState>,
+    p: GetStemEnergyParams,
+) -> Result<CommandOutput, AppError> {
+    let analysis = current_analysis(state).ok_or(AppError::ValidationError {
+        message: "No audio analysis available.".into(),
+    })?;
+    let energy = stem_energy(&analysis, &p.stem, &p.times);
+    let message = if energy.is_empty() && !p.times.is_empty() {
+        format!("No energy envelope for stem \"{}\". Run analysis with stems enabled.", p.stem)
+    } else {
+        serde_json::to_string(&energy).unwrap_or_default()
+    };
+    Ok(CommandOutput::new(message, CommandResult::GetStemEnergy(energy)))
+}
+
+/// Energy of `stem` at each of `times`. Empty when the analysis has no
+/// envelope for that stem.
+fn stem_energy(analysis: &AudioAnalysis, stem: &str, times: &[f64]) -> Vec<f64> {
+    let Some(stems) = analysis.stems.as_ref().filter(|s| s.energy.contains_key(stem)) else {
+        return Vec::new();
+    };
+    times.iter().map(|&t| stems.energy_at(stem, t).unwrap_or(0.0)).collect()
+}
+
 pub fn get_sections(state: &Arc<AppState>) -> Result<CommandOutput, AppError> {
     let analysis = current_analysis(state).ok_or(AppError::ValidationError {
         message: "No audio analysis available.".into(),
@@ -185,14 +230,87 @@ pub fn get_analysis_detail(
     }
 }
 
+fn current_audio_file(state: &Arc<AppState>) -> Result<String, AppError> {
+    state
+        .with_show(|show| show.sequences.first().and_then(|s| s.audio_file.clone()))
+        .ok_or(AppError::AnalysisError {
+            message: "No audio file in current sequence".into(),
+        })
+}
+
+pub fn export_analysis(
+    state: &Arc<AppState>,
+    p: ExportAnalysisParams,
+) -> Result<CommandOutput, AppError> {
+    let audio_file = current_audio_file(state)?;
+    let analysis = state
+        .cached_analysis(&audio_file)
+        .ok_or(AppError::ValidationError {
+            message: "No audio analysis available to export.".into(),
+        })?;
+
+    crate::analysis::export_analysis(std::path::Path::new(&p.path), &audio_file, &analysis)?;
+
+    Ok(CommandOutput::new(
+        format!("Exported analysis for '{audio_file}' to {}", p.path),
+        CommandResult::ExportAnalysis,
+    ))
+}
+
+pub fn import_analysis(
+    state: &Arc<AppState>,
+    p: ImportAnalysisParams,
+) -> Result<CommandOutput, AppError> {
+    let audio_file = current_audio_file(state)?;
+    let export = crate::analysis::import_analysis(std::path::Path::new(&p.path))?;
+
+    if export.audio_file != audio_file && !p.force {
+        return Err(AppError::ValidationError {
+            message: format!(
+                "Analysis was exported for '{}', but the current sequence uses '{audio_file}'. Pass force to import anyway.",
+                export.audio_file
+            ),
+        });
+    }
+
+    state.cache_analysis(&audio_file, export.analysis);
+
+    Ok(CommandOutput::new(
+        format!("Imported analysis for '{audio_file}' from {}", p.path),
+        CommandResult::ImportAnalysis,
+    ))
+}
+
 // ── Async handler ────────────────────────────────────────────────
 
+/// Fail fast on audio the sidecar could never analyze: a missing or
+/// unreadable file, or a format symphonia cannot decode.
+#[cfg(feature = "tauri-app")]
+fn check_audio_source(audio_path: &std::path::Path) -> Result<(), AppError> {
+    let path = audio_path.display();
+    if !audio_path.exists() {
+        return Err(AppError::AnalysisError {
+            message: format!(
+                "Audio file not found at {path}. It may have been moved or deleted; \
+                 re-import the media and try again."
+            ),
+        });
+    }
+    std::fs::File::open(audio_path).map_err(|e| AppError::AnalysisError {
+        message: format!("Audio file at {path} could not be read: {e}"),
+    })?;
+    crate::waveform::probe_audio(audio_path).map_err(|e| AppError::AnalysisError {
+        message: format!("Audio file at {path} is not in a supported format: {e}"),
+    })
+}
+
 #[cfg(feature = "tauri-app")]
 pub async fn analyze_audio(
     state: Arc<AppState>,
     app: Option<tauri::AppHandle>,
     p: crate::registry::params::AnalyzeAudioParams,
 ) -> Result<CommandOutput, AppError> {
+    let operation = state.cancellation.begin("analysis")?;
     let app_handle = app.ok_or_else(|| AppError::ApiError {
         message: "AppHandle required for analyze_audio".into(),
     })?;
@@ -216,11 +334,7 @@ pub async fn analyze_audio(
     let media_dir = crate::paths::media_dir(&data_dir, &setup_slug);
     let audio_path = media_dir.join(&audio_file);
 
-    if !audio_path.exists() {
-        return Err(AppError::NotFound {
-            what: format!("Audio file: {audio_file}"),
-        });
-    }
+    check_audio_source(&audio_path)?;
 
     let features = p.features.unwrap_or_else(|| {
         state
@@ -237,9 +351,19 @@ pub async fn analyze_audio(
         .as_ref()
         .is_some_and(|s| s.use_gpu);
 
+    // The same audio was already analyzed for this sequence with at least
+    // these features; don't run the sidecar again.
+    if let Some(cached) =
+        state.cached_analysis(&audio_file).filter(|a| a.features.covers(&features))
+    {
+        return Ok(CommandOutput::new(
+            "Audio analysis loaded from cache.",
+            CommandResult::AnalyzeAudio(Box::new(cached)),
+        ));
+    }
+
     let port = crate::python::ensure_sidecar(&state, &app_handle).await?;
 
-    let cancel_flag = state.cancellation.register("analysis");
     let output_dir = crate::paths::stems_dir(&media_dir, &audio_file);
     let models = crate::paths::models_dir(&state.app_config_dir);
 
@@ -252,12 +376,8 @@ pub async fn analyze_audio(
             &features,
             &models,
             use_gpu,
-        ) => {
-            state.cancellation.unregister("analysis");
-            result?
-        }
-        () = crate::state::wait_for_cancel(&cancel_flag) => {
-            state.cancellation.unregister("analysis");
+        ) => result?,
+        () = crate::state::wait_for_cancel(operation.flag()) => {
             crate::progress::emit_progress(&app_handle, "analysis", "Cancelled", 1.0, None);
             return Err(AppError::Cancelled { operation: "analysis".into() });
         }
@@ -268,7 +388,7 @@ pub async fn analyze_audio(
         eprintln!("[VibeLights] Failed to save analysis cache: {e}");
     }
 
-    state.cache_analysis(audio_file, analysis_result.clone());
+    state.cache_analysis(&audio_file, analysis_result.clone());
 
     Ok(CommandOutput::new(
         "Audio analysis complete.",
@@ -311,8 +431,8 @@ pub fn get_analysis(state: &Arc<AppState>) -> Result<CommandOutput, AppError> {
     }
 
     // Check memory cache
-    if let Some(cached) = state.analysis_cache.lock().get(&audio_file) {
-        return Ok(CommandOutput::new("Analysis from cache.", CommandResult::GetAnalysis(Some(Box::new(cached.clone())))));
+    if let Some(cached) = state.cached_analysis(&audio_file) {
+        return Ok(CommandOutput::new("Analysis from cache.", CommandResult::GetAnalysis(Some(Box::new(cached)))));
     }
 
     // Check disk cache
@@ -320,10 +440,239 @@ pub fn get_analysis(state: &Arc<AppState>) -> Result<CommandOutput, AppError> {
 
     if path.exists() {
         if let Ok(loaded) = crate::analysis::load_analysis(&path) {
-            state.cache_analysis(audio_file, loaded.clone());
+            state.cache_analysis(&audio_file, loaded.clone());
             return Ok(CommandOutput::new("Analysis loaded from disk.", CommandResult::GetAnalysis(Some(Box::new(loaded)))));
         }
     }
 
     Ok(CommandOutput::new("No analysis available.", CommandResult::GetAnalysis(None)))
 }
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::indexing_slicing)]
+mod tests {
+    use super::*;
+    use crate::model::analysis::{AnalysisFeatures, BeatAnalysis};
+    use crate::model::timeline::Sequence;
+    use crate::model::Show;
+
+    fn state_with_audio(audio_file: &str) -> Arc<AppState> {
+        let mut show = Show::empty();
+        show.sequences.push(Sequence {
+            audio_file: Some(audio_file.into()),
+            ..Sequence::for_test("Seq", 10.0, Vec::new())
+        });
+        Arc::new(AppState::for_test(show))
+    }
+
+    fn beats_only() -> AudioAnalysis {
+        AudioAnalysis {
+            features: AnalysisFeatures::default(),
+            beats: Some(BeatAnalysis {
+                beats: vec![0.5, 1.0, 1.5],
+                downbeats: vec![0.5],
+                tempo: 120.0,
+                time_signature: 4,
+                beat_confidences: vec![0.9, 0.8, 0.95],
+                tempo_confidence: 0.9,
+            }),
+            structure: None,
+            stems: None,
+            lyrics: None,
+            mood: None,
+            harmony: None,
+            low_level: None,
+            pitch: None,
+            drums: None,
+            vocal_presence: None,
+        }
+    }
+
+    #[test]
+    fn export_then_import_into_fresh_state() {
+        let dir = std::env::temp_dir().join("vibelights_test_analysis_export");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("song.analysis.json").to_string_lossy().to_string();
+
+        let source = state_with_audio("song.mp3");
+        source.cache_analysis("song.mp3", beats_only());
+        export_analysis(&source, ExportAnalysisParams { path: path.clone() }).unwrap();
+
+        let fresh = state_with_audio("song.mp3");
+        import_analysis(&fresh, ImportAnalysisParams { path, force: false }).unwrap();
+
+        let analysis = fresh.cached_analysis("song.mp3").unwrap();
+        let beats = analysis.beats.as_ref().unwrap();
+        assert_eq!(beats.beats, vec![0.5, 1.0, 1.5]);
+        assert!((beats.tempo - 120.0).abs() < f64::EPSILON);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn import_rejects_other_song_unless_forced() {
+        let dir = std::env::temp_dir().join("vibelights_test_analysis_mismatch");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("a.analysis.json");
+        crate::analysis::export_analysis(&path, "a.mp3", &beats_only()).unwrap();
+        let path = path.to_string_lossy().to_string();
+
+        let state = state_with_audio("b.mp3");
+        let err = import_analysis(&state, ImportAnalysisParams { path: path.clone(), force: false });
+        assert!(matches!(err, Err(AppError::ValidationError { .. })));
+        assert!(state.analysis_cache.lock().is_empty());
+
+        import_analysis(&state, ImportAnalysisParams { path, force: true }).unwrap();
+        assert!(state.cached_analysis("b.mp3").is_some());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn changing_audio_file_invalidates_cached_analysis() {
+        use crate::registry::params::{FieldUpdate, UpdateSequenceSettingsParams};
+
+        let state = state_with_audio("song.mp3");
+        *state.current_sequence.lock() = Some("seq".into());
+        // What analyze_audio stores once the sidecar returns.
+        state.cache_analysis("song.mp3", beats_only());
+        assert!(current_analysis(&state).is_some());
+
+        let settings = |audio_file: &str| UpdateSequenceSettingsParams {
+            name: None,
+            audio_file: Some(FieldUpdate::Set(audio_file.into())),
+            duration: None,
+            frame_rate: None,
+        };
+        super::super::edit::update_sequence_settings(&state, settings("other.mp3")).unwrap();
+        assert!(current_analysis(&state).is_none());
+
+        // Switching back finds the original analysis again.
+        super::super::edit::update_sequence_settings(&state, settings("song.mp3")).unwrap();
+        assert!(current_analysis(&state).is_some());
+
+        // Keys are per sequence.
+        *state.current_sequence.lock() = Some("other-seq".into());
+        assert!(current_analysis(&state).is_none());
+    }
+
+    #[test]
+    fn chords_from_sidecar_are_filtered_by_range() {
+        let payload = r#"{
+            "features": {},
+            "harmony": {
+                "key": "G major",
+                "key_confidence": 0.8,
+                "chords": [
+                    {"label": "G", "start": 0.0, "end": 2.0},
+                    {"label": "Em", "start": 2.0, "end": 4.0},
+                    {"label": "C", "start": 4.0, "end": 6.0}
+                ]
+            }
+        }"#;
+        let analysis: AudioAnalysis = serde_json::from_str(payload).unwrap();
+        let labels = |start, end| -> Vec<String> {
+            chords_in_range(&analysis, start, end).into_iter().map(|c| c.label).collect()
+        };
+        assert_eq!(labels(1.5, 4.0), ["G", "Em"]);
+        assert_eq!(labels(6.0, 8.0), Vec::<String>::new());
+        assert!(chords_in_range(&beats_only(), 0.0, 10.0).is_empty());
+    }
+
+    #[test]
+    fn drums_energy_is_sampled_between_envelope_points() {
+        let payload = r#"{
+            "features": {},
+            "stems": {
+                "vocals": "vocals.wav",
+                "drums": "drums.wav",
+                "bass": "bass.wav",
+                "other": "other.wav",
+                "energy": {"drums": [0.0, 1.0, 0.5, 0.0]},
+                "energy_time_step": 0.5
+            }
+        }"#;
+        let analysis: AudioAnalysis = serde_json::from_str(payload).unwrap();
+        let energy = stem_energy(&analysis, "drums", &[0.0, 0.25, 0.5, 1.25, 5.0]);
+        let expected = [0.0, 0.5, 1.0, 0.25, 0.0];
+        assert_eq!(energy.len(), expected.len());
+        for (got, want) in energy.iter().zip(expected) {
+            assert!((got - want).abs() < 1e-9, "{energy:?} != {expected:?}");
+        }
+
+        assert!(stem_energy(&analysis, "vocals", &[1.0]).is_empty());
+        assert!(stem_energy(&beats_only(), "drums", &[1.0]).is_empty());
+    }
+
+    #[test]
+    fn import_rejects_malformed_file() {
+        let dir = std::env::temp_dir().join("vibelights_test_analysis_malformed");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("bad.json");
+        std::fs::write(&path, r#"{"beats": [1, 2, 3]}"#).unwrap();
+
+        let state = state_with_audio("song.mp3");
+        let err = import_analysis(
+            &state,
+            ImportAnalysisParams { path: path.to_string_lossy().to_string(), force: true },
+        );
+        assert!(matches!(err, Err(AppError::AnalysisError { .. })));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[cfg(feature = "tauri-app")]
+    #[tokio::test]
+    async fn analyze_audio_is_rejected_while_another_is_in_flight() {
+        let state = state_with_audio("song.mp3");
+        let first = state.cancellation.begin("analysis").unwrap();
+
+        let second = analyze_audio(
+            Arc::clone(&state),
+            None,
+            crate::registry::params::AnalyzeAudioParams { features: None },
+        )
+        .await;
+        assert!(matches!(second, Err(AppError::Busy { ref operation }) if operation == "analysis"));
+
+        // The in-flight run stays cancellable under its CancelOperation name.
+        assert!(state.cancellation.cancel("analysis"));
+        assert!(first.flag().load(std::sync::atomic::Ordering::Relaxed));
+        drop(first);
+        assert!(!state.cancellation.cancel("analysis"));
+    }
+
+    #[cfg(feature = "tauri-app")]
+    #[test]
+    fn missing_audio_file_reports_path_and_reimport_hint() {
+        let path = std::env::temp_dir().join("vibelights_test_missing_audio/gone.mp3");
+        let err = check_audio_source(&path).unwrap_err();
+        let shown = path.display().to_string();
+        assert!(
+            matches!(&err, AppError::AnalysisError { message }
+                if message.contains(&shown) && message.contains("re-import")),
+            "{err:?}"
+        );
+    }
+
+    #[cfg(feature = "tauri-app")]
+    #[test]
+    fn undecodable_audio_is_rejected_before_the_sidecar() {
+        let dir = std::env::temp_dir().join("vibelights_test_bad_audio");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("notes.mp3");
+        std::fs::write(&path, b"definitely not audio").unwrap();
+
+        let err = check_audio_source(&path).unwrap_err();
+        assert!(
+            matches!(&err, AppError::AnalysisError { message }
+                if message.contains("supported format")),
+            "{err:?}"
+        );
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
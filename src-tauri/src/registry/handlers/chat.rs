@@ -6,7 +6,7 @@ use serde::Serialize;
 use ts_rs::TS;
 
 use crate::error::AppError;
-use crate::registry::params::ConversationIdParams;
+use crate::registry::params::{ConversationIdParams, ExportConversationParams};
 use crate::registry::{CommandOutput, CommandResult};
 use crate::state::AppState;
 
@@ -58,3 +58,109 @@ pub fn delete_agent_conversation(
         .map_err(|e| AppError::NotFound { what: e })?;
     Ok(CommandOutput::new("Conversation deleted.", CommandResult::DeleteAgentConversation))
 }
+
+pub fn get_conversation_usage(
+    state: &Arc<AppState>,
+    p: ConversationIdParams,
+) -> Result<CommandOutput, AppError> {
+    let usage = crate::chat::agent_conversation_usage(state, &p.conversation_id)
+        .map_err(|e| AppError::NotFound { what: e })?;
+    let cost = usage
+        .estimated_cost
+        .map_or_else(String::new, |c| format!(" (~${c:.4})"));
+    Ok(CommandOutput::new(
+        format!("{} tokens{cost}.", usage.usage.total()),
+        CommandResult::GetConversationUsage(usage),
+    ))
+}
+
+pub fn export_conversation(
+    state: &Arc<AppState>,
+    p: ExportConversationParams,
+) -> Result<CommandOutput, AppError> {
+    let transcript = crate::chat::export_agent_conversation(state, &p.conversation_id, p.format)
+        .map_err(|e| AppError::NotFound { what: e })?;
+    crate::project::atomic_write(std::path::Path::new(&p.path), transcript.as_bytes())?;
+    Ok(CommandOutput::new(
+        format!("Exported conversation to {}", p.path),
+        CommandResult::ExportConversation,
+    ))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::chat::{AgentConversation, ChatHistoryEntry, ChatRole, TranscriptFormat};
+    use crate::model::Show;
+
+    fn entry(role: ChatRole, text: &str, tool: Option<&str>) -> ChatHistoryEntry {
+        ChatHistoryEntry {
+            role,
+            text: text.into(),
+            usage: None,
+            tool: tool.map(Into::into),
+        }
+    }
+
+    fn state_with_conversation() -> Arc<AppState> {
+        let state = Arc::new(AppState::for_test(Show::empty()));
+        state.agent_chats.lock().conversations.push(AgentConversation {
+            id: "c1".into(),
+            created_at: "2026-01-01T00:00:00Z".into(),
+            title: "Build a chase".into(),
+            session_id: None,
+            messages: vec![
+                entry(ChatRole::User, "Add a chase on the roofline", None),
+                entry(ChatRole::Tool, "Added Chase effect.", Some("add_effect")),
+                entry(ChatRole::Assistant, "Done, the roofline now chases.", None),
+            ],
+        });
+        state
+    }
+
+    #[test]
+    fn export_writes_turns_and_tool_calls() {
+        let state = state_with_conversation();
+        let dir = std::env::temp_dir().join("vibelights_test_export_conversation");
+        std::fs::create_dir_all(&dir).unwrap();
+
+        for (format, file) in [
+            (TranscriptFormat::Markdown, "chat.md"),
+            (TranscriptFormat::Json, "chat.json"),
+        ] {
+            let path = dir.join(file).to_string_lossy().to_string();
+            export_conversation(
+                &state,
+                ExportConversationParams {
+                    conversation_id: "c1".into(),
+                    path: path.clone(),
+                    format,
+                },
+            )
+            .unwrap();
+
+            let text = std::fs::read_to_string(&path).unwrap();
+            assert!(text.contains("Add a chase on the roofline"));
+            assert!(text.contains("Done, the roofline now chases."));
+            assert!(text.contains("add_effect"));
+            assert!(text.contains("Added Chase effect."));
+        }
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn export_unknown_conversation_is_not_found() {
+        let state = state_with_conversation();
+        let err = export_conversation(
+            &state,
+            ExportConversationParams {
+                conversation_id: "missing".into(),
+                path: std::env::temp_dir().join("never.md").to_string_lossy().to_string(),
+                format: TranscriptFormat::Markdown,
+            },
+        );
+        assert!(matches!(err, Err(AppError::NotFound { .. })));
+    }
+}
@@ -3,6 +3,7 @@
 use std::sync::Arc;
 
 use crate::commands::{self, ScriptCompileResult, ScriptError};
+use crate::dsl::error::offset_to_line_col;
 use crate::error::AppError;
 use crate::registry::params::CancelOperationParams;
 use crate::registry::{CommandOutput, CommandResult};
@@ -23,6 +24,29 @@ pub fn cancel_operation(
     ))
 }
 
+/// A frontend diagnostic at byte `offset` in `source`.
+fn script_error(source: &str, message: &str, offset: usize) -> ScriptError {
+    let (line, col) = offset_to_line_col(source, offset);
+    ScriptError {
+        message: message.to_string(),
+        offset,
+        line,
+        col,
+    }
+}
+
+/// Map a compiled script's warnings to frontend diagnostics.
+pub fn script_warnings(
+    compiled: &crate::dsl::compiler::CompiledScript,
+    source: &str,
+) -> Vec<ScriptError> {
+    compiled
+        .warnings
+        .iter()
+        .map(|w| script_error(source, &w.message, w.span.start))
+        .collect()
+}
+
 /// Compile a script source, cache the result, and return a `ScriptCompileResult`.
 ///
 /// On success the compiled script is inserted into `state.script_cache`.
@@ -32,9 +56,10 @@ pub fn compile_and_cache(
     name: String,
     source: &str,
 ) -> ScriptCompileResult {
-    match crate::dsl::compile_source(source) {
+    match state.compile_script(source) {
         Ok(compiled) => {
             let params = commands::extract_script_params(&compiled);
+            let warnings = script_warnings(&compiled, source);
             state
                 .script_cache
                 .lock()
@@ -42,6 +67,7 @@ pub fn compile_and_cache(
             ScriptCompileResult {
                 success: true,
                 errors: vec![],
+                warnings,
                 name,
                 params: Some(params),
             }
@@ -50,11 +76,9 @@ pub fn compile_and_cache(
             success: false,
             errors: errors
                 .iter()
-                .map(|e| ScriptError {
-                    message: e.message.clone(),
-                    offset: e.span.start,
-                })
+                .map(|e| script_error(source, &e.message, e.span.start))
                 .collect(),
+            warnings: vec![],
             name,
             params: None,
         },
@@ -62,13 +86,14 @@ pub fn compile_and_cache(
 }
 
 /// Compile a script source for preview only (no caching, no persistence).
-pub fn compile_preview(name: String, source: &str) -> ScriptCompileResult {
-    match crate::dsl::compile_source(source) {
+pub fn compile_preview(state: &AppState, name: String, source: &str) -> ScriptCompileResult {
+    match state.compile_script(source) {
         Ok(compiled) => {
             let params = commands::extract_script_params(&compiled);
             ScriptCompileResult {
                 success: true,
                 errors: vec![],
+                warnings: script_warnings(&compiled, source),
                 name: if name.is_empty() {
                     compiled.name.clone()
                 } else {
@@ -81,11 +106,9 @@ pub fn compile_preview(name: String, source: &str) -> ScriptCompileResult {
             success: false,
             errors: errors
                 .iter()
-                .map(|e| ScriptError {
-                    message: e.message.clone(),
-                    offset: e.span.start,
-                })
+                .map(|e| script_error(source, &e.message, e.span.start))
                 .collect(),
+            warnings: vec![],
             name,
             params: None,
         },
@@ -1,11 +1,15 @@
 #![allow(clippy::needless_pass_by_value)]
 
+use std::path::Path;
 use std::sync::Arc;
 
 use crate::error::AppError;
+use crate::model::{Curve, EasingFunction, EffectPreset, ParamValue};
 use crate::setup;
 use crate::registry::params::{
-    NameParams, RenameParams, SetGlobalCurveParams, SetGlobalGradientParams, WriteScriptParams,
+    AddCurvePresetParams, EffectLocation, ExportLibraryBundleParams, ImportLibraryBundleParams,
+    NameParams, QuantizeGradientParams, RenameParams, SaveEffectPresetParams,
+    SetGlobalCurveParams, SetGlobalGradientParams, TransformGradientParams, WriteScriptParams,
 };
 use crate::registry::{CommandOutput, CommandResult};
 use crate::state::{get_data_dir, AppState};
@@ -98,6 +102,45 @@ library_crud! {
     list_fn: list_global_gradients, list_result: ListGlobalGradients,
 }
 
+pub fn transform_gradient(
+    state: &Arc<AppState>,
+    p: TransformGradientParams,
+) -> Result<CommandOutput, AppError> {
+    {
+        let mut libs = state.global_libraries.lock();
+        let Some(source) = libs.gradients.get(&p.name) else {
+            return Err(AppError::NotFound { what: format!("Gradient \"{}\"", p.name) });
+        };
+        let mut gradient = if p.reverse { source.reversed() } else { source.clone() };
+        if p.rotate != 0.0 {
+            gradient = gradient.rotated(p.rotate);
+        }
+        libs.gradients.insert(p.new_name.clone(), gradient);
+    }
+    persist_inner(state);
+    Ok(CommandOutput::new(
+        format!("Gradient \"{}\" saved.", p.new_name),
+        CommandResult::TransformGradient,
+    ))
+}
+
+pub fn quantize_gradient_to_palette(
+    state: &Arc<AppState>,
+    p: QuantizeGradientParams,
+) -> Result<CommandOutput, AppError> {
+    let gradient = p.gradient.quantized(&p.palette, p.steps).ok_or(AppError::ValidationError {
+        message: "Quantizing needs a non-empty palette and at least one step.".into(),
+    })?;
+    let msg = if let Some(name) = p.save_as {
+        state.global_libraries.lock().gradients.insert(name.clone(), gradient.clone());
+        persist_inner(state);
+        format!("Quantized gradient saved as \"{name}\".")
+    } else {
+        "Quantized gradient.".to_string()
+    };
+    Ok(CommandOutput::new(msg, CommandResult::QuantizeGradientToPalette(gradient)))
+}
+
 // ── Curves ───────────────────────────────────────────────────────
 
 library_crud! {
@@ -110,6 +153,181 @@ library_crud! {
     list_fn: list_global_curves, list_result: ListGlobalCurves,
 }
 
+/// Display name for an easing preset: `EaseInOutCubic` becomes "Ease In Out Cubic".
+fn preset_name(kind: EasingFunction) -> String {
+    let mut name = String::new();
+    for c in format!("{kind:?}").chars() {
+        if c.is_ascii_uppercase() && !name.is_empty() {
+            name.push(' ');
+        }
+        name.push(c);
+    }
+    name
+}
+
+/// Easing presets offered for the curve library. `Hold` is flat and left out.
+fn curve_presets() -> impl Iterator<Item = EasingFunction> {
+    EasingFunction::ALL.into_iter().filter(|k| *k != EasingFunction::Hold)
+}
+
+pub fn list_curve_presets(_state: &Arc<AppState>) -> Result<CommandOutput, AppError> {
+    let presets: Vec<(EasingFunction, Curve)> =
+        curve_presets().map(|kind| (kind, Curve::preset(kind))).collect();
+    Ok(CommandOutput::new(
+        format!("{} curve presets.", presets.len()),
+        CommandResult::ListCurvePresets(presets),
+    ))
+}
+
+pub fn add_curve_preset(
+    state: &Arc<AppState>,
+    p: AddCurvePresetParams,
+) -> Result<CommandOutput, AppError> {
+    let name = p.name.unwrap_or_else(|| preset_name(p.kind));
+    state.global_libraries.lock().curves.insert(name.clone(), Curve::preset(p.kind));
+    persist_inner(state);
+    Ok(CommandOutput::new(
+        format!("Curve \"{name}\" saved."),
+        CommandResult::AddCurvePreset(name),
+    ))
+}
+
+// ── Usage ────────────────────────────────────────────────────────
+
+/// Locate every effect in the active sequence that uses the library gradient
+/// or curve `name`, either by reference or by carrying an identical copy.
+pub fn find_library_usage(
+    state: &Arc<AppState>,
+    p: NameParams,
+) -> Result<CommandOutput, AppError> {
+    let (gradient, curve) = {
+        let libs = state.global_libraries.lock();
+        (libs.gradients.get(&p.name).cloned(), libs.curves.get(&p.name).cloned())
+    };
+    if gradient.is_none() && curve.is_none() {
+        return Err(AppError::NotFound { what: format!("Gradient or curve \"{}\"", p.name) });
+    }
+
+    let uses_value = |value: &ParamValue| match value {
+        ParamValue::GradientRef(n) => gradient.is_some() && *n == p.name,
+        ParamValue::CurveRef(n) => curve.is_some() && *n == p.name,
+        ParamValue::ColorGradient(g) => gradient.as_ref() == Some(g),
+        ParamValue::Curve(c) => curve.as_ref() == Some(c),
+        _ => false,
+    };
+
+    let show = state.show.lock();
+    let seq_idx = state.active_sequence_index(&show)?;
+    let sequence = show.sequences.get(seq_idx).ok_or(AppError::NoSequence)?;
+    let mut locations = Vec::new();
+    for (track_index, track) in sequence.tracks.iter().enumerate() {
+        for (effect_index, effect) in track.effects.iter().enumerate() {
+            let in_params = effect.params.inner().values().any(uses_value);
+            let in_opacity = curve.is_some() && effect.opacity_curve == curve;
+            if in_params || in_opacity {
+                locations.push(EffectLocation { track_index, effect_index });
+            }
+        }
+    }
+
+    let mut lines = vec![format!("\"{}\" is used by {} effects:", p.name, locations.len())];
+    for loc in &locations {
+        lines.push(format!("  [{}:{}]", loc.track_index, loc.effect_index));
+    }
+    Ok(CommandOutput::new(lines.join("\n"), CommandResult::FindLibraryUsage(locations)))
+}
+
+// ── Effect presets ───────────────────────────────────────────────
+
+pub fn save_effect_preset(
+    state: &Arc<AppState>,
+    p: SaveEffectPresetParams,
+) -> Result<CommandOutput, AppError> {
+    let preset = {
+        let show = state.show.lock();
+        let seq_idx = state.active_sequence_index(&show)?;
+        let effect = show
+            .sequences
+            .get(seq_idx)
+            .and_then(|s| s.tracks.get(p.track_index))
+            .ok_or(AppError::InvalidIndex { what: "track".into(), index: p.track_index })?
+            .effects
+            .get(p.effect_index)
+            .ok_or(AppError::InvalidIndex { what: "effect".into(), index: p.effect_index })?;
+        EffectPreset { kind: effect.kind.clone(), params: effect.params.clone() }
+    };
+    let kind = preset.kind.clone();
+    state.global_libraries.lock().effect_presets.insert(p.name.clone(), preset);
+    persist_inner(state);
+    Ok(CommandOutput::new(
+        format!("Effect preset \"{}\" saved ({kind:?}).", p.name),
+        CommandResult::SaveEffectPreset,
+    ))
+}
+
+pub fn list_effect_presets(state: &Arc<AppState>) -> Result<CommandOutput, AppError> {
+    let libs = state.global_libraries.lock();
+    let mut items: Vec<(String, EffectPreset)> =
+        libs.effect_presets.iter().map(|(k, v)| (k.clone(), v.clone())).collect();
+    items.sort_by(|a, b| a.0.cmp(&b.0));
+    Ok(CommandOutput::new(
+        format!("{} effect presets.", items.len()),
+        CommandResult::ListEffectPresets(items),
+    ))
+}
+
+pub fn delete_effect_preset(
+    state: &Arc<AppState>,
+    p: NameParams,
+) -> Result<CommandOutput, AppError> {
+    state.global_libraries.lock().effect_presets.remove(&p.name);
+    persist_inner(state);
+    Ok(CommandOutput::new(
+        format!("Effect preset \"{}\" deleted.", p.name),
+        CommandResult::DeleteEffectPreset,
+    ))
+}
+
+// ── Bundles ──────────────────────────────────────────────────────
+
+pub fn export_library_bundle(
+    state: &Arc<AppState>,
+    p: ExportLibraryBundleParams,
+) -> Result<CommandOutput, AppError> {
+    let libs = state.global_libraries.lock();
+    setup::export_library_bundle(Path::new(&p.path), &libs)?;
+    Ok(CommandOutput::new(
+        format!(
+            "Exported {} gradients, {} curves, {} scripts and {} effect presets to {}",
+            libs.gradients.len(),
+            libs.curves.len(),
+            libs.scripts.len(),
+            libs.effect_presets.len(),
+            p.path
+        ),
+        CommandResult::ExportLibraryBundle,
+    ))
+}
+
+/// Merge a bundle into the global library, then recompile every library
+/// script so the cache reflects overwritten sources and new `use` targets.
+pub fn import_library_bundle(
+    state: &Arc<AppState>,
+    p: ImportLibraryBundleParams,
+) -> Result<CommandOutput, AppError> {
+    let incoming = setup::import_library_bundle(Path::new(&p.path))?;
+    let summary = state.global_libraries.lock().merge(incoming, p.on_collision);
+    persist_inner(state);
+    crate::commands::recompile_all_scripts(state);
+    Ok(CommandOutput::new(
+        format!(
+            "Imported library bundle: {} added, {} overwritten, {} renamed, {} skipped.",
+            summary.added, summary.overwritten, summary.renamed, summary.skipped
+        ),
+        CommandResult::ImportLibraryBundle(summary),
+    ))
+}
+
 // ── Scripts ──────────────────────────────────────────────────────
 
 pub fn compile_global_script(
@@ -120,7 +338,200 @@ pub fn compile_global_script(
     if result.success {
         state.global_libraries.lock().scripts.insert(p.name, p.source);
         persist_inner(state);
+        // Scripts that `use` this one inlined its old functions.
+        crate::commands::recompile_all_scripts(state);
     }
     let msg = if result.success { "Compiled and saved." } else { "Compile failed." };
     Ok(CommandOutput::new(msg, CommandResult::CompileGlobalScript(result)))
 }
+
+/// Compile every script in the global library, caching the ones that compile,
+/// so broken scripts show up before playback reaches them. Results are sorted
+/// by script name.
+pub fn compile_all_global_scripts(state: &Arc<AppState>) -> Result<CommandOutput, AppError> {
+    let mut scripts: Vec<(String, String)> = state
+        .global_libraries
+        .lock()
+        .scripts
+        .iter()
+        .map(|(name, source)| (name.clone(), source.clone()))
+        .collect();
+    scripts.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let results: Vec<_> = scripts
+        .into_iter()
+        .map(|(name, source)| super::common::compile_and_cache(state, name, &source))
+        .collect();
+    let failed = results.iter().filter(|r| !r.success).count();
+    Ok(CommandOutput::new(
+        format!("Compiled {} scripts, {failed} failed.", results.len()),
+        CommandResult::CompileAllGlobalScripts(results),
+    ))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::model::{Color, Show};
+    use crate::registry::Command;
+
+    fn set_gradient(stops: serde_json::Value) -> Result<Command, serde_json::Error> {
+        serde_json::from_value(serde_json::json!({
+            "command": "SetGlobalGradient",
+            "params": { "name": "Sunset", "gradient": { "stops": stops } },
+        }))
+    }
+
+    #[test]
+    fn compile_all_reports_each_script() {
+        let state = Arc::new(AppState::for_test(Show::empty()));
+        {
+            let mut libs = state.global_libraries.lock();
+            libs.scripts.insert("good".into(), "rgb(1.0, 0.0, 0.0)".into());
+            libs.scripts.insert("broken".into(), "rgb(1.0, ".into());
+        }
+
+        let output = compile_all_global_scripts(&state).unwrap();
+        let CommandResult::CompileAllGlobalScripts(results) = output.result else {
+            panic!("expected CompileAllGlobalScripts");
+        };
+        let outcomes: Vec<(&str, bool)> =
+            results.iter().map(|r| (r.name.as_str(), r.success)).collect();
+        assert_eq!(outcomes, [("broken", false), ("good", true)]);
+
+        let cache = state.script_cache.lock();
+        assert!(cache.contains_key("good"));
+        assert!(!cache.contains_key("broken"));
+    }
+
+    #[test]
+    fn library_bundle_round_trips_into_fresh_data_dir() {
+        use crate::model::ColorGradient;
+        use crate::settings::AppSettings;
+        use crate::setup::NameCollision;
+
+        let dir = std::env::temp_dir().join("vibelights_test_library_bundle");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(dir.join("data")).unwrap();
+        let path = dir.join("library.json").to_string_lossy().to_string();
+
+        let sunset = ColorGradient::two_color(Color::rgb(255, 80, 0), Color::rgb(80, 0, 160));
+        let source = Arc::new(AppState::for_test(Show::empty()));
+        {
+            let mut libs = source.global_libraries.lock();
+            libs.gradients.insert("Sunset".into(), sunset.clone());
+            libs.curves.insert("Ramp".into(), Curve::linear());
+            libs.scripts.insert("red".into(), "rgb(1.0, 0.0, 0.0)".into());
+        }
+        export_library_bundle(&source, ExportLibraryBundleParams { path: path.clone() }).unwrap();
+
+        let fresh = Arc::new(AppState::for_test(Show::empty()));
+        *fresh.settings.lock() = Some(AppSettings::new(dir.join("data")));
+        let import = |on_collision| {
+            let p = ImportLibraryBundleParams { path: path.clone(), on_collision };
+            let output = import_library_bundle(&fresh, p).unwrap();
+            let CommandResult::ImportLibraryBundle(summary) = output.result else {
+                panic!("expected ImportLibraryBundle");
+            };
+            summary
+        };
+        assert_eq!(import(NameCollision::Skip).added, 3);
+
+        let saved = setup::load_global_libraries(&dir.join("data")).unwrap();
+        assert_eq!(saved.gradients.get("Sunset"), Some(&sunset));
+        assert_eq!(saved.curves.get("Ramp"), Some(&Curve::linear()));
+        assert_eq!(saved.scripts.get("red").map(String::as_str), Some("rgb(1.0, 0.0, 0.0)"));
+        assert!(fresh.script_cache.lock().contains_key("red"));
+
+        let again = import(NameCollision::Rename);
+        assert_eq!(again.renamed, 3);
+        assert!(fresh.global_libraries.lock().gradients.contains_key("Sunset (2)"));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn set_gradient_stores_stops_sorted_and_rejects_empty() {
+        let color = |r, g, b| serde_json::json!({ "r": r, "g": g, "b": b, "a": 255 });
+        let command = set_gradient(serde_json::json!([
+            { "position": 1.0, "color": color(0, 0, 255) },
+            { "position": 0.0, "color": color(255, 0, 0) },
+            { "position": 0.4, "color": color(0, 255, 0) },
+        ]))
+        .unwrap();
+        let Command::SetGlobalGradient(params) = command else {
+            panic!("expected SetGlobalGradient");
+        };
+        let state = Arc::new(AppState::for_test(Show::empty()));
+        set_global_gradient(&state, params).unwrap();
+
+        let libs = state.global_libraries.lock();
+        let stops = libs.gradients.get("Sunset").unwrap().stops();
+        let colors: Vec<Color> = stops.iter().map(|s| s.color).collect();
+        assert_eq!(
+            colors,
+            [Color::rgb(255, 0, 0), Color::rgb(0, 255, 0), Color::rgb(0, 0, 255)]
+        );
+        assert!(stops.iter().map(|s| s.position).is_sorted());
+
+        assert!(set_gradient(serde_json::json!([])).is_err());
+    }
+
+    #[test]
+    fn library_usage_reports_effects_using_gradient() {
+        use crate::model::{
+            BlendMode, ColorGradient, EffectInstance, EffectKind, EffectParams, EffectTarget,
+            ParamKey, Sequence, TimeRange, Track,
+        };
+
+        let sunset = ColorGradient::two_color(Color::rgb(255, 80, 0), Color::rgb(80, 0, 160));
+        let effect = |value: Option<ParamValue>| {
+            let mut params = EffectParams::new();
+            if let Some(value) = value {
+                params.set_mut(ParamKey::Gradient, value);
+            }
+            EffectInstance {
+                kind: EffectKind::Gradient,
+                params,
+                time_range: TimeRange::new(0.0, 1.0).unwrap(),
+                blend_mode: BlendMode::Override,
+                opacity: 1.0,
+                opacity_curve: None,
+                fade_in: 0.0,
+                fade_out: 0.0,
+            }
+        };
+        let mut show = Show::empty();
+        show.sequences.push(Sequence::for_test(
+            "Seq",
+            10.0,
+            vec![Track {
+                name: "Roof".into(),
+                target: EffectTarget::All,
+                locked: false,
+                muted: false,
+                blend_mode: BlendMode::Override,
+                opacity: 1.0,
+                effects: vec![
+                    effect(Some(ParamValue::GradientRef("Sunset".into()))),
+                    effect(None),
+                    effect(Some(ParamValue::ColorGradient(sunset.clone()))),
+                    effect(Some(ParamValue::GradientRef("Ocean".into()))),
+                ],
+            }],
+        ));
+        let state = Arc::new(AppState::for_test(show));
+        *state.current_sequence.lock() = Some("seq".into());
+        state.global_libraries.lock().gradients.insert("Sunset".into(), sunset);
+
+        let output = find_library_usage(&state, NameParams { name: "Sunset".into() }).unwrap();
+        let CommandResult::FindLibraryUsage(found) = output.result else {
+            panic!("expected FindLibraryUsage");
+        };
+        let found: Vec<_> = found.iter().map(|l| (l.track_index, l.effect_index)).collect();
+        assert_eq!(found, [(0, 0), (0, 2)]);
+
+        assert!(find_library_usage(&state, NameParams { name: "Missing".into() }).is_err());
+    }
+}
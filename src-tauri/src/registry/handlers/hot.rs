@@ -1,38 +1,49 @@
 #![allow(clippy::needless_pass_by_value, clippy::cast_precision_loss)]
 
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use crate::effects::resolve_effect;
 use crate::engine::{self, Frame};
 use crate::error::AppError;
-use crate::commands::{TickResult, EffectThumbnail, ScriptPreviewData};
+use crate::commands::{
+    EffectThumbnail, FrameDigest, ScriptOpCount, ScriptPreviewData, ScriptProfile, TickResult,
+};
 use crate::registry::params::{
-    GetFrameFilteredParams, GetFrameParams, PreviewScriptFrameParams, PreviewScriptParams,
-    RenderEffectThumbnailParams, TickParams,
+    ExportFramePngParams, GetFrameFilteredParams, GetFrameParams, HashSequenceFramesParams,
+    PreviewScriptFrameParams, PreviewScriptParams, ProfileScriptParams,
+    RenderEffectThumbnailParams, SimulateColorVisionParams, TickParams,
 };
+use crate::model::show::Show;
+use crate::model::Color;
 use crate::registry::{CommandOutput, CommandResult};
-use crate::state::AppState;
+use crate::state::{sequence_beats, AppState, ThumbnailCache, ThumbnailKey};
+
+/// Frames rendered ahead of the playhead after a seek: half a second at 30 fps.
+const PREFETCH_FRAMES: u64 = 15;
+
+/// Largest width or height accepted by `export_frame_png`.
+const MAX_EXPORT_SIZE: u32 = 8192;
 
-pub fn tick(state: &Arc<AppState>, _p: TickParams) -> Result<CommandOutput, AppError> {
+/// 64-bit FNV-1a offset basis and prime. Unlike `DefaultHasher`, FNV-1a is
+/// fixed across Rust releases, so frame digests can be pinned in tests.
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+pub fn tick(state: &Arc<AppState>, p: TickParams) -> Result<CommandOutput, AppError> {
     let mut playback = state.playback.lock();
     if !playback.playing {
         return Ok(CommandOutput::new("Not playing.", CommandResult::Tick(None)));
     }
 
     let now = std::time::Instant::now();
-    let real_dt = match playback.last_tick {
-        Some(prev) => now.duration_since(prev).as_secs_f64(),
-        None => 0.0,
-    };
-    playback.last_tick = Some(now);
-
     let show = state.show.lock();
-    let duration = show
+    let (duration, frame_rate) = show
         .sequences
         .get(playback.sequence_index)
-        .map_or(0.0, |s| s.duration);
+        .map_or((0.0, 0.0), |s| (s.duration, s.frame_rate));
 
-    playback.current_time += real_dt;
+    playback.advance_to(now, frame_rate);
 
     let effective_end = playback
         .region
@@ -41,32 +52,40 @@ pub fn tick(state: &Arc<AppState>, _p: TickParams) -> Result<CommandOutput, AppE
     if playback.current_time >= effective_end {
         if playback.looping {
             let loop_start = playback.region.map_or(0.0, |(s, _)| s);
-            playback.current_time = loop_start;
-            playback.last_tick = Some(now);
+            playback.seek_frame(loop_start, frame_rate);
+            playback.clock_anchor = Some((now, playback.frame_index));
         } else {
+            playback.seek_frame(effective_end, frame_rate);
             playback.current_time = effective_end;
             playback.playing = false;
-            playback.last_tick = None;
+            playback.clock_anchor = None;
         }
     }
 
-    let scripts = state.script_cache.lock();
-    let libs = state.global_libraries.lock();
-    let frame = engine::evaluate(
+    let mut frame = evaluate_cached(
+        state,
         &show,
         playback.sequence_index,
+        &playback.solo_tracks,
         playback.current_time,
-        None,
-        Some(&scripts),
-        &libs.gradients,
-        &libs.curves,
     );
+    let mut baselines = state.tick_baselines.lock();
+    let baseline = baselines.entry(p.client.unwrap_or_default()).or_default();
+    let delta = baseline.advance(&frame, p.since);
+    let is_delta = delta.is_some();
+    let cleared = delta.map_or_else(Vec::new, |(changed, cleared)| {
+        frame.fixtures = changed;
+        cleared
+    });
     Ok(CommandOutput::new(
         "Tick.",
         CommandResult::Tick(Some(TickResult {
             frame,
             current_time: playback.current_time,
             playing: playback.playing,
+            seq: baseline.seq,
+            delta: is_delta,
+            cleared,
         })),
     ))
 }
@@ -74,18 +93,120 @@ pub fn tick(state: &Arc<AppState>, _p: TickParams) -> Result<CommandOutput, AppE
 pub fn get_frame(state: &Arc<AppState>, p: GetFrameParams) -> Result<CommandOutput, AppError> {
     let show = state.show.lock();
     let playback = state.playback.lock();
-    let scripts = state.script_cache.lock();
-    let libs = state.global_libraries.lock();
-    let frame: Frame = engine::evaluate(
+    let frame = evaluate_cached(
+        state,
         &show,
         playback.sequence_index,
+        &playback.solo_tracks,
         p.time,
+    );
+    Ok(CommandOutput::new("Frame.", CommandResult::GetFrame(frame)))
+}
+
+/// Index of the frame that starts exactly at `time`, if any. Only these times
+/// are cached: the playhead always sits on one, free scrub positions rarely do.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss, clippy::float_cmp)]
+fn frame_index_at(time: f64, frame_rate: f64) -> Option<u64> {
+    if frame_rate <= 0.0 || !frame_rate.is_finite() || time < 0.0 || !time.is_finite() {
+        return None;
+    }
+    let index = (time * frame_rate).round() as u64;
+    // Exact comparison on purpose: the playhead derives its time the same way.
+    (index as f64 / frame_rate == time).then_some(index)
+}
+
+/// Evaluate the sequence at `time` without consulting the frame cache.
+fn evaluate_at(
+    state: &AppState,
+    show: &Show,
+    sequence_index: usize,
+    solo_tracks: &HashSet<usize>,
+    time: f64,
+) -> Frame {
+    let scripts = state.script_cache.lock();
+    let libs = state.global_libraries.lock();
+    let sequence_slug = state.current_sequence.lock().clone().unwrap_or_default();
+    let analysis = state.analysis_cache.lock();
+    engine::evaluate(
+        show,
+        sequence_index,
+        time,
         None,
         Some(&scripts),
         &libs.gradients,
         &libs.curves,
-    );
-    Ok(CommandOutput::new("Frame.", CommandResult::GetFrame(frame)))
+        solo_tracks,
+        sequence_beats(show, sequence_index, &sequence_slug, &analysis),
+    )
+}
+
+/// Evaluate the sequence at `time`, serving frame-aligned times from the
+/// frame cache and caching them on a miss.
+fn evaluate_cached(
+    state: &AppState,
+    show: &Show,
+    sequence_index: usize,
+    solo_tracks: &HashSet<usize>,
+    time: f64,
+) -> Frame {
+    let frame_index = show
+        .sequences
+        .get(sequence_index)
+        .and_then(|s| frame_index_at(time, s.frame_rate));
+    let Some(frame_index) = frame_index else {
+        return evaluate_at(state, show, sequence_index, solo_tracks, time);
+    };
+    if let Some(frame) = state.frame_cache.get(sequence_index, frame_index) {
+        return frame;
+    }
+    let generation = state.frame_cache.generation();
+    let frame = evaluate_at(state, show, sequence_index, solo_tracks, time);
+    state
+        .frame_cache
+        .insert(generation, sequence_index, frame_index, frame.clone());
+    frame
+}
+
+/// Render the frames from `start_frame` on into the frame cache on a
+/// background thread, so the first frames after a seek are ready when asked
+/// for. Starting another prefetch cancels this one.
+#[allow(clippy::cast_precision_loss)]
+pub fn prefetch_frames(state: &Arc<AppState>, sequence_index: usize, start_frame: u64) {
+    let epoch = state.frame_cache.begin_prefetch();
+    let worker = Arc::clone(state);
+    let handle = std::thread::spawn(move || {
+        for frame_index in start_frame..start_frame + PREFETCH_FRAMES {
+            if worker.frame_cache.prefetch_cancelled(epoch) {
+                return;
+            }
+            if worker.frame_cache.contains(sequence_index, frame_index) {
+                continue;
+            }
+            // Copy what's needed from playback before taking the show lock,
+            // so this thread never holds both.
+            let solo_tracks = {
+                let playback = worker.playback.lock();
+                if playback.sequence_index != sequence_index {
+                    return;
+                }
+                playback.solo_tracks.clone()
+            };
+            let show = worker.show.lock();
+            let Some(sequence) = show.sequences.get(sequence_index) else {
+                return;
+            };
+            let time = frame_index as f64 / sequence.frame_rate;
+            if !time.is_finite() || time > sequence.duration {
+                return;
+            }
+            let generation = worker.frame_cache.generation();
+            let frame = evaluate_at(&worker, &show, sequence_index, &solo_tracks, time);
+            worker
+                .frame_cache
+                .insert(generation, sequence_index, frame_index, frame);
+        }
+    });
+    state.frame_cache.set_prefetch(handle);
 }
 
 pub fn get_frame_filtered(
@@ -96,6 +217,8 @@ pub fn get_frame_filtered(
     let playback = state.playback.lock();
     let scripts = state.script_cache.lock();
     let libs = state.global_libraries.lock();
+    let sequence_slug = state.current_sequence.lock().clone().unwrap_or_default();
+    let analysis = state.analysis_cache.lock();
     let frame: Frame = engine::evaluate(
         &show,
         playback.sequence_index,
@@ -104,6 +227,8 @@ pub fn get_frame_filtered(
         Some(&scripts),
         &libs.gradients,
         &libs.curves,
+        &playback.solo_tracks,
+        sequence_beats(&show, playback.sequence_index, &sequence_slug, &analysis),
     );
     Ok(CommandOutput::new(
         "Filtered frame.",
@@ -111,6 +236,121 @@ pub fn get_frame_filtered(
     ))
 }
 
+pub fn export_frame_png(
+    state: &Arc<AppState>,
+    p: ExportFramePngParams,
+) -> Result<CommandOutput, AppError> {
+    if !(1..=MAX_EXPORT_SIZE).contains(&p.width) || !(1..=MAX_EXPORT_SIZE).contains(&p.height) {
+        return Err(AppError::ValidationError {
+            message: format!("Image size must be between 1 and {MAX_EXPORT_SIZE} pixels per side."),
+        });
+    }
+    let (sequence_index, solo_tracks) = {
+        let playback = state.playback.lock();
+        (playback.sequence_index, playback.solo_tracks.clone())
+    };
+    let raster = {
+        let show = state.show.lock();
+        let frame = evaluate_cached(state, &show, sequence_index, &solo_tracks, p.time);
+        let background = p.background.unwrap_or(Color::BLACK);
+        engine::raster::rasterize_on(&show, &frame, p.width, p.height, background)
+    };
+    let png = raster
+        .encode_png()
+        .map_err(|e| AppError::IoError { message: e.to_string() })?;
+    crate::project::atomic_write(std::path::Path::new(&p.path), &png)?;
+    Ok(CommandOutput::new(
+        format!("Exported the frame at {:.2}s to {}.", p.time, p.path),
+        CommandResult::ExportFramePng,
+    ))
+}
+
+fn fnv1a(hash: u64, bytes: &[u8]) -> u64 {
+    bytes
+        .iter()
+        .fold(hash, |h, &b| (h ^ u64::from(b)).wrapping_mul(FNV_PRIME))
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn hash_sequence_frames(
+    state: &Arc<AppState>,
+    p: HashSequenceFramesParams,
+) -> Result<CommandOutput, AppError> {
+    if p.every == 0 {
+        return Err(AppError::ValidationError {
+            message: "every must be at least 1.".into(),
+        });
+    }
+    let sequence_index = state.playback.lock().sequence_index;
+    let show = state.show.lock();
+    let sequence = show.sequences.get(sequence_index).ok_or(AppError::NoSequence)?;
+    let frame_rate = sequence.frame_rate;
+    if !frame_rate.is_finite() || frame_rate <= 0.0 {
+        return Err(AppError::ValidationError {
+            message: format!("Sequence \"{}\" has no usable frame rate", sequence.name),
+        });
+    }
+    let frame_count = (sequence.duration.max(0.0) * frame_rate).ceil() as usize;
+
+    // Times come from the frame index rather than an accumulated step, solo
+    // state is ignored and the frame cache is bypassed, so a show hashes the
+    // same wherever it is rendered. Unseeded random effects already get a
+    // fixed per-slot seed from the evaluator.
+    let no_solo = HashSet::new();
+    let mut hash = FNV_OFFSET;
+    let mut frames = 0;
+    for index in (0..frame_count).step_by(p.every) {
+        let time = index as f64 / frame_rate;
+        let frame = evaluate_at(state, &show, sequence_index, &no_solo, time);
+        let mut fixtures: Vec<_> = frame.fixtures.iter().collect();
+        fixtures.sort_unstable_by_key(|(id, _)| **id);
+        hash = fnv1a(hash, &(index as u64).to_le_bytes());
+        for (id, pixels) in fixtures {
+            hash = fnv1a(hash, &id.to_le_bytes());
+            hash = fnv1a(hash, &crate::util::base64_decode(pixels));
+        }
+        frames += 1;
+    }
+
+    let digest = format!("{hash:016x}");
+    Ok(CommandOutput::new(
+        format!("Hashed {frames} frames: {digest}."),
+        CommandResult::HashSequenceFrames(FrameDigest { digest, frames }),
+    ))
+}
+
+pub fn simulate_color_vision(
+    _state: &Arc<AppState>,
+    p: SimulateColorVisionParams,
+) -> Result<CommandOutput, AppError> {
+    let fixtures = p
+        .frame
+        .fixtures
+        .into_iter()
+        .map(|(id, encoded)| {
+            let rgba: Vec<u8> = crate::util::base64_decode(&encoded)
+                .chunks_exact(4)
+                .flat_map(|px| match *px {
+                    [r, g, b, a] => {
+                        let c = Color::rgba(r, g, b, a).simulate_cvd(p.kind);
+                        [c.r, c.g, c.b, c.a]
+                    }
+                    _ => [0; 4],
+                })
+                .collect();
+            (id, crate::util::base64_encode(&rgba))
+        })
+        .collect();
+    let frame = Frame {
+        fixtures,
+        warnings: p.frame.warnings,
+    };
+    Ok(CommandOutput::new(
+        format!("Simulated {:?}.", p.kind),
+        CommandResult::SimulateColorVision(frame),
+    ))
+}
+
 pub fn render_effect_thumbnail(
     state: &Arc<AppState>,
     p: RenderEffectThumbnailParams,
@@ -123,23 +363,39 @@ pub fn render_effect_thumbnail(
         let effect_instance = track.effects.get(p.effect_index)?;
         let effect = resolve_effect(&effect_instance.kind)?;
         let time_range = &effect_instance.time_range;
+        let params = ThumbnailCache::render_params(effect_instance, p.track_index, p.effect_index);
+
+        let key = ThumbnailKey {
+            effect_hash: ThumbnailCache::effect_hash(&effect_instance.kind, &params),
+            pixel_rows: p.pixel_rows,
+            time_samples: p.time_samples,
+        };
+
+        let pixels = if let Some(cached) = state.thumbnail_cache.get(&key) {
+            cached
+        } else {
+            // Samples sit at fixed normalized times and effects see no wall clock,
+            // so the same effect always renders the same pixels.
+            let mut pixels = Vec::with_capacity(p.pixel_rows * p.time_samples * 4);
 
-        let mut pixels = Vec::with_capacity(p.pixel_rows * p.time_samples * 4);
-
-        for row in 0..p.pixel_rows {
-            for col in 0..p.time_samples {
-                let t = if p.time_samples > 1 {
-                    col as f64 / (p.time_samples - 1) as f64
-                } else {
-                    0.5
-                };
-                let color = effect.evaluate(t, row, p.pixel_rows, &effect_instance.params);
-                pixels.push(color.r);
-                pixels.push(color.g);
-                pixels.push(color.b);
-                pixels.push(255);
+            for row in 0..p.pixel_rows {
+                for col in 0..p.time_samples {
+                    let t = if p.time_samples > 1 {
+                        col as f64 / (p.time_samples - 1) as f64
+                    } else {
+                        0.5
+                    };
+                    let color = effect.evaluate(t, row, p.pixel_rows, &params);
+                    pixels.push(color.r);
+                    pixels.push(color.g);
+                    pixels.push(color.b);
+                    pixels.push(255);
+                }
             }
-        }
+
+            state.thumbnail_cache.insert(key, pixels.clone());
+            pixels
+        };
 
         Some(EffectThumbnail {
             width: p.time_samples,
@@ -238,3 +494,434 @@ pub fn preview_script_frame(
         CommandResult::PreviewScriptFrame(result),
     ))
 }
+
+#[allow(clippy::cast_precision_loss)]
+pub fn profile_script(
+    state: &Arc<AppState>,
+    p: ProfileScriptParams,
+) -> Result<CommandOutput, AppError> {
+    let cache = state.script_cache.lock();
+    let compiled = cache.get(&p.name).ok_or_else(|| AppError::ApiError {
+        message: format!("Script '{}' not found in cache", p.name),
+    })?;
+
+    let profile = crate::effects::script::profile_frame(compiled, p.t, p.pixel_count, &p.params);
+    let total_ops = profile.total();
+    let ops_per_pixel = if p.pixel_count > 0 {
+        total_ops as f64 / p.pixel_count as f64
+    } else {
+        0.0
+    };
+    let ops = profile
+        .by_op(compiled)
+        .into_iter()
+        .map(|(op, count)| ScriptOpCount { op, count })
+        .collect();
+
+    Ok(CommandOutput::new(
+        format!("{ops_per_pixel:.1} ops per pixel over {} pixels.", p.pixel_count),
+        CommandResult::ProfileScript(ScriptProfile {
+            pixel_count: p.pixel_count,
+            total_ops,
+            ops_per_pixel,
+            ops,
+        }),
+    ))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic, clippy::indexing_slicing)]
+mod tests {
+    use super::*;
+    use crate::model::color::Color;
+    use crate::model::fixture::EffectTarget;
+    use crate::model::show::Show;
+    use crate::model::timeline::{
+        BlendMode, EffectInstance, EffectKind, EffectParams, ParamKey, ParamValue, Sequence,
+        TimeRange, Track,
+    };
+
+    fn state_with_solid(color: Color) -> Arc<AppState> {
+        let mut show = Show::empty();
+        show.sequences.push(Sequence::for_test(
+            "Seq",
+            10.0,
+            vec![Track {
+                name: "Track".into(),
+                target: EffectTarget::All,
+                locked: false,
+                muted: false,
+                blend_mode: BlendMode::Override,
+                opacity: 1.0,
+                effects: vec![EffectInstance {
+                    kind: EffectKind::Solid,
+                    params: EffectParams::new().set(ParamKey::Color, ParamValue::Color(color)),
+                    time_range: TimeRange::new(0.0, 5.0).unwrap(),
+                    blend_mode: BlendMode::Override,
+                    opacity: 1.0,
+                    opacity_curve: None,
+                    fade_in: 0.0,
+                    fade_out: 0.0,
+                }],
+            }],
+        ));
+        Arc::new(AppState::for_test(show))
+    }
+
+    /// Add a three-pixel RGB fixture with no layout position.
+    fn add_strip(state: &Arc<AppState>) {
+        use crate::model::fixture::{
+            BulbShape, ChannelOrder, ColorModel, FixtureDef, FixtureId, PixelType,
+        };
+
+        state.with_show_mut(|show| {
+            show.fixtures.push(FixtureDef {
+                id: FixtureId(1),
+                name: "Strip".into(),
+                color_model: ColorModel::Rgb,
+                pixel_count: 3,
+                pixel_type: PixelType::Smart,
+                bulb_shape: BulbShape::LED,
+                display_radius_override: None,
+                channel_order: ChannelOrder::Rgb,
+                gamma: None,
+            });
+        });
+    }
+
+    #[test]
+    fn exported_png_shows_red_bulbs_on_the_background() {
+        let state = state_with_solid(Color::rgb(255, 0, 0));
+        // No layout: the three pixels fall back to a strip across the middle.
+        add_strip(&state);
+        let path = std::env::temp_dir()
+            .join(format!("vibelights_test_frame_{}.png", std::process::id()));
+        let navy = Color::rgb(0, 0, 64);
+        export_frame_png(
+            &state,
+            ExportFramePngParams {
+                path: path.to_string_lossy().to_string(),
+                time: 1.0,
+                width: 64,
+                height: 32,
+                background: Some(navy),
+            },
+        )
+        .unwrap();
+
+        let bytes = std::fs::read(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+        let mut reader = png::Decoder::new(bytes.as_slice()).read_info().unwrap();
+        let mut data = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut data).unwrap();
+        assert_eq!((info.width, info.height, info.color_type), (64, 32, png::ColorType::Rgb));
+        let at = |x: usize, y: usize| {
+            let i = (y * 64 + x) * 3;
+            Color::rgb(data[i], data[i + 1], data[i + 2])
+        };
+        // 4px margin, 24px per layout unit: bulbs at x = 20, 32, 44 on row 16,
+        // added onto the navy fill.
+        for x in [20, 32, 44] {
+            assert_eq!(at(x, 16), Color::rgb(255, 0, 64), "bulb at x = {x}");
+        }
+        assert_eq!(at(0, 0), navy);
+        assert_eq!(at(26, 16), navy);
+    }
+
+    fn frame_digest(state: &Arc<AppState>) -> FrameDigest {
+        match hash_sequence_frames(state, HashSequenceFramesParams { every: 10 }).unwrap().result {
+            CommandResult::HashSequenceFrames(digest) => digest,
+            _ => panic!("expected a digest"),
+        }
+    }
+
+    #[test]
+    fn frame_digest_is_stable_and_follows_edits() {
+        let fresh = || {
+            let state = state_with_solid(Color::rgb(255, 0, 0));
+            add_strip(&state);
+            state
+        };
+        let state = fresh();
+        let first = frame_digest(&state);
+        // 10 s at 30 fps, every tenth frame.
+        assert_eq!(first.frames, 30);
+        assert_eq!(first.digest.len(), 16);
+        assert_eq!(frame_digest(&state).digest, first.digest);
+        assert_eq!(frame_digest(&fresh()).digest, first.digest);
+
+        state.with_show_mut(|show| {
+            show.sequences[0].tracks[0].effects[0]
+                .params
+                .set_mut(ParamKey::Color, ParamValue::Color(Color::rgb(0, 0, 255)));
+        });
+        assert_ne!(frame_digest(&state).digest, first.digest);
+    }
+
+    fn thumbnail_params() -> RenderEffectThumbnailParams {
+        RenderEffectThumbnailParams {
+            sequence_index: 0,
+            track_index: 0,
+            effect_index: 0,
+            time_samples: 4,
+            pixel_rows: 2,
+        }
+    }
+
+    fn render(state: &Arc<AppState>) -> EffectThumbnail {
+        match render_effect_thumbnail(state, thumbnail_params()).unwrap().result {
+            CommandResult::RenderEffectThumbnail(Some(thumb)) => thumb,
+            _ => panic!("expected a thumbnail"),
+        }
+    }
+
+    #[test]
+    fn identical_thumbnail_requests_hit_cache() {
+        let state = state_with_solid(Color::rgb(255, 0, 0));
+        let first = render(&state);
+        assert_eq!(state.thumbnail_cache.hits(), 0);
+        let second = render(&state);
+        assert_eq!(state.thumbnail_cache.hits(), 1);
+        assert_eq!(first.pixels, second.pixels);
+    }
+
+    #[test]
+    fn twinkle_thumbnails_are_reproducible() {
+        let thumbnail = |seed: Option<ParamValue>| {
+            let state = state_with_solid(Color::WHITE);
+            // The twinkle sits on the second track, whose slot seed isn't zero.
+            state.with_show_mut(|show| {
+                let tracks = &mut show.sequences[0].tracks;
+                tracks.insert(0, tracks[0].clone());
+                let effect = &mut tracks[1].effects[0];
+                effect.kind = EffectKind::Twinkle;
+                if let Some(seed) = seed {
+                    effect.params.set_mut(ParamKey::Seed, seed);
+                }
+            });
+            let p = RenderEffectThumbnailParams {
+                track_index: 1,
+                time_samples: 32,
+                pixel_rows: 16,
+                ..thumbnail_params()
+            };
+            match render_effect_thumbnail(&state, p).unwrap().result {
+                CommandResult::RenderEffectThumbnail(Some(thumb)) => thumb.pixels,
+                _ => panic!("expected a thumbnail"),
+            }
+        };
+
+        let first = thumbnail(None);
+        assert_eq!(first, thumbnail(None));
+        // Unseeded, it renders with the seed playback gives its slot.
+        let slot_seed = crate::engine::evaluator::default_seed(1, 0);
+        assert_eq!(first, thumbnail(Some(slot_seed)));
+        assert_ne!(first, thumbnail(Some(ParamValue::Int(0))));
+    }
+
+    #[test]
+    fn changed_param_misses_cache() {
+        let state = state_with_solid(Color::rgb(255, 0, 0));
+        render(&state);
+        state.with_show_mut(|show| {
+            let effect = &mut show.sequences[0].tracks[0].effects[0];
+            effect
+                .params
+                .set_mut(ParamKey::Color, ParamValue::Color(Color::rgb(0, 0, 255)));
+        });
+        let thumb = render(&state);
+        assert_eq!(state.thumbnail_cache.hits(), 0);
+        assert_eq!(thumb.pixels.get(..3), Some(&[0, 0, 255][..]));
+    }
+
+    #[test]
+    fn profile_counts_match_compiled_program() {
+        let state = state_with_solid(Color::rgb(255, 0, 0));
+        let compiled = crate::registry::handlers::common::compile_and_cache(
+            &state,
+            "Profiled".into(),
+            "let r = sin(t) * 0.5;\nrgb(r, pos, 1.0)",
+        );
+        assert!(compiled.success, "{:?}", compiled.errors);
+
+        let params = ProfileScriptParams {
+            name: "Profiled".into(),
+            params: EffectParams::new(),
+            pixel_count: 4,
+            t: 0.25,
+        };
+        let CommandResult::ProfileScript(profile) = profile_script(&state, params).unwrap().result
+        else {
+            panic!("expected a profile");
+        };
+
+        let cache = state.script_cache.lock();
+        let ops = &cache.get("Profiled").unwrap().ops;
+        // Straight-line script: every instruction runs exactly once per pixel.
+        assert_eq!(profile.total_ops, ops.len() as u64 * 4);
+        assert!((profile.ops_per_pixel - ops.len() as f64).abs() < 1e-9);
+        for count in &profile.ops {
+            let expected = ops
+                .iter()
+                .filter(|op| format!("{op:?}").split('(').next() == Some(count.op.as_str()))
+                .count() as u64;
+            assert_eq!(count.count, expected * 4, "{}", count.op);
+        }
+        assert!(profile.ops.iter().any(|c| c.op == "Sin" && c.count == 4));
+    }
+
+    #[test]
+    fn loop_wraps_to_region_start_frame() {
+        let state = state_with_solid(Color::rgb(255, 0, 0));
+        // A region start just off a frame boundary; 30 fps puts frame 10 at 1/3 s.
+        let start = 1.0 / 3.0 + 1e-9;
+        let end = 2.0;
+        {
+            let mut playback = state.playback.lock();
+            playback.playing = true;
+            playback.looping = true;
+            playback.region = Some((start, end));
+            // Parked on the region's last frame boundary.
+            playback.seek_frame(end, 30.0);
+            playback.clock_anchor = None;
+        }
+
+        let mut wrapped = None;
+        for _ in 0..10 {
+            std::thread::sleep(std::time::Duration::from_millis(1));
+            let output = tick(&state, TickParams { dt: 0.0, since: None, client: None }).unwrap();
+            let CommandResult::Tick(Some(result)) = output.result else {
+                panic!("expected a tick result");
+            };
+            if result.current_time < end - 1e-6 {
+                wrapped = Some(result.current_time);
+                break;
+            }
+        }
+        let wrapped = wrapped.unwrap();
+        assert_eq!(wrapped.to_bits(), (10.0f64 / 30.0).to_bits(), "wrapped to {wrapped}");
+        assert!(state.playback.lock().playing);
+    }
+
+    #[test]
+    fn static_solid_ticks_an_empty_delta_after_the_first_frame() {
+        use crate::model::fixture::{
+            BulbShape, ChannelOrder, ColorModel, FixtureDef, FixtureId, PixelType,
+        };
+
+        let state = state_with_solid(Color::rgb(0, 0, 255));
+        state.with_show_mut(|show| {
+            show.fixtures.push(FixtureDef {
+                id: FixtureId(1),
+                name: "Strip".into(),
+                color_model: ColorModel::Rgb,
+                pixel_count: 3,
+                pixel_type: PixelType::Smart,
+                bulb_shape: BulbShape::LED,
+                display_radius_override: None,
+                channel_order: ChannelOrder::Rgb,
+                gamma: None,
+            });
+        });
+        state.playback.lock().playing = true;
+
+        let tick_since = |since: Option<u64>| {
+            let output = tick(&state, TickParams { dt: 0.0, since, client: None }).unwrap();
+            let CommandResult::Tick(Some(result)) = output.result else {
+                panic!("expected a tick result");
+            };
+            result
+        };
+
+        let first = tick_since(None);
+        assert!(!first.delta);
+        assert_eq!(first.frame.fixtures.len(), 1);
+
+        let second = tick_since(Some(first.seq));
+        assert!(second.delta);
+        assert!(second.frame.fixtures.is_empty());
+        assert!(second.cleared.is_empty());
+
+        // A client that missed a frame gets the full frame to resync.
+        let resync = tick_since(Some(first.seq));
+        assert!(!resync.delta);
+        assert_eq!(resync.frame.fixtures.len(), 1);
+        assert_eq!(resync.seq, second.seq + 1);
+    }
+
+    #[test]
+    fn tick_clients_keep_separate_delta_baselines() {
+        let state = state_with_solid(Color::rgb(0, 0, 255));
+        state.playback.lock().playing = true;
+        let tick_as = |client: &str, since: Option<u64>| {
+            let params = TickParams { dt: 0.0, since, client: Some(client.into()) };
+            let CommandResult::Tick(Some(result)) = tick(&state, params).unwrap().result else {
+                panic!("expected a tick result");
+            };
+            result
+        };
+
+        let editor = tick_as("editor", None);
+        let preview = tick_as("preview", None);
+        // The preview's tick doesn't invalidate the editor's baseline.
+        let editor_next = tick_as("editor", Some(editor.seq));
+        assert!(editor_next.delta);
+        assert!(tick_as("preview", Some(preview.seq)).delta);
+        assert_eq!(editor_next.seq, editor.seq + 1);
+    }
+
+    #[test]
+    fn ticking_counts_frames_without_drift() {
+        let mut playback = crate::state::PlaybackState {
+            playing: true,
+            current_time: 0.0,
+            frame_index: 0,
+            sequence_index: 0,
+            clock_anchor: None,
+            region: None,
+            looping: false,
+            solo_tracks: std::collections::HashSet::new(),
+        };
+        let start = std::time::Instant::now();
+        playback.advance_to(start, 30.0);
+
+        // An hour of 30 fps ticks. Summing 1/30 this many times drifts by
+        // several ULPs; counting frames lands on the exact rational time.
+        let frames: u32 = 108_000;
+        let mut summed = 0.0f64;
+        for i in 1..=frames {
+            let now = start + std::time::Duration::from_secs_f64(f64::from(i) / 30.0);
+            playback.advance_to(now, 30.0);
+            summed += 1.0 / 30.0;
+        }
+        assert_eq!(playback.frame_index, u64::from(frames));
+        assert_eq!(playback.current_time.to_bits(), (f64::from(frames) / 30.0).to_bits());
+        assert_ne!(summed.to_bits(), playback.current_time.to_bits());
+    }
+
+    #[test]
+    fn frame_after_seek_is_served_from_prefetch() {
+        use crate::registry::execute::execute;
+        use crate::registry::params::{SeekParams, SetTrackMutedParams};
+        use crate::registry::Command;
+
+        let state = state_with_solid(Color::rgb(255, 0, 0));
+        execute(&state, Command::Seek(SeekParams { time: 2.0 })).unwrap();
+        state.frame_cache.wait_for_prefetch();
+
+        let time = state.playback.lock().current_time;
+        let hits = state.frame_cache.hits();
+        get_frame(&state, GetFrameParams { time }).unwrap();
+        assert_eq!(state.frame_cache.hits(), hits + 1);
+
+        // Editing the sequence drops everything that was prefetched.
+        *state.current_sequence.lock() = Some("seq".into());
+        let params = SetTrackMutedParams {
+            track_index: 0,
+            muted: true,
+        };
+        execute(&state, Command::SetTrackMuted(params)).unwrap();
+        get_frame(&state, GetFrameParams { time }).unwrap();
+        assert_eq!(state.frame_cache.hits(), hits + 1);
+    }
+}
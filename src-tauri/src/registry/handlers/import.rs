@@ -3,14 +3,34 @@
 use std::sync::Arc;
 
 use crate::error::AppError;
+use crate::import::vixen::VixenSequenceImport;
+use crate::model::{ColorGradient, Curve, Sequence};
 use crate::setup::{self, MEDIA_EXTENSIONS};
 use crate::registry::params::{
-    CheckVixenPreviewFileParams, ImportVixenParams, ImportVixenSetupParams,
-    ImportVixenSequenceParams, ScanVixenDirectoryParams,
+    CheckVixenPreviewFileParams, ExportXlightsParams, ImportLorParams, ImportVixenParams,
+    ImportVixenSetupParams, ImportVixenSequenceParams, ImportVixenSequencesParams,
+    ScanLorDirectoryParams,
+    ScanVixenDirectoryParams,
 };
 use crate::registry::{CommandOutput, CommandResult};
 use crate::state::{get_data_dir, AppState};
 
+/// Merge gradients and curves used by imported effects into the global
+/// library so they can be reused. Returns (gradients, curves) added.
+fn absorb_vixen_libraries(
+    state: &Arc<AppState>,
+    (gradients, curves): (Vec<ColorGradient>, Vec<Curve>),
+) -> (usize, usize) {
+    let added = state
+        .global_libraries
+        .lock()
+        .absorb_imported("Vixen", gradients, curves);
+    if added != (0, 0) {
+        super::global_lib::persist_inner(state);
+    }
+    added
+}
+
 pub fn import_vixen(
     state: &Arc<AppState>,
     p: ImportVixenParams,
@@ -19,7 +39,7 @@ pub fn import_vixen(
 
     let mut importer = crate::import::vixen::VixenImporter::new();
     importer
-        .parse_system_config(std::path::Path::new(&p.system_config_path))
+        .parse_system_config(std::path::Path::new(&p.system_config_path), None)
         .map_err(|e| AppError::ImportError {
             message: e.to_string(),
         })?;
@@ -32,6 +52,7 @@ pub fn import_vixen(
     }
 
     let guid_map = importer.guid_map().clone();
+    let library_items = importer.library_items();
     let show = importer.into_show();
 
     let setup_name = if show.name.is_empty() {
@@ -61,6 +82,7 @@ pub fn import_vixen(
         setup::save_sequence(&data_dir, &summary.slug, &seq_slug, seq)
             .map_err(AppError::from)?;
     }
+    absorb_vixen_libraries(state, library_items);
 
     let setups = setup::list_setups(&data_dir).map_err(AppError::from)?;
     let updated_summary = setups
@@ -79,7 +101,7 @@ pub fn import_vixen_setup(
 
     let mut importer = crate::import::vixen::VixenImporter::new();
     importer
-        .parse_system_config(std::path::Path::new(&p.system_config_path))
+        .parse_system_config(std::path::Path::new(&p.system_config_path), None)
         .map_err(|e| AppError::ImportError {
             message: e.to_string(),
         })?;
@@ -115,16 +137,14 @@ pub fn import_vixen_setup(
     ))
 }
 
-pub fn import_vixen_sequence(
-    state: &Arc<AppState>,
-    p: ImportVixenSequenceParams,
-) -> Result<CommandOutput, AppError> {
-    let data_dir = get_data_dir(state).map_err(|_| AppError::NoSettings)?;
-
-    let setup_data =
-        setup::load_setup(&data_dir, &p.setup_slug).map_err(AppError::from)?;
-    let guid_map =
-        setup::load_vixen_guid_map(&data_dir, &p.setup_slug).map_err(AppError::from)?;
+/// Rebuild a Vixen importer from a setup that was imported from Vixen, so
+/// sequences can be parsed against it without re-reading `SystemConfig.xml`.
+fn vixen_importer_for_setup(
+    data_dir: &std::path::Path,
+    setup_slug: &str,
+) -> Result<crate::import::vixen::VixenImporter, AppError> {
+    let setup_data = setup::load_setup(data_dir, setup_slug).map_err(AppError::from)?;
+    let guid_map = setup::load_vixen_guid_map(data_dir, setup_slug).map_err(AppError::from)?;
 
     if guid_map.is_empty() {
         return Err(AppError::ImportError {
@@ -132,13 +152,38 @@ pub fn import_vixen_sequence(
         });
     }
 
-    let mut importer = crate::import::vixen::VixenImporter::from_setup(
+    Ok(crate::import::vixen::VixenImporter::from_setup(
         setup_data.fixtures,
         setup_data.groups,
         setup_data.controllers,
         setup_data.patches,
         guid_map,
-    );
+    ))
+}
+
+/// Save an imported sequence into a setup and return its summary.
+fn save_imported_sequence(
+    data_dir: &std::path::Path,
+    setup_slug: &str,
+    seq: &Sequence,
+) -> Result<setup::SequenceSummary, AppError> {
+    let seq_slug = crate::project::slugify(&seq.name);
+    if let Err(e) = setup::create_sequence(data_dir, setup_slug, &seq.name) {
+        eprintln!("[VibeLights] Failed to create sequence entry: {e}");
+    }
+    setup::save_sequence(data_dir, setup_slug, &seq_slug, seq).map_err(AppError::from)?;
+    Ok(setup::SequenceSummary {
+        name: seq.name.clone(),
+        slug: seq_slug,
+    })
+}
+
+pub fn import_vixen_sequence(
+    state: &Arc<AppState>,
+    p: ImportVixenSequenceParams,
+) -> Result<CommandOutput, AppError> {
+    let data_dir = get_data_dir(state).map_err(|_| AppError::NoSettings)?;
+    let mut importer = vixen_importer_for_setup(&data_dir, &p.setup_slug)?;
 
     importer
         .parse_sequence(std::path::Path::new(&p.tim_path), None)
@@ -146,28 +191,63 @@ pub fn import_vixen_sequence(
             message: e.to_string(),
         })?;
 
+    let library_items = importer.library_items();
     let sequences = importer.into_sequences();
     let seq = sequences.into_iter().next().ok_or(AppError::ImportError {
         message: "No sequence parsed from file".into(),
     })?;
 
-    let seq_slug = crate::project::slugify(&seq.name);
-    if let Err(e) = setup::create_sequence(&data_dir, &p.setup_slug, &seq.name) {
-        eprintln!("[VibeLights] Failed to create sequence entry: {e}");
-    }
-    setup::save_sequence(&data_dir, &p.setup_slug, &seq_slug, &seq)
-        .map_err(AppError::from)?;
+    let summary = save_imported_sequence(&data_dir, &p.setup_slug, &seq)?;
+    absorb_vixen_libraries(state, library_items);
 
-    let summary = setup::SequenceSummary {
-        name: seq.name,
-        slug: seq_slug,
-    };
     Ok(CommandOutput::new(
         "Vixen sequence imported.",
         CommandResult::ImportVixenSequence(summary),
     ))
 }
 
+pub fn import_vixen_sequences(
+    state: &Arc<AppState>,
+    p: ImportVixenSequencesParams,
+) -> Result<CommandOutput, AppError> {
+    let data_dir = get_data_dir(state).map_err(|_| AppError::NoSettings)?;
+    let mut importer = vixen_importer_for_setup(&data_dir, &p.setup_slug)?;
+
+    let mut results = Vec::with_capacity(p.tim_paths.len());
+    for path in p.tim_paths {
+        let outcome = importer
+            .parse_sequence(std::path::Path::new(&path), None)
+            .map_err(|e| e.to_string())
+            .and_then(|()| {
+                importer
+                    .take_sequences()
+                    .into_iter()
+                    .next()
+                    .ok_or_else(|| "No sequence parsed from file".to_string())
+            })
+            .and_then(|seq| {
+                save_imported_sequence(&data_dir, &p.setup_slug, &seq).map_err(|e| e.to_string())
+            });
+        results.push(match outcome {
+            Ok(summary) => VixenSequenceImport { path, sequence: Some(summary), error: None },
+            Err(error) => VixenSequenceImport { path, sequence: None, error: Some(error) },
+        });
+    }
+    absorb_vixen_libraries(state, importer.library_items());
+
+    let imported = results.iter().filter(|r| r.sequence.is_some()).count();
+    let mut lines = vec![format!("Imported {imported} of {} Vixen sequences.", results.len())];
+    lines.extend(
+        results
+            .iter()
+            .filter_map(|r| r.error.as_ref().map(|e| format!("  Failed {}: {e}", r.path))),
+    );
+    Ok(CommandOutput::new(
+        lines.join("\n"),
+        CommandResult::ImportVixenSequences(results),
+    ))
+}
+
 pub fn scan_vixen_directory(_state: &Arc<AppState>, p: ScanVixenDirectoryParams) -> Result<CommandOutput, AppError> {
     use crate::import::vixen_preview;
 
@@ -184,7 +264,7 @@ pub fn scan_vixen_directory(_state: &Arc<AppState>, p: ScanVixenDirectoryParams)
 
     let mut importer = crate::import::vixen::VixenImporter::new();
     importer
-        .parse_system_config(&config_path)
+        .parse_system_config(&config_path, None)
         .map_err(|e| AppError::ImportError {
             message: e.to_string(),
         })?;
@@ -280,204 +360,342 @@ pub fn scan_vixen_directory(_state: &Arc<AppState>, p: ScanVixenDirectoryParams)
     Ok(CommandOutput::new("Vixen directory scanned.", CommandResult::ScanVixenDirectory(Box::new(discovery))))
 }
 
-// ── Async handler ────────────────────────────────────────────────
+// ── Light-O-Rama ─────────────────────────────────────────────────
 
-#[cfg(feature = "tauri-app")]
-#[allow(clippy::too_many_lines)]
-pub async fn execute_vixen_import(
-    state: Arc<AppState>,
-    app: Option<tauri::AppHandle>,
-    config: crate::import::vixen::VixenImportConfig,
+/// Files directly inside `dir` with extension `ext`, sorted by name.
+fn lor_files(dir: &std::path::Path, ext: &str) -> Vec<crate::import::lor::LorFileInfo> {
+    let mut files: Vec<_> = std::fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.path();
+            let matches = path
+                .extension()
+                .and_then(|e| e.to_str())
+                .is_some_and(|e| e.eq_ignore_ascii_case(ext));
+            if !matches || !path.is_file() {
+                return None;
+            }
+            Some(crate::import::lor::LorFileInfo {
+                filename: path.file_name().unwrap_or_default().to_string_lossy().to_string(),
+                path: path.to_string_lossy().to_string(),
+                size_bytes: entry.metadata().map_or(0, |m| m.len()),
+            })
+        })
+        .collect();
+    files.sort_by(|a, b| a.filename.cmp(&b.filename));
+    files
+}
+
+pub fn scan_lor_directory(
+    _state: &Arc<AppState>,
+    p: ScanLorDirectoryParams,
 ) -> Result<CommandOutput, AppError> {
-    let data_dir = get_data_dir(&state).map_err(|_| AppError::NoSettings)?;
-    let cancel_flag = state.cancellation.register("import");
+    let dir = std::path::Path::new(&p.lor_dir);
+    if !dir.is_dir() {
+        return Err(AppError::ImportError {
+            message: format!("Not a directory: {}", p.lor_dir),
+        });
+    }
+    let sequences = lor_files(dir, crate::import::LOR_SEQUENCE_EXT);
+    let channel_configs = lor_files(dir, crate::import::LOR_CHANNEL_CONFIG_EXT);
+    let msg = format!(
+        "Found {} LOR sequence(s) and {} channel config(s).",
+        sequences.len(),
+        channel_configs.len()
+    );
+    let discovery = crate::import::lor::LorDiscovery {
+        lor_dir: p.lor_dir,
+        sequences,
+        channel_configs,
+    };
+    Ok(CommandOutput::new(msg, CommandResult::ScanLorDirectory(discovery)))
+}
 
-    let app_ref = app.clone();
-    let emit = move |step: &str, msg: &str, pct: f64, detail: Option<&str>| {
-        if let Some(ref a) = app_ref {
-            crate::progress::emit_progress(a, step, msg, pct, detail);
-        }
+pub fn import_lor(state: &Arc<AppState>, p: ImportLorParams) -> Result<CommandOutput, AppError> {
+    let data_dir = get_data_dir(state).map_err(|_| AppError::NoSettings)?;
+    let path = std::path::Path::new(&p.path);
+
+    let mut importer = crate::import::lor::LorImporter::new();
+    importer.parse_file(path).map_err(|e| AppError::ImportError {
+        message: e.to_string(),
+    })?;
+    let mut warnings = importer.warnings().to_vec();
+    let (channels, effects) = (importer.channel_count(), importer.effect_count());
+    let audio_file = importer.audio_file().map(str::to_string);
+    let show = importer.into_show();
+
+    let setup_name = p
+        .setup_name
+        .filter(|n| !n.trim().is_empty())
+        .unwrap_or_else(|| show.name.clone());
+    let summary = setup::create_setup(&data_dir, &setup_name).map_err(AppError::from)?;
+    let s = setup::Setup {
+        name: setup_name,
+        slug: summary.slug.clone(),
+        fixtures: show.fixtures.clone(),
+        groups: show.groups.clone(),
+        controllers: show.controllers.clone(),
+        patches: show.patches.clone(),
+        layout: show.layout.clone(),
     };
+    setup::save_setup(&data_dir, &summary.slug, &s).map_err(AppError::from)?;
 
-    let emit2 = emit.clone();
-    let result = tokio::time::timeout(
-        tokio::time::Duration::from_secs(600),
-        tokio::task::spawn_blocking(move || {
-            let vixen_path = std::path::Path::new(&config.vixen_dir);
-            let config_path = vixen_path
-                .join(crate::import::VIXEN_SYSTEM_DATA_DIR)
-                .join(crate::import::VIXEN_SYSTEM_CONFIG_FILE);
-
-            emit2("import", "Parsing system config...", 0.05, None);
-            crate::state::check_cancelled(&cancel_flag, "import")?;
-            let mut importer = crate::import::vixen::VixenImporter::new();
-            importer
-                .parse_system_config(&config_path)
-                .map_err(|e| AppError::ImportError { message: e.to_string() })?;
-
-            crate::state::check_cancelled(&cancel_flag, "import")?;
-            let layout_items = if config.import_layout {
-                emit2("import", "Parsing layout...", 0.1, None);
-                let override_path = config
-                    .preview_file_override
-                    .as_deref()
-                    .map(std::path::Path::new);
-                match importer.parse_preview(vixen_path, override_path) {
-                    Ok(layouts) => layouts,
-                    Err(e) => {
-                        eprintln!("[VibeLights] Preview import warning: {e}");
-                        Vec::new()
-                    }
-                }
-            } else {
+    for seq in &show.sequences {
+        setup::create_sequence(&data_dir, &summary.slug, &seq.name)
+            .map_err(AppError::from)?;
+        let seq_slug = crate::project::slugify(&seq.name);
+        setup::save_sequence(&data_dir, &summary.slug, &seq_slug, seq)
+            .map_err(AppError::from)?;
+    }
+
+    // LOR records the music's absolute path on the machine that saved the
+    // sequence, so look for it next to the sequence file instead.
+    if let Some(file) = audio_file {
+        let source = path.with_file_name(&file);
+        if source.is_file() {
+            setup::import_media(&data_dir, &summary.slug, &source).map_err(AppError::from)?;
+        } else {
+            warnings.push(format!(
+                "Music file \"{file}\" was not found next to the sequence; import it separately."
+            ));
+        }
+    }
+
+    let setups = setup::list_setups(&data_dir).map_err(AppError::from)?;
+    let updated_summary = setups
+        .into_iter()
+        .find(|s| s.slug == summary.slug)
+        .unwrap_or(summary);
+
+    let mut lines = vec![format!("LOR import complete: {channels} channels, {effects} effects.")];
+    lines.extend(warnings.into_iter().map(|w| format!("  Warning: {w}")));
+    Ok(CommandOutput::new(lines.join("\n"), CommandResult::ImportLor(updated_summary)))
+}
+
+// ── Async handler ────────────────────────────────────────────────
+
+/// Run a full Vixen import from wizard configuration, reporting each phase
+/// through `emit(phase, progress, detail)` and stopping with
+/// `AppError::Cancelled` once `cancel_flag` is set.
+#[allow(clippy::too_many_lines)]
+pub fn run_vixen_import(
+    state: &Arc<AppState>,
+    data_dir: &std::path::Path,
+    config: &crate::import::vixen::VixenImportConfig,
+    cancel_flag: &std::sync::atomic::AtomicBool,
+    emit: &dyn Fn(&str, f64, Option<&str>),
+) -> Result<crate::import::vixen::VixenImportResult, AppError> {
+    let vixen_path = std::path::Path::new(&config.vixen_dir);
+    let config_path = vixen_path
+        .join(crate::import::VIXEN_SYSTEM_DATA_DIR)
+        .join(crate::import::VIXEN_SYSTEM_CONFIG_FILE);
+
+    emit("Parsing system config...", 0.05, None);
+    crate::state::check_cancelled(cancel_flag, "import")?;
+    let mut importer = crate::import::vixen::VixenImporter::new();
+    if let Some(epsilon) = config.curve_epsilon {
+        importer.set_curve_epsilon(epsilon);
+    }
+    if let Some(max) = config.max_effects {
+        importer.set_max_effects(max);
+    }
+    let config_progress = |phase: &str, frac: f64| emit(phase, 0.05 + 0.05 * frac, None);
+    importer
+        .parse_system_config(&config_path, Some(&config_progress))
+        .map_err(|e| AppError::ImportError { message: e.to_string() })?;
+
+    crate::state::check_cancelled(cancel_flag, "import")?;
+    let layout_items = if config.import_layout {
+        emit("Parsing layout...", 0.1, None);
+        let override_path = config
+            .preview_file_override
+            .as_deref()
+            .map(std::path::Path::new);
+        match importer.parse_preview(vixen_path, override_path) {
+            Ok(layouts) => layouts,
+            Err(e) => {
+                eprintln!("[VibeLights] Preview import warning: {e}");
                 Vec::new()
-            };
+            }
+        }
+    } else {
+        Vec::new()
+    };
 
-            crate::state::check_cancelled(&cancel_flag, "import")?;
-            let total_seqs = config.sequence_paths.len();
-            let mut sequences_imported = 0usize;
-            for (i, seq_path) in config.sequence_paths.iter().enumerate() {
-                crate::state::check_cancelled(&cancel_flag, "import")?;
-                #[allow(clippy::cast_precision_loss)]
-                let base_progress = 0.15 + 0.45 * (i as f64 / total_seqs.max(1) as f64);
-                #[allow(clippy::cast_precision_loss)]
-                let next_progress = 0.15 + 0.45 * ((i + 1) as f64 / total_seqs.max(1) as f64);
-                let detail = format!("Sequence {} of {}", i + 1, total_seqs);
-                emit2("import", "Parsing sequences...", base_progress, Some(&detail));
-                let emit3 = emit2.clone();
-                let detail2 = detail.clone();
-                let progress_cb = move |frac: f64| {
-                    let p = base_progress + (next_progress - base_progress) * frac;
-                    emit3("import", "Parsing sequences...", p, Some(&detail2));
-                };
-                match importer.parse_sequence(std::path::Path::new(seq_path), Some(&progress_cb)) {
-                    Ok(()) => sequences_imported += 1,
-                    Err(e) => {
-                        eprintln!("[VibeLights] Sequence import warning: {e}");
-                    }
-                }
+    crate::state::check_cancelled(cancel_flag, "import")?;
+    let total_seqs = config.sequence_paths.len();
+    let mut sequences_imported = 0usize;
+    for (i, seq_path) in config.sequence_paths.iter().enumerate() {
+        crate::state::check_cancelled(cancel_flag, "import")?;
+        #[allow(clippy::cast_precision_loss)]
+        let base_progress = 0.15 + 0.45 * (i as f64 / total_seqs.max(1) as f64);
+        #[allow(clippy::cast_precision_loss)]
+        let next_progress = 0.15 + 0.45 * ((i + 1) as f64 / total_seqs.max(1) as f64);
+        let detail = format!("Sequence {} of {}", i + 1, total_seqs);
+        emit("Parsing sequences...", base_progress, Some(&detail));
+        let progress_cb = |frac: f64| {
+            let p = base_progress + (next_progress - base_progress) * frac;
+            emit("Parsing sequences...", p, Some(&detail));
+        };
+        match importer.parse_sequence(std::path::Path::new(seq_path), Some(&progress_cb)) {
+            Ok(()) => sequences_imported += 1,
+            Err(e) => {
+                eprintln!("[VibeLights] Sequence import warning: {e}");
             }
+        }
+    }
 
-            let guid_map = importer.guid_map().clone();
-            let warnings: Vec<String> = importer.warnings().to_vec();
-            let show = importer.into_show();
+    let guid_map = importer.guid_map().clone();
+    let warnings: Vec<String> = importer.warnings().to_vec();
+    let library_items = importer.library_items();
+    let show = importer.into_show();
 
-            let fixtures_imported = show.fixtures.len();
-            let groups_imported = show.groups.len();
-            let controllers_imported = if config.import_controllers {
-                show.controllers.len()
-            } else {
-                0
-            };
-            let layout_items_imported = layout_items.len();
-
-            crate::state::check_cancelled(&cancel_flag, "import")?;
-            emit2("import", "Saving setup...", 0.65, None);
-            let setup_name = if config.setup_name.trim().is_empty() {
-                "Vixen Import".to_string()
-            } else {
-                config.setup_name.trim().to_string()
-            };
-            let summary =
-                setup::create_setup(&data_dir, &setup_name).map_err(AppError::from)?;
-
-            let layout = if layout_items.is_empty() {
-                show.layout.clone()
-            } else {
-                crate::model::show::Layout {
-                    fixtures: layout_items,
-                }
-            };
+    let fixtures_imported = show.fixtures.len();
+    let controllers_imported = if config.import_controllers {
+        show.controllers.len()
+    } else {
+        0
+    };
+    let layout_items_imported = layout_items.len();
 
-            let prof = setup::Setup {
-                name: setup_name,
-                slug: summary.slug.clone(),
-                fixtures: show.fixtures.clone(),
-                groups: show.groups.clone(),
-                controllers: if config.import_controllers {
-                    show.controllers.clone()
-                } else {
-                    Vec::new()
-                },
-                patches: if config.import_controllers {
-                    show.patches.clone()
-                } else {
-                    Vec::new()
-                },
-                layout,
-            };
-            setup::save_setup(&data_dir, &summary.slug, &prof).map_err(AppError::from)?;
-            setup::save_vixen_guid_map(&data_dir, &summary.slug, &guid_map)
-                .map_err(AppError::from)?;
-
-            crate::state::check_cancelled(&cancel_flag, "import")?;
-            let mut media_imported = 0usize;
-            if !config.media_filenames.is_empty() {
-                emit2("import", "Copying media files...", 0.70, None);
-                for media_filename in &config.media_filenames {
-                    crate::state::check_cancelled(&cancel_flag, "import")?;
-                    let source = vixen_path.join("Media").join(media_filename);
-                    if source.exists() {
-                        match setup::import_media(&data_dir, &summary.slug, &source) {
-                            Ok(_) => media_imported += 1,
-                            Err(e) => {
-                                eprintln!("[VibeLights] Media import warning: {e}");
-                            }
-                        }
+    crate::state::check_cancelled(cancel_flag, "import")?;
+    emit("Saving setup...", 0.65, None);
+    let setup_name = if config.setup_name.trim().is_empty() {
+        "Vixen Import".to_string()
+    } else {
+        config.setup_name.trim().to_string()
+    };
+    let summary = setup::create_setup(data_dir, &setup_name).map_err(AppError::from)?;
+
+    let layout = if layout_items.is_empty() {
+        show.layout.clone()
+    } else {
+        crate::model::show::Layout {
+            fixtures: layout_items,
+        }
+    };
+
+    let mut groups = show.groups.clone();
+    if config.group_by_position {
+        groups.extend(crate::import::cluster::spatial_groups(
+            &layout,
+            &groups,
+            crate::import::cluster::DEFAULT_CLUSTER_GAP,
+        ));
+    }
+    let groups_imported = groups.len();
+
+    let prof = setup::Setup {
+        name: setup_name,
+        slug: summary.slug.clone(),
+        fixtures: show.fixtures.clone(),
+        groups,
+        controllers: if config.import_controllers {
+            show.controllers.clone()
+        } else {
+            Vec::new()
+        },
+        patches: if config.import_controllers {
+            show.patches.clone()
+        } else {
+            Vec::new()
+        },
+        layout,
+    };
+    setup::save_setup(data_dir, &summary.slug, &prof).map_err(AppError::from)?;
+    setup::save_vixen_guid_map(data_dir, &summary.slug, &guid_map).map_err(AppError::from)?;
+
+    crate::state::check_cancelled(cancel_flag, "import")?;
+    let mut media_imported = 0usize;
+    if !config.media_filenames.is_empty() {
+        emit("Copying media files...", 0.70, None);
+        for media_filename in &config.media_filenames {
+            crate::state::check_cancelled(cancel_flag, "import")?;
+            let source = vixen_path.join("Media").join(media_filename);
+            if source.exists() {
+                match setup::import_media(data_dir, &summary.slug, &source) {
+                    Ok(_) => media_imported += 1,
+                    Err(e) => {
+                        eprintln!("[VibeLights] Media import warning: {e}");
                     }
                 }
             }
+        }
+    }
 
-            crate::state::check_cancelled(&cancel_flag, "import")?;
-            emit2("import", "Saving sequences...", 0.75, None);
-            for (i, seq) in show.sequences.iter().enumerate() {
-                crate::state::check_cancelled(&cancel_flag, "import")?;
-                #[allow(clippy::cast_precision_loss)]
-                let progress = 0.75 + 0.15 * (i as f64 / show.sequences.len().max(1) as f64);
-                emit2(
-                    "import",
-                    "Saving sequences...",
-                    progress,
-                    Some(&format!("Sequence {} of {}", i + 1, show.sequences.len())),
-                );
-                let mut seq = seq.clone();
-                if let Some(ref audio_path) = seq.audio_file {
-                    let audio_basename = std::path::Path::new(audio_path)
-                        .file_name()
-                        .map(|f| f.to_string_lossy().to_string());
-                    if let Some(ref basename) = audio_basename {
-                        if config.media_filenames.iter().any(|m| m == basename) {
-                            seq.audio_file = Some(basename.clone());
-                        } else {
-                            seq.audio_file = None;
-                        }
-                    }
-                }
-                if let Err(e) = setup::create_sequence(&data_dir, &summary.slug, &seq.name) {
-                    eprintln!("[VibeLights] Failed to create sequence entry: {e}");
+    crate::state::check_cancelled(cancel_flag, "import")?;
+    emit("Saving sequences...", 0.75, None);
+    for (i, seq) in show.sequences.iter().enumerate() {
+        crate::state::check_cancelled(cancel_flag, "import")?;
+        #[allow(clippy::cast_precision_loss)]
+        let progress = 0.75 + 0.15 * (i as f64 / show.sequences.len().max(1) as f64);
+        emit(
+            "Saving sequences...",
+            progress,
+            Some(&format!("Sequence {} of {}", i + 1, show.sequences.len())),
+        );
+        let mut seq = seq.clone();
+        if let Some(ref audio_path) = seq.audio_file {
+            let audio_basename = std::path::Path::new(audio_path)
+                .file_name()
+                .map(|f| f.to_string_lossy().to_string());
+            if let Some(ref basename) = audio_basename {
+                if config.media_filenames.iter().any(|m| m == basename) {
+                    seq.audio_file = Some(basename.clone());
+                } else {
+                    seq.audio_file = None;
                 }
-                let seq_slug = crate::project::slugify(&seq.name);
-                setup::save_sequence(&data_dir, &summary.slug, &seq_slug, &seq)
-                    .map_err(AppError::from)?;
             }
+        }
+        if let Err(e) = setup::create_sequence(data_dir, &summary.slug, &seq.name) {
+            eprintln!("[VibeLights] Failed to create sequence entry: {e}");
+        }
+        let seq_slug = crate::project::slugify(&seq.name);
+        setup::save_sequence(data_dir, &summary.slug, &seq_slug, &seq).map_err(AppError::from)?;
+    }
+    absorb_vixen_libraries(state, library_items);
+
+    emit("Import complete", 1.0, None);
+
+    Ok(crate::import::vixen::VixenImportResult {
+        setup_slug: summary.slug,
+        fixtures_imported,
+        groups_imported,
+        controllers_imported,
+        layout_items_imported,
+        sequences_imported,
+        media_imported,
+        warnings,
+    })
+}
 
-            emit2("import", "Import complete", 1.0, None);
-
-            Ok::<_, AppError>(crate::import::vixen::VixenImportResult {
-                setup_slug: summary.slug,
-                fixtures_imported,
-                groups_imported,
-                controllers_imported,
-                layout_items_imported,
-                sequences_imported,
-                media_imported,
-                warnings,
-            })
+#[cfg(feature = "tauri-app")]
+pub async fn execute_vixen_import(
+    state: Arc<AppState>,
+    app: Option<tauri::AppHandle>,
+    config: crate::import::vixen::VixenImportConfig,
+) -> Result<CommandOutput, AppError> {
+    let operation = state.cancellation.begin("import")?;
+    let data_dir = get_data_dir(&state).map_err(|_| AppError::NoSettings)?;
+    let cancel_flag = Arc::clone(operation.flag());
+
+    let app_ref = app.clone();
+    let lib_state = Arc::clone(&state);
+    let result = tokio::time::timeout(
+        tokio::time::Duration::from_secs(600),
+        tokio::task::spawn_blocking(move || {
+            let emit = |phase: &str, pct: f64, detail: Option<&str>| {
+                if let Some(ref a) = app_ref {
+                    crate::progress::emit_progress(a, "import", phase, pct, detail);
+                }
+            };
+            run_vixen_import(&lib_state, &data_dir, &config, &cancel_flag, &emit)
         }),
     )
     .await;
-
-    state.cancellation.unregister("import");
+    drop(operation);
 
     match result {
         Ok(join_result) => {
@@ -528,3 +746,156 @@ pub fn check_vixen_preview_file(
         CommandResult::CheckVixenPreviewFile(count),
     ))
 }
+
+pub fn export_xlights(
+    state: &Arc<AppState>,
+    p: ExportXlightsParams,
+) -> Result<CommandOutput, AppError> {
+    let export = {
+        let show = state.show.lock();
+        let index = state.active_sequence_index(&show)?;
+        let sequence = show.sequences.get(index).ok_or(AppError::NoSequence)?;
+        let libs = state.global_libraries.lock();
+        crate::export::xlights::export_sequence(&show, sequence, &libs.gradients, &libs.curves)
+    };
+    crate::project::atomic_write(std::path::Path::new(&p.path), export.xml.as_bytes())?;
+
+    let mut lines = vec![format!("Exported {} effects to {}.", export.effect_count, p.path)];
+    lines.extend(export.warnings.into_iter().map(|w| format!("  Warning: {w}")));
+    Ok(CommandOutput::new(lines.join("\n"), CommandResult::ExportXlights))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::panic, clippy::indexing_slicing)]
+mod tests {
+    use super::*;
+    use crate::model::Show;
+
+    const MINIMAL_TIM: &str = "<TimedSequenceData><Length>PT10S</Length></TimedSequenceData>";
+
+    #[test]
+    fn batch_import_saves_every_sequence_and_reports_failures() {
+        let dir = std::env::temp_dir().join("vibelights_test_vixen_batch");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let summary = setup::create_setup(&dir, "Vixen Setup").unwrap();
+        let guid_map = std::collections::HashMap::from([("node-guid".to_string(), 0)]);
+        setup::save_vixen_guid_map(&dir, &summary.slug, &guid_map).unwrap();
+
+        let tim = |name: &str| {
+            let path = dir.join(name);
+            std::fs::write(&path, MINIMAL_TIM).unwrap();
+            path.to_string_lossy().to_string()
+        };
+        let missing = dir.join("Missing.tim").to_string_lossy().to_string();
+
+        let state = Arc::new(AppState::for_test(Show::empty()));
+        *state.settings.lock() = Some(crate::settings::AppSettings::new(dir.clone()));
+
+        let out = import_vixen_sequences(
+            &state,
+            ImportVixenSequencesParams {
+                setup_slug: summary.slug.clone(),
+                tim_paths: vec![tim("Intro.tim"), missing.clone(), tim("Finale.tim")],
+            },
+        )
+        .unwrap();
+
+        let CommandResult::ImportVixenSequences(results) = out.result else {
+            panic!("unexpected result");
+        };
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].sequence.as_ref().unwrap().name, "Intro");
+        assert_eq!(results[1].path, missing);
+        assert!(results[1].sequence.is_none() && results[1].error.is_some());
+        assert_eq!(results[2].sequence.as_ref().unwrap().name, "Finale");
+
+        let mut names: Vec<_> = setup::list_sequences(&dir, &summary.slug)
+            .unwrap()
+            .into_iter()
+            .map(|s| s.name)
+            .collect();
+        names.sort();
+        assert_eq!(names, ["Finale", "Intro"]);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    fn vixen_project(dir: &std::path::Path) -> crate::import::vixen::VixenImportConfig {
+        let system = dir.join(crate::import::VIXEN_SYSTEM_DATA_DIR);
+        std::fs::create_dir_all(&system).unwrap();
+        std::fs::write(
+            system.join(crate::import::VIXEN_SYSTEM_CONFIG_FILE),
+            r#"<SystemConfig><Nodes><Node name="Star" id="star" channelId="c1" /></Nodes></SystemConfig>"#,
+        )
+        .unwrap();
+        let tim = dir.join("Intro.tim");
+        std::fs::write(&tim, MINIMAL_TIM).unwrap();
+        crate::import::vixen::VixenImportConfig {
+            vixen_dir: dir.to_string_lossy().to_string(),
+            setup_name: "Imported".into(),
+            import_controllers: false,
+            import_layout: false,
+            preview_file_override: None,
+            sequence_paths: vec![tim.to_string_lossy().to_string()],
+            media_filenames: Vec::new(),
+            curve_epsilon: None,
+            max_effects: None,
+            group_by_position: false,
+        }
+    }
+
+    #[test]
+    fn vixen_import_reports_phases_in_order() {
+        let dir = std::env::temp_dir().join("vibelights_test_vixen_progress");
+        let _ = std::fs::remove_dir_all(&dir);
+        let config = vixen_project(&dir);
+        let state = Arc::new(AppState::for_test(Show::empty()));
+
+        let phases = std::cell::RefCell::new(Vec::<String>::new());
+        let collect = |phase: &str, _: f64, _: Option<&str>| {
+            let mut phases = phases.borrow_mut();
+            if phases.last().is_none_or(|last| last != phase) {
+                phases.push(phase.to_string());
+            }
+        };
+        let cancel = std::sync::atomic::AtomicBool::new(false);
+        let result = run_vixen_import(&state, &dir, &config, &cancel, &collect).unwrap();
+        assert_eq!(result.fixtures_imported, 1);
+        assert_eq!(result.sequences_imported, 1);
+
+        let phases = phases.into_inner();
+        let major = [
+            "Parsing system config...",
+            "Parsing nodes...",
+            "Building fixtures...",
+            "Parsing sequences...",
+            "Saving setup...",
+            "Saving sequences...",
+            "Import complete",
+        ];
+        let positions: Vec<usize> = major
+            .iter()
+            .map(|m| phases.iter().position(|p| p == m).unwrap())
+            .collect();
+        assert!(positions.is_sorted(), "{phases:?}");
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn cancelled_vixen_import_stops_before_saving() {
+        let dir = std::env::temp_dir().join("vibelights_test_vixen_cancel");
+        let _ = std::fs::remove_dir_all(&dir);
+        let config = vixen_project(&dir);
+        let state = Arc::new(AppState::for_test(Show::empty()));
+
+        let cancel = std::sync::atomic::AtomicBool::new(true);
+        let result = run_vixen_import(&state, &dir, &config, &cancel, &|_, _, _| {});
+        assert!(matches!(result, Err(AppError::Cancelled { .. })));
+        assert!(setup::list_setups(&dir).unwrap().is_empty());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
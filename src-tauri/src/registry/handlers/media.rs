@@ -4,7 +4,9 @@ use std::sync::Arc;
 
 use crate::error::AppError;
 use crate::setup;
-use crate::registry::params::{ImportMediaParams, NameParams};
+use crate::registry::params::{
+    GetWaveformParams, ImportMediaParams, NameParams, UpdateSequenceSettingsParams,
+};
 use crate::registry::{CommandOutput, CommandResult};
 use crate::state::{get_data_dir, AppState};
 
@@ -27,7 +29,24 @@ pub fn import_media(
         std::path::Path::new(&p.source_path),
     )
     .map_err(AppError::from)?;
-    let msg = format!("Imported \"{}\".", media.filename);
+    // A re-import may have replaced the file's contents.
+    state.analysis_cache.lock().forget_audio(&media.filename);
+    let msg = match media.duration {
+        Some(duration) if p.set_duration => {
+            super::edit::update_sequence_settings(
+                state,
+                UpdateSequenceSettingsParams {
+                    name: None,
+                    audio_file: None,
+                    duration: Some(duration),
+                    frame_rate: None,
+                },
+            )?;
+            format!("Imported \"{}\"; sequence duration set to {duration:.2}s.", media.filename)
+        }
+        Some(duration) => format!("Imported \"{}\" ({duration:.2}s).", media.filename),
+        None => format!("Imported \"{}\".", media.filename),
+    };
     Ok(CommandOutput::new(msg, CommandResult::ImportMedia(media)))
 }
 
@@ -35,6 +54,7 @@ pub fn delete_media(state: &Arc<AppState>, p: NameParams) -> Result<CommandOutpu
     let data_dir = get_data_dir(state).map_err(|_| AppError::NoSettings)?;
     let setup_slug = state.require_setup()?;
     setup::delete_media(&data_dir, &setup_slug, &p.name).map_err(AppError::from)?;
+    state.analysis_cache.lock().forget_audio(&p.name);
     Ok(CommandOutput::new(
         format!("Media \"{}\" deleted.", p.name),
         CommandResult::DeleteMedia,
@@ -57,3 +77,76 @@ pub fn resolve_media_path(
     let path_str = path.to_string_lossy().to_string();
     Ok(CommandOutput::new(path_str.clone(), CommandResult::ResolveMediaPath(path_str)))
 }
+
+pub fn get_waveform(
+    state: &Arc<AppState>,
+    p: GetWaveformParams,
+) -> Result<CommandOutput, AppError> {
+    let audio_file = state
+        .with_show(|show| show.sequences.first().and_then(|s| s.audio_file.clone()))
+        .ok_or(AppError::ValidationError {
+            message: "No audio file in current sequence".into(),
+        })?;
+
+    let key = (audio_file, p.buckets);
+    if let Some(cached) = state.waveform_cache.lock().get(&key) {
+        return Ok(CommandOutput::new(
+            format!("Waveform from cache ({} buckets).", p.buckets),
+            CommandResult::GetWaveform(cached.clone()),
+        ));
+    }
+
+    setup::validate_filename(&key.0).map_err(AppError::from)?;
+    let data_dir = get_data_dir(state).map_err(|_| AppError::NoSettings)?;
+    let setup_slug = state.require_setup()?;
+    let path = crate::paths::media_dir(&data_dir, &setup_slug).join(&key.0);
+    if !path.exists() {
+        return Err(AppError::NotFound {
+            what: format!("Audio file: {}", key.0),
+        });
+    }
+
+    let waveform = crate::waveform::compute_waveform(&path, p.buckets)?;
+    state.cache_waveform(key, waveform.clone());
+    Ok(CommandOutput::new(
+        format!("Waveform computed ({} buckets, {:.1}s).", p.buckets, waveform.duration),
+        CommandResult::GetWaveform(waveform),
+    ))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::panic, clippy::indexing_slicing)]
+mod tests {
+    use super::*;
+    use crate::model::{Sequence, Show};
+
+    #[test]
+    fn imported_wav_sets_sequence_duration() {
+        let dir = std::env::temp_dir().join("vibelights_test_import_media");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let source = dir.join("song.wav");
+        // 2.5 seconds of silence at 8 kHz.
+        crate::waveform::write_wav(&source, 8000, &vec![0; 20_000]);
+
+        let mut show = Show::empty();
+        show.sequences.push(Sequence::for_test("Seq", 60.0, Vec::new()));
+        let state = Arc::new(AppState::for_test(show));
+        *state.settings.lock() = Some(crate::settings::AppSettings::new(dir.join("data")));
+        *state.current_setup.lock() = Some("stage".into());
+        *state.current_sequence.lock() = Some("seq".into());
+
+        let p = ImportMediaParams {
+            source_path: source.to_string_lossy().to_string(),
+            set_duration: true,
+        };
+        let output = import_media(&state, p).unwrap();
+        let CommandResult::ImportMedia(media) = output.result else {
+            panic!("expected ImportMedia");
+        };
+        assert!((media.duration.unwrap() - 2.5).abs() < 1e-9);
+        assert!((state.show.lock().sequences[0].duration - 2.5).abs() < 1e-9);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
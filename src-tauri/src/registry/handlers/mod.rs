@@ -7,6 +7,7 @@ pub mod global_lib;
 pub mod hot;
 pub mod import;
 pub mod media;
+pub mod output;
 pub mod playback;
 pub mod python;
 pub mod setup;
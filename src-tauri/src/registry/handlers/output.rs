@@ -0,0 +1,156 @@
+#![allow(clippy::needless_pass_by_value)]
+
+use std::fmt::Write;
+use std::sync::Arc;
+
+use serde::Serialize;
+use ts_rs::TS;
+
+use crate::error::AppError;
+use crate::model::fixture::FixtureId;
+use crate::output::{self, Identify, LiveOutputSession};
+use crate::registry::params::{IdentifyFixtureParams, StartLiveOutputParams};
+use crate::registry::{CommandOutput, CommandResult};
+use crate::state::AppState;
+
+/// Typed return for StartLiveOutput.
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct LiveOutputInfo {
+    /// Controllers now receiving frames.
+    pub controllers: Vec<String>,
+    /// Controllers left out, with the reason.
+    pub skipped: Vec<String>,
+}
+
+/// Highest controller update rate accepted, in frames per second.
+const MAX_OUTPUT_FPS: f64 = 120.0;
+
+/// Longest identify override accepted, in seconds.
+const MAX_IDENTIFY_SECONDS: f64 = 600.0;
+
+pub fn start_live_output(
+    state: &Arc<AppState>,
+    p: StartLiveOutputParams,
+) -> Result<CommandOutput, AppError> {
+    if let Some(fps) = p.output_fps {
+        if !fps.is_finite() || fps <= 0.0 || fps > MAX_OUTPUT_FPS {
+            return Err(AppError::ValidationError {
+                message: format!("Output frame rate must be above 0 and at most {MAX_OUTPUT_FPS}."),
+            });
+        }
+    }
+
+    // Stop any previous session before opening new sockets.
+    if let Some(session) = state.live_output.lock().take() {
+        session.stop();
+    }
+
+    let controllers = state.show.lock().controllers.clone();
+    let mut senders = Vec::new();
+    let mut info = LiveOutputInfo {
+        controllers: Vec::new(),
+        skipped: Vec::new(),
+    };
+    for controller in &controllers {
+        match output::sender_for(controller) {
+            Ok(Some(sender)) => {
+                info.controllers.push(controller.name.clone());
+                senders.push(sender);
+            }
+            Ok(None) => info
+                .skipped
+                .push(format!("{}: protocol not supported for live output", controller.name)),
+            Err(e) => info.skipped.push(format!("{}: {e}", controller.name)),
+        }
+    }
+
+    if senders.is_empty() {
+        return Err(AppError::ValidationError {
+            message: "No controllers support live output (configure a DDP controller).".into(),
+        });
+    }
+
+    *state.live_output.lock() = Some(LiveOutputSession::start(
+        Arc::clone(state),
+        senders,
+        p.output_fps,
+    ));
+    Ok(CommandOutput::new(
+        format!("Live output started to {} controller(s).", info.controllers.len()),
+        CommandResult::StartLiveOutput(info),
+    ))
+}
+
+/// Light one fixture on the live output for a while, bypassing the timeline.
+/// Transient: nothing in the show changes, so it isn't undoable.
+pub fn identify_fixture(
+    state: &Arc<AppState>,
+    p: IdentifyFixtureParams,
+) -> Result<CommandOutput, AppError> {
+    if !p.duration.is_finite() || p.duration <= 0.0 || p.duration > MAX_IDENTIFY_SECONDS {
+        return Err(AppError::ValidationError {
+            message: format!(
+                "Identify duration must be above 0 and at most {MAX_IDENTIFY_SECONDS}s."
+            ),
+        });
+    }
+    let name = state
+        .with_show(|show| {
+            show.fixtures
+                .iter()
+                .find(|f| f.id.0 == p.fixture_id)
+                .map(|f| f.name.clone())
+        })
+        .ok_or_else(|| AppError::ValidationError {
+            message: format!("Fixture with ID {} does not exist", p.fixture_id),
+        })?;
+
+    let live_output = state.live_output.lock();
+    let session = live_output.as_ref().ok_or(AppError::ValidationError {
+        message: "Live output is not running. Start it to identify fixtures.".into(),
+    })?;
+    session.identify(Identify::new(
+        FixtureId(p.fixture_id),
+        p.chase,
+        std::time::Duration::from_secs_f64(p.duration),
+    ));
+    Ok(CommandOutput::new(
+        format!("Identifying \"{name}\" for {:.0}s.", p.duration),
+        CommandResult::IdentifyFixture,
+    ))
+}
+
+pub fn stop_live_output(state: &Arc<AppState>) -> Result<CommandOutput, AppError> {
+    let message = match state.live_output.lock().take() {
+        Some(session) => {
+            session.stop();
+            "Live output stopped."
+        }
+        None => "Live output was not running.",
+    };
+    Ok(CommandOutput::new(message, CommandResult::StopLiveOutput))
+}
+
+pub fn get_output_stats(state: &Arc<AppState>) -> Result<CommandOutput, AppError> {
+    let Some(stats) = state.live_output.lock().as_ref().map(LiveOutputSession::stats) else {
+        return Ok(CommandOutput::new(
+            "Live output is not running.",
+            CommandResult::GetOutputStats(Vec::new()),
+        ));
+    };
+    let lines: Vec<String> = stats
+        .iter()
+        .map(|s| {
+            let mut line = format!(
+                "{}: {} packets sent, {} send errors",
+                s.controller, s.packets_sent, s.send_errors
+            );
+            if let Some(e) = &s.last_error {
+                let _ = write!(line, " (last: {e})");
+            }
+            line
+        })
+        .collect();
+    Ok(CommandOutput::new(lines.join("\n"), CommandResult::GetOutputStats(stats)))
+}
@@ -1,24 +1,30 @@
 #![allow(clippy::needless_pass_by_value)]
 
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Instant;
 
+use crate::dispatcher::CommandDispatcher;
 use crate::error::AppError;
-use crate::registry::params::{SeekParams, SetLoopingParams, SetRegionParams};
+use crate::model::Show;
+use crate::registry::params::{
+    GoToCueParams, SeekParams, SetLoopingParams, SetRegionParams, SetTrackMutedParams,
+    SetTrackSoloParams,
+};
 use crate::registry::{CommandOutput, CommandResult};
 use crate::state::{AppState, PlaybackInfo};
 
 pub fn play(state: &Arc<AppState>) -> Result<CommandOutput, AppError> {
     let mut playback = state.playback.lock();
     playback.playing = true;
-    playback.last_tick = Some(Instant::now());
+    playback.clock_anchor = Some((Instant::now(), playback.frame_index));
     Ok(CommandOutput::new("Playing.", CommandResult::Play))
 }
 
 pub fn pause(state: &Arc<AppState>) -> Result<CommandOutput, AppError> {
     let mut playback = state.playback.lock();
     playback.playing = false;
-    playback.last_tick = None;
+    playback.clock_anchor = None;
     Ok(CommandOutput::new("Paused.", CommandResult::Pause))
 }
 
@@ -30,41 +36,122 @@ pub fn seek(state: &Arc<AppState>, p: SeekParams) -> Result<CommandOutput, AppEr
     }
 
     // Read sequence duration from show first (lock ordering: show before playback).
-    let duration = {
+    let (duration, frame_rate) = {
         let show = state.show.lock();
         let playback = state.playback.lock();
         show.sequences
             .get(playback.sequence_index)
-            .map_or(0.0, |s| s.duration)
+            .map_or((0.0, 0.0), |s| (s.duration, s.frame_rate))
     };
 
     let mut playback = state.playback.lock();
-    playback.current_time = p.time.clamp(0.0, duration);
-    if playback.playing {
-        playback.last_tick = Some(Instant::now());
+    playback.seek_frame(p.time.clamp(0.0, duration), frame_rate);
+    playback.clock_anchor = if playback.playing {
+        Some((Instant::now(), playback.frame_index))
     } else {
-        playback.last_tick = None;
-    }
+        None
+    };
+    let (sequence_index, frame_index, current_time) =
+        (playback.sequence_index, playback.frame_index, playback.current_time);
+    drop(playback);
+
+    super::hot::prefetch_frames(state, sequence_index, frame_index);
     Ok(CommandOutput::new(
-        format!("Seeked to {:.1}s.", playback.current_time),
+        format!("Seeked to {current_time:.1}s."),
         CommandResult::Seek,
     ))
 }
 
+/// Seek to a cue's start. A ranged cue can also become the looping region,
+/// so a section can be rehearsed on repeat.
+pub fn go_to_cue(state: &Arc<AppState>, p: GoToCueParams) -> Result<CommandOutput, AppError> {
+    let cue = {
+        let show = state.show.lock();
+        let seq_idx = state.active_sequence_index(&show)?;
+        show.sequences
+            .get(seq_idx)
+            .and_then(|s| s.cues.get(p.cue_index))
+            .cloned()
+            .ok_or_else(|| AppError::InvalidIndex { what: "cue".into(), index: p.cue_index })?
+    };
+
+    if p.loop_region {
+        if let Some(end) = cue.end {
+            state.with_playback_mut(|playback| {
+                playback.region = Some((cue.time, end));
+                playback.looping = true;
+            });
+        }
+    }
+    seek(state, SeekParams { time: cue.time })?;
+
+    Ok(CommandOutput::new(
+        format!("Went to cue \"{}\" at {:.1}s.", cue.name, cue.time),
+        CommandResult::GoToCue,
+    ))
+}
+
 pub fn undo(state: &Arc<AppState>) -> Result<CommandOutput, AppError> {
-    let mut dispatcher = state.dispatcher.lock();
-    let mut show = state.show.lock();
-    let desc = dispatcher.undo(&mut show)?;
+    let desc = restore_snapshot(state, CommandDispatcher::undo)?;
     Ok(CommandOutput::new(format!("Undone: {desc}"), CommandResult::Undo))
 }
 
 pub fn redo(state: &Arc<AppState>) -> Result<CommandOutput, AppError> {
-    let mut dispatcher = state.dispatcher.lock();
-    let mut show = state.show.lock();
-    let desc = dispatcher.redo(&mut show)?;
+    let desc = restore_snapshot(state, CommandDispatcher::redo)?;
     Ok(CommandOutput::new(format!("Redone: {desc}"), CommandResult::Redo))
 }
 
+/// Run an undo or redo, which restores a whole-sequence snapshot.
+///
+/// Mutes are view state outside the undo stack, so the current ones are
+/// carried across by track name. Solo indices can't be followed through a
+/// snapshot, so the solo is dropped if the active sequence's tracks changed.
+/// `playback` is only locked once `show` is released, as `tick` takes them in
+/// the other order.
+fn restore_snapshot(
+    state: &AppState,
+    restore: fn(&mut CommandDispatcher, &mut Show) -> Result<String, AppError>,
+) -> Result<String, AppError> {
+    let sequence_index = state.playback.lock().sequence_index;
+    let (desc, tracks_changed) = {
+        let mut dispatcher = state.dispatcher.lock();
+        let mut show = state.show.lock();
+        let tracks_before = track_names(&show, sequence_index);
+        let mutes = track_mutes(&show);
+        let desc = restore(&mut dispatcher, &mut show)?;
+        restore_track_mutes(&mut show, &mutes);
+        (desc, track_names(&show, sequence_index) != tracks_before)
+    };
+    if tracks_changed {
+        state.playback.lock().solo_tracks.clear();
+    }
+    Ok(desc)
+}
+
+fn track_names(show: &Show, sequence_index: usize) -> Vec<String> {
+    show.sequences
+        .get(sequence_index)
+        .map(|s| s.tracks.iter().map(|t| t.name.clone()).collect())
+        .unwrap_or_default()
+}
+
+fn track_mutes(show: &Show) -> Vec<HashMap<String, bool>> {
+    show.sequences
+        .iter()
+        .map(|s| s.tracks.iter().map(|t| (t.name.clone(), t.muted)).collect())
+        .collect()
+}
+
+fn restore_track_mutes(show: &mut Show, mutes: &[HashMap<String, bool>]) {
+    for (sequence, mutes) in show.sequences.iter_mut().zip(mutes) {
+        for track in &mut sequence.tracks {
+            if let Some(&muted) = mutes.get(&track.name) {
+                track.muted = muted;
+            }
+        }
+    }
+}
+
 pub fn get_playback(state: &Arc<AppState>) -> Result<CommandOutput, AppError> {
     let playback = state.playback.lock();
     let show = state.show.lock();
@@ -79,6 +166,11 @@ pub fn get_playback(state: &Arc<AppState>) -> Result<CommandOutput, AppError> {
         sequence_index: playback.sequence_index,
         region: playback.region,
         looping: playback.looping,
+        solo_tracks: {
+            let mut solo: Vec<usize> = playback.solo_tracks.iter().copied().collect();
+            solo.sort_unstable();
+            solo
+        },
     };
     Ok(CommandOutput::new("Playback state", CommandResult::GetPlayback(info)))
 }
@@ -107,7 +199,60 @@ pub fn set_looping(
     ))
 }
 
+/// Mute or unmute a track. A view state for auditioning, so it bypasses the
+/// undo stack.
+pub fn set_track_muted(
+    state: &Arc<AppState>,
+    p: SetTrackMutedParams,
+) -> Result<CommandOutput, AppError> {
+    let mut show = state.show.lock();
+    let seq_idx = state.active_sequence_index(&show)?;
+    let track = show
+        .sequences
+        .get_mut(seq_idx)
+        .and_then(|s| s.tracks.get_mut(p.track_index))
+        .ok_or(AppError::InvalidIndex {
+            what: "track".into(),
+            index: p.track_index,
+        })?;
+    track.muted = p.muted;
+    let verb = if p.muted { "Muted" } else { "Unmuted" };
+    Ok(CommandOutput::new(
+        format!("{verb} track {}.", p.track_index),
+        CommandResult::SetTrackMuted,
+    ))
+}
+
+/// Add or remove a track from the transient solo set.
+pub fn set_track_solo(
+    state: &Arc<AppState>,
+    p: SetTrackSoloParams,
+) -> Result<CommandOutput, AppError> {
+    let track_count = {
+        let show = state.show.lock();
+        let seq_idx = state.active_sequence_index(&show)?;
+        show.sequences.get(seq_idx).map_or(0, |s| s.tracks.len())
+    };
+    if p.track_index >= track_count {
+        return Err(AppError::InvalidIndex {
+            what: "track".into(),
+            index: p.track_index,
+        });
+    }
+    let mut playback = state.playback.lock();
+    if p.solo {
+        playback.solo_tracks.insert(p.track_index);
+    } else {
+        playback.solo_tracks.remove(&p.track_index);
+    }
+    let message = match playback.solo_tracks.len() {
+        0 => "Solo cleared.".to_string(),
+        n => format!("{n} track(s) soloed."),
+    };
+    Ok(CommandOutput::new(message, CommandResult::SetTrackSolo))
+}
+
 pub fn get_undo_state(state: &Arc<AppState>) -> Result<CommandOutput, AppError> {
-    let undo_state = state.with_dispatcher(crate::dispatcher::CommandDispatcher::undo_state);
+    let undo_state = state.with_dispatcher(CommandDispatcher::undo_state);
     Ok(CommandOutput::new("Undo state", CommandResult::GetUndoState(undo_state)))
 }
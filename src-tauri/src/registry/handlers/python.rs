@@ -40,18 +40,17 @@ pub async fn setup_python_env(
     state: Arc<AppState>,
     app: Option<tauri::AppHandle>,
 ) -> Result<CommandOutput, AppError> {
+    let operation = state.cancellation.begin("python_setup")?;
     let app_handle = app.ok_or_else(|| AppError::ApiError {
         message: "AppHandle required for setup_python_env".into(),
     })?;
-    let cancel_flag = state.cancellation.register("python_setup");
 
     let result = tokio::time::timeout(
         tokio::time::Duration::from_secs(900),
-        python::bootstrap_python(&app_handle, &state.app_config_dir, &cancel_flag),
+        python::bootstrap_python(&app_handle, &state.app_config_dir, operation.flag()),
     )
     .await;
-
-    state.cancellation.unregister("python_setup");
+    drop(operation);
 
     match result {
         Ok(inner) => inner?,
@@ -1,15 +1,50 @@
 #![allow(clippy::needless_pass_by_value)]
 
+use std::collections::HashMap;
+use std::fmt::Write;
 use std::sync::Arc;
 
+use serde::Serialize;
+use ts_rs::TS;
+
 use crate::describe;
 use crate::effects::resolve_effect;
 use crate::error::AppError;
-use crate::registry::params::{GetEffectDetailParams, HelpParams};
+use crate::model::{EffectInstance, EffectKind, EffectTarget, FixtureId, ParamValue};
+use crate::registry::params::{
+    AnalyzeShowComplexityParams, EffectLocation, FindEffectsParams, GetEffectDetailParams,
+    HelpParams, ListEffectInstancesParams, ParamComparator, ParamPredicate,
+};
 use crate::registry::{catalog, reference};
-use crate::registry::{CommandOutput, CommandResult};
+use crate::registry::{CommandOutput, CommandResult, JsonValue};
 use crate::state::{AppState, EffectDetail, EffectInfo};
 
+/// One placed effect, located by its indices in the active sequence.
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct EffectInstanceEntry {
+    pub track_index: usize,
+    pub effect_index: usize,
+    pub kind: EffectKind,
+    pub start: f64,
+    pub end: f64,
+}
+
+/// Load figures for one track of the active sequence.
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct TrackComplexity {
+    pub track_index: usize,
+    pub name: String,
+    pub effect_count: usize,
+    /// Most effects playing at the same instant.
+    pub max_overlap: usize,
+    /// Pixels the track's target covers, rendered once per playing effect.
+    pub pixel_count: u32,
+    /// Why the track was flagged; empty when it is within every threshold.
+    pub warnings: Vec<String>,
+}
+
 pub fn get_show(state: &Arc<AppState>) -> Result<CommandOutput, AppError> {
     let show = state.show.lock();
     let summary = describe::summarize_show(&show);
@@ -47,6 +82,17 @@ pub fn describe_show(state: &Arc<AppState>) -> Result<CommandOutput, AppError> {
     Ok(CommandOutput::new(text.clone(), CommandResult::DescribeShow(text)))
 }
 
+pub fn list_markers(state: &Arc<AppState>) -> Result<CommandOutput, AppError> {
+    let show = state.show.lock();
+    let seq_idx = state.active_sequence_index(&show)?;
+    let markers = show.sequences.get(seq_idx).map(|s| s.markers.clone()).unwrap_or_default();
+    let mut lines = vec![format!("{} markers:", markers.len())];
+    for (i, m) in markers.iter().enumerate() {
+        lines.push(format!("  [{i}] {:.2}s \"{}\"", m.time, m.label));
+    }
+    Ok(CommandOutput::new(lines.join("\n"), CommandResult::ListMarkers(markers)))
+}
+
 pub fn get_effect_detail(
     state: &Arc<AppState>,
     p: GetEffectDetailParams,
@@ -86,6 +132,9 @@ pub fn get_effect_detail(
         track_name: track.name.clone(),
         blend_mode: effect_instance.blend_mode,
         opacity: effect_instance.opacity,
+        opacity_curve: effect_instance.opacity_curve.clone(),
+        fade_in: effect_instance.fade_in,
+        fade_out: effect_instance.fade_out,
     };
 
     let effect_desc = crate::describe::describe_effect(effect_instance);
@@ -96,7 +145,388 @@ pub fn get_effect_detail(
     Ok(CommandOutput::new(message, CommandResult::GetEffectDetail(detail)))
 }
 
+pub fn list_effect_instances(
+    state: &Arc<AppState>,
+    p: ListEffectInstancesParams,
+) -> Result<CommandOutput, AppError> {
+    let show = state.show.lock();
+    let seq_idx = state.active_sequence_index(&show)?;
+    let sequence = show.sequences.get(seq_idx).ok_or(AppError::NoSequence)?;
+    let start = p.start.unwrap_or(f64::NEG_INFINITY);
+    let end = p.end.unwrap_or(f64::INFINITY);
+
+    let entries: Vec<EffectInstanceEntry> = sequence
+        .tracks
+        .iter()
+        .enumerate()
+        .flat_map(|(track_index, track)| {
+            track.effects.iter().enumerate().map(move |(effect_index, effect)| {
+                EffectInstanceEntry {
+                    track_index,
+                    effect_index,
+                    kind: effect.kind.clone(),
+                    start: effect.time_range.start(),
+                    end: effect.time_range.end(),
+                }
+            })
+        })
+        .filter(|e| e.end >= start && e.start <= end)
+        .filter(|e| p.kind.as_ref().is_none_or(|kind| *kind == e.kind))
+        .collect();
+
+    let mut lines = vec![format!("{} effects:", entries.len())];
+    for e in &entries {
+        lines.push(format!(
+            "  [{}:{}] {:?} {:.2}s-{:.2}s",
+            e.track_index, e.effect_index, e.kind, e.start, e.end
+        ));
+    }
+    Ok(CommandOutput::new(lines.join("\n"), CommandResult::ListEffectInstances(entries)))
+}
+
+/// Whether `effect`'s param (or its schema default when unset) satisfies
+/// `predicate`. Params the effect has no value or default for never match.
+fn predicate_matches(effect: &EffectInstance, predicate: &ParamPredicate) -> bool {
+    let actual = effect.params.get(&predicate.key).cloned().or_else(|| {
+        resolve_effect(&effect.kind)?
+            .param_schema()
+            .into_iter()
+            .find(|s| s.key == predicate.key)
+            .map(|s| s.default)
+    });
+    let Some(actual) = actual else {
+        return false;
+    };
+
+    if let (Some(a), Some(b)) = (actual.as_float(), predicate.value.as_float()) {
+        return match predicate.op {
+            ParamComparator::Eq => (a - b).abs() < f64::EPSILON,
+            ParamComparator::Ne => (a - b).abs() >= f64::EPSILON,
+            ParamComparator::Lt => a < b,
+            ParamComparator::Le => a <= b,
+            ParamComparator::Gt => a > b,
+            ParamComparator::Ge => a >= b,
+        };
+    }
+    let same = || {
+        serde_json::to_value(&actual).ok() == serde_json::to_value(&predicate.value).ok()
+    };
+    match predicate.op {
+        ParamComparator::Eq => same(),
+        ParamComparator::Ne => !same(),
+        _ => false,
+    }
+}
+
+fn param_value_label(value: &ParamValue) -> String {
+    value.as_float().map_or_else(|| format!("{value:?}"), |v| v.to_string())
+}
+
+pub fn find_effects(state: &Arc<AppState>, p: FindEffectsParams) -> Result<CommandOutput, AppError> {
+    let show = state.show.lock();
+    let seq_idx = state.active_sequence_index(&show)?;
+    let sequence = show.sequences.get(seq_idx).ok_or(AppError::NoSequence)?;
+
+    let found: Vec<EffectLocation> = sequence
+        .tracks
+        .iter()
+        .enumerate()
+        .flat_map(|(track_index, track)| {
+            track
+                .effects
+                .iter()
+                .enumerate()
+                .map(move |(effect_index, effect)| (track_index, effect_index, effect))
+        })
+        .filter(|(_, _, effect)| p.kind.as_ref().is_none_or(|kind| *kind == effect.kind))
+        .filter(|(_, _, effect)| p.predicates.iter().all(|pr| predicate_matches(effect, pr)))
+        .map(|(track_index, effect_index, _)| EffectLocation {
+            track_index,
+            effect_index,
+        })
+        .collect();
+
+    let criteria: Vec<String> = p
+        .kind
+        .iter()
+        .map(|k| format!("{k:?}"))
+        .chain(p.predicates.iter().map(|pr| {
+            format!("{} {:?} {}", pr.key, pr.op, param_value_label(&pr.value))
+        }))
+        .collect();
+    let mut lines = vec![if criteria.is_empty() {
+        format!("{} effects found:", found.len())
+    } else {
+        format!("{} effects found matching {}:", found.len(), criteria.join(", "))
+    }];
+    for loc in &found {
+        lines.push(format!("  [{}:{}]", loc.track_index, loc.effect_index));
+    }
+    Ok(CommandOutput::new(lines.join("\n"), CommandResult::FindEffects(found)))
+}
+
+/// Most of `effects` active at once. An effect ending exactly when another
+/// starts does not overlap it.
+fn max_overlap(effects: &[EffectInstance]) -> usize {
+    let mut edges: Vec<(f64, bool)> = effects
+        .iter()
+        .flat_map(|e| [(e.time_range.start(), true), (e.time_range.end(), false)])
+        .collect();
+    // Ends sort before starts at the same time.
+    edges.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)));
+    let mut active = 0usize;
+    let mut most = 0;
+    for (_, is_start) in edges {
+        if is_start {
+            active += 1;
+            most = most.max(active);
+        } else {
+            active = active.saturating_sub(1);
+        }
+    }
+    most
+}
+
+pub fn analyze_show_complexity(
+    state: &Arc<AppState>,
+    p: AnalyzeShowComplexityParams,
+) -> Result<CommandOutput, AppError> {
+    let show = state.show.lock();
+    let seq_idx = state.active_sequence_index(&show)?;
+    let sequence = show.sequences.get(seq_idx).ok_or(AppError::NoSequence)?;
+
+    let pixels: HashMap<FixtureId, u32> =
+        show.fixtures.iter().map(|f| (f.id, f.pixel_count)).collect();
+    let target_pixels = |target: &EffectTarget| -> u32 {
+        let ids = match target {
+            EffectTarget::All => return pixels.values().sum(),
+            EffectTarget::Fixtures(ids) => ids.clone(),
+            EffectTarget::Group(id) => show
+                .groups
+                .iter()
+                .find(|g| g.id == *id)
+                .map(|g| g.resolve_fixture_ids(&show.groups))
+                .unwrap_or_default(),
+        };
+        ids.iter().filter_map(|id| pixels.get(id)).sum()
+    };
+
+    let tracks: Vec<TrackComplexity> = sequence
+        .tracks
+        .iter()
+        .enumerate()
+        .map(|(track_index, track)| {
+            let effect_count = track.effects.len();
+            let max_overlap = max_overlap(&track.effects);
+            let mut warnings = Vec::new();
+            if effect_count > p.max_effects {
+                warnings.push(format!("{effect_count} effects (limit {})", p.max_effects));
+            }
+            if max_overlap > p.max_overlap {
+                warnings.push(format!(
+                    "{max_overlap} overlapping effects (limit {})",
+                    p.max_overlap
+                ));
+            }
+            TrackComplexity {
+                track_index,
+                name: track.name.clone(),
+                effect_count,
+                max_overlap,
+                pixel_count: target_pixels(&track.target),
+                warnings,
+            }
+        })
+        .collect();
+
+    let flagged = tracks.iter().filter(|t| !t.warnings.is_empty()).count();
+    let mut lines = vec![format!("{} tracks, {flagged} over the limits:", tracks.len())];
+    for t in &tracks {
+        let mut line = format!(
+            "  [{}] \"{}\": {} effects, up to {} at once, {} pixels",
+            t.track_index, t.name, t.effect_count, t.max_overlap, t.pixel_count
+        );
+        if !t.warnings.is_empty() {
+            let _ = write!(line, " -- {}", t.warnings.join(", "));
+        }
+        lines.push(line);
+    }
+    Ok(CommandOutput::new(lines.join("\n"), CommandResult::AnalyzeShowComplexity(tracks)))
+}
+
+pub fn get_command_schema(_state: &Arc<AppState>) -> Result<CommandOutput, AppError> {
+    let document = catalog::openapi_document();
+    let count = document
+        .get("paths")
+        .and_then(serde_json::Value::as_object)
+        .map_or(0, serde_json::Map::len);
+    Ok(CommandOutput::new(
+        format!("OpenAPI document covering {count} commands."),
+        CommandResult::GetCommandSchema(JsonValue(document)),
+    ))
+}
+
 pub fn help(_state: &Arc<AppState>, p: HelpParams) -> Result<CommandOutput, AppError> {
     let text = catalog::help_text(p.topic.as_deref());
     Ok(CommandOutput::new(text.clone(), CommandResult::Help(text)))
 }
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::panic, clippy::indexing_slicing)]
+mod tests {
+    use super::*;
+    use crate::model::{
+        BlendMode, EffectInstance, EffectParams, EffectTarget, Sequence, Show, TimeRange, Track,
+    };
+
+    fn effect(kind: EffectKind, start: f64, end: f64) -> EffectInstance {
+        EffectInstance {
+            kind,
+            params: EffectParams::new(),
+            time_range: TimeRange::new(start, end).unwrap(),
+            blend_mode: BlendMode::Override,
+            opacity: 1.0,
+            opacity_curve: None,
+            fade_in: 0.0,
+            fade_out: 0.0,
+        }
+    }
+
+    fn track(name: &str, effects: Vec<EffectInstance>) -> Track {
+        Track {
+            name: name.into(),
+            target: EffectTarget::All,
+            locked: false,
+            muted: false,
+            blend_mode: BlendMode::Override,
+            opacity: 1.0,
+            effects,
+        }
+    }
+
+    fn list(state: &Arc<AppState>, p: ListEffectInstancesParams) -> Vec<(usize, usize, f64)> {
+        match list_effect_instances(state, p).unwrap().result {
+            CommandResult::ListEffectInstances(entries) => {
+                entries.iter().map(|e| (e.track_index, e.effect_index, e.start)).collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    #[test]
+    fn lists_effects_across_tracks_with_filters() {
+        let mut show = Show::empty();
+        show.sequences.push(Sequence::for_test(
+            "Seq",
+            20.0,
+            vec![
+                track("Roof", vec![
+                    effect(EffectKind::Solid, 0.0, 2.0),
+                    effect(EffectKind::Chase, 4.0, 8.0),
+                ]),
+                track("Trees", vec![effect(EffectKind::Chase, 1.0, 3.0)]),
+            ],
+        ));
+        let state = Arc::new(AppState::for_test(show));
+        *state.current_sequence.lock() = Some("seq".into());
+
+        let all = ListEffectInstancesParams { start: None, end: None, kind: None };
+        assert_eq!(list(&state, all), [(0, 0, 0.0), (0, 1, 4.0), (1, 0, 1.0)]);
+
+        let chases =
+            ListEffectInstancesParams { start: None, end: None, kind: Some(EffectKind::Chase) };
+        assert_eq!(list(&state, chases), [(0, 1, 4.0), (1, 0, 1.0)]);
+
+        // Overlapping the window counts; 2.5..3.5 catches only the Trees chase.
+        let window = ListEffectInstancesParams { start: Some(2.5), end: Some(3.5), kind: None };
+        assert_eq!(list(&state, window), [(1, 0, 1.0)]);
+    }
+
+    #[test]
+    fn finds_strobes_by_rate() {
+        use crate::model::ParamKey;
+
+        let with_rate = |kind: EffectKind, rate: f64| {
+            let mut e = effect(kind, 0.0, 1.0);
+            e.params.set_mut(ParamKey::Rate, ParamValue::Float(rate));
+            e
+        };
+        let mut show = Show::empty();
+        show.sequences.push(Sequence::for_test(
+            "Seq",
+            20.0,
+            vec![
+                track("Roof", vec![
+                    with_rate(EffectKind::Strobe, 4.0),
+                    with_rate(EffectKind::Chase, 30.0),
+                    with_rate(EffectKind::Strobe, 20.0),
+                ]),
+                track("Trees", vec![
+                    effect(EffectKind::Strobe, 0.0, 1.0),
+                    effect(EffectKind::Solid, 0.0, 1.0),
+                ]),
+            ],
+        ));
+        let state = Arc::new(AppState::for_test(show));
+        *state.current_sequence.lock() = Some("seq".into());
+
+        let find = |op: ParamComparator| -> Vec<(usize, usize)> {
+            let p = FindEffectsParams {
+                kind: Some(EffectKind::Strobe),
+                predicates: vec![ParamPredicate {
+                    key: ParamKey::Rate,
+                    op,
+                    value: ParamValue::Float(10.0),
+                }],
+            };
+            match find_effects(&state, p).unwrap().result {
+                CommandResult::FindEffects(found) => {
+                    found.iter().map(|l| (l.track_index, l.effect_index)).collect()
+                }
+                _ => Vec::new(),
+            }
+        };
+        assert_eq!(find(ParamComparator::Gt), [(0, 2)]);
+        // The unset strobe on Trees runs at the schema default of 10 Hz.
+        assert_eq!(find(ParamComparator::Ge), [(0, 2), (1, 0)]);
+        assert_eq!(find(ParamComparator::Lt), [(0, 0)]);
+    }
+
+    #[test]
+    fn complexity_flags_dense_track_only() {
+        // 600 back-to-back 10 ms chases, then 10 solids stacked on top of each other.
+        let mut dense: Vec<EffectInstance> = (0..600)
+            .map(|i| {
+                let start = f64::from(i) * 0.01;
+                effect(EffectKind::Chase, start, start + 0.01)
+            })
+            .collect();
+        dense.extend((0..10).map(|_| effect(EffectKind::Solid, 10.0, 12.0)));
+        let sparse = vec![
+            effect(EffectKind::Solid, 0.0, 2.0),
+            effect(EffectKind::Chase, 1.0, 3.0),
+            effect(EffectKind::Chase, 3.0, 4.0),
+        ];
+
+        let mut show = Show::empty();
+        let tracks = vec![track("Imported", dense), track("Roof", sparse)];
+        show.sequences.push(Sequence::for_test("Seq", 20.0, tracks));
+        let state = Arc::new(AppState::for_test(show));
+        *state.current_sequence.lock() = Some("seq".into());
+
+        let p = AnalyzeShowComplexityParams { max_effects: 500, max_overlap: 8 };
+        let CommandResult::AnalyzeShowComplexity(tracks) =
+            analyze_show_complexity(&state, p).unwrap().result
+        else {
+            panic!("expected AnalyzeShowComplexity");
+        };
+        assert_eq!(tracks[0].effect_count, 610);
+        assert_eq!(tracks[0].max_overlap, 10);
+        assert_eq!(tracks[0].warnings.len(), 2);
+
+        // Back-to-back effects don't count as overlapping.
+        assert_eq!(tracks[1].effect_count, 3);
+        assert_eq!(tracks[1].max_overlap, 2);
+        assert!(tracks[1].warnings.is_empty());
+    }
+}
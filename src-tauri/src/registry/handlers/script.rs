@@ -2,6 +2,7 @@
 
 use std::sync::Arc;
 
+use crate::commands::ScriptMeta;
 use crate::error::AppError;
 use crate::registry::params::{
     CompileScriptPreviewParams, NameParams, RenameParams, WriteScriptParams,
@@ -21,34 +22,35 @@ pub fn write_global_script(
     state: &Arc<AppState>,
     p: WriteScriptParams,
 ) -> Result<CommandOutput, AppError> {
-    match crate::dsl::compile_source(&p.source) {
+    match state.compile_script(&p.source) {
         Ok(compiled) => {
             let params_desc: Vec<String> = compiled
                 .params
                 .iter()
                 .map(|param| format!("{} ({:?})", param.name, param.ty))
                 .collect();
-            state
-                .script_cache
-                .lock()
-                .insert(p.name.clone(), Arc::new(compiled));
+            let warnings: Vec<String> = compiled
+                .warnings
+                .iter()
+                .map(|w| format!("\nwarning: {}", w.format_with_source(&p.source)))
+                .collect();
             state.global_libraries.lock().scripts.insert(p.name.clone(), p.source);
             global_lib::persist_inner(state);
-            if params_desc.is_empty() {
-                Ok(CommandOutput::new(
-                    format!("Compiled \"{}\" (no params).", p.name),
-                    CommandResult::WriteGlobalScript,
-                ))
+            // Scripts that `use` this one inlined its old functions.
+            crate::commands::recompile_all_scripts(state);
+            let summary = if params_desc.is_empty() {
+                format!("Compiled \"{}\" (no params).", p.name)
             } else {
-                Ok(CommandOutput::new(
-                    format!(
-                        "Compiled \"{}\" with params: {}.",
-                        p.name,
-                        params_desc.join(", ")
-                    ),
-                    CommandResult::WriteGlobalScript,
-                ))
-            }
+                format!(
+                    "Compiled \"{}\" with params: {}.",
+                    p.name,
+                    params_desc.join(", ")
+                )
+            };
+            Ok(CommandOutput::new(
+                format!("{summary}{}", warnings.concat()),
+                CommandResult::WriteGlobalScript,
+            ))
         }
         Err(errors) => {
             let msgs: Vec<String> = errors.iter().map(|e| e.message.clone()).collect();
@@ -74,13 +76,44 @@ pub fn get_global_script_source(
     Ok(CommandOutput::new(source.clone(), CommandResult::GetGlobalScriptSource(source)))
 }
 
+pub fn get_script_meta(state: &Arc<AppState>, p: NameParams) -> Result<CommandOutput, AppError> {
+    let source = state
+        .global_libraries
+        .lock()
+        .scripts
+        .get(&p.name)
+        .cloned()
+        .ok_or_else(|| AppError::NotFound { what: format!("Script \"{}\"", p.name) })?;
+    let script = crate::dsl::parse_source(&source).map_err(|errors| AppError::ValidationError {
+        message: errors.iter().map(ToString::to_string).collect::<Vec<_>>().join("\n"),
+    })?;
+    let meta = script_meta(&script);
+    let title = meta.title.as_deref().unwrap_or(&p.name);
+    let msg = match &meta.author {
+        Some(author) => format!("\"{title}\" by {author}."),
+        None => format!("\"{title}\"."),
+    };
+    Ok(CommandOutput::new(msg, CommandResult::GetScriptMeta(meta)))
+}
+
+fn script_meta(script: &crate::dsl::ast::Script) -> ScriptMeta {
+    let text = |key: &str| script.meta_str(key).map(str::to_string);
+    ScriptMeta {
+        title: text("title").or_else(|| text("name")),
+        author: text("author"),
+        description: text("description"),
+        spatial: script.meta_bool("spatial").unwrap_or(false),
+    }
+}
+
 pub fn delete_global_script(
     state: &Arc<AppState>,
     p: NameParams,
 ) -> Result<CommandOutput, AppError> {
     state.global_libraries.lock().scripts.remove(&p.name);
-    state.script_cache.lock().remove(&p.name);
     global_lib::persist_inner(state);
+    // Also drops scripts that `use` the deleted one.
+    crate::commands::recompile_all_scripts(state);
     Ok(CommandOutput::new(
         format!("Script \"{}\" deleted.", p.name),
         CommandResult::DeleteGlobalScript,
@@ -97,10 +130,10 @@ pub fn list_global_scripts(state: &Arc<AppState>) -> Result<CommandOutput, AppEr
 }
 
 pub fn compile_script_preview(
-    _state: &Arc<AppState>,
+    state: &Arc<AppState>,
     p: CompileScriptPreviewParams,
 ) -> Result<CommandOutput, AppError> {
-    let result = super::common::compile_preview(String::new(), &p.source);
+    let result = super::common::compile_preview(state, String::new(), &p.source);
     let msg = if result.success { "Compiled and saved." } else { "Compile failed." };
     Ok(CommandOutput::new(msg, CommandResult::CompileScriptPreview(result)))
 }
@@ -115,13 +148,9 @@ pub fn rename_global_script(
             libs.scripts.insert(p.new_name.clone(), source);
         }
     }
-    {
-        let mut cache = state.script_cache.lock();
-        if let Some(compiled) = cache.remove(&p.old_name) {
-            cache.insert(p.new_name.clone(), compiled);
-        }
-    }
     global_lib::persist_inner(state);
+    // Scripts that `use` the old name no longer resolve it.
+    crate::commands::recompile_all_scripts(state);
     Ok(CommandOutput::new(
         format!("Script renamed to \"{}\".", p.new_name),
         CommandResult::RenameGlobalScript,
@@ -142,3 +171,71 @@ pub fn get_script_params(
         CommandResult::GetScriptParams(params),
     ))
 }
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::panic)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn meta_reads_title_and_author() {
+        let script = crate::dsl::parse_source(
+            "@title \"Plasma\"\n@author \"Sam\"\n@description \"Rolling color field\"\n\
+             @spatial true\nrgb(t, pos, 1.0)",
+        )
+        .unwrap();
+        let meta = script_meta(&script);
+        assert_eq!(meta.title.as_deref(), Some("Plasma"));
+        assert_eq!(meta.author.as_deref(), Some("Sam"));
+        assert_eq!(meta.description.as_deref(), Some("Rolling color field"));
+        assert!(meta.spatial);
+    }
+
+    #[test]
+    fn meta_title_falls_back_to_name() {
+        let script = crate::dsl::parse_source("@name \"Fire\"\nrgb(1.0, 0.0, 0.0)").unwrap();
+        let meta = script_meta(&script);
+        assert_eq!(meta.title.as_deref(), Some("Fire"));
+        assert_eq!(meta.author, None);
+        assert!(!meta.spatial);
+    }
+
+    #[test]
+    fn compile_errors_report_line_and_column() {
+        let state = Arc::new(AppState::for_test(crate::model::show::Show::empty()));
+        let source = "let a = t * 2.0;\nlet b = a + 1.0;\nlet c = b + missing;\nrgb(a, b, c)";
+        let output = compile_script_preview(
+            &state,
+            CompileScriptPreviewParams {
+                source: source.to_string(),
+            },
+        )
+        .unwrap();
+        let CommandResult::CompileScriptPreview(result) = output.result else {
+            panic!("expected a compile result");
+        };
+        assert!(!result.success);
+        let error = result.errors.first().unwrap();
+        assert_eq!((error.line, error.col), (3, 13));
+        assert_eq!(source.get(error.offset..error.offset + 7), Some("missing"));
+    }
+
+    #[test]
+    fn library_changes_recompile_scripts_that_use_it() {
+        let state = Arc::new(AppState::for_test(crate::model::show::Show::empty()));
+        let write = |name: &str, source: &str| {
+            let params = WriteScriptParams { name: name.into(), source: source.into() };
+            write_global_script(&state, params).unwrap();
+        };
+        let cached = |name: &str| state.script_cache.lock().get(name).cloned();
+
+        write("easing", "fn ease(x: float) -> float { x * x }\nrgb(t, t, t)");
+        write("main", "use \"easing\";\nlet v = ease(t); rgb(v, v, v)");
+        let before = cached("main").unwrap();
+        write("easing", "fn ease(x: float) -> float { x }\nrgb(t, t, t)");
+        assert!(!Arc::ptr_eq(&before, &cached("main").unwrap()));
+
+        delete_global_script(&state, NameParams { name: "easing".into() }).unwrap();
+        assert!(cached("main").is_none());
+    }
+}
@@ -4,9 +4,12 @@ use std::sync::Arc;
 
 use crate::error::AppError;
 use crate::setup;
-use crate::registry::params::{CreateSequenceParams, SlugParams};
+use crate::registry::params::{
+    CreateSequenceFromTemplateParams, CreateSequenceParams, SlugParams,
+};
 use crate::registry::{CommandOutput, CommandResult};
 use crate::state::{get_data_dir, AppState};
+use crate::template;
 use crate::commands;
 
 pub fn list_sequences(state: &Arc<AppState>) -> Result<CommandOutput, AppError> {
@@ -33,9 +36,64 @@ pub fn create_sequence(
 ) -> Result<CommandOutput, AppError> {
     let data_dir = get_data_dir(state).map_err(|_| AppError::NoSettings)?;
     let setup_slug = state.require_setup()?;
+    let defaults = state
+        .settings
+        .lock()
+        .as_ref()
+        .map(|s| s.sequence_defaults)
+        .unwrap_or_default();
+    let summary = setup::create_sequence_with(&data_dir, &setup_slug, &p.name, defaults)
+        .map_err(AppError::from)?;
+    Ok(CommandOutput::new(format!("Sequence \"{}\" created.", summary.name), CommandResult::CreateSequence(summary)))
+}
+
+pub fn create_sequence_from_template(
+    state: &Arc<AppState>,
+    p: CreateSequenceFromTemplateParams,
+) -> Result<CommandOutput, AppError> {
+    let template = template::find_template(&p.template).ok_or_else(|| {
+        let ids: Vec<&str> = template::TEMPLATES.iter().map(|t| t.id).collect();
+        AppError::ValidationError {
+            message: format!(
+                "Unknown sequence template \"{}\". Available: {}.",
+                p.template,
+                ids.join(", ")
+            ),
+        }
+    })?;
+
+    let data_dir = get_data_dir(state).map_err(|_| AppError::NoSettings)?;
+    let setup_slug = state.require_setup()?;
+    let setup_data = setup::load_setup(&data_dir, &setup_slug).map_err(AppError::from)?;
+
+    // The new sequence has no analysis of its own yet; borrow any sequence's
+    // analysis of the same audio.
+    let sections = p.audio_file.as_ref().and_then(|audio| {
+        let audio_hash = state.analysis_key(audio).audio_hash;
+        let cache = state.analysis_cache.lock();
+        let analysis = cache.find_audio(audio_hash)?;
+        analysis.structure.as_ref().map(|s| s.sections.clone())
+    });
+    let mut sequence = template.build(&p.name, &setup_data.groups, sections.as_deref());
+    sequence.set_primary_audio(p.audio_file);
+
     let summary =
         setup::create_sequence(&data_dir, &setup_slug, &p.name).map_err(AppError::from)?;
-    Ok(CommandOutput::new(format!("Sequence \"{}\" created.", summary.name), CommandResult::CreateSequence(summary)))
+    setup::save_sequence(&data_dir, &setup_slug, &summary.slug, &sequence)
+        .map_err(AppError::from)?;
+
+    let timing = if sections.is_some_and(|s| !s.is_empty()) { "analysis" } else { "template" };
+    Ok(CommandOutput::new(
+        format!(
+            "Sequence \"{}\" created from template \"{}\": {} sections ({timing} timing), \
+             {} tracks.",
+            summary.name,
+            template.name,
+            sequence.markers.len(),
+            sequence.tracks.len()
+        ),
+        CommandResult::CreateSequenceFromTemplate(summary),
+    ))
 }
 
 pub fn open_sequence(state: &Arc<AppState>, p: SlugParams) -> Result<CommandOutput, AppError> {
@@ -55,9 +113,12 @@ pub fn open_sequence(state: &Arc<AppState>, p: SlugParams) -> Result<CommandOutp
     state.with_playback_mut(|playback| {
         playback.playing = false;
         playback.current_time = 0.0;
+        playback.frame_index = 0;
+        playback.clock_anchor = None;
         playback.sequence_index = 0;
-        playback.region = None;
-        playback.looping = false;
+        playback.region = sequence.region;
+        playback.looping = sequence.looping;
+        playback.solo_tracks.clear();
     });
 
     *state.current_sequence.lock() = Some(p.slug.clone());
@@ -99,11 +160,99 @@ pub fn save_current_sequence(state: &Arc<AppState>) -> Result<CommandOutput, App
     let setup_slug = state.require_setup()?;
     let seq_slug = state.require_sequence()?;
 
-    let show = state.show.lock();
-    let sequence = show.sequences.first().ok_or(AppError::NotFound {
+    let (region, looping) = {
+        let playback = state.playback.lock();
+        (playback.region, playback.looping)
+    };
+    let mut show = state.show.lock();
+    let sequence = show.sequences.first_mut().ok_or(AppError::NotFound {
         what: "sequence in show".into(),
     })?;
+    // Keep the working region with the sequence so reopening resumes there.
+    sequence.region = region;
+    sequence.looping = looping;
     setup::save_sequence(&data_dir, &setup_slug, &seq_slug, sequence)
         .map_err(AppError::from)?;
     Ok(CommandOutput::new("Sequence saved.", CommandResult::SaveCurrentSequence))
 }
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::registry::execute::execute;
+    use crate::registry::Command;
+    use crate::settings::AppSettings;
+    use crate::setup::LibrariesFile;
+
+    #[test]
+    fn new_sequences_use_the_configured_frame_rate() {
+        let dir = std::env::temp_dir()
+            .join(format!("vibelights_test_sequence_defaults_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let summary = setup::create_setup(&dir, "Yard").unwrap();
+
+        let mut settings = AppSettings::new(dir.clone());
+        settings.sequence_defaults.frame_rate = 40.0;
+        let state = Arc::new(AppState::headless(Some(settings), LibrariesFile::default()));
+        *state.current_setup.lock() = Some(summary.slug.clone());
+
+        let params = CreateSequenceParams {
+            name: "Song".into(),
+        };
+        let output = execute(&state, Command::CreateSequence(params)).unwrap();
+        let CommandResult::CreateSequence(created) = output.result else {
+            panic!("expected a sequence summary");
+        };
+        let sequence = setup::load_sequence(&dir, &summary.slug, &created.slug).unwrap();
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert!((sequence.frame_rate - 40.0).abs() < f64::EPSILON);
+        assert!((sequence.duration - 30.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn region_and_looping_survive_save_and_reopen() {
+        use crate::registry::params::{Region, SetLoopingParams, SetRegionParams};
+
+        let dir = std::env::temp_dir()
+            .join(format!("vibelights_test_sequence_region_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let summary = setup::create_setup(&dir, "Yard").unwrap();
+
+        let settings = AppSettings::new(dir.clone());
+        let state = Arc::new(AppState::headless(Some(settings), LibrariesFile::default()));
+        *state.current_setup.lock() = Some(summary.slug.clone());
+
+        let params = CreateSequenceParams { name: "Song".into() };
+        let output = execute(&state, Command::CreateSequence(params)).unwrap();
+        let CommandResult::CreateSequence(created) = output.result else {
+            panic!("expected a sequence summary");
+        };
+        let open = || {
+            execute(&state, Command::OpenSequence(SlugParams { slug: created.slug.clone() }))
+                .unwrap();
+        };
+
+        // Fresh sequences have no region.
+        open();
+        assert_eq!(state.playback.lock().region, None);
+        assert!(!state.playback.lock().looping);
+
+        let region = Some(Region { start: 4.0, end: 9.5 });
+        execute(&state, Command::SetRegion(SetRegionParams { region })).unwrap();
+        execute(&state, Command::SetLooping(SetLoopingParams { looping: true })).unwrap();
+        execute(&state, Command::SaveCurrentSequence).unwrap();
+
+        execute(&state, Command::SetRegion(SetRegionParams { region: None })).unwrap();
+        execute(&state, Command::SetLooping(SetLoopingParams { looping: false })).unwrap();
+        open();
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let playback = state.playback.lock();
+        assert_eq!(playback.region, Some((4.0, 9.5)));
+        assert!(playback.looping);
+    }
+}
@@ -5,8 +5,12 @@ use std::sync::Arc;
 
 use crate::error::AppError;
 use crate::registry::params::{InitializeDataDirParams, SetLlmConfigParams};
+use crate::registry::validation::validate_positive_finite;
 use crate::registry::{CommandOutput, CommandResult};
-use crate::settings::{self, AppSettings, LlmConfigInfo, LlmProviderConfig};
+use crate::settings::{
+    self, AppSettings, LlmConfigInfo, LlmProviderConfig, LlmProviderKind, OutputSettings,
+    SequenceDefaults,
+};
 use crate::state::AppState;
 
 pub fn get_settings(state: &Arc<AppState>) -> Result<CommandOutput, AppError> {
@@ -35,15 +39,26 @@ pub fn set_llm_config(
     state: &Arc<AppState>,
     p: SetLlmConfigParams,
 ) -> Result<CommandOutput, AppError> {
+    // The agent sidecar only speaks the Anthropic API.
+    if p.provider != LlmProviderKind::Anthropic {
+        return Err(AppError::ValidationError {
+            message: format!("The '{}' provider is not supported yet", p.provider.slug()),
+        });
+    }
     let mut settings_guard = state.settings.lock();
     let s = settings_guard.as_mut().ok_or(AppError::NoSettings)?;
+    let provider_changed = s.llm.provider != p.provider || s.llm.base_url != p.base_url;
+    let rates = p.rates.unwrap_or_else(|| std::mem::take(&mut s.llm.rates));
     s.llm = LlmProviderConfig {
+        provider: p.provider,
         api_key: if p.api_key.is_empty() {
             None
         } else {
             Some(p.api_key.clone())
         },
         model: p.model,
+        base_url: p.base_url,
+        rates,
     };
     settings::save_settings(&state.app_config_dir, s)
         .map_err(|e| AppError::SettingsSaveError {
@@ -53,20 +68,76 @@ pub fn set_llm_config(
         .map_err(|e| AppError::SettingsSaveError {
             message: e.to_string(),
         })?;
-    // Kill agent sidecar when API key changes so it restarts with the new key
-    if !p.api_key.is_empty() {
+    // Kill agent sidecar when the key or provider changes so it restarts with the new config
+    if !p.api_key.is_empty() || provider_changed {
         state.agent_port.store(0, std::sync::atomic::Ordering::Relaxed);
     }
     Ok(CommandOutput::new("LLM config updated.", CommandResult::SetLlmConfig))
 }
 
+pub fn set_output_settings(
+    state: &Arc<AppState>,
+    p: OutputSettings,
+) -> Result<CommandOutput, AppError> {
+    let mut settings_guard = state.settings.lock();
+    let s = settings_guard.as_mut().ok_or(AppError::NoSettings)?;
+    s.output = p;
+    settings::save_settings(&state.app_config_dir, s)
+        .map_err(|e| AppError::SettingsSaveError {
+            message: e.to_string(),
+        })?;
+    Ok(CommandOutput::new("Output settings updated.", CommandResult::SetOutputSettings))
+}
+
+pub fn set_sequence_defaults(
+    state: &Arc<AppState>,
+    p: SequenceDefaults,
+) -> Result<CommandOutput, AppError> {
+    validate_positive_finite(p.duration, "Duration")?;
+    validate_positive_finite(p.frame_rate, "Frame rate")?;
+    let mut settings_guard = state.settings.lock();
+    let s = settings_guard.as_mut().ok_or(AppError::NoSettings)?;
+    s.sequence_defaults = p;
+    settings::save_settings(&state.app_config_dir, s)
+        .map_err(|e| AppError::SettingsSaveError {
+            message: e.to_string(),
+        })?;
+    Ok(CommandOutput::new(
+        format!(
+            "New sequences will start at {}s and {} fps.",
+            p.duration, p.frame_rate
+        ),
+        CommandResult::SetSequenceDefaults,
+    ))
+}
+
 pub fn get_llm_config(state: &Arc<AppState>) -> Result<CommandOutput, AppError> {
     let info = state.settings.lock().as_ref().map_or(
-        LlmConfigInfo {
-            has_api_key: false,
-            model: None,
-        },
+        LlmConfigInfo::from_config(&LlmProviderConfig::default()),
         |s| LlmConfigInfo::from_config(&s.llm),
     );
     Ok(CommandOutput::new("LLM config", CommandResult::GetLlmConfig(info)))
 }
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use crate::model::Show;
+
+    #[test]
+    fn set_llm_config_rejects_unsupported_providers() {
+        let state = Arc::new(AppState::for_test(Show::empty()));
+        for provider in [LlmProviderKind::OpenAi, LlmProviderKind::OpenAiCompatible] {
+            let params = SetLlmConfigParams {
+                provider,
+                api_key: "sk-test".into(),
+                model: None,
+                base_url: Some("http://localhost:1234/v1".into()),
+                rates: None,
+            };
+            let err = set_llm_config(&state, params).unwrap_err();
+            assert!(matches!(err, AppError::ValidationError { .. }), "{err}");
+        }
+    }
+}
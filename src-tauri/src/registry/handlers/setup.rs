@@ -5,8 +5,8 @@ use std::sync::Arc;
 use crate::error::AppError;
 use crate::setup;
 use crate::registry::params::{
-    CreateSetupParams, SlugParams, UpdateSetupFixturesParams, UpdateSetupLayoutParams,
-    UpdateSetupOutputsParams,
+    AutoPatchParams, CreateSetupParams, SlugParams, UpdateSetupFixturesParams,
+    UpdateSetupLayoutParams, UpdateSetupOutputsParams,
 };
 use crate::registry::{CommandOutput, CommandResult};
 use crate::settings;
@@ -44,6 +44,7 @@ pub fn open_setup(state: &Arc<AppState>, p: SlugParams) -> Result<CommandOutput,
     let loaded = setup::load_setup(&data_dir, &p.slug).map_err(AppError::from)?;
     *state.current_setup.lock() = Some(p.slug.clone());
     *state.current_sequence.lock() = None;
+    state.dispatcher.lock().clear_setup_history();
 
     // Clear script cache when switching setups
     state.script_cache.lock().clear();
@@ -69,6 +70,7 @@ pub fn delete_setup(state: &Arc<AppState>, p: SlugParams) -> Result<CommandOutpu
     if current.as_deref() == Some(&p.slug) {
         *current = None;
         *state.current_sequence.lock() = None;
+        state.dispatcher.lock().clear_setup_history();
     }
 
     Ok(CommandOutput::new(
@@ -125,9 +127,11 @@ pub fn update_setup_fixtures(
     let data_dir = get_data_dir(state).map_err(|_| AppError::NoSettings)?;
     let slug = state.require_setup()?;
     let mut loaded = setup::load_setup(&data_dir, &slug).map_err(AppError::from)?;
+    let before = loaded.clone();
     loaded.fixtures = p.fixtures;
     loaded.groups = p.groups;
     setup::save_setup(&data_dir, &slug, &loaded).map_err(AppError::from)?;
+    state.dispatcher.lock().record_setup_edit("Update fixtures", before);
     Ok(CommandOutput::new("Setup fixtures updated.", CommandResult::UpdateSetupFixtures))
 }
 
@@ -138,12 +142,57 @@ pub fn update_setup_outputs(
     let data_dir = get_data_dir(state).map_err(|_| AppError::NoSettings)?;
     let slug = state.require_setup()?;
     let mut loaded = setup::load_setup(&data_dir, &slug).map_err(AppError::from)?;
+    let before = loaded.clone();
     loaded.controllers = p.controllers;
     loaded.patches = p.patches;
     setup::save_setup(&data_dir, &slug, &loaded).map_err(AppError::from)?;
+    state.dispatcher.lock().record_setup_edit("Update outputs", before);
     Ok(CommandOutput::new("Setup outputs updated.", CommandResult::UpdateSetupOutputs))
 }
 
+pub fn auto_patch(state: &Arc<AppState>, p: AutoPatchParams) -> Result<CommandOutput, AppError> {
+    if !(1..=512).contains(&p.channels_per_universe) {
+        return Err(AppError::ValidationError {
+            message: format!(
+                "Channels per universe must be between 1 and 512, got {}.",
+                p.channels_per_universe
+            ),
+        });
+    }
+
+    let data_dir = get_data_dir(state).map_err(|_| AppError::NoSettings)?;
+    let slug = state.require_setup()?;
+    let loaded = setup::load_setup(&data_dir, &slug).map_err(AppError::from)?;
+    let new_patches = setup::auto_patch(
+        &loaded.fixtures,
+        &loaded.patches,
+        p.start_universe,
+        p.channels_per_universe,
+    );
+    if new_patches.is_empty() {
+        return Ok(CommandOutput::new(
+            "All fixtures are already patched.",
+            CommandResult::AutoPatch(new_patches),
+        ));
+    }
+
+    // Apply as one outputs update so the whole patch lands in a single save.
+    let mut patches = loaded.patches;
+    patches.extend(new_patches.iter().cloned());
+    update_setup_outputs(
+        state,
+        UpdateSetupOutputsParams { controllers: loaded.controllers, patches },
+    )?;
+    Ok(CommandOutput::new(
+        format!(
+            "Auto-patched {} fixture(s) starting at universe {}.",
+            new_patches.len(),
+            u32::from(p.start_universe.0) + 1
+        ),
+        CommandResult::AutoPatch(new_patches),
+    ))
+}
+
 pub fn update_setup_layout(
     state: &Arc<AppState>,
     p: UpdateSetupLayoutParams,
@@ -151,7 +200,97 @@ pub fn update_setup_layout(
     let data_dir = get_data_dir(state).map_err(|_| AppError::NoSettings)?;
     let slug = state.require_setup()?;
     let mut loaded = setup::load_setup(&data_dir, &slug).map_err(AppError::from)?;
+    let before = loaded.clone();
     loaded.layout = p.layout;
     setup::save_setup(&data_dir, &slug, &loaded).map_err(AppError::from)?;
+    state.dispatcher.lock().record_setup_edit("Update layout", before);
     Ok(CommandOutput::new("Setup layout updated.", CommandResult::UpdateSetupLayout))
 }
+
+pub fn undo_setup(state: &Arc<AppState>) -> Result<CommandOutput, AppError> {
+    let data_dir = get_data_dir(state).map_err(|_| AppError::NoSettings)?;
+    let slug = state.require_setup()?;
+    let current = setup::load_setup(&data_dir, &slug).map_err(AppError::from)?;
+    let (desc, restored) = state.dispatcher.lock().undo_setup(current)?;
+    setup::save_setup(&data_dir, &slug, &restored).map_err(AppError::from)?;
+    Ok(CommandOutput::new(format!("Undone: {desc}"), CommandResult::UndoSetup))
+}
+
+pub fn redo_setup(state: &Arc<AppState>) -> Result<CommandOutput, AppError> {
+    let data_dir = get_data_dir(state).map_err(|_| AppError::NoSettings)?;
+    let slug = state.require_setup()?;
+    let current = setup::load_setup(&data_dir, &slug).map_err(AppError::from)?;
+    let (desc, restored) = state.dispatcher.lock().redo_setup(current)?;
+    setup::save_setup(&data_dir, &slug, &restored).map_err(AppError::from)?;
+    Ok(CommandOutput::new(format!("Redone: {desc}"), CommandResult::RedoSetup))
+}
+
+pub fn get_setup_undo_state(state: &Arc<AppState>) -> Result<CommandOutput, AppError> {
+    let undo_state = state.with_dispatcher(crate::dispatcher::CommandDispatcher::setup_undo_state);
+    Ok(CommandOutput::new("Setup undo state", CommandResult::GetSetupUndoState(undo_state)))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::model::fixture::{
+        BulbShape, ChannelOrder, ColorModel, FixtureDef, FixtureId, PixelType,
+    };
+    use crate::settings::AppSettings;
+    use crate::setup::LibrariesFile;
+
+    fn fixture(id: u32) -> FixtureDef {
+        FixtureDef {
+            id: FixtureId(id),
+            name: format!("Fixture {id}"),
+            color_model: ColorModel::Rgb,
+            pixel_count: 10,
+            pixel_type: PixelType::Smart,
+            bulb_shape: BulbShape::LED,
+            display_radius_override: None,
+            channel_order: ChannelOrder::Rgb,
+            gamma: None,
+        }
+    }
+
+    #[test]
+    fn undo_restores_the_previous_fixtures() {
+        let dir = std::env::temp_dir()
+            .join(format!("vibelights_test_setup_undo_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let summary = setup::create_setup(&dir, "Yard").unwrap();
+        let state = Arc::new(AppState::headless(
+            Some(AppSettings::new(dir.clone())),
+            LibrariesFile::default(),
+        ));
+        *state.current_setup.lock() = Some(summary.slug.clone());
+
+        let set = |ids: &[u32]| {
+            let fixtures = ids.iter().copied().map(fixture).collect();
+            update_setup_fixtures(&state, UpdateSetupFixturesParams { fixtures, groups: vec![] })
+                .unwrap();
+        };
+        let ids = || -> Vec<u32> {
+            let loaded = setup::load_setup(&dir, &summary.slug).unwrap();
+            loaded.fixtures.iter().map(|f| f.id.0).collect()
+        };
+        set(&[1]);
+        set(&[1, 2]);
+
+        undo_setup(&state).unwrap();
+        assert_eq!(ids(), [1]);
+        // Sequence undo is a separate history and has nothing to undo.
+        assert!(!state.with_dispatcher(|d| d.undo_state().can_undo));
+
+        redo_setup(&state).unwrap();
+        assert_eq!(ids(), [1, 2]);
+        undo_setup(&state).unwrap();
+        undo_setup(&state).unwrap();
+        assert!(ids().is_empty());
+        assert!(undo_setup(&state).is_err());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -9,38 +9,78 @@ use serde::{Deserialize, Serialize};
 
 // ── Param types (used in Command enum) ──────────────────────────
 use params::{
-    AddEffectParams, AddTrackParams, AnalyzeAudioParams, BatchEditParams,
-    CancelOperationParams, CheckVixenPreviewFileParams,
-    CompileScriptPreviewParams, ConversationIdParams, CreateSequenceParams, CreateSetupParams,
-    DeleteEffectsParams, DeleteTrackParams, GetAnalysisDetailParams, GetBeatsInRangeParams,
-    GetEffectDetailParams, GetFrameFilteredParams, GetFrameParams, HelpParams, ImportMediaParams,
-    ImportVixenParams, ImportVixenSequenceParams, ImportVixenSetupParams, InitializeDataDirParams,
-    MoveEffectToTrackParams, NameParams, PreviewScriptFrameParams, PreviewScriptParams,
-    RenameParams, RenderEffectThumbnailParams, ScanVixenDirectoryParams, SeekParams,
-    SendAgentMessageParams, SetGlobalCurveParams, SetGlobalGradientParams,
-    SetLlmConfigParams, SetLoopingParams, SetRegionParams, SlugParams, TickParams,
+    AddAudioTrackParams, AddCueParams, AddCurvePresetParams, AddEffectParams, AddMarkerParams,
+    AddTrackParams, AlignEffectsParams,
+    AnalyzeAudioParams, AnalyzeShowComplexityParams, ApplyEffectPresetParams, AutoPatchParams,
+    BatchEditParams, CancelOperationParams, CheckVixenPreviewFileParams,
+    CompileScriptPreviewParams, ConversationIdParams, CreateSequenceFromTemplateParams,
+    CreateSequenceParams, CreateSetupParams, CrossfadeParams,
+    DeleteCueParams, DeleteEffectsParams, DeleteMarkerParams, DeleteTrackParams,
+    ExportAnalysisParams,
+    ExportConversationParams, ExportFramePngParams, ExportLibraryBundleParams,
+    ExportXlightsParams, FindEffectsParams,
+    GenerateFromBeatsParams,
+    GetAnalysisDetailParams, GetBeatsInRangeParams, GetChordsParams, GetEffectDetailParams,
+    GetStemEnergyParams,
+    GetFrameFilteredParams, GetFrameParams, GetWaveformParams, HelpParams,
+    GoToCueParams, HashSequenceFramesParams, ImportAnalysisParams, ImportLibraryBundleParams,
+    ImportLorParams, ImportMediaParams,
+    IdentifyFixtureParams, ImportVixenParams, ImportVixenSequenceParams, ImportVixenSequencesParams,
+    ImportVixenSetupParams,
+    InitializeDataDirParams,
+    ListEffectInstancesParams,
+    MergeSequenceParams, MoveEffectToTrackParams, NameParams, NudgeEffectsParams,
+    PreviewScriptFrameParams,
+    PreviewScriptParams,
+    ProfileScriptParams, QuantizeEffectsParams, QuantizeGradientParams,
+    RemoveAudioTrackParams, RenameParams, RenderEffectThumbnailParams, ReorderTrackParams,
+    ResetEffectParamsParams,
+    ScanLorDirectoryParams,
+    ScanVixenDirectoryParams,
+    SaveEffectPresetParams, SeekParams, SendAgentMessageParams, SetEffectFadesParams,
+    SetGlobalCurveParams,
+    SetGlobalGradientParams,
+    SetLlmConfigParams, SetLoopingParams, SetRegionParams, SetTrackBlendParams,
+    SetTrackLockedParams, SetTrackMutedParams, SetTrackSoloParams, SimulateColorVisionParams,
+    SlugParams,
+    StartLiveOutputParams,
+    TickParams, TransformGradientParams,
     UpdateEffectParamParams, UpdateEffectTimeRangeParams, UpdateSequenceSettingsParams,
     UpdateSetupFixturesParams, UpdateSetupLayoutParams, UpdateSetupOutputsParams, WriteScriptParams,
 };
 
 // ── Return types (used in CommandResult enum) ───────────────────
-use crate::chat::{ChatHistoryEntry, ConversationSummary};
-use crate::commands::{EffectThumbnail, ScriptCompileResult, ScriptParamInfo, ScriptPreviewData, TickResult};
+use crate::chat::{ChatHistoryEntry, ConversationSummary, ConversationUsage};
+use crate::commands::{
+    EffectThumbnail, FrameDigest, ScriptCompileResult, ScriptMeta, ScriptParamInfo, ScriptPreviewData,
+    ScriptProfile, TickResult,
+};
 use crate::dispatcher::UndoState;
 use crate::engine::Frame;
-use crate::import::vixen::{VixenDiscovery, VixenImportResult};
-use crate::model::{AudioAnalysis, ColorGradient, Curve, PythonEnvStatus, Show, SongSection};
-use crate::settings::{AppSettings, LlmConfigInfo};
-use crate::setup::{MediaFile, SequenceSummary, Setup, SetupSummary};
+use crate::import::lor::LorDiscovery;
+use crate::import::vixen::{VixenDiscovery, VixenImportResult, VixenSequenceImport};
+use crate::model::analysis::ChordEvent;
+use crate::output::OutputStats;
+use crate::model::{
+    AudioAnalysis, ColorGradient, Curve, EasingFunction, EffectPreset, Marker, Patch,
+    PythonEnvStatus, Show, SongSection,
+};
+use crate::settings::{AppSettings, LlmConfigInfo, OutputSettings, SequenceDefaults};
+use crate::setup::{LibraryImportSummary, MediaFile, SequenceSummary, Setup, SetupSummary};
 use crate::state::{EffectDetail, EffectInfo, PlaybackInfo};
+use crate::waveform::Waveform;
 
 use handlers::analysis::{AnalysisSummary, BeatsInRange};
 use handlers::chat::NewConversationResult;
+use handlers::edit::GenerateFromBeatsResult;
+use handlers::output::LiveOutputInfo;
+use handlers::query::{EffectInstanceEntry, TrackComplexity};
+use params::EffectLocation;
 
 // ── Handler modules (dispatch targets) ──────────────────────────
 use handlers::{
-    agent, analysis, chat, common, edit, global_lib, hot, import, media, playback, python, query,
-    script, sequence, settings, setup,
+    agent, analysis, chat, common, edit, global_lib, hot, import, media, output, playback, python,
+    query, script, sequence, settings, setup,
 };
 
 // ── JsonValue newtype ───────────────────────────────────────────
@@ -75,6 +115,7 @@ pub enum CommandCategory {
     Import,
     Python,
     Agent,
+    Output,
 }
 
 impl CommandCategory {
@@ -94,6 +135,7 @@ impl CommandCategory {
             Self::Import => "import",
             Self::Python => "python",
             Self::Agent => "agent",
+            Self::Output => "output",
         }
     }
 
@@ -110,9 +152,10 @@ impl CommandCategory {
             Self::Sequence => "Sequence CRUD: list, create, open, delete",
             Self::Media => "Audio file management",
             Self::Chat => "Chat history management",
-            Self::Import => "Vixen 3 project import",
+            Self::Import => "Vixen 3 and Light-O-Rama import, xLights export",
             Self::Python => "Python environment management",
             Self::Agent => "Agent sidecar communication",
+            Self::Output => "Live output to physical controllers",
         }
     }
 
@@ -132,6 +175,7 @@ impl CommandCategory {
             Self::Import,
             Self::Python,
             Self::Agent,
+            Self::Output,
         ]
     }
 }
@@ -170,7 +214,7 @@ impl CommandOutput {
 /// 2. `CommandResult` enum (serde-tagged, ts-rs exported)
 /// 3. `Command::info()` — metadata (name, description, category, undoable)
 /// 4. `Command::dispatch()` — execute sync variants; errors on async
-/// 5. `Command::registry_entries()` — catalog entries with JSON schemas
+/// 5. `Command::registry_entries()` — catalog entries with JSON schemas and result type names
 /// 6. `Command::from_tool_call()` — deserialize from (name, JSON) pair
 /// 7. `Command::dispatch_async()` — execute ALL variants (feature-gated `tauri-app`)
 /// 8. `Command::is_async()` — returns true for async variants
@@ -276,7 +320,8 @@ macro_rules! define_commands {
                 self,
                 state: &std::sync::Arc<crate::state::AppState>,
             ) -> Result<CommandOutput, crate::error::AppError> {
-                match self {
+                let keeps_frames = self.keeps_frames();
+                let result = match self {
                     $( Command::$pv(p) => $ph(state, p), )*
                     $( Command::$nv => $nh(state), )*
                     $( Command::$apv(_) => Err(crate::error::AppError::ApiError {
@@ -291,7 +336,11 @@ macro_rules! define_commands {
                             $ann,
                         ),
                     }), )*
+                };
+                if !keeps_frames {
+                    state.frame_cache.invalidate();
                 }
+                result
             }
         }
 
@@ -308,6 +357,7 @@ macro_rules! define_commands {
                             llm_hidden: define_commands!(@has_flag llm_hidden; $($pf)*),
                         },
                         catalog::schema_value::<$pp>(),
+                        define_commands!(@result_type $($pr)?),
                     ), )*
                     $( catalog::entry(
                         CommandInfo {
@@ -318,6 +368,7 @@ macro_rules! define_commands {
                             llm_hidden: define_commands!(@has_flag llm_hidden; $($nf)*),
                         },
                         catalog::empty_object_schema(),
+                        define_commands!(@result_type $($nr)?),
                     ), )*
                     $( catalog::entry(
                         CommandInfo {
@@ -328,6 +379,7 @@ macro_rules! define_commands {
                             llm_hidden: define_commands!(@has_flag llm_hidden; $($apf)*),
                         },
                         catalog::schema_value::<$app>(),
+                        define_commands!(@result_type $($apr)?),
                     ), )*
                     $( catalog::entry(
                         CommandInfo {
@@ -338,6 +390,7 @@ macro_rules! define_commands {
                             llm_hidden: define_commands!(@has_flag llm_hidden; $($anf)*),
                         },
                         catalog::empty_object_schema(),
+                        define_commands!(@result_type $($anr)?),
                     ), )*
                 ]
             }
@@ -367,7 +420,9 @@ macro_rules! define_commands {
                 state: std::sync::Arc<crate::state::AppState>,
                 app: Option<tauri::AppHandle>,
             ) -> Result<CommandOutput, crate::error::AppError> {
-                match self {
+                let keeps_frames = self.keeps_frames();
+                let frame_cache_state = std::sync::Arc::clone(&state);
+                let result = match self {
                     // Sync params — run inline
                     $( Command::$pv(p) => $ph(&state, p), )*
                     // Sync no_params — run inline
@@ -376,7 +431,11 @@ macro_rules! define_commands {
                     $( Command::$apv(p) => $aph(state, app, p).await, )*
                     // Async no_params — .await
                     $( Command::$anv => $anh(state, app).await, )*
+                };
+                if !keeps_frames {
+                    frame_cache_state.frame_cache.invalidate();
                 }
+                result
             }
         }
 
@@ -400,6 +459,9 @@ macro_rules! define_commands {
     (@has_flag undoable; $_other:ident $($rest:ident)*) => { define_commands!(@has_flag undoable; $($rest)*) };
     (@has_flag undoable;) => { false };
 
+    (@result_type $t:ty) => { Some(stringify!($t)) };
+    (@result_type) => { None };
+
     (@has_flag llm_hidden; llm_hidden $($rest:ident)*) => { true };
     (@has_flag llm_hidden; $_other:ident $($rest:ident)*) => { define_commands!(@has_flag llm_hidden; $($rest)*) };
     (@has_flag llm_hidden;) => { false };
@@ -409,11 +471,15 @@ macro_rules! define_commands {
 
 define_commands! {
     params {
-        // ── Edit (9, all undoable) ──────────────────────────────
+        // ── Edit (27, all undoable) ─────────────────────────────
         [CommandCategory::Edit, undoable]
         AddEffect(AddEffectParams) -> usize
         => edit::add_effect, "add_effect": "Add an effect to a track. Returns the new effect index.";
 
+        [CommandCategory::Edit, undoable]
+        ApplyEffectPreset(ApplyEffectPresetParams) -> usize
+        => edit::apply_effect_preset, "apply_effect_preset": "Add an effect to a track from a saved effect preset. Returns the new effect index.";
+
         [CommandCategory::Edit, undoable]
         DeleteEffects(DeleteEffectsParams)
         => edit::delete_effects, "delete_effects": "Delete effects by (track_index, effect_index) pairs.";
@@ -422,10 +488,34 @@ define_commands! {
         UpdateEffectParam(UpdateEffectParamParams)
         => edit::update_effect_param, "update_effect_param": "Set a parameter on an effect.";
 
+        [CommandCategory::Edit, undoable]
+        ResetEffectParams(ResetEffectParamsParams)
+        => edit::reset_effect_params, "reset_effect_params": "Reset all of an effect's params to the defaults from its schema, discarding every customization. One undoable operation.";
+
         [CommandCategory::Edit, undoable]
         UpdateEffectTimeRange(UpdateEffectTimeRangeParams)
         => edit::update_effect_time_range, "update_effect_time_range": "Change the start/end time of an effect.";
 
+        [CommandCategory::Edit, undoable]
+        NudgeEffects(NudgeEffectsParams)
+        => edit::nudge_effects, "nudge_effects": "Shift effects by a signed delta in seconds (or frames when frames is true), clamped so none starts before 0 or ends past the sequence. One undoable operation.";
+
+        [CommandCategory::Edit, undoable]
+        QuantizeEffects(QuantizeEffectsParams)
+        => edit::quantize_effects, "quantize_effects": "Snap effect starts and ends to a musical grid (Whole, Half, Quarter, Eighth, Sixteenth, QuarterTriplet, EighthTriplet) derived from the analyzed tempo and phase-locked to the first downbeat. One undoable operation.";
+
+        [CommandCategory::Edit, undoable]
+        AlignEffects(AlignEffectsParams)
+        => edit::align_effects, "align_effects": "Line up effects, keeping their durations: AlignStart starts them all at the earliest start, AlignEnd ends them all at the latest end, DistributeEvenly spaces their starts evenly between the earliest and latest. One undoable operation.";
+
+        [CommandCategory::Edit, undoable]
+        CrossfadeEffects(CrossfadeParams)
+        => edit::crossfade_effects, "crossfade_effects": "Dissolve one effect into another over a time window where both are active: the first ramps its opacity down while the second ramps up. One undoable batch.";
+
+        [CommandCategory::Edit, undoable]
+        SetEffectFades(SetEffectFadesParams)
+        => edit::set_effect_fades, "set_effect_fades": "Set an effect's fade handles: seconds of linear opacity ramp-up at its start (fade_in) and ramp-down at its end (fade_out), independent of its time range. 0 disables a fade.";
+
         [CommandCategory::Edit, undoable]
         AddTrack(AddTrackParams) -> usize
         => edit::add_track, "add_track": "Create a new track targeting a fixture. Returns the new track index.";
@@ -434,6 +524,42 @@ define_commands! {
         DeleteTrack(DeleteTrackParams)
         => edit::delete_track, "delete_track": "Delete a track and all its effects by track index.";
 
+        [CommandCategory::Edit, undoable]
+        ReorderTrack(ReorderTrackParams)
+        => edit::reorder_track, "reorder_track": "Move a track to a new position. Later tracks render on top, so order affects blending.";
+
+        [CommandCategory::Edit, undoable]
+        SetTrackLocked(SetTrackLockedParams)
+        => edit::set_track_locked, "set_track_locked": "Lock or unlock a track. Locked tracks reject effect edits.";
+
+        [CommandCategory::Edit, undoable]
+        SetTrackBlend(SetTrackBlendParams)
+        => edit::set_track_blend, "set_track_blend": "Set how a whole track combines with the tracks below: blend mode and master opacity (0-1).";
+
+        [CommandCategory::Edit, undoable]
+        AddMarker(AddMarkerParams) -> usize
+        => edit::add_marker, "add_marker": "Add a labelled marker at a time in seconds. Markers stay sorted by time; returns the new marker's index.";
+
+        [CommandCategory::Edit, undoable]
+        DeleteMarker(DeleteMarkerParams)
+        => edit::delete_marker, "delete_marker": "Delete a marker by its index in the time-sorted marker list.";
+
+        [CommandCategory::Edit, undoable]
+        AddCue(AddCueParams) -> usize
+        => edit::add_cue, "add_cue": "Add a named cue at a time in seconds, optionally ending at `end` to cover a range. Cues stay sorted by time; returns the new cue's index.";
+
+        [CommandCategory::Edit, undoable]
+        DeleteCue(DeleteCueParams)
+        => edit::delete_cue, "delete_cue": "Delete a cue by its index in the time-sorted cue list.";
+
+        [CommandCategory::Edit, undoable]
+        AddAudioTrack(AddAudioTrackParams) -> usize
+        => edit::add_audio_track, "add_audio_track": "Layer another media file (a click track, a stem) onto the sequence's audio, with a gain and mute. Pass primary to make it the file analysis runs on. Returns the new audio track's index.";
+
+        [CommandCategory::Edit, undoable]
+        RemoveAudioTrack(RemoveAudioTrackParams)
+        => edit::remove_audio_track, "remove_audio_track": "Remove an audio track by index. Removing the primary track makes the next one primary.";
+
         [CommandCategory::Edit, undoable]
         MoveEffectToTrack(MoveEffectToTrackParams) -> usize
         => edit::move_effect_to_track, "move_effect_to_track": "Move an effect from one track to another.";
@@ -446,11 +572,23 @@ define_commands! {
         BatchEdit(BatchEditParams)
         => edit::batch_edit, "batch_edit": "Execute multiple edit commands as a single undoable operation.";
 
-        // ── Playback (3) ────────────────────────────────────────
+        [CommandCategory::Edit, undoable]
+        GenerateFromBeats(GenerateFromBeatsParams) -> GenerateFromBeatsResult
+        => edit::generate_from_beats, "generate_from_beats": "Create a track per fixture group with a color pulse on every analyzed beat, cycling through the given colors. One undoable batch.";
+
+        [CommandCategory::Edit, undoable]
+        MergeSequence(MergeSequenceParams)
+        => edit::merge_sequence, "merge_sequence": "Append another sequence's tracks to the current sequence, optionally time-shifted. One undoable operation.";
+
+        // ── Playback (6) ────────────────────────────────────────
         [CommandCategory::Playback]
         Seek(SeekParams)
         => playback::seek, "seek": "Seek to a time in seconds.";
 
+        [CommandCategory::Playback]
+        GoToCue(GoToCueParams)
+        => playback::go_to_cue, "go_to_cue": "Seek to a cue by index. With loop_region, a ranged cue also becomes the looping playback region.";
+
         [CommandCategory::Playback]
         SetRegion(SetRegionParams)
         => playback::set_region, "set_region": "Set or clear the playback region.";
@@ -459,25 +597,61 @@ define_commands! {
         SetLooping(SetLoopingParams)
         => playback::set_looping, "set_looping": "Enable or disable playback looping.";
 
-        // ── Query (2) ───────────────────────────────────────────
+        [CommandCategory::Playback]
+        SetTrackMuted(SetTrackMutedParams)
+        => playback::set_track_muted, "set_track_muted": "Mute or unmute a track. Muted tracks don't render. Not undoable.";
+
+        [CommandCategory::Playback]
+        SetTrackSolo(SetTrackSoloParams)
+        => playback::set_track_solo, "set_track_solo": "Solo or unsolo a track. While any track is soloed, only soloed tracks render. Transient, not undoable.";
+
+        // ── Query (5) ───────────────────────────────────────────
         [CommandCategory::Query]
         GetEffectDetail(GetEffectDetailParams) -> EffectDetail
         => query::get_effect_detail, "get_effect_detail": "Get schema and current params for a placed effect.";
 
+        [CommandCategory::Query]
+        ListEffectInstances(ListEffectInstancesParams) -> Vec<EffectInstanceEntry>
+        => query::list_effect_instances, "list_effect_instances": "List every placed effect in the active sequence as track/effect indices, kind and time range, optionally filtered by time range or kind.";
+
+        [CommandCategory::Query]
+        FindEffects(FindEffectsParams) -> Vec<EffectLocation>
+        => query::find_effects, "find_effects": "Find effects in the active sequence by kind and param predicates (e.g. Rate Gt 10), returning track/effect indices.";
+
+        [CommandCategory::Query]
+        AnalyzeShowComplexity(AnalyzeShowComplexityParams) -> Vec<TrackComplexity>
+        => query::analyze_show_complexity, "analyze_show_complexity": "Per-track effect count, peak overlap and targeted pixels for the active sequence, flagging tracks over max_effects or max_overlap. Use to find what slows scrubbing.";
+
         [CommandCategory::Query]
         Help(HelpParams) -> String
         => query::help, "help": "Discover available commands and categories. Call with no args for all categories, or with a topic for details.";
 
-        // ── Analysis (2) ────────────────────────────────────────
+        // ── Analysis (6) ────────────────────────────────────────
         [CommandCategory::Analysis]
         GetBeatsInRange(GetBeatsInRangeParams) -> BeatsInRange
         => analysis::get_beats_in_range, "get_beats_in_range": "Get beat timestamps within a time range.";
 
+        [CommandCategory::Analysis]
+        GetChords(GetChordsParams) -> Vec<ChordEvent>
+        => analysis::get_chords, "get_chords": "Get the detected chord labels overlapping a time range, in order. Empty when the analysis has no chord data.";
+
+        [CommandCategory::Analysis]
+        GetStemEnergy(GetStemEnergyParams) -> Vec<f64>
+        => analysis::get_stem_energy, "get_stem_energy": "Sample a stem's energy envelope (0-1) at the given times, e.g. to drive effects from just the drums. Empty when the analysis has no envelope for that stem.";
+
         [CommandCategory::Analysis]
         GetAnalysisDetail(GetAnalysisDetailParams) -> JsonValue
         => analysis::get_analysis_detail, "get_analysis_detail": "Get full detail for one analysis feature.";
 
-        // ── Library (6) ─────────────────────────────────────────
+        [CommandCategory::Analysis]
+        ExportAnalysis(ExportAnalysisParams)
+        => analysis::export_analysis, "export_analysis": "Export the current sequence's audio analysis to a portable JSON file.";
+
+        [CommandCategory::Analysis]
+        ImportAnalysis(ImportAnalysisParams)
+        => analysis::import_analysis, "import_analysis": "Load an exported analysis JSON file for the current sequence. Rejects files from a different audio file unless force is set.";
+
+        // ── Library (14) ────────────────────────────────────────
         [CommandCategory::Library]
         SetGlobalGradient(SetGlobalGradientParams)
         => global_lib::set_global_gradient, "set_global_gradient": "Create or update a named gradient in the global library.";
@@ -490,6 +664,22 @@ define_commands! {
         RenameGlobalGradient(RenameParams)
         => global_lib::rename_global_gradient, "rename_global_gradient": "Rename a gradient in the global library.";
 
+        [CommandCategory::Library]
+        TransformGradient(TransformGradientParams)
+        => global_lib::transform_gradient, "transform_gradient": "Reverse and/or rotate a library gradient and save the result under a new name.";
+
+        [CommandCategory::Library]
+        QuantizeGradientToPalette(QuantizeGradientParams) -> ColorGradient
+        => global_lib::quantize_gradient_to_palette, "quantize_gradient_to_palette": "Posterize a gradient onto a fixed palette for props that only show a few colors: steps equal bands, each the perceptually nearest palette color, with hard edges. Optionally saves the result to the library as save_as.";
+
+        [CommandCategory::Library]
+        SaveEffectPreset(SaveEffectPresetParams)
+        => global_lib::save_effect_preset, "save_effect_preset": "Save an effect's kind and params from the current sequence as a named preset.";
+
+        [CommandCategory::Library]
+        DeleteEffectPreset(NameParams)
+        => global_lib::delete_effect_preset, "delete_effect_preset": "Delete an effect preset from the global library.";
+
         [CommandCategory::Library]
         SetGlobalCurve(SetGlobalCurveParams)
         => global_lib::set_global_curve, "set_global_curve": "Create or update a named curve in the global library.";
@@ -502,7 +692,23 @@ define_commands! {
         RenameGlobalCurve(RenameParams)
         => global_lib::rename_global_curve, "rename_global_curve": "Rename a curve in the global library.";
 
-        // ── Script (9) ──────────────────────────────────────────
+        [CommandCategory::Library]
+        AddCurvePreset(AddCurvePresetParams) -> String
+        => global_lib::add_curve_preset, "add_curve_preset": "Add a standard easing curve (e.g. EaseInOutCubic) to the global library. Returns the saved name.";
+
+        [CommandCategory::Library]
+        FindLibraryUsage(NameParams) -> Vec<EffectLocation>
+        => global_lib::find_library_usage, "find_library_usage": "List the effects in the active sequence that use a library gradient or curve, by reference or by value. Check before deleting.";
+
+        [CommandCategory::Library]
+        ExportLibraryBundle(ExportLibraryBundleParams)
+        => global_lib::export_library_bundle, "export_library_bundle": "Export the whole global library (gradients, curves, scripts, effect presets) to one shareable file.";
+
+        [CommandCategory::Library]
+        ImportLibraryBundle(ImportLibraryBundleParams) -> LibraryImportSummary
+        => global_lib::import_library_bundle, "import_library_bundle": "Merge a library bundle file into the global library. on_collision decides whether items with an existing name are skipped, overwritten or renamed.";
+
+        // ── Script (10) ─────────────────────────────────────────
         [CommandCategory::Script]
         WriteGlobalScript(WriteScriptParams)
         => script::write_global_script, "write_global_script": "Compile and save a DSL script to the global library.";
@@ -531,7 +737,11 @@ define_commands! {
         GetScriptParams(NameParams) -> Vec<ScriptParamInfo>
         => script::get_script_params, "get_script_params": "Get the parameter definitions for a compiled script.";
 
-        // ── Settings (2) ────────────────────────────────────────
+        [CommandCategory::Script]
+        GetScriptMeta(NameParams) -> ScriptMeta
+        => script::get_script_meta, "get_script_meta": "Get a library script's @title/@author/@description metadata.";
+
+        // ── Settings (4) ────────────────────────────────────────
         [CommandCategory::Settings]
         InitializeDataDir(InitializeDataDirParams) -> AppSettings
         => settings::initialize_data_dir, "initialize_data_dir": "Initialize the data directory on first launch.";
@@ -540,7 +750,15 @@ define_commands! {
         SetLlmConfig(SetLlmConfigParams)
         => settings::set_llm_config, "set_llm_config": "Configure the LLM provider, API key, and model.";
 
-        // ── Setup (6) ───────────────────────────────────────────
+        [CommandCategory::Settings]
+        SetOutputSettings(OutputSettings)
+        => settings::set_output_settings, "set_output_settings": "Configure how frames are packed for output (e.g. dithering).";
+
+        [CommandCategory::Settings]
+        SetSequenceDefaults(SequenceDefaults)
+        => settings::set_sequence_defaults, "set_sequence_defaults": "Set the duration and frame rate new sequences start with.";
+
+        // ── Setup (7) ───────────────────────────────────────────
         [CommandCategory::Setup]
         CreateSetup(CreateSetupParams) -> SetupSummary
         => setup::create_setup, "create_setup": "Create a new setup.";
@@ -561,15 +779,23 @@ define_commands! {
         UpdateSetupOutputs(UpdateSetupOutputsParams)
         => setup::update_setup_outputs, "update_setup_outputs": "Update controllers and patches in the current setup.";
 
+        [CommandCategory::Setup]
+        AutoPatch(AutoPatchParams) -> Vec<Patch>
+        => setup::auto_patch, "auto_patch": "Assign sequential DMX addresses to unpatched fixtures in the current setup.";
+
         [CommandCategory::Setup]
         UpdateSetupLayout(UpdateSetupLayoutParams)
         => setup::update_setup_layout, "update_setup_layout": "Update the layout in the current setup.";
 
-        // ── Sequence (3) ────────────────────────────────────────
+        // ── Sequence (4) ────────────────────────────────────────
         [CommandCategory::Sequence]
         CreateSequence(CreateSequenceParams) -> SequenceSummary
         => sequence::create_sequence, "create_sequence": "Create a new sequence in the current setup.";
 
+        [CommandCategory::Sequence]
+        CreateSequenceFromTemplate(CreateSequenceFromTemplateParams) -> SequenceSummary
+        => sequence::create_sequence_from_template, "create_sequence_from_template": "Create a sequence pre-populated with section markers and one empty track per group from a song-structure template (pop, edm, ballad). Uses the audio file's analyzed sections when available.";
+
         [CommandCategory::Sequence]
         OpenSequence(SlugParams) -> Box<Show>
         => sequence::open_sequence, "open_sequence": "Open a sequence by slug. Loads it into the editor.";
@@ -578,10 +804,10 @@ define_commands! {
         DeleteSequence(SlugParams)
         => sequence::delete_sequence, "delete_sequence": "Delete a sequence by slug.";
 
-        // ── Media (3) ───────────────────────────────────────────
+        // ── Media (4) ───────────────────────────────────────────
         [CommandCategory::Media]
         ImportMedia(ImportMediaParams) -> MediaFile
-        => media::import_media, "import_media": "Import a media file into the current setup.";
+        => media::import_media, "import_media": "Import a media file into the current setup. Audio length is detected; pass set_duration to make it the current sequence's duration.";
 
         [CommandCategory::Media]
         DeleteMedia(NameParams)
@@ -591,7 +817,11 @@ define_commands! {
         ResolveMediaPath(NameParams) -> String
         => media::resolve_media_path, "resolve_media_path": "Get the absolute path for a media filename.";
 
-        // ── Chat (2) ────────────────────────────────────────────
+        [CommandCategory::Media]
+        GetWaveform(GetWaveformParams) -> Waveform
+        => media::get_waveform, "get_waveform": "Get min/max waveform peaks for the current sequence's audio, downsampled to a bucket count.";
+
+        // ── Chat (4) ────────────────────────────────────────────
         [CommandCategory::Chat]
         SwitchAgentConversation(ConversationIdParams)
         => chat::switch_agent_conversation, "switch_agent_conversation": "Switch to a different agent conversation by ID.";
@@ -600,7 +830,15 @@ define_commands! {
         DeleteAgentConversation(ConversationIdParams)
         => chat::delete_agent_conversation, "delete_agent_conversation": "Delete an agent conversation by ID.";
 
-        // ── Import (5) ──────────────────────────────────────────
+        [CommandCategory::Chat]
+        GetConversationUsage(ConversationIdParams) -> ConversationUsage
+        => chat::get_conversation_usage, "get_conversation_usage": "Get cumulative token usage and estimated cost for an agent conversation.";
+
+        [CommandCategory::Chat]
+        ExportConversation(ExportConversationParams)
+        => chat::export_conversation, "export_conversation": "Export an agent conversation, including its tool calls and their results, to a Markdown or JSON transcript file.";
+
+        // ── Import (9) ──────────────────────────────────────────
         [CommandCategory::Import]
         ImportVixen(ImportVixenParams) -> SetupSummary
         => import::import_vixen, "import_vixen": "Import a Vixen 3 project (setup + sequences).";
@@ -613,6 +851,10 @@ define_commands! {
         ImportVixenSequence(ImportVixenSequenceParams) -> SequenceSummary
         => import::import_vixen_sequence, "import_vixen_sequence": "Import a single Vixen .tim sequence into an existing setup.";
 
+        [CommandCategory::Import]
+        ImportVixenSequences(ImportVixenSequencesParams) -> Vec<VixenSequenceImport>
+        => import::import_vixen_sequences, "import_vixen_sequences": "Import several Vixen .tim sequences into an existing setup in one pass. Files that fail are reported per path; the rest are still imported.";
+
         [CommandCategory::Import]
         ScanVixenDirectory(ScanVixenDirectoryParams) -> Box<VixenDiscovery>
         => import::scan_vixen_directory, "scan_vixen_directory": "Scan a Vixen 3 directory and return discovery info.";
@@ -621,10 +863,22 @@ define_commands! {
         CheckVixenPreviewFile(CheckVixenPreviewFileParams) -> usize
         => import::check_vixen_preview_file, "check_vixen_preview_file": "Validate a Vixen preview file and return item count.";
 
-        // ── Hot-path (7) ────────────────────────────────────────
+        [CommandCategory::Import]
+        ScanLorDirectory(ScanLorDirectoryParams) -> LorDiscovery
+        => import::scan_lor_directory, "scan_lor_directory": "List the Light-O-Rama sequences (.lms) and channel configs (.lcc) in a directory.";
+
+        [CommandCategory::Import]
+        ImportLor(ImportLorParams) -> SetupSummary
+        => import::import_lor, "import_lor": "Import a Light-O-Rama .lms sequence or .lcc channel config as a new setup. Channels become fixtures, RGB channels and channel groups become groups.";
+
+        [CommandCategory::Import]
+        ExportXlights(ExportXlightsParams)
+        => import::export_xlights, "export_xlights": "Export the current sequence to an xLights .xsq file. Fixtures and groups become models, tracks become effect layers, and markers become a timing track. Effects without an xLights equivalent are skipped with a warning.";
+
+        // ── Hot-path (11) ───────────────────────────────────────
         [CommandCategory::Playback]
         Tick(TickParams) -> Option<TickResult>
-        => hot::tick, "tick": "Advance playback by one frame tick. Returns frame if playing.";
+        => hot::tick, "tick": "Advance playback by one frame tick. Returns frame if playing; pass the previous result's seq as since to get only changed fixtures.";
 
         [CommandCategory::Query]
         GetFrame(GetFrameParams) -> Frame
@@ -634,6 +888,18 @@ define_commands! {
         GetFrameFiltered(GetFrameFilteredParams) -> Frame
         => hot::get_frame_filtered, "get_frame_filtered": "Evaluate a frame rendering only specified effects.";
 
+        [CommandCategory::Query]
+        SimulateColorVision(SimulateColorVisionParams) -> Frame
+        => hot::simulate_color_vision, "simulate_color_vision": "Transform a frame's colors to show how it looks with protanopia, deuteranopia or tritanopia.";
+
+        [CommandCategory::Import]
+        ExportFramePng(ExportFramePngParams)
+        => hot::export_frame_png, "export_frame_png": "Render the frame at a time as bulbs on the layout and save it as a PNG. Shows without a layout are drawn as one strip.";
+
+        [CommandCategory::Query]
+        HashSequenceFrames(HashSequenceFramesParams) -> FrameDigest
+        => hot::hash_sequence_frames, "hash_sequence_frames": "Render every Nth frame of the current sequence and return a digest of the pixel data. The same show always gives the same digest, so it can pin output across engine changes.";
+
         [CommandCategory::Query]
         RenderEffectThumbnail(RenderEffectThumbnailParams) -> Option<EffectThumbnail>
         => hot::render_effect_thumbnail, "render_effect_thumbnail": "Pre-render an effect as a thumbnail for the timeline.";
@@ -646,6 +912,19 @@ define_commands! {
         PreviewScriptFrame(PreviewScriptFrameParams) -> Vec<[u8; 4]>
         => hot::preview_script_frame, "preview_script_frame": "Evaluate a single frame of a compiled script.";
 
+        [CommandCategory::Script]
+        ProfileScript(ProfileScriptParams) -> ScriptProfile
+        => hot::profile_script, "profile_script": "Count VM ops executed per pixel for one frame of a compiled script.";
+
+        // ── Output (2) ──────────────────────────────────────────
+        [CommandCategory::Output]
+        StartLiveOutput(StartLiveOutputParams) -> LiveOutputInfo
+        => output::start_live_output, "start_live_output": "Start streaming frames at the playhead to all configured controllers, optionally at a faster output frame rate.";
+
+        [CommandCategory::Output]
+        IdentifyFixture(IdentifyFixtureParams)
+        => output::identify_fixture, "identify_fixture": "During live output, light only one fixture (all white, or a chase across its pixels) for a few seconds to find it on the hardware. Not undoable.";
+
         // ── Cancellation (1) ────────────────────────────────────
         [CommandCategory::Settings]
         CancelOperation(CancelOperationParams) -> bool
@@ -673,7 +952,16 @@ define_commands! {
         GetUndoState -> UndoState
         => playback::get_undo_state, "get_undo_state": "Get undo/redo availability and descriptions.";
 
-        // ── Query (4) ───────────────────────────────────────────
+        // ── Output (2) ──────────────────────────────────────────
+        [CommandCategory::Output]
+        StopLiveOutput
+        => output::stop_live_output, "stop_live_output": "Stop live output.";
+
+        [CommandCategory::Output]
+        GetOutputStats -> Vec<OutputStats>
+        => output::get_output_stats, "get_output_stats": "Get per-controller live-output counters: packets sent, send errors, and the last error. Empty when live output is not running.";
+
+        // ── Query (6) ───────────────────────────────────────────
         [CommandCategory::Query]
         GetShow -> Box<Show>
         => query::get_show, "get_show": "Get the full show model including fixtures, tracks, and effects.";
@@ -690,6 +978,14 @@ define_commands! {
         DescribeShow -> String
         => query::describe_show, "describe_show": "Get a human-readable description of the current show and sequence.";
 
+        [CommandCategory::Query]
+        ListMarkers -> Vec<Marker>
+        => query::list_markers, "list_markers": "List the current sequence's markers, sorted by time.";
+
+        [CommandCategory::Query, llm_hidden]
+        GetCommandSchema -> JsonValue
+        => query::get_command_schema, "get_command_schema": "Get an OpenAPI document describing every command's params, result type, and metadata.";
+
         // ── Analysis (3) ────────────────────────────────────────
         [CommandCategory::Analysis]
         GetAnalysisSummary -> AnalysisSummary
@@ -703,7 +999,7 @@ define_commands! {
         GetAnalysis -> Option<Box<AudioAnalysis>>
         => analysis::get_analysis, "get_analysis": "Get the cached audio analysis for the current sequence.";
 
-        // ── Library (2) ─────────────────────────────────────────
+        // ── Library (4) ─────────────────────────────────────────
         [CommandCategory::Library]
         ListGlobalGradients -> Vec<(String, ColorGradient)>
         => global_lib::list_global_gradients, "list_global_gradients": "List all gradients in the global library with their data.";
@@ -712,7 +1008,15 @@ define_commands! {
         ListGlobalCurves -> Vec<(String, Curve)>
         => global_lib::list_global_curves, "list_global_curves": "List all curves in the global library with their data.";
 
-        // ── Script (2) ──────────────────────────────────────────
+        [CommandCategory::Library]
+        ListEffectPresets -> Vec<(String, EffectPreset)>
+        => global_lib::list_effect_presets, "list_effect_presets": "List saved effect presets with their kind and params.";
+
+        [CommandCategory::Library]
+        ListCurvePresets -> Vec<(EasingFunction, Curve)>
+        => global_lib::list_curve_presets, "list_curve_presets": "List the standard easing curve presets with their sampled curves.";
+
+        // ── Script (3) ──────────────────────────────────────────
         [CommandCategory::Script]
         GetDslReference -> String
         => script::get_dsl_reference, "get_dsl_reference": "Get the complete DSL language reference.";
@@ -721,6 +1025,10 @@ define_commands! {
         ListGlobalScripts -> Vec<(String, String)>
         => script::list_global_scripts, "list_global_scripts": "List all script names in the global library.";
 
+        [CommandCategory::Script]
+        CompileAllGlobalScripts -> Vec<ScriptCompileResult>
+        => global_lib::compile_all_global_scripts, "compile_all_global_scripts": "Compile every script in the global library and report per-script errors, so broken scripts are caught before playback.";
+
         // ── Settings (3) ────────────────────────────────────────
         [CommandCategory::Settings]
         GetSettings -> Option<AppSettings>
@@ -730,7 +1038,7 @@ define_commands! {
         GetLlmConfig -> LlmConfigInfo
         => settings::get_llm_config, "get_llm_config": "Get the current LLM configuration (key is masked).";
 
-        // ── Setup (2) ───────────────────────────────────────────
+        // ── Setup (5) ───────────────────────────────────────────
         [CommandCategory::Setup]
         ListSetups -> Vec<SetupSummary>
         => setup::list_setups, "list_setups": "List all setups.";
@@ -738,6 +1046,16 @@ define_commands! {
         [CommandCategory::Setup]
         SaveSetup => setup::save_setup, "save_setup": "Save the current setup to disk.";
 
+        [CommandCategory::Setup]
+        UndoSetup => setup::undo_setup, "undo_setup": "Undo the last fixture, output or layout edit to the current setup. Setup edits have their own history, separate from sequence undo.";
+
+        [CommandCategory::Setup]
+        RedoSetup => setup::redo_setup, "redo_setup": "Redo the last undone setup edit.";
+
+        [CommandCategory::Setup]
+        GetSetupUndoState -> UndoState
+        => setup::get_setup_undo_state, "get_setup_undo_state": "Get undo/redo availability and descriptions for setup edits.";
+
         // ── Sequence (2) ────────────────────────────────────────
         [CommandCategory::Sequence]
         ListSequences -> Vec<SequenceSummary>
@@ -809,3 +1127,25 @@ define_commands! {
         => agent::clear_agent_session, "clear_agent_session": "Clear the agent session and reset conversation context.";
     }
 }
+
+impl Command {
+    /// Whether this command leaves every rendered frame as it was, so the
+    /// frame cache survives it. Reads, rendering and transport controls
+    /// qualify; everything else invalidates the cache when it runs.
+    fn keeps_frames(&self) -> bool {
+        self.info().category == CommandCategory::Query
+            || matches!(
+                self,
+                Command::Seek(_)
+                    | Command::ExportFramePng(_)
+                    | Command::IdentifyFixture(_)
+                    | Command::SetRegion(_)
+                    | Command::SetLooping(_)
+                    | Command::Tick(_)
+                    | Command::Play
+                    | Command::Pause
+                    | Command::GetPlayback
+                    | Command::GetUndoState
+            )
+    }
+}
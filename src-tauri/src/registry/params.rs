@@ -1,11 +1,18 @@
+use std::collections::HashMap;
+
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::engine::Frame;
 use crate::model::{
-    BlendMode, ColorGradient, Controller, Curve, EffectKind, EffectParams, FixtureDef,
-    FixtureGroup, Layout, Patch, ParamKey, ParamValue,
+    BlendMode, Color, ColorGradient, ColorVisionDeficiency, Controller, Curve, EasingFunction,
+    EffectKind, EffectParams, FixtureDef, FixtureGroup, Layout, Patch, ParamKey, ParamValue,
 };
-use crate::model::AnalysisFeatures;
+use crate::model::fixture::Universe;
+use crate::model::{AnalysisFeatures, GridDivision};
+use crate::chat::TranscriptFormat;
+use crate::settings::{LlmProviderKind, ModelRate};
+use crate::setup::NameCollision;
 
 /// Represents a field update that distinguishes "absent" from "null" from "value".
 /// Use as `Option<FieldUpdate<T>>` with `#[serde(default, deserialize_with = "field_update_opt::deserialize")]`.
@@ -87,6 +94,14 @@ pub struct UpdateEffectParamParams {
     pub value: ParamValue,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub struct ResetEffectParamsParams {
+    pub track_index: usize,
+    pub effect_index: usize,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
 #[cfg_attr(feature = "tauri-app", ts(export))]
@@ -97,6 +112,53 @@ pub struct UpdateEffectTimeRangeParams {
     pub end: f64,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub struct NudgeEffectsParams {
+    /// Effects to shift together.
+    pub targets: Vec<EffectLocation>,
+    /// Signed shift; negative moves earlier.
+    pub delta: f64,
+    /// When true, `delta` counts frames at the sequence's frame rate
+    /// instead of seconds.
+    #[serde(default)]
+    pub frames: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub struct QuantizeEffectsParams {
+    /// Effects whose start and end snap to the grid.
+    pub targets: Vec<EffectLocation>,
+    /// Grid spacing as a note value at the analyzed tempo. Defaults to quarter notes.
+    #[serde(default)]
+    pub grid: GridDivision,
+}
+
+/// How `align_effects` lines effects up. Every effect keeps its duration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub enum AlignMode {
+    /// Start every effect where the earliest one starts.
+    AlignStart,
+    /// End every effect where the latest one ends.
+    AlignEnd,
+    /// Keep the earliest and latest starts and space the rest evenly between.
+    DistributeEvenly,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub struct AlignEffectsParams {
+    /// Effects to line up; at least two.
+    pub targets: Vec<EffectLocation>,
+    pub mode: AlignMode,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
 #[cfg_attr(feature = "tauri-app", ts(export))]
@@ -112,6 +174,101 @@ pub struct DeleteTrackParams {
     pub track_index: usize,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub struct ReorderTrackParams {
+    pub from_index: usize,
+    pub to_index: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub struct SetTrackLockedParams {
+    pub track_index: usize,
+    pub locked: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub struct SetTrackBlendParams {
+    pub track_index: usize,
+    pub blend_mode: BlendMode,
+    /// Master opacity of the whole track, 0.0-1.0.
+    pub opacity: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub struct AddMarkerParams {
+    /// Time in seconds.
+    pub time: f64,
+    pub label: String,
+    /// Defaults to amber.
+    #[serde(default)]
+    pub color: Option<Color>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub struct DeleteMarkerParams {
+    /// Index into the time-sorted marker list.
+    pub marker_index: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub struct AddCueParams {
+    pub name: String,
+    /// Start time in seconds.
+    pub time: f64,
+    /// End time in seconds, for a cue covering a range.
+    #[serde(default)]
+    pub end: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub struct DeleteCueParams {
+    /// Index into the time-sorted cue list.
+    pub cue_index: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub struct AddAudioTrackParams {
+    /// File name in the setup's media directory.
+    pub path: String,
+    /// Linear playback gain. Defaults to 1.0.
+    #[serde(default = "default_audio_gain")]
+    pub gain: f64,
+    #[serde(default)]
+    pub muted: bool,
+    /// Make this the primary file that analysis runs on. The first audio
+    /// added to a sequence is primary either way.
+    #[serde(default)]
+    pub primary: bool,
+}
+
+fn default_audio_gain() -> f64 {
+    1.0
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub struct RemoveAudioTrackParams {
+    /// Index into the sequence's audio tracks.
+    pub audio_index: usize,
+}
+
 /// A single action within a batch edit operation.
 /// Typed union — adding a variant without handling it is a compiler error.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -131,6 +288,12 @@ pub enum BatchAction {
     AddTrack(AddTrackParams),
     #[serde(rename = "delete_track")]
     DeleteTrack(DeleteTrackParams),
+    #[serde(rename = "reorder_track")]
+    ReorderTrack(ReorderTrackParams),
+    #[serde(rename = "set_track_locked")]
+    SetTrackLocked(SetTrackLockedParams),
+    #[serde(rename = "set_track_blend")]
+    SetTrackBlend(SetTrackBlendParams),
     #[serde(rename = "move_effect_to_track")]
     MoveEffectToTrack(MoveEffectToTrackParams),
     #[serde(rename = "update_sequence_settings")]
@@ -187,6 +350,22 @@ impl BatchAction {
                 sequence_index,
                 track_index: p.track_index,
             })),
+            BatchAction::ReorderTrack(p) => Ok(Some(EditCommand::ReorderTrack {
+                sequence_index,
+                from_index: p.from_index,
+                to_index: p.to_index,
+            })),
+            BatchAction::SetTrackLocked(p) => Ok(Some(EditCommand::SetTrackLocked {
+                sequence_index,
+                track_index: p.track_index,
+                locked: p.locked,
+            })),
+            BatchAction::SetTrackBlend(p) => Ok(Some(EditCommand::SetTrackBlend {
+                sequence_index,
+                track_index: p.track_index,
+                blend_mode: p.blend_mode,
+                opacity: p.opacity,
+            })),
             BatchAction::MoveEffectToTrack(p) => Ok(Some(EditCommand::MoveEffectToTrack {
                 sequence_index,
                 from_track: p.from_track,
@@ -207,6 +386,53 @@ impl BatchAction {
     }
 }
 
+impl From<BatchAction> for super::Command {
+    fn from(action: BatchAction) -> Self {
+        use super::Command;
+
+        match action {
+            BatchAction::AddEffect(p) => Command::AddEffect(p),
+            BatchAction::DeleteEffects(p) => Command::DeleteEffects(p),
+            BatchAction::UpdateEffectParam(p) => Command::UpdateEffectParam(p),
+            BatchAction::UpdateEffectTimeRange(p) => Command::UpdateEffectTimeRange(p),
+            BatchAction::AddTrack(p) => Command::AddTrack(p),
+            BatchAction::DeleteTrack(p) => Command::DeleteTrack(p),
+            BatchAction::ReorderTrack(p) => Command::ReorderTrack(p),
+            BatchAction::SetTrackLocked(p) => Command::SetTrackLocked(p),
+            BatchAction::SetTrackBlend(p) => Command::SetTrackBlend(p),
+            BatchAction::MoveEffectToTrack(p) => Command::MoveEffectToTrack(p),
+            BatchAction::UpdateSequenceSettings(p) => Command::UpdateSequenceSettings(p),
+            BatchAction::WriteScript(p) => Command::WriteGlobalScript(p),
+        }
+    }
+}
+
+/// The inverse of `From<BatchAction>`: every batch action's command maps back
+/// onto it, anything else is rejected.
+impl TryFrom<super::Command> for BatchAction {
+    type Error = String;
+
+    fn try_from(cmd: super::Command) -> Result<Self, Self::Error> {
+        use super::Command;
+
+        match cmd {
+            Command::AddEffect(p) => Ok(BatchAction::AddEffect(p)),
+            Command::DeleteEffects(p) => Ok(BatchAction::DeleteEffects(p)),
+            Command::UpdateEffectParam(p) => Ok(BatchAction::UpdateEffectParam(p)),
+            Command::UpdateEffectTimeRange(p) => Ok(BatchAction::UpdateEffectTimeRange(p)),
+            Command::AddTrack(p) => Ok(BatchAction::AddTrack(p)),
+            Command::DeleteTrack(p) => Ok(BatchAction::DeleteTrack(p)),
+            Command::ReorderTrack(p) => Ok(BatchAction::ReorderTrack(p)),
+            Command::SetTrackLocked(p) => Ok(BatchAction::SetTrackLocked(p)),
+            Command::SetTrackBlend(p) => Ok(BatchAction::SetTrackBlend(p)),
+            Command::MoveEffectToTrack(p) => Ok(BatchAction::MoveEffectToTrack(p)),
+            Command::UpdateSequenceSettings(p) => Ok(BatchAction::UpdateSequenceSettings(p)),
+            Command::WriteGlobalScript(p) => Ok(BatchAction::WriteScript(p)),
+            other => Err(format!("'{}' cannot be part of a batch edit", other.info().name)),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
 #[cfg_attr(feature = "tauri-app", ts(export))]
@@ -216,6 +442,58 @@ pub struct BatchEditParams {
     pub commands: Vec<BatchAction>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub struct GenerateFromBeatsParams {
+    /// Colors cycled across successive beats.
+    pub colors: Vec<Color>,
+    /// Pulse length in seconds. Defaults to half a beat at the analyzed tempo.
+    #[serde(default)]
+    pub pulse_length: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub struct MergeSequenceParams {
+    /// Slug of the sequence whose tracks are appended.
+    pub slug: String,
+    /// Setup the sequence lives in. Defaults to the current setup.
+    #[serde(default)]
+    pub setup: Option<String>,
+    /// Seconds added to every merged effect's start and end.
+    #[serde(default)]
+    pub time_offset: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub struct CrossfadeParams {
+    /// Effect that fades out across the window.
+    pub from: EffectLocation,
+    /// Effect that fades in across the window.
+    pub to: EffectLocation,
+    /// Crossfade window in seconds; must lie within both effects.
+    pub start: f64,
+    pub end: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub struct SetEffectFadesParams {
+    pub track_index: usize,
+    pub effect_index: usize,
+    /// Seconds of opacity ramp-up at the start; 0 for none.
+    #[serde(default)]
+    pub fade_in: f64,
+    /// Seconds of opacity ramp-down at the end; 0 for none.
+    #[serde(default)]
+    pub fade_out: f64,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
 #[cfg_attr(feature = "tauri-app", ts(export))]
@@ -248,6 +526,17 @@ pub struct SeekParams {
     pub time: f64,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub struct GoToCueParams {
+    /// Index into the time-sorted cue list.
+    pub cue_index: usize,
+    /// For a ranged cue, also set the playback region to it and enable looping.
+    #[serde(default)]
+    pub loop_region: bool,
+}
+
 // ── Analysis params ─────────────────────────────────────────────
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -258,6 +547,24 @@ pub struct GetBeatsInRangeParams {
     pub end: f64,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub struct GetChordsParams {
+    pub start: f64,
+    pub end: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub struct GetStemEnergyParams {
+    /// Stem name: "vocals", "drums", "bass" or "other".
+    pub stem: String,
+    /// Times to sample, in seconds.
+    pub times: Vec<f64>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
 #[cfg_attr(feature = "tauri-app", ts(export))]
@@ -265,6 +572,23 @@ pub struct GetAnalysisDetailParams {
     pub feature: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub struct ExportAnalysisParams {
+    pub path: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub struct ImportAnalysisParams {
+    pub path: String,
+    /// Attach the analysis even if it was exported for a different audio file.
+    #[serde(default)]
+    pub force: bool,
+}
+
 // ── Script params ───────────────────────────────────────────────
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -312,9 +636,17 @@ pub struct InitializeDataDirParams {
 #[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
 #[cfg_attr(feature = "tauri-app", ts(export))]
 pub struct SetLlmConfigParams {
+    #[serde(default)]
+    pub provider: LlmProviderKind,
     pub api_key: String,
     #[serde(default)]
     pub model: Option<String>,
+    /// API base URL override (e.g. a proxy). None = provider default.
+    #[serde(default)]
+    pub base_url: Option<String>,
+    /// Per-model token prices for cost estimates. None = keep existing rates.
+    #[serde(default)]
+    pub rates: Option<HashMap<String, ModelRate>>,
 }
 
 // ── Setup params ──────────────────────────────────────────────
@@ -342,6 +674,16 @@ pub struct UpdateSetupOutputsParams {
     pub patches: Vec<Patch>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub struct AutoPatchParams {
+    /// First universe to patch into (0-indexed).
+    pub start_universe: Universe,
+    /// Channels to use in each universe before wrapping to the next (1-512).
+    pub channels_per_universe: u16,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
 #[cfg_attr(feature = "tauri-app", ts(export))]
@@ -358,6 +700,19 @@ pub struct CreateSequenceParams {
     pub name: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub struct CreateSequenceFromTemplateParams {
+    pub name: String,
+    /// Template id: "pop", "edm" or "ballad".
+    pub template: String,
+    /// Media filename to attach. Its cached analysis sections, if any,
+    /// replace the template's default section timing.
+    #[serde(default)]
+    pub audio_file: Option<String>,
+}
+
 // ── Media params ───────────────────────────────────────────────
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -365,6 +720,17 @@ pub struct CreateSequenceParams {
 #[cfg_attr(feature = "tauri-app", ts(export))]
 pub struct ImportMediaParams {
     pub source_path: String,
+    /// Also set the current sequence's duration to the audio's length.
+    #[serde(default)]
+    pub set_duration: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub struct GetWaveformParams {
+    /// Number of min/max peak pairs spanning the audio file.
+    pub buckets: usize,
 }
 
 // ── Playback extended params ───────────────────────────────────
@@ -391,6 +757,51 @@ pub struct SetLoopingParams {
     pub looping: bool,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub struct SetTrackMutedParams {
+    pub track_index: usize,
+    pub muted: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub struct SetTrackSoloParams {
+    pub track_index: usize,
+    pub solo: bool,
+}
+
+// ── Output params ──────────────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub struct StartLiveOutputParams {
+    /// Controller update rate in frames per second. Frames between the
+    /// sequence's own are interpolated. Defaults to the sequence's frame rate.
+    #[serde(default)]
+    pub output_fps: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub struct IdentifyFixtureParams {
+    pub fixture_id: u32,
+    /// How long to light the fixture, in seconds. Defaults to 10.
+    #[serde(default = "default_identify_duration")]
+    pub duration: f64,
+    /// Run a single pixel along the fixture instead of lighting all of it.
+    #[serde(default)]
+    pub chase: bool,
+}
+
+fn default_identify_duration() -> f64 {
+    10.0
+}
+
 // ── Help params ────────────────────────────────────────────────
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -414,6 +825,77 @@ pub struct GetEffectDetailParams {
     pub effect_index: usize,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub struct ListEffectInstancesParams {
+    /// Only list effects that overlap [start, end] (seconds). Either bound may be omitted.
+    #[serde(default)]
+    pub start: Option<f64>,
+    #[serde(default)]
+    pub end: Option<f64>,
+    /// Only list effects of this kind.
+    #[serde(default)]
+    pub kind: Option<EffectKind>,
+}
+
+/// How a [`ParamPredicate`] compares an effect's param against its value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub enum ParamComparator {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+/// A test on one effect param, e.g. `Rate Gt 10`. Ordering comparators only
+/// match numeric params.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub struct ParamPredicate {
+    pub key: ParamKey,
+    pub op: ParamComparator,
+    pub value: ParamValue,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub struct FindEffectsParams {
+    /// Only match effects of this kind.
+    #[serde(default)]
+    pub kind: Option<EffectKind>,
+    /// Every predicate must hold. Params an effect leaves unset are compared
+    /// at their schema default.
+    #[serde(default)]
+    pub predicates: Vec<ParamPredicate>,
+}
+
+fn default_max_track_effects() -> usize {
+    500
+}
+
+fn default_max_track_overlap() -> usize {
+    8
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub struct AnalyzeShowComplexityParams {
+    /// Flag tracks holding more effects than this. Defaults to 500.
+    #[serde(default = "default_max_track_effects")]
+    pub max_effects: usize,
+    /// Flag tracks where more effects than this play at once. Defaults to 8.
+    #[serde(default = "default_max_track_overlap")]
+    pub max_overlap: usize,
+}
+
 // ── Global library params ──────────────────────────────────────
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -432,6 +914,92 @@ pub struct SetGlobalCurveParams {
     pub curve: Curve,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub struct AddCurvePresetParams {
+    pub kind: EasingFunction,
+    /// Library name for the curve. Defaults to the preset's name, e.g. "Ease In Out Cubic".
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub struct TransformGradientParams {
+    /// Library gradient to transform.
+    pub name: String,
+    /// Name to save the result under.
+    pub new_name: String,
+    /// Flip the gradient end to end.
+    #[serde(default)]
+    pub reverse: bool,
+    /// Shift stops forward by this fraction, wrapping at 1. Applied after `reverse`.
+    #[serde(default)]
+    pub rotate: f64,
+}
+
+fn default_quantize_steps() -> usize {
+    16
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub struct QuantizeGradientParams {
+    pub gradient: ColorGradient,
+    /// Colors the prop can show. Each band takes the perceptually nearest one.
+    pub palette: Vec<Color>,
+    /// Number of equal-width bands. Defaults to 16.
+    #[serde(default = "default_quantize_steps")]
+    pub steps: usize,
+    /// Also save the result to the global library under this name.
+    #[serde(default)]
+    pub save_as: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub struct SaveEffectPresetParams {
+    pub name: String,
+    /// Effect in the current sequence whose kind and params are snapshotted.
+    pub track_index: usize,
+    pub effect_index: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub struct ApplyEffectPresetParams {
+    pub name: String,
+    pub track_index: usize,
+    pub start: f64,
+    pub end: f64,
+    #[serde(default = "default_blend_mode")]
+    pub blend_mode: BlendMode,
+    #[serde(default = "default_opacity")]
+    pub opacity: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub struct ExportLibraryBundleParams {
+    pub path: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub struct ImportLibraryBundleParams {
+    pub path: String,
+    /// What to do with items whose name is already in the library. Defaults to Skip.
+    #[serde(default)]
+    pub on_collision: NameCollision,
+}
+
 // ── Script extended params ─────────────────────────────────────
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -450,6 +1018,16 @@ pub struct ConversationIdParams {
     pub conversation_id: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub struct ExportConversationParams {
+    pub conversation_id: String,
+    /// Destination file (`.md` or `.json`).
+    pub path: String,
+    pub format: TranscriptFormat,
+}
+
 // ── Vixen import params ────────────────────────────────────────
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -475,6 +1053,14 @@ pub struct ImportVixenSequenceParams {
     pub tim_path: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub struct ImportVixenSequencesParams {
+    pub setup_slug: String,
+    pub tim_paths: Vec<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
 #[cfg_attr(feature = "tauri-app", ts(export))]
@@ -489,6 +1075,32 @@ pub struct CheckVixenPreviewFileParams {
     pub file_path: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub struct ScanLorDirectoryParams {
+    pub lor_dir: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub struct ImportLorParams {
+    /// Path to a `.lms` sequence or `.lcc` channel configuration.
+    pub path: String,
+    /// Name for the new setup. Defaults to the file name.
+    #[serde(default)]
+    pub setup_name: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub struct ExportXlightsParams {
+    /// Destination `.xsq` file.
+    pub path: String,
+}
+
 // ── Hot-path params ────────────────────────────────────────────
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -496,6 +1108,16 @@ pub struct CheckVixenPreviewFileParams {
 #[cfg_attr(feature = "tauri-app", ts(export))]
 pub struct TickParams {
     pub dt: f64,
+    /// `seq` of the last tick this client applied. When it is still the
+    /// latest, the result is a delta against it; otherwise (or when absent)
+    /// the full frame is returned.
+    #[serde(default)]
+    #[cfg_attr(feature = "tauri-app", ts(type = "number | null"))]
+    pub since: Option<u64>,
+    /// Identifies the caller, so each client gets deltas against the frames
+    /// it received. Clients that omit it share one baseline.
+    #[serde(default)]
+    pub client: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -513,6 +1135,44 @@ pub struct GetFrameFilteredParams {
     pub effects: Vec<(usize, usize)>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub struct SimulateColorVisionParams {
+    pub kind: ColorVisionDeficiency,
+    /// A frame as returned by `get_frame`.
+    #[schemars(with = "serde_json::Value")]
+    pub frame: Frame,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub struct ExportFramePngParams {
+    /// Destination `.png` file.
+    pub path: String,
+    /// Time in seconds of the frame to export.
+    pub time: f64,
+    pub width: u32,
+    pub height: u32,
+    /// Fill behind the bulbs. Defaults to black, like the preview.
+    #[serde(default)]
+    pub background: Option<Color>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub struct HashSequenceFramesParams {
+    /// Hash every Nth frame. Defaults to 1, every frame.
+    #[serde(default = "default_hash_every")]
+    pub every: usize,
+}
+
+fn default_hash_every() -> usize {
+    1
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
 #[cfg_attr(feature = "tauri-app", ts(export))]
@@ -544,6 +1204,16 @@ pub struct PreviewScriptFrameParams {
     pub t: f64,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "tauri-app", derive(ts_rs::TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub struct ProfileScriptParams {
+    pub name: String,
+    pub params: EffectParams,
+    pub pixel_count: usize,
+    pub t: f64,
+}
+
 // ── Cancellation params ────────────────────────────────────────
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
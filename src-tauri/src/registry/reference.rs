@@ -10,8 +10,10 @@ fn builtin_functions_table() -> String {
     // Ordered list of (category_key, display_title)
     let categories: &[(&str, &str)] = &[
         ("math", "Math"),
+        ("wave", "Waveforms"),
         ("color", "Color Constructors"),
         ("vec2", "Vec2"),
+        ("vec3", "Vec3"),
         ("hash", "Hash / Random"),
         ("easing", "Easing"),
         ("noise", "Noise"),
@@ -73,6 +75,7 @@ pub fn dsl_reference() -> String {
 ## Script Structure
 ```
 @name "My Effect"       // metadata: display name
+@author "Sam"           // optional: @title, @author, @description (descriptive only)
 @spatial true           // enable 2D position data (pos2d variable)
 
 // Type definitions (before params)
@@ -113,7 +116,7 @@ if mode == Mode.Pulse {
 
 ## Statement Termination
 - `;` required after `let` statements, expression statements, and `param` declarations
-- `;` NOT required after blocks: `enum { }`, `fn { }`, `if/else { }`, `switch { }`
+- `;` NOT required after blocks: `enum { }`, `fn { }`, `if/else { }`, `switch { }`, `match { }`
 - `;` optional for `@metadata` directives
 - Last expression in a block (the return value) doesn't need `;`
 - Extra semicolons between statements are tolerated
@@ -135,6 +138,7 @@ if mode == Mode.Pulse {
 - `bool` — true/false
 - `color` — RGBA color (r, g, b, a fields, 0-255)
 - `vec2` — 2D vector (x, y fields)
+- `vec3` — 3D vector (x, y, z fields)
 - `gradient` — color gradient (callable: `grad(position)`)
 - `curve` — timing curve (callable: `curve1(x)`)
 - `path` — motion path (bare ident → Vec2 at abs_t, callable: `orb(time)` → Vec2)
@@ -163,10 +167,11 @@ if mode == Mode.Pulse {
 ### Logical
 | Operator | Description |
 |----------|-------------|
-| `&&` | Logical AND |
-| `\|\|` | Logical OR |
+| `&&` | Logical AND (skips the right side when the left is false) |
+| `\|\|` | Logical OR (skips the right side when the left is true) |
 | `!`  | Logical NOT (unary) |
 | `&`  | Flag test (bitwise AND for flags) |
+| `c ? a : b` | `a` when `c` is true, else `b`; only the chosen side runs |
 
 ## Parameter Types
 - `float(min, max)` — float slider with range
@@ -211,6 +216,16 @@ if mode == Mode.Pulse {
 | `vec2 * float` | Scale both components |
 | `float * vec2` | Scale both components (commutative) |
 
+### Vec3 Operations
+| Operation | Description |
+|-----------|-------------|
+| `v.x`, `v.y`, `v.z` | Component access |
+| `vec3 + vec3` | Component-wise addition |
+| `vec3 - vec3` | Component-wise subtraction |
+| `vec3 * float` | Scale all components |
+| `float * vec3` | Scale all components (commutative) |
+| `dot(vec3, vec3)` | Dot product |
+
 ## Control Flow
 
 ### If / Else If / Else
@@ -225,6 +240,17 @@ if condition {
 ```
 If-expressions return a value (the last expression in the taken branch).
 
+### Match
+```
+match dir {
+    Left => color1,
+    Direction.Right => color2,
+    _ => #000000,
+}
+```
+Arms compare the scrutinee against each pattern in order. On an enum param, bare
+variant names are allowed. The final `_ => ...` arm is required.
+
 ### Boolean Logic
 ```
 // Combine conditions with && and ||
@@ -272,7 +298,12 @@ fn function_name(param1: type, param2: type) -> return_type {
     expr
 }
 ```
-Supported types in signatures: `float`, `int`, `bool`, `color`, `vec2`, `gradient`, `curve`, `path`.
+Supported types in signatures: `float`, `int`, `bool`, `color`, `vec2`, `vec3`, `gradient`, `curve`, `path`.
+
+## Script Libraries
+`use "library_name";` at the top of a script pulls in every `fn` defined in the global script
+`library_name` (and the libraries it uses). Only functions are imported; the library's params
+and body are ignored. A function defined in the script itself shadows one of the same name.
 
 ## Examples
 
@@ -366,6 +397,8 @@ pub fn design_guide() -> String {
     out.push_str(
         "\n## Beat Sync Techniques\n\
          - Use `get_beats_in_range` to get exact beat timestamps\n\
+         - Use `get_chords` to change colors on harmonic changes\n\
+         - Use `get_stem_energy` on \"drums\" or \"vocals\" to drive a track from one instrument\n\
          - Place Strobe effects on beats for impact\n\
          - Use Fade effects spanning 2-4 beats for rhythmic breathing\n\
          - Chase effects with speed matching BPM: `speed = tempo / 60`\n\
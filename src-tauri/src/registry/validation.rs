@@ -4,13 +4,14 @@
 //! handlers produce consistent error messages.
 
 use crate::error::AppError;
+use crate::model::TimeRange;
 
 /// Validate a time range: both values must be finite, non-negative, and start < end.
 pub fn validate_time_range(start: f64, end: f64) -> Result<(), AppError> {
-    crate::model::TimeRange::new(start, end).ok_or_else(|| AppError::ValidationError {
-        message: format!(
-            "Invalid time range: start={start:.3}, end={end:.3}. Must be finite, start >= 0, start < end"
-        ),
+    TimeRange::new(start, end).ok_or_else(|| AppError::InvalidTimeRange {
+        start,
+        end,
+        reason: "must be finite, start >= 0, start < end".into(),
     })?;
     Ok(())
 }
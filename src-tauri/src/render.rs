@@ -0,0 +1,163 @@
+//! Headless rendering for batch jobs and frame regression checks.
+//!
+//! A sequence is opened and evaluated through the same synchronous command
+//! path the GUI and CLI use, so scripts, libraries and blending behave exactly
+//! as they do in the editor.
+
+use std::sync::Arc;
+
+use crate::engine::Frame;
+use crate::error::AppError;
+use crate::model::show::Show;
+use crate::registry::execute::execute;
+use crate::registry::params::{GetFrameParams, SlugParams};
+use crate::registry::{Command, CommandResult};
+use crate::settings::AppSettings;
+use crate::setup;
+use crate::state::AppState;
+
+/// Every frame of one sequence, plus the show it was rendered against.
+#[derive(Debug)]
+pub struct RenderedSequence {
+    pub show: Show,
+    pub frame_rate: f64,
+    /// One frame per `1 / frame_rate` seconds, starting at time zero.
+    pub frames: Vec<Frame>,
+}
+
+/// Load `sequence_slug` from `setup_slug` under `settings.data_dir` and
+/// evaluate it from start to end at the sequence's frame rate.
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::cast_precision_loss
+)]
+pub fn render_sequence(
+    settings: AppSettings,
+    setup_slug: &str,
+    sequence_slug: &str,
+) -> Result<RenderedSequence, AppError> {
+    let libraries = setup::load_global_libraries(&settings.data_dir).map_err(AppError::from)?;
+    let state = Arc::new(AppState::headless(Some(settings), libraries));
+    // Set directly rather than via OpenSetup, which records the setup in the
+    // user's settings file.
+    *state.current_setup.lock() = Some(setup_slug.to_string());
+    execute(
+        &state,
+        Command::OpenSequence(SlugParams {
+            slug: sequence_slug.to_string(),
+        }),
+    )?;
+
+    let show = state.show.lock().clone();
+    let sequence = show.sequences.first().ok_or(AppError::NoSequence)?;
+    let frame_rate = sequence.frame_rate;
+    if !frame_rate.is_finite() || frame_rate <= 0.0 {
+        return Err(AppError::ValidationError {
+            message: format!("Sequence \"{sequence_slug}\" has no usable frame rate"),
+        });
+    }
+    let frame_count = (sequence.duration.max(0.0) * frame_rate).ceil() as usize;
+
+    let frames = (0..frame_count)
+        .map(|i| {
+            let time = i as f64 / frame_rate;
+            let output = execute(&state, Command::GetFrame(GetFrameParams { time }))?;
+            let CommandResult::GetFrame(frame) = output.result else {
+                return Err(AppError::ValidationError {
+                    message: "GetFrame returned an unexpected result".into(),
+                });
+            };
+            Ok(frame)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(RenderedSequence {
+        show,
+        frame_rate,
+        frames,
+    })
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::indexing_slicing)]
+mod tests {
+    use super::*;
+    use crate::model::color::Color;
+    use crate::model::fixture::{
+        BulbShape, ChannelOrder, ColorModel, EffectTarget, FixtureDef, FixtureId, PixelType,
+    };
+    use crate::model::timeline::{
+        BlendMode, EffectInstance, EffectKind, EffectParams, ParamKey, ParamValue, Sequence,
+        TimeRange, Track,
+    };
+    use crate::util::base64_decode;
+
+    #[test]
+    fn renders_every_frame_of_a_saved_sequence() {
+        let dir =
+            std::env::temp_dir().join(format!("vibelights_test_render_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let summary = setup::create_setup(&dir, "Yard").unwrap();
+        let mut setup_data = setup::load_setup(&dir, &summary.slug).unwrap();
+        setup_data.fixtures.push(FixtureDef {
+            id: FixtureId(0),
+            name: "Arch".into(),
+            color_model: ColorModel::Rgb,
+            pixel_count: 2,
+            pixel_type: PixelType::Smart,
+            bulb_shape: BulbShape::LED,
+            display_radius_override: None,
+            channel_order: ChannelOrder::Rgb,
+            gamma: None,
+        });
+        setup::save_setup(&dir, &summary.slug, &setup_data).unwrap();
+
+        let entry = setup::create_sequence(&dir, &summary.slug, "Song").unwrap();
+        let solid = EffectInstance {
+            kind: EffectKind::Solid,
+            params: EffectParams::new()
+                .set(ParamKey::Color, ParamValue::Color(Color::rgb(255, 0, 0))),
+            time_range: TimeRange::new(0.0, 0.5).unwrap(),
+            blend_mode: BlendMode::Override,
+            opacity: 1.0,
+            opacity_curve: None,
+            fade_in: 0.0,
+            fade_out: 0.0,
+        };
+        let sequence = Sequence {
+            frame_rate: 10.0,
+            ..Sequence::for_test(
+                "Song",
+                1.0,
+                vec![Track {
+                    name: "Wash".into(),
+                    target: EffectTarget::All,
+                    locked: false,
+                    muted: false,
+                    blend_mode: BlendMode::Override,
+                    opacity: 1.0,
+                    effects: vec![solid],
+                }],
+            )
+        };
+        setup::save_sequence(&dir, &summary.slug, &entry.slug, &sequence).unwrap();
+
+        let rendered =
+            render_sequence(AppSettings::new(dir.clone()), &summary.slug, &entry.slug).unwrap();
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert_eq!(rendered.frames.len(), 10);
+        let pixels = |i: usize| {
+            rendered.frames[i]
+                .fixtures
+                .get(&0)
+                .map(|b64| base64_decode(b64))
+                .unwrap_or_default()
+        };
+        assert_eq!(pixels(0), [255, 0, 0, 255, 255, 0, 0, 255]);
+        assert!(pixels(7).iter().step_by(4).all(|&r| r == 0));
+    }
+}
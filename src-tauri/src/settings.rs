@@ -1,5 +1,7 @@
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
@@ -8,35 +10,145 @@ use crate::project::{read_json, write_json, ProjectError};
 
 // ── LLM config types ────────────────────────────────────────────
 
-/// Full configuration for the LLM provider (Anthropic only).
+/// Which LLM API the agent talks to.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, TS, JsonSchema)]
+#[ts(export)]
+#[serde(rename_all = "snake_case")]
+pub enum LlmProviderKind {
+    /// Anthropic Messages API.
+    #[default]
+    Anthropic,
+    /// OpenAI chat-completions API. The agent sidecar can't use it yet, so
+    /// `SetLlmConfig` refuses it; kept so settings that name it still load.
+    #[ts(skip)]
+    #[schemars(skip)]
+    OpenAi,
+    /// Any server speaking the OpenAI chat-completions protocol at a custom
+    /// base URL (LM Studio, Ollama, vLLM, ...). Unsupported, like `OpenAi`.
+    #[ts(skip)]
+    #[schemars(skip)]
+    OpenAiCompatible,
+}
+
+impl LlmProviderKind {
+    /// Stable identifier, matching the serialized form.
+    pub fn slug(self) -> &'static str {
+        match self {
+            Self::Anthropic => "anthropic",
+            Self::OpenAi => "open_ai",
+            Self::OpenAiCompatible => "open_ai_compatible",
+        }
+    }
+}
+
+/// Full configuration for the LLM provider.
 ///
 /// The `api_key` field is never written to `settings.json`. It is stored in a
 /// separate credentials file and loaded/saved via [`load_api_key`]/[`save_api_key`].
 #[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
 #[ts(export)]
 pub struct LlmProviderConfig {
+    #[serde(default)]
+    pub provider: LlmProviderKind,
     /// Received over IPC but never persisted in settings.json (stored in separate credentials file).
     #[serde(default, skip_serializing)]
     pub api_key: Option<String>,
     /// Model override. None = use provider default.
     #[serde(default)]
     pub model: Option<String>,
+    /// API base URL override. Required for `OpenAiCompatible`; None = provider default.
+    #[serde(default)]
+    pub base_url: Option<String>,
+    /// Token prices keyed by model name, used to estimate conversation cost.
+    /// The `"default"` entry applies when no model override is set.
+    #[serde(default)]
+    pub rates: HashMap<String, ModelRate>,
+}
+
+/// Price of a model in USD per million tokens.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, TS, JsonSchema)]
+#[ts(export)]
+pub struct ModelRate {
+    pub input_per_mtok: f64,
+    pub output_per_mtok: f64,
+}
+
+impl LlmProviderConfig {
+    /// Rate for the configured model, falling back to the `"default"` entry.
+    #[must_use]
+    pub fn rate_for_current_model(&self) -> Option<ModelRate> {
+        self.model
+            .as_deref()
+            .and_then(|m| self.rates.get(m))
+            .or_else(|| self.rates.get("default"))
+            .copied()
+    }
 }
 
 /// Redacted view of the LLM config returned to the frontend (no raw API key).
 #[derive(Debug, Clone, Serialize, TS)]
 #[ts(export)]
 pub struct LlmConfigInfo {
+    pub provider: LlmProviderKind,
     pub has_api_key: bool,
     pub model: Option<String>,
+    pub base_url: Option<String>,
 }
 
 impl LlmConfigInfo {
     #[must_use]
     pub fn from_config(config: &LlmProviderConfig) -> Self {
         Self {
+            provider: config.provider,
             has_api_key: config.api_key.as_ref().is_some_and(|k| !k.is_empty()),
             model: config.model.clone(),
+            base_url: config.base_url.clone(),
+        }
+    }
+}
+
+// ── Output settings ──────────────────────────────────────────────
+
+/// How evaluated frames are packed into channel bytes for live output and export.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize, TS, JsonSchema)]
+#[ts(export)]
+pub struct OutputSettings {
+    /// Apply ordered dithering when quantizing channels to 8 bits. Off by
+    /// default so output is exactly reproducible.
+    #[serde(default)]
+    pub dither: bool,
+    /// Gamma for fixtures without their own. None = linear.
+    #[serde(default)]
+    pub gamma: Option<f32>,
+}
+
+// ── Sequence defaults ────────────────────────────────────────────
+
+fn default_sequence_duration() -> f64 {
+    30.0
+}
+
+fn default_sequence_frame_rate() -> f64 {
+    30.0
+}
+
+/// Starting duration and frame rate for newly created sequences.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, TS, JsonSchema)]
+#[ts(export)]
+pub struct SequenceDefaults {
+    /// Length in seconds.
+    #[serde(default = "default_sequence_duration")]
+    pub duration: f64,
+    /// Frames per second.
+    #[serde(default = "default_sequence_frame_rate")]
+    pub frame_rate: f64,
+}
+
+impl Default for SequenceDefaults {
+    fn default() -> Self {
+        Self {
+            duration: default_sequence_duration(),
+            frame_rate: default_sequence_frame_rate(),
         }
     }
 }
@@ -58,6 +170,18 @@ pub struct AppSettings {
     /// Default features to run when analyzing audio. None = all enabled.
     #[serde(default)]
     pub default_analysis_features: Option<AnalysisFeatures>,
+    #[serde(default)]
+    pub output: OutputSettings,
+    /// How many edits the undo history keeps before evicting the oldest.
+    /// Read once at startup; a changed value takes effect on the next launch.
+    #[serde(default = "default_max_undo_levels")]
+    pub max_undo_levels: usize,
+    #[serde(default)]
+    pub sequence_defaults: SequenceDefaults,
+}
+
+fn default_max_undo_levels() -> usize {
+    crate::dispatcher::DEFAULT_MAX_UNDO_LEVELS
 }
 
 const SETTINGS_VERSION: u32 = 1;
@@ -71,6 +195,9 @@ impl AppSettings {
             llm: LlmProviderConfig::default(),
             use_gpu: false,
             default_analysis_features: None,
+            output: OutputSettings::default(),
+            max_undo_levels: default_max_undo_levels(),
+            sequence_defaults: SequenceDefaults::default(),
         }
     }
 }
@@ -143,6 +270,13 @@ mod tests {
         let _ = std::fs::remove_dir_all(&dir);
     }
 
+    #[test]
+    fn settings_without_sequence_defaults_use_built_in_defaults() {
+        let json = r#"{ "version": 1, "data_dir": "/data", "last_setup": null }"#;
+        let settings: AppSettings = serde_json::from_str(json).unwrap();
+        assert_eq!(settings.sequence_defaults, SequenceDefaults::default());
+    }
+
     #[test]
     fn test_load_missing_returns_none() {
         let dir = std::env::temp_dir().join("vibelights_test_no_settings");
@@ -1,17 +1,22 @@
 use std::fs;
 use std::path::Path;
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
-use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
 
 use crate::model::color_gradient::ColorGradient;
 use crate::model::curve::Curve;
-use crate::model::fixture::{Controller, FixtureDef, FixtureGroup, Patch};
+use crate::model::fixture::{
+    ChannelOrder, Controller, DmxAddress, FixtureDef, FixtureGroup, FixtureId, OutputMapping, Patch, Universe,
+};
 use crate::model::show::{Layout, Show};
-use crate::model::timeline::Sequence;
+use crate::model::timeline::{EffectPreset, Sequence};
 use crate::project::{read_json, slugify, write_json, ProjectError};
+use crate::settings::SequenceDefaults;
 
 // ── Setup types ───────────────────────────────────────────────────
 
@@ -66,6 +71,10 @@ pub struct MediaFile {
     pub filename: String,
     #[ts(type = "number")]
     pub size_bytes: u64,
+    /// Audio length in seconds, detected on import.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub duration: Option<f64>,
 }
 
 // ── Envelope types for disk files ──────────────────────────────────
@@ -94,13 +103,173 @@ pub struct LibrariesFile {
     pub curves: HashMap<String, Curve>,
     #[serde(default)]
     pub scripts: HashMap<String, String>,
+    #[serde(default)]
+    pub effect_presets: HashMap<String, EffectPreset>,
+}
+
+impl LibrariesFile {
+    /// Add imported gradients and curves under generated names
+    /// (`"{prefix} Gradient 1"`, …). Items identical to one already in the
+    /// library, or earlier in the input, are skipped. Returns how many
+    /// gradients and curves were added.
+    pub fn absorb_imported(
+        &mut self,
+        prefix: &str,
+        gradients: Vec<ColorGradient>,
+        curves: Vec<Curve>,
+    ) -> (usize, usize) {
+        (
+            absorb_into(&mut self.gradients, &format!("{prefix} Gradient"), gradients),
+            absorb_into(&mut self.curves, &format!("{prefix} Curve"), curves),
+        )
+    }
+}
+
+/// Insert `items` into `library` under unique `"{base} N"` names, skipping
+/// values whose serialized form already exists. Returns the count added.
+fn absorb_into<T: Serialize>(
+    library: &mut HashMap<String, T>,
+    base: &str,
+    items: Vec<T>,
+) -> usize {
+    let mut seen: HashSet<String> = library
+        .values()
+        .filter_map(|v| serde_json::to_string(v).ok())
+        .collect();
+    let mut next = 1usize;
+    let mut added = 0;
+    for item in items {
+        let Ok(key) = serde_json::to_string(&item) else { continue };
+        if !seen.insert(key) {
+            continue;
+        }
+        while library.contains_key(&format!("{base} {next}")) {
+            next += 1;
+        }
+        library.insert(format!("{base} {next}"), item);
+        added += 1;
+    }
+    added
+}
+
+// ── Library bundles ───────────────────────────────────────────────
+
+/// Current version of the shareable library bundle format.
+pub const LIBRARY_BUNDLE_VERSION: u32 = 1;
+
+/// A whole library (gradients, curves, scripts, effect presets) in one file
+/// that can be shared between installs.
+#[derive(Deserialize)]
+struct LibraryBundle {
+    version: u32,
+    libraries: LibrariesFile,
+}
+
+/// What to do when an imported library item has the same name as an
+/// existing one.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, TS, JsonSchema)]
+#[ts(export)]
+pub enum NameCollision {
+    /// Keep the existing item and drop the imported one.
+    #[default]
+    Skip,
+    /// Replace the existing item with the imported one.
+    Overwrite,
+    /// Add the imported item as `"{name} (2)"`, `"{name} (3)"`, ….
+    Rename,
+}
+
+/// Item counts from merging a library bundle.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, TS)]
+#[ts(export)]
+pub struct LibraryImportSummary {
+    pub added: usize,
+    pub overwritten: usize,
+    pub renamed: usize,
+    pub skipped: usize,
+}
+
+impl LibrariesFile {
+    /// Merge every item of `incoming` into this library, resolving name
+    /// clashes with `collision`.
+    pub fn merge(
+        &mut self,
+        incoming: LibrariesFile,
+        collision: NameCollision,
+    ) -> LibraryImportSummary {
+        let mut summary = LibraryImportSummary::default();
+        merge_into(&mut self.gradients, incoming.gradients, collision, &mut summary);
+        merge_into(&mut self.curves, incoming.curves, collision, &mut summary);
+        merge_into(&mut self.scripts, incoming.scripts, collision, &mut summary);
+        merge_into(&mut self.effect_presets, incoming.effect_presets, collision, &mut summary);
+        summary
+    }
+}
+
+fn merge_into<T>(
+    library: &mut HashMap<String, T>,
+    incoming: HashMap<String, T>,
+    collision: NameCollision,
+    summary: &mut LibraryImportSummary,
+) {
+    // Sorted so renamed items get the same suffixes on every import.
+    let mut incoming: Vec<_> = incoming.into_iter().collect();
+    incoming.sort_by(|a, b| a.0.cmp(&b.0));
+    for (name, item) in incoming {
+        let existing = match library.entry(name) {
+            Entry::Vacant(slot) => {
+                slot.insert(item);
+                summary.added += 1;
+                continue;
+            }
+            Entry::Occupied(existing) => existing,
+        };
+        match collision {
+            NameCollision::Skip => summary.skipped += 1,
+            NameCollision::Overwrite => {
+                *existing.into_mut() = item;
+                summary.overwritten += 1;
+            }
+            NameCollision::Rename => {
+                let name = existing.key().clone();
+                let mut n = 2usize;
+                while library.contains_key(&format!("{name} ({n})")) {
+                    n += 1;
+                }
+                library.insert(format!("{name} ({n})"), item);
+                summary.renamed += 1;
+            }
+        }
+    }
+}
+
+/// Write `libs` to `path` as a library bundle.
+pub fn export_library_bundle(path: &Path, libs: &LibrariesFile) -> Result<(), ProjectError> {
+    #[derive(Serialize)]
+    struct BundleRef<'a> {
+        version: u32,
+        libraries: &'a LibrariesFile,
+    }
+    write_json(path, &BundleRef { version: LIBRARY_BUNDLE_VERSION, libraries: libs })
+}
+
+/// Read a library bundle, rejecting files written by a newer format version.
+pub fn import_library_bundle(path: &Path) -> Result<LibrariesFile, ProjectError> {
+    let bundle: LibraryBundle = read_json(path)?;
+    if bundle.version > LIBRARY_BUNDLE_VERSION {
+        return Err(ProjectError::InvalidProject(format!(
+            "Library bundle version {} is newer than supported version {LIBRARY_BUNDLE_VERSION}",
+            bundle.version
+        )));
+    }
+    Ok(bundle.libraries)
 }
 
 // ── Setup operations ──────────────────────────────────────────────
 
 use crate::paths;
 
-/// Load global libraries (gradients, curves, scripts).
+/// Load global libraries (gradients, curves, scripts, effect presets).
 pub fn load_global_libraries(data_dir: &Path) -> Result<LibrariesFile, ProjectError> {
     let path = paths::global_libraries_path(data_dir);
     if !path.exists() {
@@ -109,7 +278,7 @@ pub fn load_global_libraries(data_dir: &Path) -> Result<LibrariesFile, ProjectEr
     read_json(&path)
 }
 
-/// Save global libraries (gradients, curves, scripts).
+/// Save global libraries (gradients, curves, scripts, effect presets).
 pub fn save_global_libraries(
     data_dir: &Path,
     libs: &LibrariesFile,
@@ -245,13 +414,18 @@ pub fn load_setup(data_dir: &Path, slug: &str) -> Result<Setup, ProjectError> {
 
     let meta: SetupMeta = read_json(&dir.join(paths::SETUP_META_FILE))?;
     let fixtures_file: FixturesFile = read_json(&dir.join(paths::FIXTURES_FILE))?;
-    let setup_file: SetupFile = read_json(&dir.join(paths::SETUP_FILE))?;
+    let setup_value: serde_json::Value = read_json(&dir.join(paths::SETUP_FILE))?;
+    let legacy_orders = legacy_channel_orders(&setup_value);
+    let setup_file: SetupFile = serde_json::from_value(setup_value)?;
     let layout: Layout = read_json(&dir.join(paths::LAYOUT_FILE))?;
 
+    let mut fixtures = fixtures_file.fixtures;
+    migrate_channel_orders(&mut fixtures, &legacy_orders);
+
     Ok(Setup {
         name: meta.name,
         slug: slug.to_string(),
-        fixtures: fixtures_file.fixtures,
+        fixtures,
         groups: fixtures_file.groups,
         controllers: setup_file.controllers,
         patches: setup_file.patches,
@@ -259,6 +433,45 @@ pub fn load_setup(data_dir: &Path, slug: &str) -> Result<Setup, ProjectError> {
     })
 }
 
+/// Per-patch channel orders from a setup file written before channel order
+/// moved onto the fixture, keyed by the patched fixture.
+fn legacy_channel_orders(setup: &serde_json::Value) -> HashMap<FixtureId, ChannelOrder> {
+    let Some(patches) = setup.get("patches").and_then(serde_json::Value::as_array) else {
+        return HashMap::new();
+    };
+    patches
+        .iter()
+        .filter_map(|patch| {
+            let id = FixtureId::deserialize(patch.get("fixture_id")?).ok()?;
+            // `output` is an externally tagged enum: `{ "Dmx": { ... } }`.
+            let mapping = patch.get("output")?.as_object()?.values().next()?;
+            let order = ChannelOrder::deserialize(mapping.get("channel_order")?).ok()?;
+            Some((id, order))
+        })
+        .collect()
+}
+
+/// Move legacy per-patch channel orders onto their fixtures. A fixture that
+/// already sets its own (non-default) order keeps it.
+fn migrate_channel_orders(
+    fixtures: &mut [FixtureDef],
+    legacy_orders: &HashMap<FixtureId, ChannelOrder>,
+) {
+    for fixture in fixtures {
+        let Some(&order) = legacy_orders.get(&fixture.id) else {
+            continue;
+        };
+        if fixture.channel_order == ChannelOrder::default() {
+            fixture.channel_order = order;
+        } else if fixture.channel_order != order {
+            eprintln!(
+                "[VibeLights] Fixture '{}' is patched as {order:?} but set to {:?}; using the fixture's order",
+                fixture.name, fixture.channel_order
+            );
+        }
+    }
+}
+
 /// Save setup house data (fixtures, groups, controllers, patches, layout).
 pub fn save_setup(data_dir: &Path, slug: &str, setup: &Setup) -> Result<(), ProjectError> {
     let dir = paths::setup_dir(data_dir, slug);
@@ -338,11 +551,22 @@ pub fn list_sequences(
     Ok(seqs)
 }
 
-/// Create a new empty sequence in a setup.
+/// Create a new empty sequence in a setup, with the built-in default
+/// duration and frame rate.
 pub fn create_sequence(
     data_dir: &Path,
     setup_slug: &str,
     name: &str,
+) -> Result<SequenceSummary, ProjectError> {
+    create_sequence_with(data_dir, setup_slug, name, SequenceDefaults::default())
+}
+
+/// Create a new empty sequence in a setup, starting from `defaults`.
+pub fn create_sequence_with(
+    data_dir: &Path,
+    setup_slug: &str,
+    name: &str,
+    defaults: SequenceDefaults,
 ) -> Result<SequenceSummary, ProjectError> {
     let slug = slugify(name);
     let dir = paths::sequences_dir(data_dir, setup_slug);
@@ -357,11 +581,16 @@ pub fn create_sequence(
 
     let seq = Sequence {
         name: name.to_string(),
-        duration: 30.0,
-        frame_rate: 30.0,
+        duration: defaults.duration,
+        frame_rate: defaults.frame_rate,
         audio_file: None,
+        audio_tracks: Vec::new(),
         tracks: Vec::new(),
         motion_paths: std::collections::HashMap::new(),
+        markers: Vec::new(),
+        cues: Vec::new(),
+        region: None,
+        looping: false,
     };
     write_json(&path, &seq)?;
 
@@ -462,6 +691,7 @@ pub fn list_media(
         files.push(MediaFile {
             filename: entry.file_name().to_string_lossy().to_string(),
             size_bytes,
+            duration: None,
         });
     }
 
@@ -491,6 +721,7 @@ pub fn import_media(
     Ok(MediaFile {
         filename: filename.to_string_lossy().to_string(),
         size_bytes,
+        duration: crate::waveform::audio_duration(&dest).ok(),
     })
 }
 
@@ -508,6 +739,64 @@ pub fn delete_media(
     Ok(())
 }
 
+// ── Auto-patching ──────────────────────────────────────────────────
+
+/// Assign sequential DMX addresses to every fixture that has no patch yet.
+///
+/// Fixtures are walked in order from channel 1 of `start_universe`, using at
+/// most `channels_per_universe` channels of each universe. A fixture that does
+/// not fit in the rest of a universe starts the next one; a fixture larger than
+/// a whole universe starts fresh and runs on into the following ones. Universes
+/// that already carry DMX patches are skipped. Returns only the new patches.
+pub fn auto_patch(
+    fixtures: &[FixtureDef],
+    patches: &[Patch],
+    start_universe: Universe,
+    channels_per_universe: u16,
+) -> Vec<Patch> {
+    let budget = u32::from(channels_per_universe.clamp(1, 512));
+    let patched: HashSet<FixtureId> = patches.iter().map(|p| p.fixture_id).collect();
+    let occupied: HashSet<u16> = patches
+        .iter()
+        .filter_map(|p| match p.output {
+            OutputMapping::Dmx { universe, .. } => Some(universe.0),
+            OutputMapping::PixelPort { .. } => None,
+        })
+        .collect();
+
+    let mut universe = start_universe.0;
+    let mut offset = 0u32;
+    let mut new_patches = Vec::new();
+    for fixture in fixtures.iter().filter(|f| !patched.contains(&f.id)) {
+        let channels = fixture.total_channels();
+        if offset > 0 && offset + channels > budget {
+            universe = universe.saturating_add(1);
+            offset = 0;
+        }
+        if offset == 0 {
+            let span = u16::try_from(channels.div_ceil(budget).max(1)).unwrap_or(u16::MAX);
+            while (0..span).any(|i| occupied.contains(&universe.saturating_add(i))) {
+                universe = universe.saturating_add(1);
+            }
+        }
+        let Some(start_address) = u16::try_from(offset + 1).ok().and_then(DmxAddress::new) else {
+            continue;
+        };
+        new_patches.push(Patch {
+            fixture_id: fixture.id,
+            output: OutputMapping::Dmx {
+                universe: Universe(universe),
+                start_address,
+            },
+        });
+        offset += channels;
+        let wrapped = u16::try_from(offset / budget).unwrap_or(u16::MAX);
+        universe = universe.saturating_add(wrapped);
+        offset %= budget;
+    }
+    new_patches
+}
+
 // ── Assembly ───────────────────────────────────────────────────────
 
 /// Combine a Setup (fixtures, outputs) with a single Sequence into a full Show
@@ -673,6 +962,7 @@ mod tests {
             bulb_shape: Default::default(),
             display_radius_override: None,
             channel_order: Default::default(),
+            gamma: None,
         });
         save_setup(&data_dir, "test", &setup).unwrap();
 
@@ -684,6 +974,40 @@ mod tests {
         let _ = fs::remove_dir_all(&data_dir);
     }
 
+    #[test]
+    fn legacy_patch_channel_order_moves_onto_the_fixture() {
+        let data_dir = make_test_dir();
+        create_setup(&data_dir, "Test").unwrap();
+        let mut setup = load_setup(&data_dir, "test").unwrap();
+        for (id, order) in [(1, ChannelOrder::Rgb), (2, ChannelOrder::Bgr)] {
+            setup.fixtures.push(crate::model::FixtureDef {
+                id: FixtureId(id),
+                name: format!("String {id}"),
+                color_model: crate::model::fixture::ColorModel::Rgb,
+                pixel_count: 50,
+                pixel_type: Default::default(),
+                bulb_shape: Default::default(),
+                display_radius_override: None,
+                channel_order: order,
+                gamma: None,
+            });
+        }
+        save_setup(&data_dir, "test", &setup).unwrap();
+
+        let legacy = r#"{"controllers":[],"patches":[
+            {"fixture_id":1,"output":{"Dmx":{"universe":1,"start_address":1,"channel_order":"Grb"}}},
+            {"fixture_id":2,"output":{"Dmx":{"universe":1,"start_address":151,"channel_order":"Grb"}}}
+        ]}"#;
+        fs::write(paths::setup_dir(&data_dir, "test").join(paths::SETUP_FILE), legacy).unwrap();
+
+        let reloaded = load_setup(&data_dir, "test").unwrap();
+        assert_eq!(reloaded.patches.len(), 2);
+        assert_eq!(reloaded.fixtures[0].channel_order, ChannelOrder::Grb);
+        assert_eq!(reloaded.fixtures[1].channel_order, ChannelOrder::Bgr);
+
+        let _ = fs::remove_dir_all(&data_dir);
+    }
+
     #[test]
     fn test_sequence_crud() {
         let data_dir = make_test_dir();
@@ -736,23 +1060,57 @@ mod tests {
                 bulb_shape: Default::default(),
                 display_radius_override: None,
                 channel_order: Default::default(),
+                gamma: None,
             }],
             groups: Vec::new(),
             controllers: Vec::new(),
             patches: Vec::new(),
             layout: Layout { fixtures: Vec::new() },
         };
-        let sequence = Sequence {
-            name: "Xmas".into(),
-            duration: 30.0,
-            frame_rate: 30.0,
-            audio_file: None,
-            tracks: Vec::new(),
-            motion_paths: std::collections::HashMap::new(),
-        };
+        let sequence = Sequence::for_test("Xmas", 30.0, Vec::new());
         let show = assemble_show(&setup, &sequence);
         assert_eq!(show.name, "Xmas");
         assert_eq!(show.fixtures.len(), 1);
         assert_eq!(show.sequences.len(), 1);
     }
+
+    fn rgb_fixture(id: u32, pixel_count: u32) -> FixtureDef {
+        FixtureDef {
+            id: FixtureId(id),
+            name: format!("Fixture {id}"),
+            color_model: crate::model::fixture::ColorModel::Rgb,
+            pixel_count,
+            pixel_type: Default::default(),
+            bulb_shape: Default::default(),
+            display_radius_override: None,
+            channel_order: Default::default(),
+            gamma: None,
+        }
+    }
+
+    fn dmx_address(patch: &Patch) -> (FixtureId, u16, u16) {
+        match patch.output {
+            OutputMapping::Dmx { universe, start_address, .. } => {
+                (patch.fixture_id, universe.0, start_address.get())
+            }
+            OutputMapping::PixelPort { .. } => (patch.fixture_id, u16::MAX, 0),
+        }
+    }
+
+    #[test]
+    fn test_auto_patch_wraps_universes() {
+        // 50 RGB pixels = 150 channels; two fit in a 360-channel universe.
+        let fixtures = vec![rgb_fixture(1, 50), rgb_fixture(2, 50), rgb_fixture(3, 50)];
+        let patches = auto_patch(&fixtures, &[], Universe(2), 360);
+        let addresses: Vec<_> = patches.iter().map(dmx_address).collect();
+        assert_eq!(
+            addresses,
+            vec![(FixtureId(1), 2, 1), (FixtureId(2), 2, 151), (FixtureId(3), 3, 1)]
+        );
+
+        // Already-patched fixtures keep their patch and their universe is avoided.
+        let patches = auto_patch(&fixtures, &patches[..1], Universe(2), 360);
+        let addresses: Vec<_> = patches.iter().map(dmx_address).collect();
+        assert_eq!(addresses, vec![(FixtureId(2), 3, 1), (FixtureId(3), 3, 151)]);
+    }
 }
@@ -1,7 +1,10 @@
-use std::collections::HashMap;
-use std::path::PathBuf;
-use std::sync::atomic::{AtomicBool, AtomicU16, Ordering};
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU16, AtomicU64, Ordering};
 use std::sync::Arc;
+use std::thread::JoinHandle;
 use std::time::Instant;
 
 use indexmap::IndexMap;
@@ -9,15 +12,21 @@ use parking_lot::Mutex;
 
 use serde::Serialize;
 
-use crate::dispatcher::CommandDispatcher;
+use crate::dispatcher::{CommandDispatcher, EditCommand, DEFAULT_MAX_UNDO_LEVELS};
 use crate::dsl::compiler::CompiledScript;
+use crate::dsl::error::CompileError;
 use crate::effects;
+use crate::engine::Frame;
 use crate::error::AppError;
 use crate::model::analysis::AudioAnalysis;
 use crate::model::show::Show;
-use crate::model::{BlendMode, EffectKind, EffectParams, ParamSchema, TimeRange};
+use crate::output::LiveOutputSession;
+use crate::model::{
+    BlendMode, EffectInstance, EffectKind, EffectParams, ParamKey, ParamSchema, TimeRange,
+};
 use crate::setup::LibrariesFile;
 use crate::settings::AppSettings;
+use crate::waveform::Waveform;
 
 // ── Cancellation Registry ──────────────────────────────────────────
 
@@ -68,6 +77,48 @@ impl CancellationRegistry {
     pub fn unregister(&self, operation: &str) {
         self.flags.lock().remove(operation);
     }
+
+    /// Claim the named operation for as long as the returned guard lives.
+    /// Unlike [`register`](Self::register), a second claim while the first is
+    /// in flight fails with `AppError::Busy`, so repeated triggers of an
+    /// expensive operation can't pile up duplicate work.
+    pub fn begin(&self, operation: &str) -> Result<OperationGuard<'_>, AppError> {
+        let mut flags = self.flags.lock();
+        if flags.contains_key(operation) {
+            return Err(AppError::Busy {
+                operation: operation.to_string(),
+            });
+        }
+        let flag = Arc::new(AtomicBool::new(false));
+        flags.insert(operation.to_string(), Arc::clone(&flag));
+        Ok(OperationGuard {
+            registry: self,
+            operation: operation.to_string(),
+            flag,
+        })
+    }
+}
+
+/// Single-flight claim on a cancellable operation, returned by
+/// [`CancellationRegistry::begin`]. The operation stays registered (and
+/// cancellable by name) until the guard is dropped.
+pub struct OperationGuard<'a> {
+    registry: &'a CancellationRegistry,
+    operation: String,
+    flag: Arc<AtomicBool>,
+}
+
+impl OperationGuard<'_> {
+    /// The cancel flag for this operation.
+    pub fn flag(&self) -> &Arc<AtomicBool> {
+        &self.flag
+    }
+}
+
+impl Drop for OperationGuard<'_> {
+    fn drop(&mut self) {
+        self.registry.unregister(&self.operation);
+    }
 }
 
 /// Poll the cancel flag every 250ms. Resolves when the flag becomes true.
@@ -92,6 +143,320 @@ pub fn check_cancelled(flag: &AtomicBool, operation: &str) -> Result<(), AppErro
     }
 }
 
+// ── Thumbnail Cache ────────────────────────────────────────────────
+
+/// Maximum number of rendered thumbnails kept in memory.
+const MAX_THUMBNAIL_CACHE: usize = 256;
+
+/// Cache key for a rendered effect thumbnail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ThumbnailKey {
+    /// Hash of the effect kind and its params (see [`ThumbnailCache::effect_hash`]).
+    pub effect_hash: u64,
+    pub pixel_rows: usize,
+    pub time_samples: usize,
+}
+
+/// Rendered thumbnail pixels keyed by effect content, so unchanged effects
+/// are not re-rendered every time the timeline redraws. Entries are evicted
+/// in insertion order once the cache exceeds `MAX_THUMBNAIL_CACHE`.
+#[derive(Default)]
+pub struct ThumbnailCache {
+    entries: Mutex<IndexMap<ThumbnailKey, Vec<u8>>>,
+    hits: AtomicU64,
+}
+
+impl ThumbnailCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Hash an effect's kind and params. Params are hashed in key order so the
+    /// result does not depend on `HashMap` iteration order.
+    pub fn effect_hash(kind: &EffectKind, params: &EffectParams) -> u64 {
+        let mut entries: Vec<(String, String)> = params
+            .inner()
+            .iter()
+            .map(|(k, v)| (k.to_string(), serde_json::to_string(v).unwrap_or_default()))
+            .collect();
+        entries.sort();
+
+        let mut hasher = DefaultHasher::new();
+        kind.to_string().hash(&mut hasher);
+        entries.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Params a thumbnail of the effect at (`track_index`, `effect_index`) renders
+    /// with. Unseeded randomized effects get the same slot seed playback uses, so
+    /// the thumbnail is reproducible and matches what plays.
+    pub fn render_params(
+        effect: &EffectInstance,
+        track_index: usize,
+        effect_index: usize,
+    ) -> Cow<'_, EffectParams> {
+        if effects::needs_seed(&effect.kind, &effect.params) {
+            let seed = crate::engine::evaluator::default_seed(track_index, effect_index);
+            Cow::Owned(effect.params.clone().set(ParamKey::Seed, seed))
+        } else {
+            Cow::Borrowed(&effect.params)
+        }
+    }
+
+    /// Look up cached pixels, counting a hit when found.
+    pub fn get(&self, key: &ThumbnailKey) -> Option<Vec<u8>> {
+        let pixels = self.entries.lock().get(key).cloned()?;
+        self.hits.fetch_add(1, Ordering::Relaxed);
+        Some(pixels)
+    }
+
+    pub fn insert(&self, key: ThumbnailKey, pixels: Vec<u8>) {
+        let mut entries = self.entries.lock();
+        entries.insert(key, pixels);
+        while entries.len() > MAX_THUMBNAIL_CACHE {
+            entries.shift_remove_index(0);
+        }
+    }
+
+    /// Drop every cached size of the effect with the given hash.
+    pub fn invalidate_effect(&self, effect_hash: u64) {
+        self.entries
+            .lock()
+            .retain(|key, _| key.effect_hash != effect_hash);
+    }
+
+    /// Number of lookups served from the cache since startup.
+    pub fn hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+}
+
+// ── Frame Cache ────────────────────────────────────────────────────
+
+/// Maximum number of evaluated frames kept in memory.
+const MAX_FRAME_CACHE: usize = 120;
+
+/// Evaluated frames keyed by `(sequence_index, frame_index)`, so the playhead
+/// can land on frames rendered ahead of time. Commands that may change
+/// rendered output call [`FrameCache::invalidate`], which empties the cache and
+/// bumps its generation; frames evaluated under an older generation are
+/// dropped on insert instead of going stale in the cache.
+#[derive(Default)]
+pub struct FrameCache {
+    entries: Mutex<IndexMap<(usize, u64), Frame>>,
+    generation: AtomicU64,
+    hits: AtomicU64,
+    /// Bumped by every prefetch; a running prefetch stops once it no longer matches.
+    prefetch_epoch: AtomicU64,
+    prefetch: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl FrameCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Generation to pass to [`FrameCache::insert`] for a frame evaluated now.
+    pub fn generation(&self) -> u64 {
+        self.generation.load(Ordering::Acquire)
+    }
+
+    /// Drop every cached frame and reject inserts of frames evaluated before now.
+    pub fn invalidate(&self) {
+        let mut entries = self.entries.lock();
+        self.generation.fetch_add(1, Ordering::AcqRel);
+        entries.clear();
+    }
+
+    /// Look up a cached frame, counting a hit when found.
+    pub fn get(&self, sequence_index: usize, frame_index: u64) -> Option<Frame> {
+        let frame = self.entries.lock().get(&(sequence_index, frame_index)).cloned()?;
+        self.hits.fetch_add(1, Ordering::Relaxed);
+        Some(frame)
+    }
+
+    pub fn contains(&self, sequence_index: usize, frame_index: u64) -> bool {
+        self.entries.lock().contains_key(&(sequence_index, frame_index))
+    }
+
+    /// Store a frame evaluated under `generation`, unless the cache has been
+    /// invalidated since.
+    pub fn insert(&self, generation: u64, sequence_index: usize, frame_index: u64, frame: Frame) {
+        let mut entries = self.entries.lock();
+        if generation != self.generation.load(Ordering::Acquire) {
+            return;
+        }
+        entries.insert((sequence_index, frame_index), frame);
+        while entries.len() > MAX_FRAME_CACHE {
+            entries.shift_remove_index(0);
+        }
+    }
+
+    /// Number of lookups served from the cache since startup.
+    pub fn hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    /// Cancel any running prefetch and return the epoch for a new one.
+    pub fn begin_prefetch(&self) -> u64 {
+        self.prefetch_epoch.fetch_add(1, Ordering::AcqRel) + 1
+    }
+
+    /// Whether a later prefetch has superseded the one started at `epoch`.
+    pub fn prefetch_cancelled(&self, epoch: u64) -> bool {
+        self.prefetch_epoch.load(Ordering::Acquire) != epoch
+    }
+
+    /// Keep the running prefetch thread's handle, detaching the previous one.
+    pub fn set_prefetch(&self, handle: JoinHandle<()>) {
+        *self.prefetch.lock() = Some(handle);
+    }
+
+    /// Block until the most recent prefetch finishes.
+    #[cfg(test)]
+    pub(crate) fn wait_for_prefetch(&self) {
+        let handle = self.prefetch.lock().take();
+        if let Some(handle) = handle {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// The last frame `tick` returned to one client, numbered so the client asking
+/// for a delta can tell whether it holds the same frame.
+#[derive(Default)]
+pub struct TickBaseline {
+    /// 0 until the first tick.
+    pub seq: u64,
+    pub fixtures: HashMap<u32, String>,
+}
+
+impl TickBaseline {
+    /// Record `frame` as the next tick. When `since` names the previous tick,
+    /// returns the fixtures that changed since it and the ids of those that
+    /// went dark; otherwise `None`, and the client needs the full frame.
+    pub fn advance(
+        &mut self,
+        frame: &Frame,
+        since: Option<u64>,
+    ) -> Option<(HashMap<u32, String>, Vec<u32>)> {
+        let delta = (since == Some(self.seq)).then(|| {
+            let changed = frame
+                .fixtures
+                .iter()
+                .filter(|&(id, data)| self.fixtures.get(id) != Some(data))
+                .map(|(&id, data)| (id, data.clone()))
+                .collect();
+            let mut cleared: Vec<u32> = self
+                .fixtures
+                .keys()
+                .filter(|&id| !frame.fixtures.contains_key(id))
+                .copied()
+                .collect();
+            cleared.sort_unstable();
+            (changed, cleared)
+        });
+        self.seq += 1;
+        self.fixtures.clone_from(&frame.fixtures);
+        delta
+    }
+}
+
+// ── Analysis Cache ─────────────────────────────────────────────────
+
+/// Maximum number of audio analyses kept in memory.
+const MAX_ANALYSIS_CACHE: usize = 10;
+
+/// Cache key for an audio analysis: the sequence it belongs to plus a hash of
+/// the audio it was run on. Re-importing identical audio keeps the key, while
+/// pointing the sequence at different audio changes it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AnalysisKey {
+    /// Sequence slug (empty when no sequence is open).
+    pub sequence: String,
+    /// See [`AnalysisCache::audio_hash`].
+    pub audio_hash: u64,
+}
+
+/// Audio analyses keyed by [`AnalysisKey`], so the sidecar only runs again
+/// when a sequence's audio actually changes. Entries are evicted in insertion
+/// order once the cache exceeds `MAX_ANALYSIS_CACHE`.
+#[derive(Default)]
+pub struct AnalysisCache {
+    entries: IndexMap<AnalysisKey, AudioAnalysis>,
+    /// Content hash per media filename, with the path it was read from, so
+    /// lookups don't re-read the audio.
+    audio_hashes: HashMap<String, (Option<PathBuf>, u64)>,
+}
+
+impl AnalysisCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Hash of an audio file's contents. Audio that can't be read (e.g. not
+    /// imported yet) is hashed by name instead. Reads the whole file, so call
+    /// it without holding the cache lock.
+    pub fn audio_hash(audio_file: &str, path: Option<&Path>) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        match path.and_then(|p| std::fs::read(p).ok()) {
+            Some(bytes) => bytes.hash(&mut hasher),
+            None => audio_file.hash(&mut hasher),
+        }
+        hasher.finish()
+    }
+
+    /// The memoized hash for `audio_file`, if it was computed from `path`.
+    pub fn memoized_audio_hash(&self, audio_file: &str, path: Option<&Path>) -> Option<u64> {
+        self.audio_hashes
+            .get(audio_file)
+            .filter(|(p, _)| p.as_deref() == path)
+            .map(|&(_, hash)| hash)
+    }
+
+    /// Remember the hash of `audio_file` as read from `path`.
+    pub fn memoize_audio_hash(&mut self, audio_file: &str, path: Option<&Path>, hash: u64) {
+        self.audio_hashes.insert(audio_file.to_string(), (path.map(Path::to_path_buf), hash));
+    }
+
+    /// Drop the memoized hash for a media file whose contents may have
+    /// changed. Its analyses stay cached and are found again if the new
+    /// contents hash the same.
+    pub fn forget_audio(&mut self, audio_file: &str) {
+        self.audio_hashes.remove(audio_file);
+    }
+
+    pub fn get(&self, key: &AnalysisKey) -> Option<&AudioAnalysis> {
+        self.entries.get(key)
+    }
+
+    /// Look up by sequence and filename without touching the disk, for hot
+    /// paths. Misses when the file's hash hasn't been computed yet.
+    pub fn lookup(&self, sequence: &str, audio_file: &str) -> Option<&AudioAnalysis> {
+        let &(_, audio_hash) = self.audio_hashes.get(audio_file)?;
+        self.entries.get(&AnalysisKey { sequence: sequence.to_string(), audio_hash })
+    }
+
+    /// Any sequence's analysis of the given audio.
+    pub fn find_audio(&self, audio_hash: u64) -> Option<&AudioAnalysis> {
+        self.entries
+            .iter()
+            .find(|(key, _)| key.audio_hash == audio_hash)
+            .map(|(_, analysis)| analysis)
+    }
+
+    pub fn insert(&mut self, key: AnalysisKey, analysis: AudioAnalysis) {
+        self.entries.insert(key, analysis);
+        while self.entries.len() > MAX_ANALYSIS_CACHE {
+            self.entries.shift_remove_index(0);
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
 // ── Application State ──────────────────────────────────────────────
 
 /// Application state shared across Tauri commands and the HTTP API.
@@ -109,9 +474,11 @@ pub struct AppState {
     pub python_sidecar: Mutex<Option<tokio::process::Child>>,
     /// Port the Python sidecar is listening on (0 = not running).
     pub python_port: AtomicU16,
-    /// Cache of audio analysis results. Key is media filename.
+    /// Cache of audio analysis results, keyed by sequence and audio hash.
+    pub analysis_cache: Mutex<AnalysisCache>,
+    /// Cache of decoded waveform peaks. Key is (media filename, bucket count).
     /// Uses IndexMap to preserve insertion order for FIFO eviction.
-    pub analysis_cache: Mutex<IndexMap<String, AudioAnalysis>>,
+    pub waveform_cache: Mutex<IndexMap<(String, usize), Waveform>>,
     /// Handle to the agent sidecar process (Node.js).
     pub agent_sidecar: Mutex<Option<tokio::process::Child>>,
     /// Port the agent sidecar is listening on (0 = not running).
@@ -128,6 +495,14 @@ pub struct AppState {
     pub cancellation: CancellationRegistry,
     /// Port the internal HTTP API server is listening on (0 = not started).
     pub api_port: AtomicU16,
+    /// Rendered effect thumbnails for the timeline.
+    pub thumbnail_cache: ThumbnailCache,
+    /// Evaluated frames around the playhead.
+    pub frame_cache: FrameCache,
+    /// Baselines for delta ticks, keyed by `TickParams::client`.
+    pub tick_baselines: Mutex<HashMap<String, TickBaseline>>,
+    /// Running live-output session, if any.
+    pub live_output: Mutex<Option<LiveOutputSession>>,
 }
 
 impl AppState {
@@ -195,13 +570,50 @@ impl AppState {
         self.current_sequence.lock().clone().ok_or(AppError::NoSequence)
     }
 
-    /// Insert an analysis result into the cache, evicting the oldest entry
-    /// (by insertion order) if the cache exceeds `MAX_ANALYSIS_CACHE` entries.
-    pub fn cache_analysis(&self, key: String, value: AudioAnalysis) {
-        const MAX_ANALYSIS_CACHE: usize = 10;
-        let mut cache = self.analysis_cache.lock();
+    /// Compile a DSL source, resolving `use "..."` directives against the
+    /// global script library. Callers must not hold `global_libraries`.
+    pub fn compile_script(&self, source: &str) -> Result<CompiledScript, Vec<CompileError>> {
+        let libs = self.global_libraries.lock();
+        crate::dsl::compile_source_with(source, |name| libs.scripts.get(name).cloned())
+    }
+
+    /// Cache key for the current sequence's analysis of `audio_file`, hashing
+    /// the file in the current setup's media directory when it exists.
+    ///
+    /// Callers must not hold `analysis_cache`.
+    pub fn analysis_key(&self, audio_file: &str) -> AnalysisKey {
+        let path = get_data_dir(self).ok().zip(self.current_setup.lock().clone()).map(
+            |(data_dir, setup)| crate::paths::media_dir(&data_dir, &setup).join(audio_file),
+        );
+        let sequence = self.current_sequence.lock().clone().unwrap_or_default();
+        let memoized = self.analysis_cache.lock().memoized_audio_hash(audio_file, path.as_deref());
+        let audio_hash = memoized.unwrap_or_else(|| {
+            let hash = AnalysisCache::audio_hash(audio_file, path.as_deref());
+            self.analysis_cache.lock().memoize_audio_hash(audio_file, path.as_deref(), hash);
+            hash
+        });
+        AnalysisKey { sequence, audio_hash }
+    }
+
+    /// Insert an analysis result into the cache for the current sequence.
+    pub fn cache_analysis(&self, audio_file: &str, value: AudioAnalysis) {
+        let key = self.analysis_key(audio_file);
+        self.analysis_cache.lock().insert(key, value);
+    }
+
+    /// The current sequence's cached analysis of `audio_file`, if any.
+    pub fn cached_analysis(&self, audio_file: &str) -> Option<AudioAnalysis> {
+        let key = self.analysis_key(audio_file);
+        self.analysis_cache.lock().get(&key).cloned()
+    }
+
+    /// Insert waveform peaks into the cache, evicting the oldest entry once
+    /// more than `MAX_WAVEFORM_CACHE` bucket resolutions are held.
+    pub fn cache_waveform(&self, key: (String, usize), value: Waveform) {
+        const MAX_WAVEFORM_CACHE: usize = 16;
+        let mut cache = self.waveform_cache.lock();
         cache.insert(key, value);
-        while cache.len() > MAX_ANALYSIS_CACHE {
+        while cache.len() > MAX_WAVEFORM_CACHE {
             cache.shift_remove_index(0);
         }
     }
@@ -231,17 +643,139 @@ impl AppState {
     }
 }
 
+impl AppState {
+    /// State for running commands without the GUI: an empty show, no
+    /// sidecars, no persisted chats and no config directory. Handlers that
+    /// persist settings should not be run against it.
+    pub fn headless(settings: Option<AppSettings>, global_libraries: LibrariesFile) -> Self {
+        Self {
+            show: Mutex::new(Show::empty()),
+            playback: Mutex::new(PlaybackState {
+                playing: false,
+                current_time: 0.0,
+                frame_index: 0,
+                sequence_index: 0,
+                clock_anchor: None,
+                region: None,
+                looping: false,
+                solo_tracks: HashSet::new(),
+            }),
+            dispatcher: Mutex::new(CommandDispatcher::with_max_undo_levels(
+                settings.as_ref().map_or(DEFAULT_MAX_UNDO_LEVELS, |s| s.max_undo_levels),
+            )),
+            app_config_dir: PathBuf::new(),
+            settings: Mutex::new(settings),
+            current_setup: Mutex::new(None),
+            current_sequence: Mutex::new(None),
+            script_cache: Mutex::new(HashMap::new()),
+            python_sidecar: Mutex::new(None),
+            python_port: AtomicU16::new(0),
+            analysis_cache: Mutex::new(AnalysisCache::new()),
+            waveform_cache: Mutex::new(IndexMap::new()),
+            agent_sidecar: Mutex::new(None),
+            agent_port: AtomicU16::new(0),
+            agent_session_id: Mutex::new(None),
+            agent_display_messages: Mutex::new(Vec::new()),
+            agent_chats: Mutex::new(crate::chat::AgentChatsData::default()),
+            global_libraries: Mutex::new(global_libraries),
+            cancellation: CancellationRegistry::new(),
+            api_port: AtomicU16::new(0),
+            thumbnail_cache: ThumbnailCache::new(),
+            frame_cache: FrameCache::new(),
+            tick_baselines: Mutex::new(HashMap::new()),
+            live_output: Mutex::new(None),
+        }
+    }
+}
+
+#[cfg(test)]
+impl AppState {
+    /// Minimal state around `show` for handler tests: no settings, sidecars,
+    /// or persisted chats.
+    pub(crate) fn for_test(show: Show) -> Self {
+        Self {
+            show: Mutex::new(show),
+            ..Self::headless(None, LibrariesFile::default())
+        }
+    }
+}
+
+#[derive(Clone)]
 pub struct PlaybackState {
     pub playing: bool,
+    /// Playhead time in seconds, derived from `frame_index` so it never
+    /// accumulates float error over a long session.
     pub current_time: f64,
+    /// Playhead position in whole frames at the sequence's frame rate.
+    pub frame_index: u64,
     pub sequence_index: usize,
-    /// Real-time clock anchor for computing dt in `tick()`.
-    /// Set when playback starts; cleared on pause/seek.
-    pub last_tick: Option<Instant>,
+    /// Real-time clock anchor for `tick()`: the instant playback (re)started
+    /// and the frame it started from. Set on play/seek; cleared on pause.
+    pub clock_anchor: Option<(Instant, u64)>,
     /// Optional playback region (start, end) in seconds.
     pub region: Option<(f64, f64)>,
     /// Whether playback should loop within the region.
     pub looping: bool,
+    /// Soloed track indices in the current sequence. When non-empty, only
+    /// these tracks render. Transient: never saved and not undoable.
+    pub solo_tracks: HashSet<usize>,
+}
+
+impl PlaybackState {
+    /// Move the playhead to the frame nearest `time`.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn seek_frame(&mut self, time: f64, frame_rate: f64) {
+        if frame_rate > 0.0 {
+            self.set_frame((time.max(0.0) * frame_rate).round() as u64, frame_rate);
+        } else {
+            self.frame_index = 0;
+            self.current_time = time;
+        }
+    }
+
+    /// Advance to the frame the real-time clock has reached since the anchor.
+    /// Frames are counted from the anchor rather than summed per tick, so the
+    /// playhead stays locked to wall-clock time (and the audio) indefinitely.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn advance_to(&mut self, now: Instant, frame_rate: f64) {
+        if frame_rate <= 0.0 {
+            return;
+        }
+        let (start, start_frame) = *self.clock_anchor.get_or_insert((now, self.frame_index));
+        let elapsed = now.saturating_duration_since(start).as_secs_f64();
+        self.set_frame(start_frame + (elapsed * frame_rate).round() as u64, frame_rate);
+    }
+
+    /// Playhead time at `now` with sub-frame precision: while playing, the
+    /// time the real-time clock has reached since the anchor rather than the
+    /// last whole frame. Paused, it is `current_time`.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn clock_time(&self, now: Instant, frame_rate: f64) -> f64 {
+        match self.clock_anchor {
+            Some((start, start_frame)) if self.playing && frame_rate > 0.0 => {
+                start_frame as f64 / frame_rate
+                    + now.saturating_duration_since(start).as_secs_f64()
+            }
+            _ => self.current_time,
+        }
+    }
+
+    /// Follow soloed tracks through an edit that moves or deletes tracks, so
+    /// the solo stays on the same tracks. Deleted tracks drop out.
+    pub fn remap_solo_tracks(&mut self, cmd: &EditCommand) {
+        let sequence_index = self.sequence_index;
+        self.solo_tracks = self
+            .solo_tracks
+            .iter()
+            .filter_map(|&i| cmd.remap_track(sequence_index, i))
+            .collect();
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn set_frame(&mut self, frame_index: u64, frame_rate: f64) {
+        self.frame_index = frame_index;
+        self.current_time = frame_index as f64 / frame_rate;
+    }
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -254,6 +788,8 @@ pub struct PlaybackInfo {
     pub sequence_index: usize,
     pub region: Option<(f64, f64)>,
     pub looping: bool,
+    /// Soloed track indices, ascending.
+    pub solo_tracks: Vec<usize>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -267,6 +803,9 @@ pub struct EffectDetail {
     pub track_name: String,
     pub blend_mode: BlendMode,
     pub opacity: f64,
+    pub opacity_curve: Option<crate::model::Curve>,
+    pub fade_in: f64,
+    pub fade_out: f64,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -302,3 +841,16 @@ pub fn get_data_dir(state: &AppState) -> Result<PathBuf, String> {
         .map(|s| s.data_dir.clone())
         .ok_or_else(|| "No data directory configured".to_string())
 }
+
+/// Beat times for a sequence's audio, if its analysis is cached under
+/// `sequence_slug`.
+pub fn sequence_beats<'a>(
+    show: &Show,
+    sequence_index: usize,
+    sequence_slug: &str,
+    analysis_cache: &'a AnalysisCache,
+) -> Option<&'a [f64]> {
+    let audio_file = show.sequences.get(sequence_index)?.audio_file.as_ref()?;
+    let beats = analysis_cache.lookup(sequence_slug, audio_file)?.beats.as_ref()?;
+    Some(&beats.beats)
+}
@@ -0,0 +1,198 @@
+use std::collections::HashMap;
+
+use crate::model::analysis::SongSection;
+use crate::model::fixture::{EffectTarget, FixtureGroup};
+use crate::model::timeline::{BlendMode, Marker, Sequence, Track};
+
+/// A starting point for a new sequence: a song structure laid out as
+/// section markers, plus one empty track per fixture group.
+#[derive(Debug)]
+pub struct SequenceTemplate {
+    /// Lookup key used by `CreateSequenceFromTemplate`.
+    pub id: &'static str,
+    pub name: &'static str,
+    /// Section labels and their default lengths in seconds, in song order.
+    pub sections: &'static [(&'static str, f64)],
+}
+
+/// Built-in templates for common song structures.
+pub const TEMPLATES: &[SequenceTemplate] = &[
+    SequenceTemplate {
+        id: "pop",
+        name: "Verse/Chorus Pop",
+        sections: &[
+            ("intro", 8.0),
+            ("verse", 16.0),
+            ("chorus", 16.0),
+            ("verse", 16.0),
+            ("chorus", 16.0),
+            ("bridge", 8.0),
+            ("chorus", 16.0),
+            ("outro", 8.0),
+        ],
+    },
+    SequenceTemplate {
+        id: "edm",
+        name: "EDM Build/Drop",
+        sections: &[
+            ("intro", 16.0),
+            ("build", 16.0),
+            ("drop", 32.0),
+            ("breakdown", 16.0),
+            ("build", 16.0),
+            ("drop", 32.0),
+            ("outro", 16.0),
+        ],
+    },
+    SequenceTemplate {
+        id: "ballad",
+        name: "Ballad",
+        sections: &[
+            ("intro", 12.0),
+            ("verse", 24.0),
+            ("chorus", 20.0),
+            ("verse", 24.0),
+            ("chorus", 20.0),
+            ("outro", 16.0),
+        ],
+    },
+];
+
+/// Look up a built-in template by id (case-insensitive).
+pub fn find_template(id: &str) -> Option<&'static SequenceTemplate> {
+    TEMPLATES.iter().find(|t| t.id.eq_ignore_ascii_case(id.trim()))
+}
+
+impl SequenceTemplate {
+    /// A marker at the start of each section, laid end to end using the
+    /// template's default lengths.
+    pub fn markers(&self) -> Vec<Marker> {
+        let mut time = 0.0;
+        self.sections
+            .iter()
+            .map(|&(label, length)| {
+                let marker = section_marker(label.to_string(), time);
+                time += length;
+                marker
+            })
+            .collect()
+    }
+
+    /// Total length in seconds with the default section lengths.
+    pub fn length(&self) -> f64 {
+        self.sections.iter().map(|&(_, length)| length).sum()
+    }
+
+    /// Build a sequence from this template.
+    ///
+    /// When `analysis_sections` is non-empty the detected song structure replaces
+    /// the template's default timing, so markers line up with the actual audio.
+    pub fn build(
+        &self,
+        name: &str,
+        groups: &[FixtureGroup],
+        analysis_sections: Option<&[SongSection]>,
+    ) -> Sequence {
+        let (markers, duration) = match analysis_sections {
+            Some(sections) if !sections.is_empty() => (
+                sections.iter().map(|s| section_marker(s.label.clone(), s.start)).collect(),
+                sections.iter().map(|s| s.end).fold(0.0, f64::max),
+            ),
+            _ => (self.markers(), self.length()),
+        };
+
+        let tracks = if groups.is_empty() {
+            vec![placeholder_track("All Fixtures".into(), EffectTarget::All)]
+        } else {
+            groups
+                .iter()
+                .map(|g| placeholder_track(g.name.clone(), EffectTarget::Group(g.id)))
+                .collect()
+        };
+
+        Sequence {
+            name: name.to_string(),
+            duration: if duration > 0.0 { duration } else { 30.0 },
+            frame_rate: 30.0,
+            audio_file: None,
+            audio_tracks: Vec::new(),
+            tracks,
+            motion_paths: HashMap::new(),
+            markers,
+            cues: Vec::new(),
+            region: None,
+            looping: false,
+        }
+    }
+}
+
+fn section_marker(label: String, time: f64) -> Marker {
+    Marker { time, label, color: Marker::DEFAULT_COLOR }
+}
+
+fn placeholder_track(name: String, target: EffectTarget) -> Track {
+    Track {
+        name,
+        target,
+        locked: false,
+        muted: false,
+        blend_mode: BlendMode::Override,
+        opacity: 1.0,
+        effects: Vec::new(),
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::indexing_slicing)]
+mod tests {
+    use super::*;
+    use crate::model::fixture::{GroupId, GroupMember};
+    use crate::model::FixtureId;
+
+    fn group(id: u32, name: &str) -> FixtureGroup {
+        FixtureGroup {
+            id: GroupId(id),
+            name: name.into(),
+            members: vec![GroupMember::Fixture(FixtureId(id))],
+        }
+    }
+
+    #[test]
+    fn pop_template_builds_markers_and_group_tracks() {
+        let template = find_template("POP").unwrap();
+        let seq = template.build("Song", &[group(0, "Roof"), group(1, "Trees")], None);
+
+        let labels: Vec<&str> = seq.markers.iter().map(|m| m.label.as_str()).collect();
+        assert_eq!(
+            labels,
+            ["intro", "verse", "chorus", "verse", "chorus", "bridge", "chorus", "outro"]
+        );
+        assert!((seq.markers[1].time - 8.0).abs() < 1e-9);
+        assert!((seq.duration - 104.0).abs() < 1e-9);
+
+        let names: Vec<&str> = seq.tracks.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, ["Roof", "Trees"]);
+        assert!(seq.tracks.iter().all(|t| t.effects.is_empty()));
+        assert!(matches!(seq.tracks[1].target, EffectTarget::Group(GroupId(1))));
+    }
+
+    #[test]
+    fn analysis_sections_override_template_timing() {
+        let sections = vec![
+            SongSection { label: "intro".into(), start: 0.0, end: 5.5, confidence: 0.9 },
+            SongSection { label: "chorus".into(), start: 5.5, end: 42.0, confidence: 0.8 },
+        ];
+        let seq = find_template("edm").unwrap().build("Song", &[], Some(&sections));
+
+        assert_eq!(seq.markers.len(), 2);
+        assert_eq!(seq.markers[1].label, "chorus");
+        assert!((seq.duration - 42.0).abs() < 1e-9);
+        assert_eq!(seq.tracks.len(), 1);
+        assert!(matches!(seq.tracks[0].target, EffectTarget::All));
+    }
+
+    #[test]
+    fn unknown_template_is_none() {
+        assert!(find_template("polka").is_none());
+    }
+}
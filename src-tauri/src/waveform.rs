@@ -0,0 +1,258 @@
+//! Audio waveform peaks for the timeline.
+//!
+//! Decodes an audio file with symphonia, mixes it down to mono, and reduces it
+//! to a fixed number of min/max buckets spanning the file's full length.
+
+use std::path::Path;
+
+use serde::Serialize;
+use symphonia::core::audio::SampleBuffer;
+use symphonia::core::codecs::{Decoder, DecoderOptions};
+use symphonia::core::errors::Error as SymphoniaError;
+use symphonia::core::formats::{FormatOptions, FormatReader};
+use symphonia::core::io::{MediaSourceStream, MediaSourceStreamOptions};
+use symphonia::core::meta::MetadataOptions;
+use symphonia::core::probe::Hint;
+
+#[cfg(feature = "tauri-app")]
+use ts_rs::TS;
+
+use crate::error::AppError;
+
+/// Upper bound on requested buckets; far more than any timeline can draw.
+pub const MAX_WAVEFORM_BUCKETS: usize = 65_536;
+
+/// Lowest and highest sample (-1.0 to 1.0) within one bucket.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[cfg_attr(feature = "tauri-app", derive(TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub struct WaveformPeak {
+    pub min: f32,
+    pub max: f32,
+}
+
+/// Downsampled waveform of a whole audio file.
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "tauri-app", derive(TS))]
+#[cfg_attr(feature = "tauri-app", ts(export))]
+pub struct Waveform {
+    /// Length of the decoded audio in seconds. Buckets span `[0, duration]`.
+    pub duration: f64,
+    pub peaks: Vec<WaveformPeak>,
+}
+
+/// Reduce mono samples to `buckets` min/max pairs. When there are fewer
+/// samples than buckets, neighbouring buckets share samples; an empty signal
+/// gives flat zero buckets.
+pub fn peaks_from_samples(samples: &[f32], buckets: usize) -> Vec<WaveformPeak> {
+    let len = samples.len();
+    (0..buckets)
+        .map(|i| {
+            let start = i * len / buckets;
+            let end = ((i + 1) * len / buckets).max(start + 1).min(len);
+            let chunk = samples.get(start..end).unwrap_or_default();
+            if chunk.is_empty() {
+                return WaveformPeak { min: 0.0, max: 0.0 };
+            }
+            chunk.iter().fold(
+                WaveformPeak { min: f32::MAX, max: f32::MIN },
+                |acc, &s| WaveformPeak { min: acc.min.min(s), max: acc.max.max(s) },
+            )
+        })
+        .collect()
+}
+
+/// An opened audio file ready to yield decoded packets.
+struct AudioSource {
+    format: Box<dyn FormatReader>,
+    decoder: Box<dyn Decoder>,
+    track_id: u32,
+    sample_rate: u32,
+    /// Frame count from the container header, when it records one.
+    n_frames: Option<u64>,
+}
+
+fn decode_error(path: &Path) -> impl Fn(SymphoniaError) -> AppError + '_ {
+    move |e| AppError::IoError {
+        message: format!("Failed to decode {}: {e}", path.display()),
+    }
+}
+
+/// Probe `path`'s container and build a decoder for its default track.
+fn open_source(path: &Path) -> Result<AudioSource, AppError> {
+    let file = std::fs::File::open(path)?;
+    let stream = MediaSourceStream::new(Box::new(file), MediaSourceStreamOptions::default());
+    let mut hint = Hint::new();
+    if let Some(ext) = path.extension().and_then(|e| e.to_str()) {
+        hint.with_extension(ext);
+    }
+
+    let probed = symphonia::default::get_probe()
+        .format(&hint, stream, &FormatOptions::default(), &MetadataOptions::default())
+        .map_err(decode_error(path))?;
+    let format = probed.format;
+    let track = format.default_track().ok_or_else(|| AppError::IoError {
+        message: format!("No audio track in {}", path.display()),
+    })?;
+    let track_id = track.id;
+    let sample_rate = track.codec_params.sample_rate.unwrap_or(44_100);
+    let n_frames = track.codec_params.n_frames;
+    let decoder = symphonia::default::get_codecs()
+        .make(&track.codec_params, &DecoderOptions::default())
+        .map_err(decode_error(path))?;
+    Ok(AudioSource { format, decoder, track_id, sample_rate, n_frames })
+}
+
+/// Check that `path` is an audio file we can decode, without decoding it.
+pub fn probe_audio(path: &Path) -> Result<(), AppError> {
+    open_source(path).map(|_| ())
+}
+
+/// Decode `path` to mono f32 samples, returning them with the sample rate.
+fn decode_mono(path: &Path) -> Result<(Vec<f32>, u32), AppError> {
+    let decode_err = decode_error(path);
+    let AudioSource { mut format, mut decoder, track_id, sample_rate, .. } = open_source(path)?;
+
+    let mut mono = Vec::new();
+    let mut buf: Option<SampleBuffer<f32>> = None;
+    loop {
+        let packet = match format.next_packet() {
+            Ok(packet) => packet,
+            Err(SymphoniaError::IoError(e)) if e.kind() == std::io::ErrorKind::UnexpectedEof => {
+                break;
+            }
+            Err(e) => return Err(decode_err(e)),
+        };
+        if packet.track_id() != track_id {
+            continue;
+        }
+        let decoded = match decoder.decode(&packet) {
+            Ok(decoded) => decoded,
+            // Corrupt frames are skipped rather than failing the whole file.
+            Err(SymphoniaError::DecodeError(_)) => continue,
+            Err(e) => return Err(decode_err(e)),
+        };
+
+        let spec = *decoded.spec();
+        let channels = spec.channels.count().max(1);
+        let buf = match &mut buf {
+            Some(b) if b.capacity() >= decoded.capacity() * channels => b,
+            slot => slot.insert(SampleBuffer::new(decoded.capacity() as u64, spec)),
+        };
+        buf.copy_interleaved_ref(decoded);
+
+        #[allow(clippy::cast_precision_loss)]
+        let scale = 1.0 / channels as f32;
+        mono.extend(
+            buf.samples()
+                .chunks(channels)
+                .map(|frame| frame.iter().sum::<f32>() * scale),
+        );
+    }
+
+    Ok((mono, sample_rate))
+}
+
+/// Length of `path` in seconds. Read from the header when the container
+/// records a frame count; otherwise the whole file is decoded.
+#[allow(clippy::cast_precision_loss)]
+pub fn audio_duration(path: &Path) -> Result<f64, AppError> {
+    let source = open_source(path)?;
+    if let Some(frames) = source.n_frames {
+        return Ok(frames as f64 / f64::from(source.sample_rate.max(1)));
+    }
+    let (samples, sample_rate) = decode_mono(path)?;
+    Ok(samples.len() as f64 / f64::from(sample_rate.max(1)))
+}
+
+/// Decode `path` and reduce it to `buckets` min/max peaks.
+pub fn compute_waveform(path: &Path, buckets: usize) -> Result<Waveform, AppError> {
+    if buckets == 0 || buckets > MAX_WAVEFORM_BUCKETS {
+        return Err(AppError::ValidationError {
+            message: format!("Bucket count must be between 1 and {MAX_WAVEFORM_BUCKETS}"),
+        });
+    }
+    let (samples, sample_rate) = decode_mono(path)?;
+    #[allow(clippy::cast_precision_loss)]
+    let duration = samples.len() as f64 / f64::from(sample_rate.max(1));
+    Ok(Waveform {
+        duration,
+        peaks: peaks_from_samples(&samples, buckets),
+    })
+}
+
+/// Minimal 16-bit mono PCM WAV writer, for tests that need real audio.
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+pub(crate) fn write_wav(path: &Path, sample_rate: u32, samples: &[i16]) {
+    let data_len = u32::try_from(samples.len() * 2).unwrap();
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(b"RIFF");
+    bytes.extend_from_slice(&(36 + data_len).to_le_bytes());
+    bytes.extend_from_slice(b"WAVEfmt ");
+    bytes.extend_from_slice(&16u32.to_le_bytes());
+    bytes.extend_from_slice(&1u16.to_le_bytes()); // PCM
+    bytes.extend_from_slice(&1u16.to_le_bytes()); // mono
+    bytes.extend_from_slice(&sample_rate.to_le_bytes());
+    bytes.extend_from_slice(&(sample_rate * 2).to_le_bytes());
+    bytes.extend_from_slice(&2u16.to_le_bytes());
+    bytes.extend_from_slice(&16u16.to_le_bytes());
+    bytes.extend_from_slice(b"data");
+    bytes.extend_from_slice(&data_len.to_le_bytes());
+    for s in samples {
+        bytes.extend_from_slice(&s.to_le_bytes());
+    }
+    std::fs::write(path, bytes).unwrap();
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::indexing_slicing)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+    fn sine_wav_gives_symmetric_peaks() {
+        let dir = std::env::temp_dir().join("vibelights_test_waveform");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("sine.wav");
+
+        // One second of a 100 Hz sine at half amplitude.
+        let rate = 8000;
+        let samples: Vec<i16> = (0..rate)
+            .map(|i| {
+                let t = f64::from(i) / f64::from(rate);
+                ((t * 100.0 * std::f64::consts::TAU).sin() * 16384.0) as i16
+            })
+            .collect();
+        write_wav(&path, rate, &samples);
+
+        let waveform = compute_waveform(&path, 20).unwrap();
+        assert_eq!(waveform.peaks.len(), 20);
+        assert!((waveform.duration - 1.0).abs() < 1e-3);
+        for peak in &waveform.peaks {
+            assert!((peak.max - 0.5).abs() < 0.01, "{peak:?}");
+            assert!((peak.min + 0.5).abs() < 0.01, "{peak:?}");
+        }
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn more_buckets_than_samples_reuses_samples() {
+        let peaks = peaks_from_samples(&[0.5, -0.25], 4);
+        assert_eq!(peaks.len(), 4);
+        assert_eq!(peaks[0], WaveformPeak { min: 0.5, max: 0.5 });
+        assert_eq!(peaks[3], WaveformPeak { min: -0.25, max: -0.25 });
+
+        let silent = peaks_from_samples(&[], 3);
+        assert!(silent.iter().all(|p| *p == WaveformPeak { min: 0.0, max: 0.0 }));
+    }
+
+    #[test]
+    fn zero_buckets_rejected() {
+        let err = compute_waveform(Path::new("missing.wav"), 0);
+        assert!(matches!(err, Err(AppError::ValidationError { .. })));
+    }
+}
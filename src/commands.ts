@@ -16,6 +16,7 @@ import type {
   UnitCommand,
 } from "./commandMap";
 import type {
+  Color,
   ColorGradient,
   Controller,
   Curve,
@@ -23,12 +24,15 @@ import type {
   FixtureDef,
   FixtureGroup,
   Layout,
+  NameCollision,
   Patch,
   ParamKey,
+  ParamPredicate,
   ParamValue,
   BlendMode,
   AnalysisFeatures,
   EffectParams,
+  TranscriptFormat,
   VixenImportConfig,
 } from "./types";
 
@@ -62,6 +66,8 @@ export const cmd = {
   play: () => exec("Play"),
   pause: () => exec("Pause"),
   seek: (time: number) => exec("Seek", { time }),
+  goToCue: (cueIndex: number, loopRegion = false) =>
+    exec("GoToCue", { cue_index: cueIndex, loop_region: loopRegion }),
   undo: () => exec("Undo"),
   redo: () => exec("Redo"),
   getPlayback: () => execData("GetPlayback"),
@@ -85,6 +91,10 @@ export const cmd = {
       track_index: trackIndex,
       effect_index: effectIndex,
     }),
+  findEffects: (kind: EffectKind | null, predicates: ParamPredicate[] = []) =>
+    execData("FindEffects", { kind, predicates }),
+  analyzeShowComplexity: (maxEffects = 500, maxOverlap = 8) =>
+    execData("AnalyzeShowComplexity", { max_effects: maxEffects, max_overlap: maxOverlap }),
 
   // ── Edit ────────────────────────────────────────────────
   addEffect: (
@@ -135,6 +145,8 @@ export const cmd = {
     execData("AddTrack", { name, fixture_id: fixtureId }),
   deleteTrack: (trackIndex: number) =>
     exec("DeleteTrack", { track_index: trackIndex }),
+  setTrackBlend: (trackIndex: number, blendMode: BlendMode, opacity: number) =>
+    exec("SetTrackBlend", { track_index: trackIndex, blend_mode: blendMode, opacity }),
   moveEffectToTrack: (
     fromTrack: number,
     effectIndex: number,
@@ -188,6 +200,9 @@ export const cmd = {
     exec("UpdateSetupOutputs", { controllers, patches }),
   updateSetupLayout: (layout: Layout) =>
     exec("UpdateSetupLayout", { layout }),
+  undoSetup: () => exec("UndoSetup"),
+  redoSetup: () => exec("RedoSetup"),
+  getSetupUndoState: () => execData("GetSetupUndoState"),
 
   // ── Sequence CRUD ───────────────────────────────────────
   listSequences: () => execData("ListSequences"),
@@ -199,8 +214,8 @@ export const cmd = {
 
   // ── Media ───────────────────────────────────────────────
   listMedia: () => execData("ListMedia"),
-  importMedia: (sourcePath: string) =>
-    execData("ImportMedia", { source_path: sourcePath }),
+  importMedia: (sourcePath: string, setDuration = false) =>
+    execData("ImportMedia", { source_path: sourcePath, set_duration: setDuration }),
   deleteMedia: (name: string) => exec("DeleteMedia", { name }),
   resolveMediaPath: (name: string) =>
     execData("ResolveMediaPath", { name }),
@@ -211,6 +226,8 @@ export const cmd = {
   newAgentConversation: () => execData("NewAgentConversation"),
   switchAgentConversation: (id: string) => exec("SwitchAgentConversation", { conversation_id: id }),
   deleteAgentConversation: (id: string) => exec("DeleteAgentConversation", { conversation_id: id }),
+  exportConversation: (id: string, path: string, format: TranscriptFormat) =>
+    exec("ExportConversation", { conversation_id: id, path, format }),
   sendAgentMessage: (message: string, context?: string) =>
     exec("SendAgentMessage", { message, context: context ?? null }),
   cancelAgentMessage: () => exec("CancelAgentMessage"),
@@ -224,6 +241,13 @@ export const cmd = {
     exec("DeleteGlobalGradient", { name }),
   renameGlobalGradient: (oldName: string, newName: string) =>
     exec("RenameGlobalGradient", { old_name: oldName, new_name: newName }),
+  quantizeGradientToPalette: (
+    gradient: ColorGradient,
+    palette: Color[],
+    steps = 16,
+    saveAs: string | null = null,
+  ) =>
+    execData("QuantizeGradientToPalette", { gradient, palette, steps, save_as: saveAs }),
   listGlobalCurves: () => execData("ListGlobalCurves"),
   setGlobalCurve: (name: string, curve: Curve) =>
     exec("SetGlobalCurve", { name, curve }),
@@ -231,7 +255,12 @@ export const cmd = {
     exec("DeleteGlobalCurve", { name }),
   renameGlobalCurve: (oldName: string, newName: string) =>
     exec("RenameGlobalCurve", { old_name: oldName, new_name: newName }),
+  findLibraryUsage: (name: string) => execData("FindLibraryUsage", { name }),
+  exportLibraryBundle: (path: string) => exec("ExportLibraryBundle", { path }),
+  importLibraryBundle: (path: string, onCollision: NameCollision = "Skip") =>
+    execData("ImportLibraryBundle", { path, on_collision: onCollision }),
   listGlobalScripts: () => execData("ListGlobalScripts"),
+  compileAllGlobalScripts: () => execData("CompileAllGlobalScripts"),
   getGlobalScriptSource: (name: string) =>
     execData("GetGlobalScriptSource", { name }),
   deleteGlobalScript: (name: string) =>
@@ -250,7 +279,8 @@ export const cmd = {
     execData("GetScriptParams", { name }),
 
   // ── Hot-path (playback/preview) ─────────────────────────
-  tick: (dt: number) => execData("Tick", { dt }),
+  tick: (dt: number, since: number | null = null, client: string | null = null) =>
+    execData("Tick", { dt, since, client }),
   getFrame: (time: number) => execData("GetFrame", { time }),
   getFrameFiltered: (time: number, effects: [number, number][]) =>
     execData("GetFrameFiltered", { time, effects }),
@@ -309,6 +339,11 @@ export const cmd = {
       setup_slug: setupSlug,
       tim_path: timPath,
     }),
+  importVixenSequences: (setupSlug: string, timPaths: string[]) =>
+    execData("ImportVixenSequences", {
+      setup_slug: setupSlug,
+      tim_paths: timPaths,
+    }),
   scanVixenDirectory: (vixenDir: string) =>
     execData("ScanVixenDirectory", { vixen_dir: vixenDir }),
   checkVixenPreviewFile: (filePath: string) =>
@@ -320,6 +320,12 @@ export function ChatPanel({ open, onClose, onRefresh, screen }: ChatPanelProps)
                   <span className="truncate opacity-60">— {msg.result.slice(0, 80)}</span>
                 )}
               </div>
+            ) : msg.role === "tool" ? (
+              <div className="text-text-2 flex items-center gap-1.5 text-[11px] py-0.5">
+                <Check size={10} className="shrink-0" />
+                <span className="font-mono">{msg.tool ?? "tool"}</span>
+                {msg.text && <span className="truncate opacity-60">— {msg.text.slice(0, 80)}</span>}
+              </div>
             ) : (
               <div className="bg-surface-2 text-text max-w-[90%] rounded-lg px-3 py-2 prose-chat">
                 <Markdown>{msg.text}</Markdown>
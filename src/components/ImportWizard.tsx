@@ -34,6 +34,7 @@ export function ImportWizard({ onComplete, onCancel }: Props) {
   const [setupName, setProfileName] = useState("Vixen Import");
   const [importControllers, setImportControllers] = useState(true);
   const [importLayout, setImportLayout] = useState(true);
+  const [groupByPosition, setGroupByPosition] = useState(false);
   const [previewFileOverride, setPreviewFileOverride] = useState<string | null>(
     null,
   );
@@ -111,6 +112,9 @@ export function ImportWizard({ onComplete, onCancel }: Props) {
         preview_file_override: previewFileOverride,
         sequence_paths: Array.from(selectedSequences),
         media_filenames: Array.from(selectedMedia),
+        curve_epsilon: null,
+        max_effects: null,
+        group_by_position: importLayout && groupByPosition,
       };
       const res = await cmd.executeVixenImport(config);
       setResult(res);
@@ -124,6 +128,7 @@ export function ImportWizard({ onComplete, onCancel }: Props) {
     setupName,
     importControllers,
     importLayout,
+    groupByPosition,
     previewFileOverride,
     selectedSequences,
     selectedMedia,
@@ -222,6 +227,8 @@ export function ImportWizard({ onComplete, onCancel }: Props) {
               onImportControllersChange={setImportControllers}
               importLayout={importLayout}
               onImportLayoutChange={setImportLayout}
+              groupByPosition={groupByPosition}
+              onGroupByPositionChange={setGroupByPosition}
               onBrowsePreview={() => { void handleBrowsePreview(); }}
               selectedSequences={selectedSequences}
               onToggleSequence={(path) => {
@@ -414,6 +421,8 @@ function StepReview({
   onImportControllersChange,
   importLayout,
   onImportLayoutChange,
+  groupByPosition,
+  onGroupByPositionChange,
   onBrowsePreview,
   selectedSequences,
   onToggleSequence,
@@ -429,6 +438,8 @@ function StepReview({
   onImportControllersChange: (v: boolean) => void;
   importLayout: boolean;
   onImportLayoutChange: (v: boolean) => void;
+  groupByPosition: boolean;
+  onGroupByPositionChange: (v: boolean) => void;
   onBrowsePreview: () => void;
   selectedSequences: Set<string>;
   onToggleSequence: (path: string) => void;
@@ -466,11 +477,19 @@ function StepReview({
 
       {/* Preview Layout */}
       {discovery.preview_available ? (
-        <Section
-          label={`Preview Layout: ${discovery.preview_item_count} display items`}
-          checked={importLayout}
-          onChange={onImportLayoutChange}
-        />
+        <>
+          <Section
+            label={`Preview Layout: ${discovery.preview_item_count} display items`}
+            checked={importLayout}
+            onChange={onImportLayoutChange}
+          />
+          <Section
+            label="Also group fixtures that sit close together in the layout"
+            checked={importLayout && groupByPosition}
+            onChange={onGroupByPositionChange}
+            disabled={!importLayout}
+          />
+        </>
       ) : (
         <div className="border-border rounded border px-3 py-2">
           <div className="flex items-center justify-between">
@@ -245,6 +245,7 @@ export function LibraryPanel({ onLibraryChange, showVersion }: Props) {
         { x: 0, y: 0 },
         { x: 1, y: 1 },
       ],
+      interp: "Linear",
     };
     try {
       await cmd.setGlobalCurve(name, curve);
@@ -280,13 +281,14 @@ export function LibraryPanel({ onLibraryChange, showVersion }: Props) {
 
   const updateCurve = useCallback(async (name: string, points: CurvePoint[]) => {
     try {
-      await cmd.setGlobalCurve(name, { points });
+      const interp = curves.find(([n]) => n === name)?.[1].interp ?? "Linear";
+      await cmd.setGlobalCurve(name, { points, interp });
       onLibraryChange();
       await refresh();
     } catch (e) {
       showError(e);
     }
-  }, [onLibraryChange, refresh]);
+  }, [curves, onLibraryChange, refresh]);
 
   // ── Script actions ───────────────────────────────────────────
 
@@ -1,7 +1,7 @@
 import { useCallback, useEffect, useRef, useState } from "react";
 import { cmd } from "../commands";
 import { Link, Maximize2 } from "lucide-react";
-import type { BlendMode, Color, ColorMode, ColorStop, CurvePoint, EffectDetail, ParamKey, ParamSchema, ParamValue } from "../types";
+import type { BlendMode, Color, ColorMode, ColorStop, CurveInterp, CurvePoint, EffectDetail, ParamKey, ParamSchema, ParamValue } from "../types";
 import { useDebouncedCallback } from "../hooks/useDebounce";
 import { CurveEditorDialog } from "./CurveEditorDialog";
 import { GradientEditorDialog } from "./GradientEditorDialog";
@@ -46,6 +46,16 @@ function getParamCurve(
   return fallback;
 }
 
+/** Interpolation of an inline curve param, so point edits keep it. */
+function getParamCurveInterp(
+  params: Record<string, ParamValue>,
+  key: string,
+): CurveInterp {
+  const v = params[key];
+  if (v && "Curve" in v) return v.Curve.interp ?? "Linear";
+  return "Linear";
+}
+
 function getDefaultCurve(schema: ParamSchema): CurvePoint[] {
   if ("Curve" in schema.default) return schema.default.Curve.points;
   return [
@@ -220,7 +230,9 @@ export function PropertyPanel({
         <CurveEditorDialog
           initialValue={expandedEditor.curveValue}
           onApply={(v) => {
-            updateParam(expandedEditor.key, { Curve: { points: v } });
+            updateParam(expandedEditor.key, {
+              Curve: { points: v, interp: getParamCurveInterp(detail.params, expandedEditor.key) },
+            });
             setExpandedEditor(null);
           }}
           onCancel={() => setExpandedEditor(null)}
@@ -344,7 +356,7 @@ function ParamControl({ schema, params, onChange, gradientNames, curveNames, onE
           onUnlink={() => {
             // Unlink: replace ref with a default inline curve
             const fallback = getDefaultCurve(schema);
-            onChange({ Curve: { points: fallback } });
+            onChange({ Curve: { points: fallback, interp: "Linear" } });
           }}
         />
       );
@@ -425,7 +437,7 @@ function ParamControl({ schema, params, onChange, gradientNames, curveNames, onE
         <CurveEditor
           label=""
           value={value}
-          onChange={(v) => onChange({ Curve: { points: v } })}
+          onChange={(v) => onChange({ Curve: { points: v, interp: getParamCurveInterp(params, keyStr) } })}
         />
       </div>
     );
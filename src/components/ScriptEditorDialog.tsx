@@ -153,7 +153,7 @@ export function ScriptEditorDialog({
             <div className="bg-red-500/10 max-h-24 overflow-y-auto rounded p-2">
               {compileResult.errors.map((err, i) => (
                 <div key={i} className="text-[11px] text-red-400">
-                  <span className="text-red-300 font-mono">@{err.offset}</span>{" "}
+                  <span className="text-red-300 font-mono">{err.line}:{err.col}</span>{" "}
                   {err.message}
                 </div>
               ))}
@@ -1,5 +1,5 @@
 import { useCallback } from "react";
-import type { ParamValue, ScriptParams, ScriptParamInfo } from "../../types";
+import type { Curve, ParamValue, ScriptParams, ScriptParamInfo } from "../../types";
 import {
   FloatSlider,
   IntSlider,
@@ -138,18 +138,21 @@ function ParamControl({
   }
 
   if (pt === "Curve") {
-    const v =
+    const curve: Curve =
       value && "Curve" in value
-        ? value.Curve.points
-        : [
-            { x: 0, y: 0 },
-            { x: 1, y: 1 },
-          ];
+        ? value.Curve
+        : {
+            points: [
+              { x: 0, y: 0 },
+              { x: 1, y: 1 },
+            ],
+            interp: "Linear",
+          };
     return (
       <CurveEditor
         label={info.name}
-        value={v}
-        onChange={(points) => onChange({ Curve: { points } })}
+        value={curve.points}
+        onChange={(points) => onChange({ Curve: { ...curve, points } })}
       />
     );
   }
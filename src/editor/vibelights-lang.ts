@@ -9,7 +9,7 @@ import {
 // ── DSL Keywords & Builtins ────────────────────────────────────────
 
 const KEYWORDS = new Set([
-  "let", "fn", "if", "else", "param", "enum", "flags", "return",
+  "let", "fn", "if", "else", "param", "enum", "flags", "use", "return",
   "switch", "case", "default",
 ]);
 
@@ -20,7 +20,9 @@ const TYPES = new Set([
 const BUILTINS = new Set([
   "sin", "cos", "tan", "abs", "floor", "ceil", "round", "fract", "sqrt",
   "pow", "min", "max", "clamp", "mix", "smoothstep", "step", "atan2",
+  "saw", "tri", "square",
   "rgb", "hsv", "rgba", "hash", "distance", "length", "vec2",
+  "vec3", "dot", "color_to_vec3", "vec3_to_color",
 ]);
 
 const IMPLICIT_VARS = new Set([
@@ -89,12 +91,12 @@ function vibelightsCompletion(context: CompletionContext): CompletionResult | nu
 
   const options = [
     // Keywords
-    ...["let", "fn", "if", "else", "param", "enum", "flags", "return"].map((label) => ({
+    ...["let", "fn", "if", "else", "param", "enum", "flags", "use", "return"].map((label) => ({
       label,
       type: "keyword" as const,
     })),
     // Types
-    ...["float", "int", "bool", "color", "vec2", "gradient", "curve"].map((label) => ({
+    ...["float", "int", "bool", "color", "vec2", "vec3", "gradient", "curve"].map((label) => ({
       label,
       type: "type" as const,
       detail: "type",
@@ -1,6 +1,6 @@
 import { useEffect, useRef } from "react";
 import type { Frame, Show } from "../types";
-import { BULB_SHAPE_RADIUS } from "../types";
+import { BULB_SHAPE_RADIUS, SQUARE_BULB_SHAPES } from "../types";
 import type { PreviewSettings } from "./usePreviewSettings";
 import type { CameraState } from "./usePreviewCamera";
 
@@ -18,6 +18,10 @@ interface Geometry {
   ys: Float32Array;
   /** Per-pixel display radius. */
   radii: Float32Array;
+  /** Per-pixel flag: 1 when the bulb is drawn as a square. */
+  squares: Uint8Array;
+  /** Per-pixel preview brightness multiplier (display only, never output). */
+  gains: Float32Array;
   /** Map from fixture_id to [startIndex, count] in the flat arrays. */
   fixtureSlices: Map<number, [number, number]>;
   /** Total pixel count. */
@@ -34,7 +38,13 @@ function computeGeometry(
 ): Geometry {
   // Count total pixels and build fixture order
   let totalPixels = 0;
-  const fixtureOrder: { fixtureId: number; positions: { x: number; y: number }[]; radiusMul: number }[] = [];
+  const fixtureOrder: {
+    fixtureId: number;
+    positions: { x: number; y: number }[];
+    radiusMul: number;
+    square: boolean;
+    gain: number;
+  }[] = [];
 
   for (const fl of show.layout.fixtures) {
     const def = show.fixtures.find((f) => f.id === fl.fixture_id);
@@ -44,6 +54,8 @@ function computeGeometry(
       fixtureId: fl.fixture_id,
       positions: fl.pixel_positions,
       radiusMul,
+      square: SQUARE_BULB_SHAPES.has(bulbShape),
+      gain: Math.max(0, fl.preview_gain ?? 1),
     });
     totalPixels += fl.pixel_positions.length;
   }
@@ -85,6 +97,8 @@ function computeGeometry(
   const xs = new Float32Array(totalPixels);
   const ys = new Float32Array(totalPixels);
   const radii = new Float32Array(totalPixels);
+  const squares = new Uint8Array(totalPixels);
+  const gains = new Float32Array(totalPixels);
   const fixtureSlices = new Map<number, [number, number]>();
 
   let idx = 0;
@@ -95,12 +109,14 @@ function computeGeometry(
       xs[idx] = offsetX + (p.x - minX) * scale;
       ys[idx] = offsetY + (p.y - minY) * scale;
       radii[idx] = r;
+      squares[idx] = fo.square ? 1 : 0;
+      gains[idx] = fo.gain;
       idx++;
     }
     fixtureSlices.set(fo.fixtureId, [start, fo.positions.length]);
   }
 
-  return { xs, ys, radii, fixtureSlices, totalPixels, width, height };
+  return { xs, ys, radii, squares, gains, fixtureSlices, totalPixels, width, height };
 }
 
 // ── Frame Rendering ─────────────────────────────────────────────────
@@ -157,7 +173,13 @@ function renderFrame(
   const colorGroups = new Map<number, number[]>();
   for (let i = 0; i < geo.totalPixels; i++) {
     const off = i * 4;
-    const r = colorBuf[off]!, g = colorBuf[off + 1]!, b = colorBuf[off + 2]!;
+    const gain = geo.gains[i]!;
+    let r = colorBuf[off]!, g = colorBuf[off + 1]!, b = colorBuf[off + 2]!;
+    if (gain !== 1) {
+      r = Math.min(255, Math.round(r * gain));
+      g = Math.min(255, Math.round(g * gain));
+      b = Math.min(255, Math.round(b * gain));
+    }
     if (r === 0 && g === 0 && b === 0) continue;
     const key = (r << 16) | (g << 8) | b;
     let group = colorGroups.get(key);
@@ -243,8 +265,12 @@ function renderFrame(
     ctx.beginPath();
     for (const i of indices) {
       const r = geo.radii[i]! * bulbSize;
-      ctx.moveTo(geo.xs[i]! + r, geo.ys[i]!);
-      ctx.arc(geo.xs[i]!, geo.ys[i]!, r, 0, Math.PI * 2);
+      if (geo.squares[i]) {
+        ctx.rect(geo.xs[i]! - r, geo.ys[i]! - r, r * 2, r * 2);
+      } else {
+        ctx.moveTo(geo.xs[i]! + r, geo.ys[i]!);
+        ctx.arc(geo.xs[i]!, geo.ys[i]!, r, 0, Math.PI * 2);
+      }
     }
     ctx.fillStyle = `rgb(${cr},${cg},${cb})`;
     ctx.fill();
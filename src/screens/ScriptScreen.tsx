@@ -290,6 +290,17 @@ export function ScriptScreen({
               </div>
             )}
 
+            {/* Warnings */}
+            {compileResult?.success && compileResult.warnings.length > 0 && (
+              <div className="bg-yellow-500/10 rounded p-2">
+                {compileResult.warnings.map((warning, i) => (
+                  <div key={i} className="text-yellow-400 text-[10px]">
+                    {warning.message}
+                  </div>
+                ))}
+              </div>
+            )}
+
             {/* Preview */}
             <ScriptPreview
               heatmap={preview.heatmap}
@@ -29,6 +29,7 @@ export function CurvesTab({ setError }: Props) {
         { x: 0, y: 0 },
         { x: 1, y: 1 },
       ],
+      interp: "Linear",
     };
     cmd.setGlobalCurve(name, defaultCurve)
       .then(refresh)
@@ -75,7 +76,7 @@ export function CurvesTab({ setError }: Props) {
         <CurveEditor
           label="Edit Curve"
           value={c.points}
-          onChange={(points: CurvePoint[]) => handleUpdate(name, { points })}
+          onChange={(points: CurvePoint[]) => handleUpdate(name, { ...c, points })}
         />
       )}
     />
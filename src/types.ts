@@ -4,18 +4,23 @@
 
 // ── Re-exports from generated Rust bindings ─────────────────────────
 
+export type { AlignMode } from "../src-tauri/bindings/AlignMode";
 export type { AppError } from "../src-tauri/bindings/AppError";
 export type { AppSettings } from "../src-tauri/bindings/AppSettings";
+export type { AudioTrackRef } from "../src-tauri/bindings/AudioTrackRef";
 export type { BlendMode } from "../src-tauri/bindings/BlendMode";
 export type { BulbShape } from "../src-tauri/bindings/BulbShape";
 export type { ChannelOrder } from "../src-tauri/bindings/ChannelOrder";
 export type { Color } from "../src-tauri/bindings/Color";
+export type { ColorCalibration } from "../src-tauri/bindings/ColorCalibration";
 export type { ColorGradient } from "../src-tauri/bindings/ColorGradient";
 export type { ColorMode } from "../src-tauri/bindings/ColorMode";
 export type { ColorModel } from "../src-tauri/bindings/ColorModel";
 export type { ColorStop } from "../src-tauri/bindings/ColorStop";
+export type { Cue } from "../src-tauri/bindings/Cue";
 export type { Curve } from "../src-tauri/bindings/Curve";
 export type { CurvePoint } from "../src-tauri/bindings/CurvePoint";
+export type { CurveInterp } from "../src-tauri/bindings/CurveInterp";
 export type { Controller } from "../src-tauri/bindings/Controller";
 export type { ControllerId } from "../src-tauri/bindings/ControllerId";
 export type { ControllerProtocol } from "../src-tauri/bindings/ControllerProtocol";
@@ -37,11 +42,15 @@ export type { GroupId } from "../src-tauri/bindings/GroupId";
 export type { GroupMember } from "../src-tauri/bindings/GroupMember";
 export type { Layout } from "../src-tauri/bindings/Layout";
 export type { LayoutShape } from "../src-tauri/bindings/LayoutShape";
+export type { LibraryImportSummary } from "../src-tauri/bindings/LibraryImportSummary";
 export type { LlmConfigInfo } from "../src-tauri/bindings/LlmConfigInfo";
 export type { LlmProviderConfig } from "../src-tauri/bindings/LlmProviderConfig";
 export type { MediaFile } from "../src-tauri/bindings/MediaFile";
+export type { NameCollision } from "../src-tauri/bindings/NameCollision";
 export type { OutputMapping } from "../src-tauri/bindings/OutputMapping";
+export type { ParamComparator } from "../src-tauri/bindings/ParamComparator";
 export type { ParamKey } from "../src-tauri/bindings/ParamKey";
+export type { ParamPredicate } from "../src-tauri/bindings/ParamPredicate";
 export type { ParamSchema } from "../src-tauri/bindings/ParamSchema";
 export type { ParamType } from "../src-tauri/bindings/ParamType";
 // ParamValue re-exported above alongside EffectParams
@@ -62,11 +71,13 @@ export type { Show } from "../src-tauri/bindings/Show";
 export type { TickResult } from "../src-tauri/bindings/TickResult";
 export type { TimeRange } from "../src-tauri/bindings/TimeRange";
 export type { Track } from "../src-tauri/bindings/Track";
+export type { TrackComplexity } from "../src-tauri/bindings/TrackComplexity";
 export type { UndoState } from "../src-tauri/bindings/UndoState";
 export type { Universe } from "../src-tauri/bindings/Universe";
 export type { VixenDiscovery } from "../src-tauri/bindings/VixenDiscovery";
 export type { VixenImportConfig } from "../src-tauri/bindings/VixenImportConfig";
 export type { VixenImportResult } from "../src-tauri/bindings/VixenImportResult";
+export type { VixenSequenceImport } from "../src-tauri/bindings/VixenSequenceImport";
 export type { VixenMediaInfo } from "../src-tauri/bindings/VixenMediaInfo";
 export type { VixenSequenceInfo } from "../src-tauri/bindings/VixenSequenceInfo";
 export type { EasingFunction } from "../src-tauri/bindings/EasingFunction";
@@ -93,6 +104,7 @@ export type { ChatRole } from "../src-tauri/bindings/ChatRole";
 export type { ConversationSummary } from "../src-tauri/bindings/ConversationSummary";
 export type { NewConversationResult } from "../src-tauri/bindings/NewConversationResult";
 export type { SectionSummary } from "../src-tauri/bindings/SectionSummary";
+export type { TranscriptFormat } from "../src-tauri/bindings/TranscriptFormat";
 
 // ── Command param types ─────────────────────────────────────────────
 
@@ -108,6 +120,7 @@ export type { CreateSequenceParams } from "../src-tauri/bindings/CreateSequenceP
 export type { CreateSetupParams } from "../src-tauri/bindings/CreateSetupParams";
 export type { DeleteEffectsParams } from "../src-tauri/bindings/DeleteEffectsParams";
 export type { DeleteTrackParams } from "../src-tauri/bindings/DeleteTrackParams";
+export type { ExportConversationParams } from "../src-tauri/bindings/ExportConversationParams";
 export type { GetAnalysisDetailParams } from "../src-tauri/bindings/GetAnalysisDetailParams";
 export type { GetBeatsInRangeParams } from "../src-tauri/bindings/GetBeatsInRangeParams";
 export type { GetEffectDetailParams } from "../src-tauri/bindings/GetEffectDetailParams";
@@ -116,6 +129,7 @@ export type { GetFrameParams } from "../src-tauri/bindings/GetFrameParams";
 export type { ImportMediaParams } from "../src-tauri/bindings/ImportMediaParams";
 export type { ImportVixenParams } from "../src-tauri/bindings/ImportVixenParams";
 export type { ImportVixenSequenceParams } from "../src-tauri/bindings/ImportVixenSequenceParams";
+export type { ImportVixenSequencesParams } from "../src-tauri/bindings/ImportVixenSequencesParams";
 export type { ImportVixenSetupParams } from "../src-tauri/bindings/ImportVixenSetupParams";
 export type { InitializeDataDirParams } from "../src-tauri/bindings/InitializeDataDirParams";
 export type { MoveEffectToTrackParams } from "../src-tauri/bindings/MoveEffectToTrackParams";
@@ -149,6 +163,7 @@ export type { AudioAnalysis } from "../src-tauri/bindings/AudioAnalysis";
 export type { BeatAnalysis } from "../src-tauri/bindings/BeatAnalysis";
 export type { ChordEvent } from "../src-tauri/bindings/ChordEvent";
 export type { DrumAnalysis } from "../src-tauri/bindings/DrumAnalysis";
+export type { GridDivision } from "../src-tauri/bindings/GridDivision";
 export type { HarmonyAnalysis } from "../src-tauri/bindings/HarmonyAnalysis";
 export type { LowLevelFeatures } from "../src-tauri/bindings/LowLevelFeatures";
 export type { LyricWord } from "../src-tauri/bindings/LyricWord";
@@ -184,6 +199,9 @@ export const BULB_SHAPE_RADIUS: Record<BulbShape, number> = {
   Flood: 3.0,
 };
 
+/** Bulb shapes drawn as squares in the preview; all others are round. */
+export const SQUARE_BULB_SHAPES: ReadonlySet<BulbShape> = new Set<BulbShape>(["Flood"]);
+
 export type InteractionMode = "select" | "edit" | "swipe";
 
 /** Simple string-keyed param map for script params (all Custom keys). */
@@ -25,6 +25,22 @@ describe("formatTauriError", () => {
     expect(formatTauriError(err)).toBe("Required model not installed: whisper-large");
   });
 
+  it("extracts AppError with detail.reason (InvalidTimeRange)", () => {
+    const err = {
+      code: "InvalidTimeRange",
+      detail: { start: 4, end: 3, reason: "start must be before end" },
+    };
+    expect(formatTauriError(err)).toBe("Invalid time range: start must be before end");
+  });
+
+  it("extracts AppError with detail.key and detail.reason (InvalidParam)", () => {
+    const err = {
+      code: "InvalidParam",
+      detail: { key: "Speed", reason: "40 is outside 0.1..=20" },
+    };
+    expect(formatTauriError(err)).toBe("Invalid param Speed: 40 is outside 0.1..=20");
+  });
+
   it("formats bare code errors (no detail)", () => {
     const err = { code: "PythonNotReady" };
     expect(formatTauriError(err)).toBe("Python Not Ready");
@@ -26,6 +26,10 @@ export function formatTauriError(e: unknown): string {
       if (typeof detail["model"] === "string")
         return `Required model not installed: ${detail["model"]}`;
       if (typeof detail["what"] === "string") return `${detail["what"]} not found`;
+      if (typeof detail["key"] === "string" && typeof detail["reason"] === "string")
+        return `Invalid param ${detail["key"]}: ${detail["reason"]}`;
+      if (typeof detail["reason"] === "string")
+        return `Invalid time range: ${detail["reason"]}`;
     }
 
     // Bare code with no detail (e.g., "PythonNotReady", "NoSetup")